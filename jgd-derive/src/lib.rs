@@ -0,0 +1,423 @@
+//! # JGD Derive
+//!
+//! `#[derive(JgdSchema)]` builds a JGD [`Entity`] from a Rust struct, so the schema
+//! used to generate test data stays in sync with the type it deserializes into.
+//!
+//! This crate is re-exported by `jgd-rs` behind the `derive` feature; depend on
+//! `jgd-rs` with `features = ["derive"]` instead of using it directly.
+//!
+//! ```rust,ignore
+//! use jgd_rs::JgdSchema;
+//!
+//! #[derive(JgdSchema)]
+//! #[jgd(count = 10)]
+//! struct User {
+//!     #[jgd(fake = "ulid")]
+//!     id: String,
+//!     #[jgd(fake = "internet.safeEmail")]
+//!     email: String,
+//!     #[jgd(min = 18, max = 90)]
+//!     age: u8,
+//!     #[jgd(prob = 0.3)]
+//!     nickname: Option<String>,
+//!     #[jgd(count = "1..3")]
+//!     addresses: Vec<Address>,
+//! }
+//! ```
+//!
+//! ## Attributes
+//!
+//! Struct level:
+//! - `count = N` or `count = "MIN..MAX"`: entity count (a single object when omitted)
+//! - `#[serde(rename_all = "...")]` is honored for the output field names
+//!
+//! Field level:
+//! - `fake = "key"`: generates the value with the `${key}` fake key
+//! - `template = "..."`: generates the value from a template string
+//! - `min = N`, `max = N`: number range for numeric fields
+//! - `prob = P`: probability of `Option<T>` fields being present (default 0.5)
+//! - `count = N` or `count = "MIN..MAX"`: item count of `Vec<T>` fields (default 1..5)
+//! - `rename = "name"`: output field name (`#[serde(rename = "name")]` is honored too)
+//! - `skip`: leaves the field out of the schema
+//!
+//! ## Type Mapping
+//!
+//! - `String`, `&str`: `${lorem.word}`
+//! - `char`: `${string.random(1, alpha)}`, a single letter
+//! - integers: `number` from 0 to 100, integer
+//! - `f32`, `f64`: `number` from 0 to 100
+//! - `bool`: `${boolean.boolean}`
+//! - `Option<T>`: `optional` of `T`
+//! - `Vec<T>`: `array` of `T`
+//! - any other type: the nested entity of `<T as JgdSchema>`
+//!
+//! `fake` and `template` apply to the innermost type, so `#[jgd(fake = "...")]` on an
+//! `Option<String>` still produces an `optional` field.
+//!
+//! [`Entity`]: https://docs.rs/jgd-rs/latest/jgd_rs/struct.Entity.html
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Attribute, Data, DeriveInput, Expr, ExprLit,
+    Fields, GenericArgument, Lit, LitStr, Meta, PathArguments, Token, Type,
+};
+
+/// Default range of numeric fields.
+const DEFAULT_NUMBER_RANGE: (f64, f64) = (0.0, 100.0);
+
+/// Default probability of `Option<T>` fields.
+const DEFAULT_PROB: f64 = 0.5;
+
+/// Default item count of `Vec<T>` fields.
+const DEFAULT_ARRAY_COUNT: (u64, u64) = (1, 5);
+
+/// Derives `jgd_rs::JgdSchema` for a struct with named fields.
+#[proc_macro_derive(JgdSchema, attributes(jgd))]
+pub fn derive_jgd_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+enum CountAttr {
+    Fixed(u64),
+    Range(u64, u64),
+}
+
+impl CountAttr {
+    fn parse(lit: &Lit) -> syn::Result<Self> {
+        match lit {
+            Lit::Int(value) => Ok(CountAttr::Fixed(value.base10_parse()?)),
+            Lit::Str(value) => {
+                let text = value.value();
+                let (min, max) = text.split_once("..")
+                    .ok_or_else(|| syn::Error::new(value.span(), "expected a count range like \"1..5\""))?;
+                let parse = |part: &str| part.trim().trim_start_matches('=').trim().parse::<u64>()
+                    .map_err(|_| syn::Error::new(value.span(), "expected a count range like \"1..5\""));
+                Ok(CountAttr::Range(parse(min)?, parse(max)?))
+            },
+            _ => Err(syn::Error::new(lit.span(), "expected an integer or a range string")),
+        }
+    }
+
+    fn tokens(&self) -> TokenStream2 {
+        match self {
+            CountAttr::Fixed(value) => quote!(::jgd_rs::Count::Fixed(#value)),
+            CountAttr::Range(min, max) => quote!(::jgd_rs::Count::Range((#min, #max))),
+        }
+    }
+}
+
+#[derive(Default)]
+struct JgdAttrs {
+    fake: Option<String>,
+    template: Option<String>,
+    min: Option<f64>,
+    max: Option<f64>,
+    prob: Option<f64>,
+    count: Option<CountAttr>,
+    rename: Option<String>,
+    skip: bool,
+}
+
+fn parse_number(lit: &Lit) -> syn::Result<f64> {
+    match lit {
+        Lit::Int(value) => value.base10_parse(),
+        Lit::Float(value) => value.base10_parse(),
+        _ => Err(syn::Error::new(lit.span(), "expected a number")),
+    }
+}
+
+fn parse_attrs(attrs: &[Attribute]) -> syn::Result<JgdAttrs> {
+    let mut result = JgdAttrs::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("jgd")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                result.skip = true;
+                return Ok(());
+            }
+
+            let lit: Lit = meta.value()?.parse()?;
+            let string = |lit: &Lit| match lit {
+                Lit::Str(value) => Ok(value.value()),
+                _ => Err(syn::Error::new(lit.span(), "expected a string")),
+            };
+
+            if meta.path.is_ident("fake") {
+                result.fake = Some(string(&lit)?);
+            } else if meta.path.is_ident("template") {
+                result.template = Some(string(&lit)?);
+            } else if meta.path.is_ident("rename") {
+                result.rename = Some(string(&lit)?);
+            } else if meta.path.is_ident("min") {
+                result.min = Some(parse_number(&lit)?);
+            } else if meta.path.is_ident("max") {
+                result.max = Some(parse_number(&lit)?);
+            } else if meta.path.is_ident("prob") {
+                result.prob = Some(parse_number(&lit)?);
+            } else if meta.path.is_ident("count") {
+                result.count = Some(CountAttr::parse(&lit)?);
+            } else {
+                return Err(meta.error("unknown jgd attribute"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if result.fake.is_some() && result.template.is_some() {
+        return Err(syn::Error::new(attrs[0].span(), "`fake` and `template` cannot be used together"));
+    }
+
+    Ok(result)
+}
+
+/// Returns the string set with `#[serde(<key> = "...")]`, if any.
+fn serde_string(attrs: &[Attribute], key: &str) -> syn::Result<Option<LitStr>> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            if let Meta::NameValue(name_value) = meta {
+                if name_value.path.is_ident(key) {
+                    if let Expr::Lit(ExprLit { lit: Lit::Str(value), .. }) = name_value.value {
+                        return Ok(Some(value));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Converts a snake_case field name with a `#[serde(rename_all = "...")]` rule.
+fn rename_all(name: &str, rule: &LitStr) -> syn::Result<String> {
+    let words = name.split('_').filter(|word| !word.is_empty());
+    let capitalize = |word: &str| {
+        let mut chars = word.chars();
+        chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+    };
+
+    Ok(match rule.value().as_str() {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "snake_case" => name.to_string(),
+        "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
+        "PascalCase" => words.map(capitalize).collect(),
+        "camelCase" => words.enumerate()
+            .map(|(index, word)| if index == 0 { word.to_string() } else { capitalize(word) })
+            .collect(),
+        _ => return Err(syn::Error::new(rule.span(), "unknown serde rename_all rule")),
+    })
+}
+
+/// Returns the single generic argument of `Option<T>` or `Vec<T>`.
+fn wrapped_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+        Type::Reference(reference) => type_name(&reference.elem),
+        _ => None,
+    }
+}
+
+fn number_field(attrs: &JgdAttrs, integer: bool) -> TokenStream2 {
+    let min = attrs.min.unwrap_or(DEFAULT_NUMBER_RANGE.0);
+    let max = attrs.max.unwrap_or(DEFAULT_NUMBER_RANGE.1);
+    quote!(::jgd_rs::derive_support::number(#min, #max, #integer))
+}
+
+fn field_tokens(ty: &Type, attrs: &JgdAttrs) -> TokenStream2 {
+    if let Some(inner) = wrapped_type(ty, "Option") {
+        let of = field_tokens(inner, attrs);
+        let prob = attrs.prob.unwrap_or(DEFAULT_PROB);
+        return quote!(::jgd_rs::derive_support::optional(#of, #prob));
+    }
+
+    if let Some(inner) = wrapped_type(ty, "Vec") {
+        let of = field_tokens(inner, attrs);
+        let count = attrs.count.as_ref()
+            .map(CountAttr::tokens)
+            .unwrap_or_else(|| CountAttr::Range(DEFAULT_ARRAY_COUNT.0, DEFAULT_ARRAY_COUNT.1).tokens());
+        return quote!(::jgd_rs::derive_support::array(#of, #count));
+    }
+
+    if let Some(fake) = &attrs.fake {
+        let template = LitStr::new(&format!("${{{}}}", fake), ty.span());
+        return quote!(::jgd_rs::derive_support::template(#template));
+    }
+
+    if let Some(template) = &attrs.template {
+        return quote!(::jgd_rs::derive_support::template(#template));
+    }
+
+    match type_name(ty).as_deref() {
+        Some("String" | "str") => quote!(::jgd_rs::derive_support::template("${lorem.word}")),
+        Some("char") => quote!(::jgd_rs::derive_support::template("${string.random(1, alpha)}")),
+        Some("bool") => quote!(::jgd_rs::derive_support::template("${boolean.boolean}")),
+        Some("f32" | "f64") => number_field(attrs, false),
+        Some("i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128" | "usize") => {
+            number_field(attrs, true)
+        },
+        _ => quote!(::jgd_rs::derive_support::nested(<#ty as ::jgd_rs::JgdSchema>::jgd_entity())),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(input.ident.span(), "JgdSchema can only be derived for structs"));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new(input.ident.span(), "JgdSchema requires a struct with named fields"));
+    };
+
+    let struct_attrs = parse_attrs(&input.attrs)?;
+    let count = match &struct_attrs.count {
+        Some(count) => {
+            let count = count.tokens();
+            quote!(::std::option::Option::Some(#count))
+        },
+        None => quote!(::std::option::Option::None),
+    };
+
+    let rule = serde_string(&input.attrs, "rename_all")?;
+
+    let mut fields = Vec::new();
+    for field in &named.named {
+        let attrs = parse_attrs(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let name = match attrs.rename.clone() {
+            Some(name) => name,
+            None => match serde_string(&field.attrs, "rename")? {
+                Some(name) => name.value(),
+                None => {
+                    let ident = field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_default();
+                    let ident = ident.strip_prefix("r#").unwrap_or(&ident).to_string();
+                    match &rule {
+                        Some(rule) => rename_all(&ident, rule)?,
+                        None => ident,
+                    }
+                },
+            },
+        };
+        let value = field_tokens(&field.ty, &attrs);
+        fields.push(quote!((#name, #value)));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::jgd_rs::JgdSchema for #ident #ty_generics #where_clause {
+            fn jgd_entity() -> ::jgd_rs::Entity {
+                ::jgd_rs::derive_support::entity(#count, ::std::vec![#(#fields),*])
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn expanded(input: DeriveInput) -> String {
+        expand(input).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_expands_types_and_attributes() {
+        let tokens = expanded(parse_quote! {
+            #[jgd(count = "2..4")]
+            struct User {
+                #[jgd(fake = "internet.safeEmail")]
+                email: String,
+                initial: char,
+                #[jgd(min = 18, max = 90)]
+                age: u8,
+                #[jgd(prob = 0.3)]
+                nickname: Option<String>,
+                #[jgd(count = 2)]
+                tags: Vec<bool>,
+                address: Address,
+                #[jgd(skip)]
+                internal: u32,
+            }
+        });
+
+        assert!(tokens.contains("Count :: Range ((2u64 , 4u64))"), "{}", tokens);
+        assert!(tokens.contains("\"${internet.safeEmail}\""));
+        assert!(tokens.contains("(\"initial\" , :: jgd_rs :: derive_support :: template (\"${string.random(1, alpha)}\"))"));
+        assert!(tokens.contains("number (18f64 , 90f64 , true)"));
+        assert!(tokens.contains("optional (:: jgd_rs :: derive_support :: template (\"${lorem.word}\") , 0.3f64)"));
+        assert!(tokens.contains("Count :: Fixed (2u64)"));
+        assert!(tokens.contains("< Address as :: jgd_rs :: JgdSchema > :: jgd_entity ()"));
+        assert!(!tokens.contains("internal"));
+    }
+
+    #[test]
+    fn test_expands_serde_renames() {
+        let tokens = expanded(parse_quote! {
+            #[serde(rename_all = "camelCase")]
+            struct Order {
+                order_id: String,
+                #[serde(rename = "total")]
+                total_amount: f64,
+                #[jgd(rename = "placed")]
+                placed_at: String,
+                r#type: String,
+            }
+        });
+
+        assert!(tokens.contains("(\"orderId\" ,"), "{}", tokens);
+        assert!(tokens.contains("(\"total\" ,"));
+        assert!(tokens.contains("(\"placed\" ,"));
+        assert!(tokens.contains("(\"type\" ,"));
+
+        let rule: LitStr = parse_quote!("SCREAMING-KEBAB-CASE");
+        assert_eq!(rename_all("order_id", &rule).unwrap(), "ORDER-ID");
+        assert_eq!(rename_all("order_id", &parse_quote!("PascalCase")).unwrap(), "OrderId");
+        assert!(rename_all("order_id", &parse_quote!("Title Case")).is_err());
+    }
+
+    #[test]
+    fn test_rejects_unsupported_inputs() {
+        let error = expand(parse_quote! { enum Kind { A, B } }).unwrap_err();
+        assert_eq!(error.to_string(), "JgdSchema can only be derived for structs");
+
+        let error = expand(parse_quote! { struct Pair(u8, u8); }).unwrap_err();
+        assert_eq!(error.to_string(), "JgdSchema requires a struct with named fields");
+
+        let error = expand(parse_quote! {
+            struct User { #[jgd(fake = "name.firstName", template = "x")] name: String }
+        }).unwrap_err();
+        assert_eq!(error.to_string(), "`fake` and `template` cannot be used together");
+    }
+}
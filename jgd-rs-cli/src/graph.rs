@@ -0,0 +1,123 @@
+use clap::ValueEnum;
+use jgd_rs::ExplainPlan;
+
+/// Diagram languages of the `graph` subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz (`dot -Tsvg`)
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+/// A `ref` from a field of one entity to another entity.
+struct Edge<'a> {
+    from: usize,
+    to: usize,
+    field: &'a str,
+}
+
+/// Ref edges of the plan, by entity position. Refs that do not match any entity are
+/// left out; `explain` and `validate` report them.
+fn edges(plan: &ExplainPlan) -> Vec<Edge<'_>> {
+    let mut edges = Vec::new();
+    for (from, entity) in plan.entities.iter().enumerate() {
+        for field in &entity.fields {
+            for r#ref in &field.refs {
+                let target = r#ref.split('.').next().unwrap_or_default();
+                if let Some(to) = plan.entities.iter().position(|entity| entity.name == target) {
+                    edges.push(Edge { from, to, field: &field.path });
+                }
+            }
+        }
+    }
+    edges
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn dot(plan: &ExplainPlan) -> String {
+    let mut graph = String::from("digraph entities {\n    rankdir=LR;\n    node [shape=box];\n");
+    for entity in &plan.entities {
+        let name = dot_escape(&entity.name);
+        graph.push_str(&format!("    \"{}\" [label=\"{}\\n{}\"];\n", name, name, dot_escape(&entity.count)));
+    }
+    for edge in edges(plan) {
+        graph.push_str(&format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+            dot_escape(&plan.entities[edge.from].name), dot_escape(&plan.entities[edge.to].name), dot_escape(edge.field)
+        ));
+    }
+    graph.push_str("}\n");
+    graph
+}
+
+/// Mermaid labels are quoted, so quotes become their HTML entity.
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn mermaid(plan: &ExplainPlan) -> String {
+    // Entity names may contain characters Mermaid does not accept in ids, so nodes are
+    // numbered and the name goes in the label
+    let mut graph = String::from("flowchart LR\n");
+    for (position, entity) in plan.entities.iter().enumerate() {
+        graph.push_str(&format!(
+            "    e{}[\"{}<br/>{}\"]\n", position, mermaid_escape(&entity.name), mermaid_escape(&entity.count)
+        ));
+    }
+    for edge in edges(plan) {
+        graph.push_str(&format!("    e{} -->|\"{}\"| e{}\n", edge.from, mermaid_escape(edge.field), edge.to));
+    }
+    graph
+}
+
+/// Renders the entities of `plan`, with their counts, and an edge for each `ref`,
+/// pointing from the referencing entity to the referenced one.
+pub fn render(plan: &ExplainPlan, format: GraphFormat) -> String {
+    match format {
+        GraphFormat::Dot => dot(plan),
+        GraphFormat::Mermaid => mermaid(plan),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_render_entities_counts_and_refs() {
+        let plan = jgd_rs::Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "users": { "count": 3, "fields": { "id": "${ulid}" } },
+                "posts": {
+                    "count": [1, 5],
+                    "fields": { "authorId": { "ref": "users.id" }, "editorId": { "ref": "users.id" } }
+                }
+            }
+        })).explain();
+
+        assert_eq!(render(&plan, GraphFormat::Dot), r#"digraph entities {
+    rankdir=LR;
+    node [shape=box];
+    "users" [label="users\n3"];
+    "posts" [label="posts\n1..=5"];
+    "posts" -> "users" [label="authorId"];
+    "posts" -> "users" [label="editorId"];
+}
+"#);
+
+        assert_eq!(render(&plan, GraphFormat::Mermaid), r#"flowchart LR
+    e0["users<br/>3"]
+    e1["posts<br/>1..=5"]
+    e1 -->|"authorId"| e0
+    e1 -->|"editorId"| e0
+"#);
+    }
+}
@@ -0,0 +1,195 @@
+use std::io::{self, Write};
+
+use chrono::DateTime;
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+/// Log line formats of the `--log-format` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// `key=value` pairs of every field, e.g. `level=info msg="user logged in"`
+    Logfmt,
+    /// RFC 5424 syslog lines, e.g. `<14>1 2025-01-01T10:00:00Z web-1 api 42 - - user logged in`
+    Syslog,
+    /// Apache combined log lines, e.g. `10.0.0.1 - - [01/Jan/2025:10:00:00 +0000] "GET / HTTP/1.1" 200 512 "-" "curl/8.0"`
+    Apache,
+}
+
+/// Fields of a syslog line: `{pri}` is computed from the `severity` or `level` field.
+const SYSLOG_TEMPLATE: &str = "<{pri}>1 {timestamp} {host} {app} {pid} {msgId} - {message}";
+
+/// Fields of an Apache combined log line.
+const APACHE_TEMPLATE: &str = "{ip} - {user} [{timestamp}] \"{method} {path} {protocol}\" {status} {bytes} \"{referer}\" \"{userAgent}\"";
+
+/// Syslog severities, by their number.
+const SEVERITIES: &[&str] = &["emerg", "alert", "crit", "err", "warn", "notice", "info", "debug"];
+
+/// Syslog facility of the lines: `user`.
+const USER_FACILITY: u64 = 1;
+
+/// Alternative field names looked up when a template field is missing from a record.
+const ALIASES: &[(&str, &[&str])] = &[
+    ("timestamp", &["time", "ts", "date", "createdAt"]),
+    ("host", &["hostname"]),
+    ("app", &["appName", "service"]),
+    ("pid", &["procId"]),
+    ("message", &["msg"]),
+    ("ip", &["remoteAddr", "clientIp"]),
+    ("path", &["url", "uri"]),
+    ("bytes", &["size", "sizeBytes"]),
+    ("userAgent", &["agent"]),
+];
+
+/// Default values of the template fields missing from a record.
+const DEFAULTS: &[(&str, &str)] = &[("method", "GET"), ("path", "/"), ("protocol", "HTTP/1.1"), ("status", "200")];
+
+impl LogFormat {
+    fn template(self) -> Option<&'static str> {
+        match self {
+            LogFormat::Logfmt => None,
+            LogFormat::Syslog => Some(SYSLOG_TEMPLATE),
+            LogFormat::Apache => Some(APACHE_TEMPLATE),
+        }
+    }
+}
+
+/// Follows a dot separated path into a record.
+fn lookup<'a>(record: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(record, |value, segment| value.get(segment))
+}
+
+/// Looks a template field up in a record, trying its aliases.
+fn field<'a>(record: &'a Value, name: &str) -> Option<&'a Value> {
+    let aliases = ALIASES.iter().find(|(field, _)| *field == name).map_or(&[][..], |(_, aliases)| aliases);
+    std::iter::once(&name).chain(aliases)
+        .find_map(|name| lookup(record, name))
+        .filter(|value| !value.is_null())
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Syslog priority: the `user` facility and the severity of the `severity` or `level` field.
+fn priority(record: &Value) -> u64 {
+    let severity = match lookup(record, "severity").or_else(|| lookup(record, "level")) {
+        Some(Value::Number(number)) => number.as_u64().unwrap_or(6).min(7),
+        Some(Value::String(level)) => {
+            let level = level.to_lowercase();
+            SEVERITIES.iter().position(|severity| level.starts_with(severity)).unwrap_or(match level.as_str() {
+                "fatal" | "critical" => 2,
+                "trace" => 7,
+                _ => 6,
+            }) as u64
+        },
+        _ => 6,
+    };
+    USER_FACILITY * 8 + severity
+}
+
+/// Renders a template field of a record for `format`; missing fields are `-`.
+fn render_field(format: LogFormat, record: &Value, name: &str) -> String {
+    if name == "pri" {
+        return priority(record).to_string();
+    }
+
+    let Some(value) = field(record, name) else {
+        return DEFAULTS.iter()
+            .find(|(field, _)| format == LogFormat::Apache && *field == name)
+            .map_or("-".to_string(), |(_, default)| default.to_string());
+    };
+    let value = text(value);
+    match (format, name) {
+        (LogFormat::Apache, "timestamp") => DateTime::parse_from_rfc3339(&value)
+            .map_or(value, |timestamp| timestamp.format("%d/%b/%Y:%H:%M:%S %z").to_string()),
+        (LogFormat::Syslog, "message") => value,
+        (LogFormat::Syslog, _) => value.replace(' ', "_"),
+        _ => value,
+    }
+}
+
+/// Replaces the `{field}` placeholders of a template with the values of a record.
+fn render_template(format: LogFormat, template: &str, record: &Value) -> String {
+    let mut line = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        line.push_str(&rest[..start]);
+        line.push_str(&render_field(format, record, &rest[start + 1..start + end]));
+        rest = &rest[start + end + 1..];
+    }
+    line.push_str(rest);
+    line
+}
+
+/// Adds the `key=value` pairs of an object, with nested keys joined by dots.
+fn logfmt_pairs(prefix: &str, object: &Map<String, Value>, pairs: &mut Vec<String>) {
+    for (key, value) in object {
+        let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match value {
+            Value::Object(object) => logfmt_pairs(&key, object, pairs),
+            Value::Null => pairs.push(format!("{}=", key)),
+            value => {
+                let value = text(value);
+                let quoted = value.is_empty() || value.contains([' ', '=', '"']);
+                match quoted {
+                    true => pairs.push(format!("{}={:?}", key, value)),
+                    false => pairs.push(format!("{}={}", key, value)),
+                }
+            },
+        }
+    }
+}
+
+/// Renders a record as one log line, with `template` or the default line of `format`.
+pub fn render(format: LogFormat, template: Option<&str>, record: &Value) -> String {
+    match (template.or(format.template()), record) {
+        (Some(template), record) => render_template(format, template, record),
+        (None, Value::Object(object)) => {
+            let mut pairs = Vec::new();
+            logfmt_pairs("", object, &mut pairs);
+            pairs.join(" ")
+        },
+        (None, record) => format!("msg={:?}", text(record)),
+    }
+}
+
+/// Writes each record as a log line.
+pub fn write_lines<W: Write>(writer: &mut W, format: LogFormat, template: Option<&str>, records: &[&Value]) -> io::Result<()> {
+    for record in records {
+        writeln!(writer, "{}", render(format, template, record))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_render_log_lines() {
+        let record = json!({
+            "timestamp": "2025-01-01T10:00:00Z",
+            "level": "warning",
+            "host": "web-1",
+            "service": "api",
+            "msg": "disk almost full",
+            "http": { "status": 200 }
+        });
+        assert_eq!(render(LogFormat::Logfmt, None, &record),
+            "timestamp=2025-01-01T10:00:00Z level=warning host=web-1 service=api msg=\"disk almost full\" http.status=200");
+        assert_eq!(render(LogFormat::Syslog, None, &record), "<12>1 2025-01-01T10:00:00Z web-1 api - - - disk almost full");
+
+        let request = json!({ "ip": "10.0.0.1", "timestamp": "2025-01-01T10:00:00Z", "path": "/login", "status": 302, "bytes": 512 });
+        assert_eq!(render(LogFormat::Apache, None, &request),
+            "10.0.0.1 - - [01/Jan/2025:10:00:00 +0000] \"GET /login HTTP/1.1\" 302 512 \"-\" \"-\"");
+        assert_eq!(render(LogFormat::Logfmt, Some("{ip} {http.status} {missing}"), &json!({ "ip": "::1", "http": { "status": 404 } })), "::1 404 -");
+    }
+}
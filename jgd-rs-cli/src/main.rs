@@ -1,47 +1,705 @@
-use clap::Parser;
-use std::{fs, path::PathBuf};
+use clap::{ArgGroup, Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use std::{io::{self, BufWriter, Write}, path::{Path, PathBuf}, process::ExitCode};
+
+use crate::{graph::GraphFormat, log_format::LogFormat, output::{Compression, MetaOutput, OutputFormat, OutputWriter, ShardSplit, ShardWriter}};
+
+mod graph;
+mod log_format;
+mod output;
 
 #[derive(Parser, Debug)]
-#[command(version, about = "Generate JSON from .jgd definitions")]
+#[command(
+    version,
+    about = "Generate JSON from .jgd definitions",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Cli {
-    /// Path to .jgd file
-    input: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Print the completion script for a shell
+    #[arg(long, value_enum, exclusive = true)]
+    completions: Option<Shell>,
+    // Without a subcommand, `jgd-rs-cli schema.jgd` is `jgd-rs-cli generate schema.jgd`
+    #[command(flatten)]
+    generate: GenerateArgs,
+}
+
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("destination").args(["out", "out_dir"])))]
+struct GenerateArgs {
+    /// Path to .jgd file, or `-` to read it from stdin
+    #[arg(required = true)]
+    input: Option<PathBuf>,
+    /// Partial .jgd files merged over the input in order (objects merge, `null` removes a property)
+    overlays: Vec<PathBuf>,
     /// Output file (JSON). If omitted, prints to stdout.
     #[arg(short, long)]
     out: Option<PathBuf>,
+    /// Write each entity to its own file in this directory, e.g. `users.json` (entities mode only)
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["out", "shards", "max_records_per_file"])]
+    out_dir: Option<PathBuf>,
     /// Seed override
     #[arg(long)]
     seed: Option<u64>,
+    /// Fixed "now" of the relative dates, in RFC 3339 (overrides the schema `referenceDate`)
+    #[arg(long, value_name = "DATETIME")]
+    now: Option<String>,
+    /// Replace the real-looking top-level domains of generated emails with `.test` (sets the schema `safeEmails`)
+    #[arg(long)]
+    safe_emails: bool,
     /// Pretty print
     #[arg(short, long)]
     pretty: bool,
+    /// Compress the output files on the fly (requires --out or --out-dir)
+    #[arg(long, value_enum, requires = "destination")]
+    compress: Option<Compression>,
+    /// Split the output records evenly across N NDJSON files (requires --out).
+    /// Use `{shard}` in the --out path to place the shard number, e.g. `out-{shard}.ndjson`,
+    /// and `{entity}` to place the entity name; each entity is split into its own files
+    #[arg(long, requires = "out", conflicts_with = "max_records_per_file", value_parser = clap::value_parser!(u64).range(1..))]
+    shards: Option<u64>,
+    /// Split the output records into NDJSON files of at most N records (requires --out);
+    /// each entity is split into its own files
+    #[arg(long, requires = "out", value_parser = clap::value_parser!(u64).range(1..))]
+    max_records_per_file: Option<u64>,
+    /// Stop with an error when more than N records would be generated
+    #[arg(long)]
+    max_records: Option<u64>,
+    /// Stop with an error when entities and arrays nest deeper than N levels
+    #[arg(long)]
+    max_depth: Option<usize>,
+    /// Stop with an error when the output grows past N bytes
+    #[arg(long)]
+    max_output_bytes: Option<u64>,
+    /// Fuzzing: chance (0.0 to 1.0) that each optional field is left out of its object
+    #[arg(long, value_name = "RATE")]
+    fuzz_drop_optional: Option<f64>,
+    /// Fuzzing: chance (0.0 to 1.0) that each field is replaced by a boundary value
+    /// (empty or very long string, 0, i64::MIN, i64::MAX, empty array)
+    #[arg(long, value_name = "RATE")]
+    fuzz_boundaries: Option<f64>,
+    /// Realism profile of the whole schema: strict, messy or adversarial
+    #[arg(long, value_name = "PROFILE")]
+    realism: Option<jgd_rs::Realism>,
+    /// Naming convention for output keys: camelCase, snake_case, kebab-case or PascalCase
+    #[arg(long)]
+    key_case: Option<jgd_rs::KeyCase>,
+    /// Output layout: json (one document, the default) or ndjson (one record per line: the
+    /// root items, or the items of every entity)
+    #[arg(long, value_enum, conflicts_with_all = ["pretty", "log_format", "out_dir", "checkpoint"])]
+    format: Option<OutputFormat>,
+    /// Render each record as a log line instead of JSON: logfmt, syslog (RFC 5424) or
+    /// apache (combined log format). Records are the root items or the items of every entity
+    #[arg(long, value_enum, conflicts_with_all = ["pretty", "canonical", "meta", "checkpoint", "shards", "max_records_per_file", "out_dir"])]
+    log_format: Option<LogFormat>,
+    /// Template of the log lines, with `{field}` placeholders for record fields (dot paths allowed)
+    #[arg(long, value_name = "TEMPLATE", requires = "log_format")]
+    log_template: Option<String>,
+    /// Byte-stable output: sort object keys and normalize numbers (disables streaming)
+    #[arg(long)]
+    canonical: bool,
+    /// Generate only these entities (comma separated); the entities they reference are generated too
+    #[arg(long, value_delimiter = ',')]
+    only: Vec<String>,
+    /// Leave an entity (`users`) or a field (`users.address.city`) out of the generation. Repeatable
+    #[arg(long, value_delimiter = ',')]
+    exclude: Vec<String>,
+    /// Override a schema value before generation, e.g. `users.count=100` or `users.fields.status=active`.
+    /// The value is parsed as JSON, falling back to a plain string. Repeatable
+    #[arg(long = "set", value_name = "PATH=VALUE", value_parser = parse_override)]
+    overrides: Vec<(String, serde_json::Value)>,
+    /// Use the values of a JSON array or CSV file for a key in a locale, e.g.
+    /// `PT_BR:address.cityName=cities.csv`. Repeatable
+    #[arg(long, value_name = "LOCALE:KEY=FILE", value_parser = parse_data_pack)]
+    data_pack: Vec<(String, String, PathBuf)>,
+    /// Write the output paths of the fields tagged with `pii` or `tags` to this JSON file
+    #[arg(long, value_name = "FILE")]
+    tag_report: Option<PathBuf>,
+    /// Record the run metadata (schema version, seed used, locale, timestamp, crate version)
+    /// as a `_meta` block in the output or as a sidecar file (requires --out or --out-dir)
+    #[arg(long, value_enum, requires_if("sidecar", "destination"),
+        conflicts_with_all = ["checkpoint", "shards", "max_records_per_file"])]
+    meta: Option<MetaOutput>,
+    /// Write per-field statistics of the generated data (min/max/mean, distinct values, null rate)
+    /// to this JSON file (disables streaming)
+    #[arg(long, value_name = "FILE", conflicts_with = "checkpoint")]
+    stats: Option<PathBuf>,
+    /// Check the generated data (refs resolve, uniqueBy holds, the schema `checks`) and exit
+    /// with an error after writing it when an invariant is violated (disables streaming)
+    #[arg(long, conflicts_with = "checkpoint")]
+    check: bool,
+    /// Reject unknown schema properties (e.g. a misspelled `feilds`) instead of ignoring them
+    #[arg(long)]
+    strict: bool,
+    /// Record the progress in this file so a cancelled run can be resumed (requires --out).
+    /// When the file exists, the run resumes from it; it is removed once the run completes
+    #[arg(long, value_name = "FILE", requires = "out",
+        conflicts_with_all = ["pretty", "canonical", "compress", "shards", "max_records_per_file"])]
+    checkpoint: Option<PathBuf>,
+    /// Number of records of each entity between two checkpoints
+    #[arg(long, value_name = "N", default_value_t = 10_000, requires = "checkpoint")]
+    checkpoint_every: u64,
 }
 
-fn main() -> Result<(), String> {
-    let cli = Cli::parse();
+/// Parses a `PATH=VALUE` override; values that are not valid JSON are taken as strings.
+fn parse_override(value: &str) -> Result<(String, serde_json::Value), String> {
+    let Some((path, raw)) = value.split_once('=') else {
+        return Err(format!("expected PATH=VALUE, got {}", value));
+    };
 
-    let generated = jgd_rs::generate_jgd_from_file(&cli.input);
+    let parsed = serde_json::from_str(raw).unwrap_or_else(|_| serde_json::Value::String(raw.to_string()));
+    Ok((path.trim().to_string(), parsed))
+}
 
-    if let Err(error) = generated {
-        eprintln!("{}", error);
-        return Ok(());
+/// Parses a `LOCALE:KEY=FILE` data pack.
+fn parse_data_pack(value: &str) -> Result<(String, String, PathBuf), String> {
+    let Some((locale, key, file)) = value.split_once(':')
+        .and_then(|(locale, rest)| rest.split_once('=').map(|(key, file)| (locale, key, file))) else {
+        return Err(format!("expected LOCALE:KEY=FILE, got {}", value));
+    };
+
+    Ok((locale.trim().to_string(), key.trim().to_string(), PathBuf::from(file.trim())))
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Generate JSON from a .jgd file (the default when no subcommand is given)
+    Generate(Box<GenerateArgs>),
+    /// Check a .jgd file for unknown properties, unknown keys and unresolvable refs
+    Validate {
+        /// Path to .jgd file, or `-` to read it from stdin
+        input: PathBuf,
+    },
+    /// Print the generation plan of a .jgd file without generating data
+    Explain {
+        /// Path to .jgd file, or `-` to read it from stdin
+        input: PathBuf,
+    },
+    /// Print a diagram of the entities of a .jgd file, their counts and ref edges
+    Graph {
+        /// Path to .jgd file, or `-` to read it from stdin
+        input: PathBuf,
+        /// Diagram language
+        #[arg(long, value_enum, default_value = "dot")]
+        format: GraphFormat,
+    },
+    /// Anonymize a JSON document, replacing the fields declared in a .jgd mask
+    Mask {
+        /// Path to the JSON document to anonymize, or `-` to read it from stdin
+        input: PathBuf,
+        /// Path to the .jgd mask
+        mask: PathBuf,
+        /// Output file (JSON). If omitted, prints to stdout.
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        /// Pretty print
+        #[arg(short, long)]
+        pretty: bool,
+    },
+    /// List the fake keys available in templates
+    Keys {
+        /// Only list the keys of this category, e.g. `lorem`
+        #[arg(long)]
+        category: Option<String>,
+        /// Only list the keys with their own data in this locale, e.g. `PT_BR`
+        #[arg(long)]
+        locale: Option<String>,
+    },
+    /// Print sample values of a fake key (`name.nameWithTitle`) or template (`${name.firstName} ${name.lastName}`)
+    Preview {
+        /// Key or template to generate
+        pattern: String,
+        /// Locale of the values, e.g. `de_DE` or `PT_BR`
+        #[arg(long, default_value = "EN")]
+        locale: String,
+        /// Number of values to print
+        #[arg(short, long, default_value_t = 10)]
+        n: usize,
+        /// Seed for reproducible samples
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+}
+
+/// Input path that stands for the standard input.
+const STDIN: &str = "-";
+
+/// Reads a file, or the standard input when `path` is `-`.
+fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new(STDIN) {
+        return io::read_to_string(io::stdin());
+    }
+
+    std::fs::read_to_string(path)
+}
+
+/// How the records are split across shards, when sharded output was requested.
+fn shard_split(args: &GenerateArgs) -> Option<ShardSplit> {
+    match (args.shards, args.max_records_per_file) {
+        (Some(shards), _) => Some(ShardSplit::Shards(shards as usize)),
+        (None, Some(per_file)) => Some(ShardSplit::PerFile(per_file as usize)),
+        (None, None) => None,
+    }
+}
+
+/// Describes a failure to write the output.
+fn record_error(error: io::Error) -> String {
+    format!("Error to record the file. Details: {}", error)
+}
+
+/// Anonymizes `input` through the `mask` schema and writes the result.
+fn mask(input: &Path, mask: &Path, out: Option<&PathBuf>, pretty: bool) -> Result<(), String> {
+    let document = read_input(input)
+        .map_err(|error| error.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|error| error.to_string()))
+        .map_err(|error| format!("Error to read the input document. Details: {}", error))?;
+
+    let masked = read_schema(mask)?.mask(document).map_err(|error| error.to_string())?;
+
+    let io_result = match out {
+        Some(path) => OutputWriter::create(path, None).and_then(|mut writer| {
+            output::write_json(&mut writer, &masked, pretty)?;
+            writer.finish()
+        }),
+        None => {
+            let mut stdout = io::stdout().lock();
+            output::write_json(&mut stdout, &masked, pretty).and_then(|_| writeln!(stdout))
+        }
+    };
+    io_result.map_err(record_error)
+}
+
+/// Prints the fake keys matching the `category` and `locale` filters.
+fn keys(category: Option<&str>, locale: Option<&str>) -> Result<(), String> {
+    let locale = locale.map(str::to_uppercase);
+    let keys: Vec<jgd_rs::FakeKeyInfo> = jgd_rs::FakeKeys::all()
+        .into_iter()
+        .filter(|info| category.is_none_or(|category| info.category == category))
+        .filter(|info| locale.as_deref().is_none_or(|locale| info.locales.contains(&locale)))
+        .collect();
+
+    if keys.is_empty() {
+        return Err("No fake keys match the given category and locale".to_string());
+    }
+
+    let width = keys.iter().map(|info| info.key.len()).max().unwrap_or_default();
+    for info in keys {
+        match info.arguments {
+            Some(arguments) => println!("{:width$}  ({})", info.key, arguments, width = width),
+            None => println!("{}", info.key),
+        }
+    }
+    Ok(())
+}
+
+/// Prints `n` values of a key or template, one per line.
+fn preview(pattern: &str, locale: &str, n: usize, seed: Option<u64>) -> Result<(), String> {
+    let template = if pattern.contains("${") { pattern.to_string() } else { format!("${{{}}}", pattern) };
+    let collection = jgd_rs::ReplacerCollection::new(template);
+    let mut config = jgd_rs::GeneratorConfig::new(&locale.to_uppercase(), seed);
+
+    for _ in 0..n {
+        match collection.replace(&mut config, None) {
+            Ok(serde_json::Value::String(value)) => println!("{}", value),
+            Ok(value) => println!("{}", value),
+            Err(error) => return Err(error.to_string()),
+        }
+    }
+    Ok(())
+}
+
+/// Reads and parses a schema, reporting read and parse failures as messages.
+fn read_schema(input: &Path) -> Result<jgd_rs::Jgd, String> {
+    let schema = read_input(input)
+        .map_err(|error| format!("Error to read the schema {}. Details: {}", input.display(), error))?;
+    serde_json::from_str(&schema)
+        .map_err(|error| format!("Error to parse the schema {}. Details: {}", input.display(), error))
+}
+
+/// Loads the schema, applying the command line overrides over the schema settings.
+fn load(args: &GenerateArgs, input: &Path) -> Result<jgd_rs::Jgd, jgd_rs::JgdGeneratorError> {
+    let document: serde_json::Value = read_input(input)
+        .map_err(|error| error.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|error| error.to_string()))
+        .map_err(|error| jgd_rs::JgdGeneratorError {
+            message: format!("Error to read the schema {}. Details: {}", input.display(), error),
+            kind: jgd_rs::JgdErrorKind::Schema,
+            ..Default::default()
+        })?;
+
+    for warning in jgd_rs::Jgd::deprecations(&document) {
+        eprintln!("Warning: {}", warning);
     }
 
-    let generated = generated.unwrap();
-    let serialized = if cli.pretty {
-        serde_json::to_string_pretty(&generated).unwrap()
+    let mut jgd = if args.strict {
+        jgd_rs::Jgd::from_value_strict(document)?
     } else {
-        serde_json::to_string(&generated).unwrap()
+        serde_json::from_value(document).map_err(|error| jgd_rs::JgdGeneratorError {
+            message: format!("Error to parse the schema {}. Details: {}", input.display(), error),
+            kind: jgd_rs::JgdErrorKind::Schema,
+            ..Default::default()
+        })?
     };
 
-    if let Some(path) = cli.out {
-        let io_result = fs::write(path, serialized);
-        if let Err(error) = io_result {
-            println!("Error to record the file. Details: {}", error);
+    for overlay in &args.overlays {
+        let document = read_input(overlay)
+            .map_err(|error| error.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|error| error.to_string()))
+            .map_err(|error| jgd_rs::JgdGeneratorError {
+                message: format!("Error to read the overlay {}. Details: {}", overlay.display(), error),
+                kind: jgd_rs::JgdErrorKind::Schema,
+                ..Default::default()
+            })?;
+        jgd.merge(document)?;
+    }
+
+    for (path, value) in &args.overrides {
+        jgd.set(path, value.clone())?;
+    }
+
+    if args.seed.is_some() {
+        jgd.seed = args.seed;
+    }
+    if let Some(now) = &args.now {
+        jgd.set("referenceDate", serde_json::Value::String(now.clone()))?;
+    }
+    if args.safe_emails {
+        jgd.safe_emails = true;
+    }
+    if args.meta.is_some() && jgd.seed.is_none() {
+        // Pin the drawn seed so the metadata records the seed of the run
+        jgd.seed = Some(jgd.create_config().seed);
+    }
+
+    if !args.only.is_empty() {
+        jgd.select_entities(&args.only)?;
+    }
+    for path in &args.exclude {
+        jgd.exclude(path)?;
+    }
+
+    if args.max_records.is_some() {
+        jgd.limits.max_records = args.max_records;
+    }
+    if args.max_depth.is_some() {
+        jgd.limits.max_depth = args.max_depth;
+    }
+    if args.max_output_bytes.is_some() {
+        jgd.limits.max_output_bytes = args.max_output_bytes;
+    }
+    if let Some(rate) = args.fuzz_drop_optional {
+        jgd.fuzz.drop_optional = rate;
+    }
+    if let Some(rate) = args.fuzz_boundaries {
+        jgd.fuzz.boundaries = rate;
+    }
+    if args.realism.is_some() {
+        jgd.realism = args.realism;
+    }
+    if args.key_case.is_some() {
+        jgd.key_case = args.key_case;
+    }
+
+    for (locale, key, file) in &args.data_pack {
+        jgd_rs::Jgd::load_data_pack(locale, key, file)?;
+    }
+
+    for fallback in jgd.explain().fallbacks {
+        eprintln!("Warning: {}", fallback);
+    }
+
+    if let Some(path) = &args.tag_report {
+        let report = serde_json::to_string_pretty(&jgd.tag_report()).unwrap();
+        std::fs::write(path, report + "\n").map_err(|error| jgd_rs::JgdGeneratorError {
+            message: format!("Error to record the tag report {}. Details: {}", path.display(), error),
+            kind: jgd_rs::JgdErrorKind::Output,
+            ..Default::default()
+        })?;
+    }
+
+    Ok(jgd)
+}
+
+/// Writes the run metadata next to the output when `--meta sidecar` is set.
+fn write_meta_sidecar(args: &GenerateArgs, jgd: &jgd_rs::Jgd) -> Result<(), String> {
+    let path = match (args.meta, &args.out_dir, &args.out) {
+        (Some(MetaOutput::Sidecar), Some(dir), _) => dir.join("_meta.json"),
+        (Some(MetaOutput::Sidecar), None, Some(out)) => output::meta_path(out),
+        _ => return Ok(()),
+    };
+
+    let metadata = jgd_rs::RunMetadata::new(jgd, jgd.seed.unwrap_or_default());
+    let content = serde_json::to_string_pretty(&metadata).unwrap();
+    std::fs::write(&path, content + "\n").map_err(record_error)
+}
+
+/// Reads the checkpoint to resume from, if the checkpoint file exists.
+fn read_checkpoint(path: &Path) -> Result<Option<jgd_rs::Checkpoint>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    std::fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|error| error.to_string()))
+        .map(Some)
+        .map_err(|error| format!("Error to read the checkpoint {}. Details: {}", path.display(), error))
+}
+
+/// Replaces the checkpoint file, through a temporary file so it is never left half written.
+fn write_checkpoint(path: &Path, checkpoint: &jgd_rs::Checkpoint) -> Result<(), jgd_rs::JgdGeneratorError> {
+    let temporary = path.with_extension("tmp");
+    serde_json::to_vec(checkpoint)
+        .map_err(io::Error::from)
+        .and_then(|content| std::fs::write(&temporary, content))
+        .and_then(|_| std::fs::rename(&temporary, path))
+        .map_err(|error| jgd_rs::JgdGeneratorError {
+            message: format!("Error to write the checkpoint {}. Details: {}", path.display(), error),
+            kind: jgd_rs::JgdErrorKind::Output,
+            ..Default::default()
+        })
+}
+
+/// Streams into `out`, recording checkpoints and resuming from the existing checkpoint file.
+fn stream_with_checkpoints(args: &GenerateArgs, jgd: &jgd_rs::Jgd, out: &Path, checkpoint_path: &Path) -> Result<(), String> {
+    let resume = read_checkpoint(checkpoint_path)?;
+
+    let writer = match &resume {
+        Some(resume) => OutputWriter::resume(out, resume.bytes),
+        None => OutputWriter::create(out, None),
+    };
+    let mut writer = writer.map_err(record_error)?;
+
+    let result = jgd.generate_to_writer_resumable(&mut writer, resume.as_ref(), args.checkpoint_every,
+        |checkpoint| write_checkpoint(checkpoint_path, checkpoint));
+    writer.finish().map_err(record_error)?;
+
+    result.map_err(|error| error.to_string())?;
+    let _ = std::fs::remove_file(checkpoint_path);
+    Ok(())
+}
+
+/// Generates straight into the output sink with `Jgd::generate_to_writer`.
+fn stream(args: &GenerateArgs, input: &Path) -> Result<(), String> {
+    let jgd = load(args, input).map_err(|error| error.to_string())?;
+
+    if let (Some(path), Some(checkpoint)) = (&args.out, &args.checkpoint) {
+        stream_with_checkpoints(args, &jgd, path, checkpoint)
+    } else if let Some(path) = &args.out {
+        let mut writer = OutputWriter::create(path, args.compress).map_err(record_error)?;
+        jgd.generate_to_writer(&mut writer).map_err(|error| error.to_string())?;
+        writer.finish().map_err(record_error)?;
+        write_meta_sidecar(args, &jgd)
+    } else {
+        let mut stdout = BufWriter::new(io::stdout().lock());
+        let result = jgd.generate_to_writer(&mut stdout);
+        let _ = writeln!(stdout);
+        result.map_err(|error| error.to_string())
+    }
+}
+
+/// Streams the records to NDJSON shards as they are generated, each entity to its own shards.
+fn stream_shards(args: &GenerateArgs, input: &Path, pattern: &Path, split: ShardSplit) -> Result<(), String> {
+    let jgd = load(args, input).map_err(|error| error.to_string())?;
+    let entities_mode = jgd.entities.is_some();
+
+    let mut shards = ShardWriter::new(pattern, split, args.compress);
+    jgd.generate_each_record(|entity, record| {
+        shards.write(entities_mode.then_some(entity), &record).map_err(|error| jgd_rs::JgdGeneratorError {
+            message: record_error(error),
+            kind: jgd_rs::JgdErrorKind::Output,
+            ..Default::default()
+        })
+    }).map_err(|error| error.to_string())?;
+
+    shards.finish().map(|_| ()).map_err(record_error)
+}
+
+/// Generates the data of a .jgd file, streaming it when the output options allow it.
+fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let Some(input) = &args.input else {
+        return Ok(());
+    };
+
+    // Shards are written record by record, unless the whole tree is needed first
+    if let (Some(pattern), Some(split)) = (&args.out, shard_split(args)) {
+        if !args.canonical && args.stats.is_none() && !args.check {
+            return stream_shards(args, input, pattern, split);
         }
+    }
+
+    // Compact, unsharded, non-canonical output is streamed item by item instead of building the whole tree
+    if !args.pretty && !args.canonical && args.shards.is_none() && args.max_records_per_file.is_none()
+        && args.out_dir.is_none() && args.meta != Some(MetaOutput::Embed) && args.stats.is_none() && !args.check && args.log_format.is_none()
+        && args.format != Some(OutputFormat::Ndjson) {
+        return stream(args, input);
+    }
+
+    let jgd = load(args, input).map_err(|error| error.to_string())?;
+    if args.out_dir.is_some() && jgd.entities.is_none() {
+        return Err("Error to split the output by entity. Details: --out-dir needs a schema in entities mode".to_string());
+    }
+
+    let generated = jgd.generate().map_err(|error| error.to_string())?;
+    let generated = if args.canonical {
+        jgd_rs::canonicalize(generated)
     } else {
+        generated
+    };
+    if let Some(path) = &args.stats {
+        let stats = serde_json::to_string_pretty(&jgd_rs::StatsReport::new(&generated)).unwrap();
+        std::fs::write(path, stats + "\n")
+            .map_err(|error| format!("Error to record the stats report {}. Details: {}", path.display(), error))?;
+    }
+    let report = args.check.then(|| jgd.check(&generated));
+    let generated = match args.meta {
+        Some(MetaOutput::Embed) => jgd_rs::RunMetadata::new(&jgd, jgd.seed.unwrap_or_default()).embed(generated),
+        _ => generated,
+    };
+
+    write(args, &jgd, generated)?;
+
+    match report.filter(|report| !report.is_valid()) {
+        Some(report) => Err(report.to_string().trim_end().to_string()),
+        None => Ok(()),
+    }
+}
+
+/// Writes the generated data in the requested format and destination.
+fn write(args: &GenerateArgs, jgd: &jgd_rs::Jgd, generated: serde_json::Value) -> Result<(), String> {
+    if let Some(format) = args.log_format {
+        let records = output::records(&generated, jgd.entities.is_some());
+        let template = args.log_template.as_deref();
+        let io_result = match &args.out {
+            Some(path) => OutputWriter::create(path, args.compress).and_then(|mut writer| {
+                log_format::write_lines(&mut writer, format, template, &records)?;
+                writer.finish()
+            }),
+            None => log_format::write_lines(&mut BufWriter::new(io::stdout().lock()), format, template, &records),
+        };
+        return io_result.map_err(record_error);
+    }
+
+    if args.format == Some(OutputFormat::Ndjson) && shard_split(args).is_none() {
+        let records = output::records(&generated, jgd.entities.is_some());
+        let io_result = match &args.out {
+            Some(path) => OutputWriter::create(path, args.compress).and_then(|mut writer| {
+                output::write_ndjson(&mut writer, &records)?;
+                writer.finish()
+            }),
+            None => output::write_ndjson(&mut BufWriter::new(io::stdout().lock()), &records),
+        };
+        io_result.map_err(record_error)?;
+        return write_meta_sidecar(args, jgd);
+    }
+
+    if let (Some(dir), serde_json::Value::Object(entities)) = (&args.out_dir, &generated) {
+        output::write_entities(dir, entities, args.pretty, args.compress).map_err(record_error)?;
+    } else if let (Some(path), Some(split)) = (&args.out, shard_split(args)) {
+        output::write_shards(path, &generated, jgd.entities.is_some(), split, args.compress).map_err(record_error)?;
+    } else if let Some(path) = &args.out {
+        OutputWriter::create(path, args.compress)
+            .and_then(|mut writer| {
+                output::write_json(&mut writer, &generated, args.pretty)?;
+                writer.finish()
+            })
+            .map_err(record_error)?;
+    } else {
+        let serialized = if args.pretty {
+            serde_json::to_string_pretty(&generated).unwrap()
+        } else {
+            serde_json::to_string(&generated).unwrap()
+        };
         println!("{}", serialized);
     }
+    write_meta_sidecar(args, jgd)
+}
+
+
+/// Checks a schema strictly and reports its problems.
+fn validate(input: &Path) -> Result<(), String> {
+    let document = read_input(input)
+        .map_err(|error| error.to_string())
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).map_err(|error| error.to_string()))
+        .map_err(|error| format!("Error to read the schema {}. Details: {}", input.display(), error))?;
+
+    for warning in jgd_rs::Jgd::deprecations(&document) {
+        eprintln!("Warning: {}", warning);
+    }
+
+    let jgd = jgd_rs::Jgd::from_value_strict(document).map_err(|error| error.to_string())?;
+
+    let plan = jgd.explain();
+    for fallback in &plan.fallbacks {
+        eprintln!("Warning: {}", fallback);
+    }
 
+    if !plan.warnings.is_empty() {
+        return Err(plan.warnings.join("\n"));
+    }
+    println!("{} is valid", input.display());
     Ok(())
 }
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // Diagnostics go to stderr so they never mix with generated JSON on stdout,
+    // e.g. `RUST_LOG=jgd_rs=debug jgd-rs-cli schema.jgd`
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+
+    if let Some(shell) = cli.completions {
+        clap_complete::generate(shell, &mut Cli::command(), "jgd-rs-cli", &mut io::stdout().lock());
+        return ExitCode::SUCCESS;
+    }
+
+    let result = match &cli.command {
+        Some(Command::Generate(args)) => generate(args),
+        Some(Command::Validate { input }) => validate(input),
+        Some(Command::Explain { input }) => read_schema(input).map(|jgd| print!("{}", jgd.explain())),
+        Some(Command::Graph { input, format }) => {
+            read_schema(input).map(|jgd| print!("{}", graph::render(&jgd.explain(), *format)))
+        },
+        Some(Command::Mask { input, mask: mask_path, out, pretty }) => mask(input, mask_path, out.as_ref(), *pretty),
+        Some(Command::Keys { category, locale }) => keys(category.as_deref(), locale.as_deref()),
+        Some(Command::Preview { pattern, locale, n, seed }) => preview(pattern, locale, *n, *seed),
+        None => generate(&cli.generate),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(parse_override("users.count=100"), Ok(("users.count".to_string(), json!(100))));
+        assert_eq!(parse_override("users.fields.status=active"), Ok(("users.fields.status".to_string(), json!("active"))));
+        assert_eq!(parse_override("root.fields.tags=[\"a\"]"), Ok(("root.fields.tags".to_string(), json!(["a"]))));
+        assert!(parse_override("seed").is_err());
+    }
+
+    #[test]
+    fn test_parse_data_pack() {
+        assert_eq!(
+            parse_data_pack("PT_BR:address.cityName=packs/cities.csv"),
+            Ok(("PT_BR".to_string(), "address.cityName".to_string(), PathBuf::from("packs/cities.csv")))
+        );
+        assert!(parse_data_pack("address.cityName=cities.csv").is_err());
+        assert!(parse_data_pack("PT_BR:address.cityName").is_err());
+    }
+}
@@ -0,0 +1,428 @@
+use std::{fs::{self, File, OpenOptions}, io::{self, BufWriter, Write}, path::{Path, PathBuf}};
+
+use clap::ValueEnum;
+use flate2::write::GzEncoder;
+use serde_json::{Map, Value};
+
+/// Compression applied to the generated output when writing to a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Compression {
+    /// gzip stream (`.gz`)
+    Gzip,
+    /// Zstandard stream (`.zst`)
+    Zstd,
+}
+
+/// Layout of the generated output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// One JSON document (default)
+    Json,
+    /// One JSON record per line: the root items, or the items of every entity
+    Ndjson,
+}
+
+/// Where the run metadata (`_meta`: schema version, seed, locale, timestamp) is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MetaOutput {
+    /// `_meta` block at the top of the generated document
+    Embed,
+    /// `<name>.meta.json` file next to the output (`_meta.json` in --out-dir)
+    Sidecar,
+}
+
+/// Builds the path of the metadata file written next to an output file.
+///
+/// `data.json` and `data.json.gz` both become `data.meta.json`.
+pub fn meta_path(out: &Path) -> PathBuf {
+    let file_name = out.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    let stem = file_name.split('.').next().unwrap_or_default();
+    out.with_file_name(format!("{}.meta.json", stem))
+}
+
+/// File sink that compresses on the fly according to the selected [`Compression`].
+pub enum OutputWriter {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+    Zstd(zstd::Encoder<'static, BufWriter<File>>),
+}
+
+impl OutputWriter {
+    pub fn create(path: &Path, compression: Option<Compression>) -> io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+
+        let writer = match compression {
+            None => OutputWriter::Plain(file),
+            Some(Compression::Gzip) => OutputWriter::Gzip(GzEncoder::new(file, flate2::Compression::default())),
+            Some(Compression::Zstd) => OutputWriter::Zstd(zstd::Encoder::new(file, 0)?),
+        };
+
+        Ok(writer)
+    }
+
+    /// Reopens an uncompressed output to append to it, dropping everything after its
+    /// first `len` bytes.
+    pub fn resume(path: &Path, len: u64) -> io::Result<Self> {
+        let file = OpenOptions::new().append(true).open(path)?;
+        file.set_len(len)?;
+
+        Ok(OutputWriter::Plain(BufWriter::new(file)))
+    }
+
+    /// Flushes the remaining data and writes the compression trailer, if any.
+    pub fn finish(self) -> io::Result<()> {
+        let mut file = match self {
+            OutputWriter::Plain(file) => file,
+            OutputWriter::Gzip(encoder) => encoder.finish()?,
+            OutputWriter::Zstd(encoder) => encoder.finish()?,
+        };
+
+        file.flush()
+    }
+}
+
+impl Write for OutputWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            OutputWriter::Plain(writer) => writer.write(buf),
+            OutputWriter::Gzip(writer) => writer.write(buf),
+            OutputWriter::Zstd(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            OutputWriter::Plain(writer) => writer.flush(),
+            OutputWriter::Gzip(writer) => writer.flush(),
+            OutputWriter::Zstd(writer) => writer.flush(),
+        }
+    }
+}
+
+/// Serializes `value` straight into `writer`, without an intermediate string.
+pub fn write_json<W: Write>(writer: &mut W, value: &Value, pretty: bool) -> io::Result<()> {
+    if pretty {
+        serde_json::to_writer_pretty(writer, value)?;
+    } else {
+        serde_json::to_writer(writer, value)?;
+    }
+
+    Ok(())
+}
+
+/// Writes `records` as NDJSON, one compact record per line.
+pub fn write_ndjson<W: Write>(writer: &mut W, records: &[&Value]) -> io::Result<()> {
+    for record in records {
+        write_json(writer, record, false)?;
+        writer.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+/// Placeholder replaced by the shard number in sharded output paths.
+const SHARD_PLACEHOLDER: &str = "{shard}";
+
+/// Placeholder replaced by the entity name in sharded output paths.
+const ENTITY_PLACEHOLDER: &str = "{entity}";
+
+/// Builds the path of a single shard from the `--out` pattern.
+///
+/// The `{shard}` placeholder is replaced by the shard number. When the pattern
+/// does not contain the placeholder, `-{shard}` is inserted before the extension,
+/// so `out.ndjson` becomes `out-0.ndjson`, `out-1.ndjson`, ...
+///
+/// In entities mode every entity has its own shards: the `{entity}` placeholder is
+/// replaced by the entity name or, when absent, the name is put before the shard
+/// number, so `out-{shard}.ndjson` becomes `out-users-0.ndjson`.
+pub fn shard_path(pattern: &Path, entity: Option<&str>, shard: usize) -> PathBuf {
+    let pattern_str = pattern.to_string_lossy();
+    let shard = match entity {
+        Some(entity) if !pattern_str.contains(ENTITY_PLACEHOLDER) => format!("{}-{}", entity, shard),
+        _ => shard.to_string(),
+    };
+    let pattern_str = pattern_str.replace(ENTITY_PLACEHOLDER, entity.unwrap_or("root"));
+    if pattern_str.contains(SHARD_PLACEHOLDER) {
+        return PathBuf::from(pattern_str.replace(SHARD_PLACEHOLDER, &shard));
+    }
+
+    let pattern = Path::new(&pattern_str);
+    let stem = pattern.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+    let file_name = match pattern.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, shard, extension.to_string_lossy()),
+        None => format!("{}-{}", stem, shard),
+    };
+
+    pattern.with_file_name(file_name)
+}
+
+/// Splits the generated value into records for NDJSON, sharded and log line output.
+///
+/// A top-level array yields one record per item, and in entities mode every entity
+/// yields one record per item, in entity order; any other value is a single record.
+pub fn records(value: &Value, entities_mode: bool) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Object(entities) if entities_mode => entities.values()
+            .flat_map(|entity| match entity {
+                Value::Array(items) => items.iter().collect(),
+                item => vec![item],
+            })
+            .collect(),
+        _ => vec![value],
+    }
+}
+
+/// How the records of an entity are split across NDJSON shards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShardSplit {
+    /// Deal the records evenly across this many shards, record `i` going to shard `i % n`
+    Shards(usize),
+    /// Fill shards of at most this many records, one after the other
+    PerFile(usize),
+}
+
+/// Writes records to NDJSON shards as they are generated, each entity to its own shards.
+///
+/// A shard file is created with its first record, so no empty shard is written, and
+/// the shards of an entity are finished as soon as the records of the next one arrive.
+pub struct ShardWriter<'a> {
+    pattern: &'a Path,
+    split: ShardSplit,
+    compression: Option<Compression>,
+    entity: Option<String>,
+    open: Vec<Option<OutputWriter>>,
+    written: usize,
+    paths: Vec<PathBuf>,
+}
+
+impl<'a> ShardWriter<'a> {
+    pub fn new(pattern: &'a Path, split: ShardSplit, compression: Option<Compression>) -> Self {
+        let open = match split {
+            ShardSplit::Shards(shards) => (0..shards.max(1)).map(|_| None).collect(),
+            ShardSplit::PerFile(_) => vec![None],
+        };
+
+        ShardWriter { pattern, split, compression, entity: None, open, written: 0, paths: Vec::new() }
+    }
+
+    /// Writes a record of `entity` (`None` outside entities mode) to its shard.
+    pub fn write(&mut self, entity: Option<&str>, record: &Value) -> io::Result<()> {
+        if self.written > 0 && self.entity.as_deref() != entity {
+            self.finish_open()?;
+            self.written = 0;
+        }
+        self.entity = entity.map(str::to_string);
+
+        let (slot, shard) = match self.split {
+            ShardSplit::Shards(shards) => {
+                let shard = self.written % shards.max(1);
+                (shard, shard)
+            },
+            ShardSplit::PerFile(per_file) => {
+                let per_file = per_file.max(1);
+                if self.written > 0 && self.written.is_multiple_of(per_file) {
+                    self.finish_open()?;
+                }
+                (0, self.written / per_file)
+            },
+        };
+
+        if self.open[slot].is_none() {
+            let path = shard_path(self.pattern, entity, shard);
+            self.open[slot] = Some(OutputWriter::create(&path, self.compression)?);
+            self.paths.push(path);
+        }
+        let writer = self.open[slot].as_mut().unwrap();
+        write_json(writer, record, false)?;
+        writer.write_all(b"\n")?;
+        self.written += 1;
+
+        Ok(())
+    }
+
+    /// Finishes the open shards and returns the paths of every written shard.
+    pub fn finish(mut self) -> io::Result<Vec<PathBuf>> {
+        self.finish_open()?;
+        Ok(self.paths)
+    }
+
+    fn finish_open(&mut self) -> io::Result<()> {
+        for writer in self.open.iter_mut().filter_map(Option::take) {
+            writer.finish()?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the records of a generated value to NDJSON shards, each entity to its own shards.
+///
+/// Returns the paths of the written shards.
+pub fn write_shards(
+    pattern: &Path,
+    value: &Value,
+    entities_mode: bool,
+    split: ShardSplit,
+    compression: Option<Compression>,
+) -> io::Result<Vec<PathBuf>> {
+    let mut writer = ShardWriter::new(pattern, split, compression);
+
+    match value {
+        Value::Object(entities) if entities_mode => {
+            for (name, entity) in entities {
+                for record in records(entity, false) {
+                    writer.write(Some(name), record)?;
+                }
+            }
+        },
+        value => {
+            for record in records(value, false) {
+                writer.write(None, record)?;
+            }
+        },
+    }
+
+    writer.finish()
+}
+
+/// Builds the path of the file of one entity in the output directory.
+///
+/// `users` becomes `users.json`, or `users.json.gz` / `users.json.zst` when compressed.
+pub fn entity_path(dir: &Path, entity: &str, compression: Option<Compression>) -> PathBuf {
+    let extension = match compression {
+        None => "json",
+        Some(Compression::Gzip) => "json.gz",
+        Some(Compression::Zstd) => "json.zst",
+    };
+
+    dir.join(format!("{}.{}", entity, extension))
+}
+
+/// Writes each generated entity to its own JSON file in `dir`, creating the directory.
+///
+/// Returns the paths of the written files.
+pub fn write_entities(
+    dir: &Path,
+    entities: &Map<String, Value>,
+    pretty: bool,
+    compression: Option<Compression>,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut paths = Vec::new();
+
+    for (entity, value) in entities {
+        let path = entity_path(dir, entity, compression);
+        let mut writer = OutputWriter::create(&path, compression)?;
+        write_json(&mut writer, value, pretty)?;
+        writer.finish()?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shard_path_with_placeholder() {
+        let path = shard_path(Path::new("data/out-{shard}.ndjson"), None, 3);
+        assert_eq!(path, PathBuf::from("data/out-3.ndjson"));
+    }
+
+    #[test]
+    fn test_shard_path_without_placeholder() {
+        assert_eq!(shard_path(Path::new("data/out.ndjson"), None, 0), PathBuf::from("data/out-0.ndjson"));
+        assert_eq!(shard_path(Path::new("out"), None, 2), PathBuf::from("out-2"));
+    }
+
+    #[test]
+    fn test_shard_path_of_entity() {
+        assert_eq!(shard_path(Path::new("data/out-{shard}.ndjson"), Some("users"), 1), PathBuf::from("data/out-users-1.ndjson"));
+        assert_eq!(shard_path(Path::new("out.ndjson"), Some("users"), 0), PathBuf::from("out-users-0.ndjson"));
+        assert_eq!(shard_path(Path::new("{entity}/{shard}.ndjson"), Some("posts"), 2), PathBuf::from("posts/2.ndjson"));
+    }
+
+    #[test]
+    fn test_shard_writer_splits_each_entity() {
+        let dir = std::env::temp_dir().join(format!("jgd-shard-writer-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let pattern = dir.join("{entity}-{shard}.ndjson");
+        let read = |name: &str| fs::read_to_string(dir.join(name)).unwrap();
+
+        let mut writer = ShardWriter::new(&pattern, ShardSplit::Shards(2), None);
+        for (entity, id) in [("users", 1), ("users", 2), ("users", 3), ("posts", 4)] {
+            writer.write(Some(entity), &serde_json::json!({ "id": id })).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap().len(), 3);
+        assert_eq!(read("users-0.ndjson"), "{\"id\":1}\n{\"id\":3}\n");
+        assert_eq!(read("users-1.ndjson"), "{\"id\":2}\n");
+        assert_eq!(read("posts-0.ndjson"), "{\"id\":4}\n");
+
+        let mut writer = ShardWriter::new(&pattern, ShardSplit::PerFile(2), None);
+        for id in 1..=3 {
+            writer.write(Some("users"), &serde_json::json!({ "id": id })).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap().len(), 2);
+        assert_eq!(read("users-0.ndjson"), "{\"id\":1}\n{\"id\":2}\n");
+        assert_eq!(read("users-1.ndjson"), "{\"id\":3}\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_meta_path() {
+        assert_eq!(meta_path(Path::new("out/data.json")), PathBuf::from("out/data.meta.json"));
+        assert_eq!(meta_path(Path::new("data.ndjson.gz")), PathBuf::from("data.meta.json"));
+    }
+
+    #[test]
+    fn test_compressed_output_round_trips() {
+        let content = "{\"id\":1}\n{\"id\":2}\n";
+        let path = std::env::temp_dir().join(format!("jgd-compress-{}", std::process::id()));
+
+        for compression in [Compression::Gzip, Compression::Zstd] {
+            let mut writer = OutputWriter::create(&path, Some(compression)).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+            writer.finish().unwrap();
+
+            let file = File::open(&path).unwrap();
+            let decoded = match compression {
+                Compression::Gzip => {
+                    let mut decoded = Vec::new();
+                    io::Read::read_to_end(&mut flate2::read::GzDecoder::new(file), &mut decoded).unwrap();
+                    decoded
+                }
+                Compression::Zstd => zstd::decode_all(file).unwrap(),
+            };
+            assert_eq!(String::from_utf8(decoded).unwrap(), content, "{:?}", compression);
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_entity_path() {
+        assert_eq!(entity_path(Path::new("data"), "users", None), PathBuf::from("data/users.json"));
+        assert_eq!(entity_path(Path::new("data"), "posts", Some(Compression::Zstd)), PathBuf::from("data/posts.json.zst"));
+    }
+
+    #[test]
+    fn test_write_ndjson() {
+        let mut buffer = Vec::new();
+        write_ndjson(&mut buffer, &[&serde_json::json!({"a": 1}), &serde_json::json!([2])]).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "{\"a\":1}\n[2]\n");
+    }
+
+    #[test]
+    fn test_records_from_array_and_object() {
+        let array = serde_json::json!([1, 2, 3]);
+        assert_eq!(records(&array, false).len(), 3);
+
+        let object = serde_json::json!({"a": 1});
+        assert_eq!(records(&object, false).len(), 1);
+
+        let entities = serde_json::json!({ "users": [1, 2], "posts": [3], "settings": { "theme": "dark" } });
+        assert_eq!(records(&entities, true), [&entities["users"][0], &entities["users"][1], &entities["posts"][0], &entities["settings"]]);
+        assert_eq!(records(&entities, false).len(), 1);
+    }
+}
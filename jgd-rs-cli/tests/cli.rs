@@ -0,0 +1,164 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Runs the CLI with `args`, writing `stdin` to its standard input.
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_jgd-rs-cli"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_reads_the_schema_from_stdin_as_ndjson() {
+    let schema = r#"{
+        "$format": "jgd/v1",
+        "version": "1.0",
+        "entities": {
+            "users": { "count": 3, "fields": { "id": "${index}" } },
+            "settings": { "fields": { "theme": "dark" } }
+        }
+    }"#;
+
+    let output = run(&["-", "--format", "ndjson"], schema);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(stdout(&output), "{\"id\":1}\n{\"id\":2}\n{\"id\":3}\n{\"theme\":\"dark\"}\n");
+
+    let output = run(&["-", "--seed", "1"], schema);
+    let document: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(document["users"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_completion_scripts_cover_subcommands_and_flags() {
+    let bash = stdout(&run(&["--completions", "bash"], ""));
+    assert!(bash.contains("jgd__rs__cli__subcmd__generate") && bash.contains("--log-format"), "{}", bash);
+
+    let zsh = stdout(&run(&["--completions", "zsh"], ""));
+    assert!(zsh.starts_with("#compdef jgd-rs-cli") && zsh.contains("--compress"));
+
+    let fish = stdout(&run(&["--completions", "fish"], ""));
+    assert!(fish.contains("-a \"validate\"") && fish.contains("-l format"));
+}
+
+#[test]
+fn test_shards_split_the_records_of_each_entity() {
+    let dir = std::env::temp_dir().join(format!("jgd-shards-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let pattern = dir.join("out-{shard}.ndjson");
+    let schema = r#"{
+        "$format": "jgd/v1",
+        "version": "1.0",
+        "entities": {
+            "users": { "count": 3, "fields": { "id": "${index}" } },
+            "posts": { "count": 3, "fields": { "title": "post" } }
+        }
+    }"#;
+
+    let output = run(&["-", "-o", pattern.to_str().unwrap(), "--shards", "2"], schema);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let shard = |name: &str| std::fs::read_to_string(dir.join(format!("out-{}.ndjson", name))).unwrap();
+    assert_eq!(shard("users-0"), "{\"id\":1}\n{\"id\":3}\n");
+    assert_eq!(shard("users-1"), "{\"id\":2}\n");
+    assert_eq!(shard("posts-0"), "{\"title\":\"post\"}\n".repeat(2));
+    assert_eq!(shard("posts-1"), "{\"title\":\"post\"}\n");
+
+    // Buffered output (--canonical) is sharded the same way
+    for args in [&["--max-records-per-file", "2"][..], &["--max-records-per-file", "2", "--canonical"]] {
+        let output = run(&[&["-", "-o", pattern.to_str().unwrap()][..], args].concat(), schema);
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        assert_eq!(shard("users-0"), "{\"id\":1}\n{\"id\":2}\n");
+        assert_eq!(shard("users-1"), "{\"id\":3}\n");
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let output = run(&["-", "-o", "out.ndjson", "--shards", "0"], schema);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid value '0' for '--shards <SHARDS>'"));
+}
+
+#[test]
+fn test_seed_overrides_the_schema_seed() {
+    let schema = r#"{
+        "$format": "jgd/v1",
+        "version": "1.0",
+        "seed": 7,
+        "root": { "fields": { "id": "${uuid.v4}" } }
+    }"#;
+
+    let seeded = |seed: &str| stdout(&run(&["-", "--seed", seed], schema));
+    assert_eq!(seeded("1"), seeded("1"));
+    assert_ne!(seeded("1"), seeded("2"));
+    assert_ne!(seeded("1"), stdout(&run(&["-"], schema)));
+}
+
+#[test]
+fn test_preview_renders_templates_in_the_locale() {
+    let template = "${name.firstName} ${name.lastName} <${internet.safeEmail}>";
+    let preview = |locale: &str| stdout(&run(&["preview", template, "--locale", locale, "-n", "3", "--seed", "42"], ""));
+
+    let german = preview("de_DE");
+    assert_eq!(german, preview("de_DE"));
+    assert_ne!(german, preview("EN"));
+    let lines: Vec<&str> = german.lines().collect();
+    assert_eq!(lines.len(), 3, "{}", german);
+    for line in lines {
+        let (name, email) = line.split_once(" <").unwrap();
+        assert_eq!(name.split(' ').count(), 2, "{}", line);
+        assert!(email.ends_with(".com>") || email.ends_with(".net>"), "{}", line);
+    }
+
+    let titled = stdout(&run(&["preview", "name.nameWithTitle", "--locale", "de_DE", "-n", "5"], ""));
+    assert!(titled.lines().all(|line| line.starts_with("Herr ") || line.starts_with("Frau ")), "{}", titled);
+
+    let output = run(&["preview", "${nope.key}"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_mask_reports_unreadable_masks() {
+    let dir = std::env::temp_dir().join(format!("jgd-mask-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let bad = dir.join("bad.jgd");
+    std::fs::write(&bad, "{ not json").unwrap();
+
+    for mask in [bad, dir.join("missing.jgd")] {
+        let output = run(&["mask", "-", mask.to_str().unwrap()], "{\"name\":\"Alice\"}");
+        assert_eq!(output.status.code(), Some(1));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Error to"), "{:?}", output);
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_every_subcommand_exits_with_failure_on_errors() {
+    let schema = r#"{
+        "$format": "jgd/v1",
+        "version": "1.0",
+        "entities": { "users": { "count": 1, "fields": { "id": "${index}" } } }
+    }"#;
+
+    for args in [
+        &["explain", "missing.jgd"][..],
+        &["graph", "missing.jgd"],
+        &["validate", "missing.jgd"],
+        &["generate", "missing.jgd"],
+        &["missing.jgd"],
+    ] {
+        let output = run(args, "");
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Error to"), "{:?}", output);
+    }
+
+    let output = run(&["-", "--only", "nope"], schema);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,40 @@
+//! Builders used by the code generated by `#[derive(JgdSchema)]`.
+//!
+//! Not part of the public API.
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::{ArraySpec, Count, Entity, Field, NumberSpec, OptionalSpec};
+
+pub fn template(value: &str) -> Field {
+    serde_json::from_value(Value::String(value.to_string()))
+        .unwrap_or_else(|_| Field::Str(value.to_string()))
+}
+
+pub fn number(min: f64, max: f64, integer: bool) -> Field {
+    let number = if integer { NumberSpec::new_integer(min, max) } else { NumberSpec::new_float(min, max) };
+    Field::Number { number }
+}
+
+pub fn optional(of: Field, prob: f64) -> Field {
+    Field::Optional { optional: OptionalSpec { of: Box::new(of), prob } }
+}
+
+pub fn array(of: Field, count: Count) -> Field {
+    Field::Array { array: ArraySpec { of: Box::new(of), count: Some(count), prob_null: None, prob_empty: None, target_size: None } }
+}
+
+pub fn nested(entity: Entity) -> Field {
+    Field::Entity(entity)
+}
+
+pub fn entity(count: Option<Count>, fields: Vec<(&str, Field)>) -> Entity {
+    Entity {
+        count,
+        fields: fields.into_iter()
+            .map(|(name, field)| (name.to_string(), field))
+            .collect::<IndexMap<_, _>>(),
+        ..Default::default()
+    }
+}
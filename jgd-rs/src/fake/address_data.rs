@@ -0,0 +1,187 @@
+//! Coherent address samples per locale.
+//!
+//! The `address.*` fake keys are independent, so a generated city rarely belongs to
+//! the generated state or country. [`AddressRecord`] ties a country, state, city and
+//! postal code pattern together, for entity correlations and the `address` field.
+
+use rand::{rngs::StdRng, Rng};
+
+use crate::{fake::FakeKeys, locales_keys::LocalesKeys};
+
+/// One coherent place: a city with its state, country and postal code pattern.
+pub(crate) struct AddressRecord {
+    pub(crate) country: &'static str,
+    pub(crate) country_code: &'static str,
+    pub(crate) state: &'static str,
+    pub(crate) state_abbr: &'static str,
+    pub(crate) city: &'static str,
+    /// Postal code pattern: `#` is any digit and `?` any uppercase letter.
+    pub(crate) zip: &'static str,
+}
+
+/// Fake keys a record can provide, usable as correlation values.
+pub(crate) const ATTRIBUTES: &[&str] = &[
+    FakeKeys::ADDRESS_COUNTRY_NAME,
+    FakeKeys::ADDRESS_COUNTRY_CODE,
+    FakeKeys::ADDRESS_STATE_NAME,
+    FakeKeys::ADDRESS_STATE_ABBR,
+    FakeKeys::ADDRESS_CITY_NAME,
+    FakeKeys::ADDRESS_ZIP_CODE,
+    FakeKeys::ADDRESS_POST_CODE,
+];
+
+const fn record(
+    country: &'static str,
+    country_code: &'static str,
+    state: &'static str,
+    state_abbr: &'static str,
+    city: &'static str,
+    zip: &'static str,
+) -> AddressRecord {
+    AddressRecord { country, country_code, state, state_abbr, city, zip }
+}
+
+const EN: &[AddressRecord] = &[
+    record("United States", "US", "New York", "NY", "New York", "100##"),
+    record("United States", "US", "California", "CA", "Los Angeles", "900##"),
+    record("United States", "US", "California", "CA", "San Francisco", "941##"),
+    record("United States", "US", "Texas", "TX", "Austin", "787##"),
+    record("United States", "US", "Illinois", "IL", "Chicago", "606##"),
+    record("United States", "US", "Washington", "WA", "Seattle", "981##"),
+];
+
+const FR_FR: &[AddressRecord] = &[
+    record("France", "FR", "Île-de-France", "IDF", "Paris", "750##"),
+    record("France", "FR", "Auvergne-Rhône-Alpes", "ARA", "Lyon", "6900#"),
+    record("France", "FR", "Provence-Alpes-Côte d'Azur", "PAC", "Marseille", "130##"),
+    record("France", "FR", "Occitanie", "OCC", "Toulouse", "310##"),
+];
+
+const IT_IT: &[AddressRecord] = &[
+    record("Italia", "IT", "Lazio", "RM", "Roma", "001##"),
+    record("Italia", "IT", "Lombardia", "MI", "Milano", "201##"),
+    record("Italia", "IT", "Campania", "NA", "Napoli", "801##"),
+    record("Italia", "IT", "Piemonte", "TO", "Torino", "101##"),
+];
+
+const JA_JP: &[AddressRecord] = &[
+    record("日本", "JP", "東京都", "13", "千代田区", "100-####"),
+    record("日本", "JP", "大阪府", "27", "大阪市", "530-####"),
+    record("日本", "JP", "北海道", "01", "札幌市", "060-####"),
+    record("日本", "JP", "愛知県", "23", "名古屋市", "450-####"),
+];
+
+const DE_DE: &[AddressRecord] = &[
+    record("Deutschland", "DE", "Berlin", "BE", "Berlin", "10###"),
+    record("Deutschland", "DE", "Bayern", "BY", "München", "80###"),
+    record("Deutschland", "DE", "Hamburg", "HH", "Hamburg", "20###"),
+    record("Deutschland", "DE", "Hessen", "HE", "Frankfurt am Main", "60###"),
+    record("Deutschland", "DE", "Nordrhein-Westfalen", "NW", "Köln", "50###"),
+];
+
+const PT_BR: &[AddressRecord] = &[
+    record("Brasil", "BR", "São Paulo", "SP", "São Paulo", "01###-###"),
+    record("Brasil", "BR", "Rio de Janeiro", "RJ", "Rio de Janeiro", "20###-###"),
+    record("Brasil", "BR", "Minas Gerais", "MG", "Belo Horizonte", "30###-###"),
+    record("Brasil", "BR", "Bahia", "BA", "Salvador", "40###-###"),
+    record("Brasil", "BR", "Distrito Federal", "DF", "Brasília", "70###-###"),
+];
+
+const AR_SA: &[AddressRecord] = &[
+    record("المملكة العربية السعودية", "SA", "منطقة الرياض", "01", "الرياض", "11###"),
+    record("المملكة العربية السعودية", "SA", "منطقة مكة المكرمة", "02", "جدة", "21###"),
+    record("المملكة العربية السعودية", "SA", "منطقة مكة المكرمة", "02", "مكة المكرمة", "24###"),
+    record("المملكة العربية السعودية", "SA", "المنطقة الشرقية", "04", "الدمام", "32###"),
+];
+
+const CY_GB: &[AddressRecord] = &[
+    record("Y Deyrnas Unedig", "GB", "Cymru", "WLS", "Caerdydd", "CF1# #??"),
+    record("Y Deyrnas Unedig", "GB", "Cymru", "WLS", "Abertawe", "SA1 #??"),
+    record("Y Deyrnas Unedig", "GB", "Cymru", "WLS", "Casnewydd", "NP2# #??"),
+    record("Y Deyrnas Unedig", "GB", "Cymru", "WLS", "Bangor", "LL57 #??"),
+];
+
+const ALL: &[&[AddressRecord]] = &[EN, FR_FR, IT_IT, JA_JP, DE_DE, PT_BR, AR_SA, CY_GB];
+
+/// Returns the records of `locale`, falling back to `EN` like [`FakeGenerator`](crate::FakeGenerator).
+pub(crate) fn records(locale: &str) -> &'static [AddressRecord] {
+    match LocalesKeys::from(locale) {
+        LocalesKeys::En => EN,
+        LocalesKeys::FrFr => FR_FR,
+        LocalesKeys::ItIt => IT_IT,
+        LocalesKeys::JaJp => JA_JP,
+        LocalesKeys::DeDe => DE_DE,
+        LocalesKeys::PtBr => PT_BR,
+        LocalesKeys::ArSa => AR_SA,
+        LocalesKeys::CyGb => CY_GB,
+    }
+}
+
+/// Returns the records of every locale.
+pub(crate) fn all_records() -> impl Iterator<Item = &'static AddressRecord> {
+    ALL.iter().flat_map(|records| records.iter())
+}
+
+impl AddressRecord {
+    /// Generates the value of `attribute`, filling the postal code pattern with `rng`.
+    pub(crate) fn value(&self, attribute: &str, rng: &mut StdRng) -> Option<String> {
+        match attribute {
+            FakeKeys::ADDRESS_ZIP_CODE | FakeKeys::ADDRESS_POST_CODE => Some(self.zip_code(rng)),
+            _ => self.value_of(attribute).map(str::to_string),
+        }
+    }
+
+    /// Whether `value` is the value of `attribute` in this record, or fits its postal code pattern.
+    pub(crate) fn matches(&self, attribute: &str, value: &str) -> bool {
+        match attribute {
+            FakeKeys::ADDRESS_ZIP_CODE | FakeKeys::ADDRESS_POST_CODE => {
+                self.zip.chars().count() == value.chars().count()
+                    && self.zip.chars().zip(value.chars()).all(|(pattern, char)| match pattern {
+                        '#' => char.is_ascii_digit(),
+                        '?' => char.is_ascii_uppercase(),
+                        _ => pattern == char,
+                    })
+            },
+            _ => self.value_of(attribute) == Some(value),
+        }
+    }
+
+    fn value_of(&self, attribute: &str) -> Option<&'static str> {
+        match attribute {
+            FakeKeys::ADDRESS_COUNTRY_NAME => Some(self.country),
+            FakeKeys::ADDRESS_COUNTRY_CODE => Some(self.country_code),
+            FakeKeys::ADDRESS_STATE_NAME => Some(self.state),
+            FakeKeys::ADDRESS_STATE_ABBR => Some(self.state_abbr),
+            FakeKeys::ADDRESS_CITY_NAME => Some(self.city),
+            _ => None,
+        }
+    }
+
+    fn zip_code(&self, rng: &mut StdRng) -> String {
+        self.zip.chars().map(|char| match char {
+            '#' => char::from(b'0' + rng.random_range(0..10u8)),
+            '?' => char::from(b'A' + rng.random_range(0..26u8)),
+            _ => char,
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_zip_codes_fit_their_pattern() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for record in all_records() {
+            let zip = record.value(FakeKeys::ADDRESS_ZIP_CODE, &mut rng).unwrap();
+            assert!(record.matches(FakeKeys::ADDRESS_ZIP_CODE, &zip), "{} for {}", zip, record.city);
+        }
+
+        let paris = &records("FR_FR")[0];
+        assert!(paris.matches(FakeKeys::ADDRESS_CITY_NAME, "Paris"));
+        assert!(!paris.matches(FakeKeys::ADDRESS_ZIP_CODE, "69001"));
+        assert_eq!(paris.value("address.timeZone", &mut rng), None);
+    }
+}
@@ -0,0 +1,99 @@
+//! Working hours, working days and holidays of the `chrono.businessDateTime` key.
+
+use chrono::{Datelike, DateTime, Duration, NaiveDate, Utc, Weekday};
+use rand::{rngs::StdRng, Rng};
+
+use crate::Arguments;
+
+/// Format of the holiday arguments.
+pub(crate) const HOLIDAY_FORMAT: &str = "%Y-%m-%d";
+
+/// Business time of `chrono.businessDateTime(startHour..endHour, firstDay..lastDay, holidays...)`.
+///
+/// Times are in UTC, from `start_hour` (inclusive) to `end_hour` (exclusive), on the
+/// days from `first_day` to `last_day` (wrapping around the week, so `Fri..Mon` is
+/// valid) that are not holidays.
+pub(crate) struct BusinessHours {
+    start_hour: u32,
+    end_hour: u32,
+    first_day: Weekday,
+    last_day: Weekday,
+    holidays: Vec<NaiveDate>,
+}
+
+impl BusinessHours {
+    /// Reads the business time from the key arguments; missing values default to `9..17, Mon..Fri`.
+    pub(crate) fn from_arguments(arguments: &Arguments) -> Self {
+        Self {
+            start_hour: arguments.get_num(0).unwrap_or(9),
+            end_hour: arguments.get_num(1).unwrap_or(17),
+            first_day: arguments.get_num(2).unwrap_or(Weekday::Mon),
+            last_day: arguments.get_num(3).unwrap_or(Weekday::Fri),
+            holidays: arguments.values().iter().skip(4)
+                .filter_map(|holiday| NaiveDate::parse_from_str(holiday, HOLIDAY_FORMAT).ok())
+                .collect(),
+        }
+    }
+
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        let day = date.weekday().num_days_from_monday();
+        let (first, last) = (self.first_day.num_days_from_monday(), self.last_day.num_days_from_monday());
+        let working = match first <= last {
+            true => first <= day && day <= last,
+            false => day >= first || day <= last,
+        };
+        working && !self.holidays.contains(&date)
+    }
+
+    /// Picks a business time on one of the whole days between `start` and `end`.
+    ///
+    /// Returns `None` when no business time falls in the period.
+    pub(crate) fn date_time_between(&self, rng: &mut StdRng, start: DateTime<Utc>, end: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.start_hour >= self.end_hour || self.end_hour > 24 {
+            return None;
+        }
+
+        let days: Vec<NaiveDate> = start.date_naive().iter_days()
+            .skip(1)
+            .take_while(|date| *date < end.date_naive())
+            .filter(|date| self.is_business_day(*date))
+            .collect();
+        if days.is_empty() {
+            return None;
+        }
+        let date = days[rng.random_range(0..days.len())];
+
+        let seconds = rng.random_range(self.start_hour as i64 * 3600..self.end_hour as i64 * 3600);
+        Some(date.and_hms_opt(0, 0, 0)?.and_utc() + Duration::seconds(seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_business_time_skips_nights_weekends_and_holidays() {
+        let hours = BusinessHours::from_arguments(&Arguments::from("(9..17, Mon..Fri, 2025-03-05)"));
+        let start = "2025-03-01T00:00:00Z".parse().unwrap();
+        let end = "2025-03-15T00:00:00Z".parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for _ in 0..200 {
+            let time = hours.date_time_between(&mut rng, start, end).unwrap();
+            assert!((9..17).contains(&time.hour()), "{}", time);
+            assert!(time.weekday().num_days_from_monday() < 5, "{}", time);
+            assert_ne!(time.date_naive(), NaiveDate::from_ymd_opt(2025, 3, 5).unwrap());
+        }
+
+        let weekend = BusinessHours::from_arguments(&Arguments::from("(22..24, Sat..Sun)"));
+        let time = weekend.date_time_between(&mut rng, start, end).unwrap();
+        assert!(time.hour() >= 22 && time.weekday().num_days_from_monday() >= 5, "{}", time);
+
+        let none = BusinessHours::from_arguments(&Arguments::from("(17..9)"));
+        assert_eq!(none.date_time_between(&mut rng, start, end), None);
+    }
+}
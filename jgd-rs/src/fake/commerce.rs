@@ -0,0 +1,182 @@
+//! Product catalog words of the `commerce.*` keys, per locale.
+//!
+//! The `fake` crate has no product data, so e-commerce fixtures used to combine
+//! `company.buzzword` values. [`CommerceWords`] holds curated word lists for each locale
+//! with a product name pattern that keeps the local word order and agreement:
+//! `Ergonomic Wooden Chair`, `Chaise ergonomique en bois`, `Komfort-Stuhl aus Holz`.
+//! `CY_GB` has no lists and uses the English ones.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::Value;
+
+use super::pick;
+use crate::locales_keys::LocalesKeys;
+
+/// Word lists of the commerce keys for one locale.
+struct CommerceWords {
+    /// Product name pattern with `{adjective}`, `{material}` and `{product}` placeholders.
+    product_name: &'static str,
+    adjectives: &'static [&'static str],
+    materials: &'static [&'static str],
+    products: &'static [&'static str],
+    departments: &'static [&'static str],
+    /// Price tiers, from the cheapest to the most expensive.
+    price_tiers: &'static [&'static str],
+}
+
+const EN: CommerceWords = CommerceWords {
+    product_name: "{adjective} {material} {product}",
+    adjectives: &["Ergonomic", "Compact", "Rustic", "Sleek", "Durable", "Handcrafted", "Lightweight", "Premium",
+        "Smart", "Vintage"],
+    materials: &["Wooden", "Steel", "Cotton", "Leather", "Ceramic", "Bamboo", "Glass", "Wool"],
+    products: &["Chair", "Lamp", "Backpack", "Table", "Mug", "Jacket", "Headphones", "Watch", "Blanket", "Shoes"],
+    departments: &["Electronics", "Books", "Home & Kitchen", "Garden", "Toys", "Clothing", "Sports & Outdoors",
+        "Beauty", "Grocery", "Automotive"],
+    price_tiers: &["budget", "standard", "premium", "luxury"],
+};
+
+/// Adjectives with the same masculine and feminine forms, and singular products.
+const FR_FR: CommerceWords = CommerceWords {
+    product_name: "{product} {adjective} {material}",
+    adjectives: &["ergonomique", "pratique", "moderne", "robuste", "confortable", "durable", "magnifique",
+        "rustique"],
+    materials: &["en bois", "en acier", "en coton", "en cuir", "en céramique", "en bambou", "en verre", "en laine"],
+    products: &["Chaise", "Lampe", "Sac à dos", "Table", "Tasse", "Veste", "Casque", "Montre", "Couverture",
+        "Étagère"],
+    departments: &["Électronique", "Livres", "Maison et cuisine", "Jardin", "Jouets", "Vêtements",
+        "Sports et loisirs", "Beauté", "Épicerie", "Auto et moto"],
+    price_tiers: &["économique", "standard", "premium", "luxe"],
+};
+
+/// Adjectives ending in `-e`, the same for both genders, and singular products.
+const IT_IT: CommerceWords = CommerceWords {
+    product_name: "{product} {adjective} {material}",
+    adjectives: &["elegante", "resistente", "sostenibile", "confortevole", "intelligente", "eccezionale",
+        "versatile", "affidabile"],
+    materials: &["in legno", "in acciaio", "in cotone", "in pelle", "in ceramica", "in bambù", "in vetro", "in lana"],
+    products: &["Sedia", "Lampada", "Zaino", "Tavolo", "Tazza", "Giacca", "Borsa", "Orologio", "Coperta",
+        "Mensola"],
+    departments: &["Elettronica", "Libri", "Casa e cucina", "Giardino", "Giochi", "Abbigliamento",
+        "Sport e tempo libero", "Bellezza", "Alimentari", "Auto e moto"],
+    price_tiers: &["economico", "standard", "premium", "lusso"],
+};
+
+const JA_JP: CommerceWords = CommerceWords {
+    product_name: "{adjective}{material}{product}",
+    adjectives: &["高級", "軽量", "多機能", "北欧風", "コンパクト", "プレミアム", "ヴィンテージ", "スマート"],
+    materials: &["木製", "ステンレス製", "コットン", "本革", "陶器", "竹製", "ガラス製", "ウール"],
+    products: &["チェア", "ランプ", "リュック", "テーブル", "マグカップ", "ジャケット", "ヘッドホン", "腕時計",
+        "ブランケット", "スニーカー"],
+    departments: &["家電", "本", "ホーム＆キッチン", "ガーデン", "おもちゃ", "ファッション", "スポーツ＆アウトドア",
+        "ビューティー", "食品", "車＆バイク"],
+    price_tiers: &["お手頃", "スタンダード", "プレミアム", "ラグジュアリー"],
+};
+
+/// Adjectives as compound prefixes, which do not decline.
+const DE_DE: CommerceWords = CommerceWords {
+    product_name: "{adjective}-{product} {material}",
+    adjectives: &["Komfort", "Design", "Premium", "Profi", "Öko", "Retro", "Kompakt", "Outdoor"],
+    materials: &["aus Holz", "aus Stahl", "aus Baumwolle", "aus Leder", "aus Keramik", "aus Bambus", "aus Glas",
+        "aus Wolle"],
+    products: &["Stuhl", "Lampe", "Rucksack", "Tisch", "Tasse", "Jacke", "Kopfhörer", "Uhr", "Decke", "Schuhe"],
+    departments: &["Elektronik", "Bücher", "Küche & Haushalt", "Garten", "Spielzeug", "Bekleidung",
+        "Sport & Freizeit", "Beauty", "Lebensmittel", "Auto & Motorrad"],
+    price_tiers: &["günstig", "standard", "premium", "luxus"],
+};
+
+/// Adjectives with the same masculine and feminine forms.
+const PT_BR: CommerceWords = CommerceWords {
+    product_name: "{product} {adjective} {material}",
+    adjectives: &["Elegante", "Resistente", "Sustentável", "Confortável", "Inteligente", "Versátil", "Incrível",
+        "Leve"],
+    materials: &["de Madeira", "de Aço", "de Algodão", "de Couro", "de Cerâmica", "de Bambu", "de Vidro", "de Lã"],
+    products: &["Cadeira", "Luminária", "Mochila", "Mesa", "Caneca", "Jaqueta", "Fone de Ouvido", "Relógio",
+        "Cobertor", "Tênis"],
+    departments: &["Eletrônicos", "Livros", "Casa e Cozinha", "Jardim", "Brinquedos", "Moda", "Esporte e Lazer",
+        "Beleza", "Mercado", "Automotivo"],
+    price_tiers: &["econômico", "padrão", "premium", "luxo"],
+};
+
+/// Masculine products, so that the adjectives agree.
+const AR_SA: CommerceWords = CommerceWords {
+    product_name: "{product} {adjective} {material}",
+    adjectives: &["أنيق", "فاخر", "عملي", "متين", "مريح", "ذكي", "خفيف", "عصري"],
+    materials: &["من الخشب", "من الفولاذ", "من القطن", "من الجلد", "من السيراميك", "من الخيزران", "من الزجاج",
+        "من الصوف"],
+    products: &["كرسي", "مصباح", "قميص", "مكتب", "كوب", "معطف", "حذاء", "هاتف", "غطاء", "حاسوب"],
+    departments: &["الإلكترونيات", "الكتب", "المنزل والمطبخ", "الحديقة", "الألعاب", "الأزياء", "الرياضة",
+        "الجمال", "البقالة", "السيارات"],
+    price_tiers: &["اقتصادي", "قياسي", "مميز", "فاخر"],
+};
+
+/// Returns the word lists of `locale`, falling back to `EN` like [`FakeGenerator`](crate::FakeGenerator).
+fn words(locale: &str) -> &'static CommerceWords {
+    match LocalesKeys::from(locale) {
+        LocalesKeys::FrFr => &FR_FR,
+        LocalesKeys::ItIt => &IT_IT,
+        LocalesKeys::JaJp => &JA_JP,
+        LocalesKeys::DeDe => &DE_DE,
+        LocalesKeys::PtBr => &PT_BR,
+        LocalesKeys::ArSa => &AR_SA,
+        LocalesKeys::En | LocalesKeys::CyGb => &EN,
+    }
+}
+
+/// Generates `commerce.productName`, such as `Rustic Bamboo Lamp`.
+pub(crate) fn product_name(locale: &str, rng: &mut StdRng) -> Value {
+    let words = words(locale);
+    let adjective = pick(words.adjectives, rng);
+    let material = pick(words.materials, rng);
+    let product = pick(words.products, rng);
+    Value::String(words.product_name
+        .replace("{adjective}", adjective)
+        .replace("{material}", material)
+        .replace("{product}", product))
+}
+
+/// Generates `commerce.department`, such as `Home & Kitchen`.
+pub(crate) fn department(locale: &str, rng: &mut StdRng) -> Value {
+    Value::String(pick(words(locale).departments, rng).to_string())
+}
+
+/// Generates `commerce.priceTier`, such as `premium`.
+pub(crate) fn price_tier(locale: &str, rng: &mut StdRng) -> Value {
+    Value::String(pick(words(locale).price_tiers, rng).to_string())
+}
+
+/// Generates `commerce.sku`: three uppercase letters, five digits and a two-letter
+/// variant, such as `KTB-04821-BL`.
+pub(crate) fn sku(rng: &mut StdRng) -> Value {
+    let mut letters = |count: usize| -> String {
+        (0..count).map(|_| char::from(b'A' + rng.random_range(0..26u8))).collect()
+    };
+    let prefix = letters(3);
+    let variant = letters(2);
+    Value::String(format!("{}-{:05}-{}", prefix, rng.random_range(0..100_000), variant))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_commerce_words_per_locale() {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let name = product_name("EN", &mut rng);
+        assert_eq!(name.as_str().unwrap().split(' ').count(), 3);
+        let name = product_name("DE_DE", &mut rng);
+        assert!(name.as_str().unwrap().contains("-") && name.as_str().unwrap().contains(" aus "), "{}", name);
+        assert!(DE_DE.departments.contains(&department("DE_DE", &mut rng).as_str().unwrap()));
+        assert!(EN.price_tiers.contains(&price_tier("CY_GB", &mut rng).as_str().unwrap()));
+
+        for _ in 0..20 {
+            let sku = sku(&mut rng);
+            let sku = sku.as_str().unwrap();
+            assert_eq!(sku.len(), "KTB-04821-BL".len());
+            assert!(sku[4..9].bytes().all(|byte| byte.is_ascii_digit()), "{}", sku);
+        }
+    }
+}
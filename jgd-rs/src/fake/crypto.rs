@@ -0,0 +1,95 @@
+//! Blockchain identifiers of the `crypto.*` keys, built from random bytes with the
+//! checksums wallets and libraries verify.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+/// Alphabet of Base58 (Bitcoin), without `0`, `O`, `I` and `l`.
+const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Version byte of the Bitcoin mainnet pay-to-public-key-hash addresses.
+const BTC_P2PKH_VERSION: u8 = 0x00;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Encodes bytes in Base58, with a `1` per leading zero byte.
+fn base58(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+    let mut digits: Vec<u8> = Vec::new();
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    std::iter::repeat_n(b'1', zeros)
+        .chain(digits.iter().rev().map(|digit| BASE58[usize::from(*digit)]))
+        .map(char::from)
+        .collect()
+}
+
+/// Applies the EIP-55 mixed-case checksum to a lowercase hex address without `0x`:
+/// a letter is uppercase when the matching nibble of its Keccak-256 hash is 8 or more.
+fn eip55(address: &str) -> String {
+    let hash = Keccak256::digest(address.as_bytes());
+    address.chars().enumerate().map(|(index, char)| {
+        let nibble = (hash[index / 2] >> (4 * (1 - index % 2))) & 0x0f;
+        if nibble >= 8 { char.to_ascii_uppercase() } else { char }
+    }).collect()
+}
+
+/// Generates `crypto.ethAddress`: `0x` and 40 hex digits with the EIP-55 checksum.
+pub(crate) fn eth_address(rng: &mut StdRng) -> Value {
+    let bytes: [u8; 20] = rng.random();
+    Value::String(format!("0x{}", eip55(&hex(&bytes))))
+}
+
+/// Generates `crypto.btcAddress`: a legacy (P2PKH) mainnet address, Base58Check
+/// encoded with a valid checksum, such as `1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2`.
+pub(crate) fn btc_address(rng: &mut StdRng) -> Value {
+    let mut payload = vec![BTC_P2PKH_VERSION];
+    payload.extend(rng.random::<[u8; 20]>());
+    let checksum = Sha256::digest(Sha256::digest(&payload));
+    payload.extend(&checksum[..4]);
+    Value::String(base58(&payload))
+}
+
+/// Generates `crypto.txHash`: `0x` and 64 hex digits, the shape of an Ethereum
+/// transaction hash.
+pub(crate) fn tx_hash(rng: &mut StdRng) -> Value {
+    let bytes: [u8; 32] = rng.random();
+    Value::String(format!("0x{}", hex(&bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_crypto_checksums() {
+        assert_eq!(eip55("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"), "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert_eq!(base58(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+        assert_eq!(base58(&[0, 0, 0x28, 0x7f]), "1145k");
+
+        let mut rng = StdRng::seed_from_u64(8);
+        let address = btc_address(&mut rng);
+        let address = address.as_str().unwrap();
+        assert!(address.starts_with('1') && (26..=34).contains(&address.len()), "{}", address);
+
+        assert_eq!(eth_address(&mut rng).as_str().unwrap().len(), 42);
+        assert_eq!(tx_hash(&mut rng).as_str().unwrap().len(), 66);
+    }
+}
@@ -0,0 +1,137 @@
+//! Universities, degrees and fields of study of the `education.*` keys, per locale.
+
+use rand::rngs::StdRng;
+use serde_json::Value;
+
+use super::pick;
+use crate::locales_keys::LocalesKeys;
+
+/// Word lists of the education keys for one locale.
+struct EducationWords {
+    universities: &'static [&'static str],
+    degrees: &'static [&'static str],
+    fields_of_study: &'static [&'static str],
+}
+
+const EN: EducationWords = EducationWords {
+    universities: &["Harvard University", "Stanford University", "Massachusetts Institute of Technology",
+        "University of California, Berkeley", "University of Michigan", "Columbia University", "Yale University",
+        "Princeton University", "University of Chicago", "University of Texas at Austin"],
+    degrees: &["Associate of Arts", "Bachelor of Arts", "Bachelor of Science", "Bachelor of Engineering",
+        "Master of Arts", "Master of Science", "Master of Business Administration", "Doctor of Philosophy"],
+    fields_of_study: &["Computer Science", "Mechanical Engineering", "Economics", "Psychology", "Biology",
+        "Mathematics", "History", "Nursing", "Business Administration", "Physics", "Political Science", "Chemistry"],
+};
+
+const FR_FR: EducationWords = EducationWords {
+    universities: &["Sorbonne Université", "Université Paris Cité", "Université Paris-Saclay", "Aix-Marseille Université",
+        "Université de Bordeaux", "Université de Strasbourg", "Université Claude Bernard Lyon 1",
+        "Université Toulouse III - Paul Sabatier", "École polytechnique", "Sciences Po"],
+    degrees: &["BTS", "Licence", "Licence professionnelle", "Master", "Diplôme d'ingénieur", "Doctorat"],
+    fields_of_study: &["Informatique", "Génie mécanique", "Économie", "Psychologie", "Biologie", "Mathématiques",
+        "Histoire", "Droit", "Gestion", "Physique", "Science politique", "Chimie"],
+};
+
+const IT_IT: EducationWords = EducationWords {
+    universities: &["Università di Bologna", "Sapienza Università di Roma", "Politecnico di Milano",
+        "Università degli Studi di Milano", "Università di Padova", "Università di Pisa",
+        "Università degli Studi di Napoli Federico II", "Università di Torino", "Politecnico di Torino",
+        "Università Bocconi"],
+    degrees: &["Laurea triennale", "Laurea magistrale", "Laurea magistrale a ciclo unico", "Master di primo livello",
+        "Dottorato di ricerca"],
+    fields_of_study: &["Informatica", "Ingegneria meccanica", "Economia", "Psicologia", "Biologia", "Matematica",
+        "Storia", "Giurisprudenza", "Medicina e chirurgia", "Fisica", "Scienze politiche", "Chimica"],
+};
+
+const JA_JP: EducationWords = EducationWords {
+    universities: &["東京大学", "京都大学", "大阪大学", "東北大学", "名古屋大学", "九州大学", "北海道大学", "一橋大学",
+        "早稲田大学", "慶應義塾大学"],
+    degrees: &["学士（文学）", "学士（理学）", "学士（工学）", "修士（経済学）", "修士（工学）", "博士（理学）", "博士（医学）"],
+    fields_of_study: &["情報科学", "機械工学", "経済学", "心理学", "生物学", "数学", "歴史学", "法学", "医学", "物理学",
+        "政治学", "化学"],
+};
+
+const DE_DE: EducationWords = EducationWords {
+    universities: &["Ludwig-Maximilians-Universität München", "Technische Universität München",
+        "Humboldt-Universität zu Berlin", "Freie Universität Berlin", "Universität Heidelberg", "RWTH Aachen",
+        "Universität Hamburg", "Universität zu Köln", "Goethe-Universität Frankfurt",
+        "Karlsruher Institut für Technologie"],
+    degrees: &["Bachelor of Arts", "Bachelor of Science", "Master of Arts", "Master of Science", "Diplom",
+        "Staatsexamen", "Promotion"],
+    fields_of_study: &["Informatik", "Maschinenbau", "Volkswirtschaftslehre", "Psychologie", "Biologie", "Mathematik",
+        "Geschichte", "Rechtswissenschaft", "Betriebswirtschaftslehre", "Physik", "Politikwissenschaft", "Chemie"],
+};
+
+const PT_BR: EducationWords = EducationWords {
+    universities: &["Universidade de São Paulo", "Universidade Estadual de Campinas",
+        "Universidade Federal do Rio de Janeiro", "Universidade Federal de Minas Gerais",
+        "Universidade Federal do Rio Grande do Sul", "Universidade de Brasília", "Universidade Federal da Bahia",
+        "Pontifícia Universidade Católica do Rio de Janeiro", "Universidade Federal de Pernambuco",
+        "Universidade Estadual Paulista"],
+    degrees: &["Tecnólogo", "Bacharelado", "Licenciatura", "Especialização", "Mestrado", "Doutorado"],
+    fields_of_study: &["Ciência da Computação", "Engenharia Mecânica", "Economia", "Psicologia", "Biologia",
+        "Matemática", "História", "Direito", "Administração", "Física", "Ciência Política", "Química"],
+};
+
+const AR_SA: EducationWords = EducationWords {
+    universities: &["جامعة الملك سعود", "جامعة الملك عبد العزيز", "جامعة الملك فهد للبترول والمعادن",
+        "جامعة الملك عبد الله للعلوم والتقنية", "جامعة أم القرى", "جامعة الإمام محمد بن سعود الإسلامية",
+        "جامعة الأميرة نورة بنت عبد الرحمن", "جامعة الملك فيصل", "جامعة القصيم", "جامعة طيبة"],
+    degrees: &["دبلوم", "بكالوريوس", "ماجستير", "دكتوراه"],
+    fields_of_study: &["علوم الحاسب", "الهندسة الميكانيكية", "الاقتصاد", "علم النفس", "الأحياء", "الرياضيات",
+        "التاريخ", "القانون", "إدارة الأعمال", "الفيزياء", "العلوم السياسية", "الكيمياء"],
+};
+
+const CY_GB: EducationWords = EducationWords {
+    universities: &["Prifysgol Caerdydd", "Prifysgol Abertawe", "Prifysgol Bangor", "Prifysgol Aberystwyth",
+        "Prifysgol De Cymru", "Prifysgol Metropolitan Caerdydd", "Prifysgol Cymru Y Drindod Dewi Sant",
+        "Prifysgol Wrecsam"],
+    degrees: &["Baglor yn y Celfyddydau", "Baglor mewn Gwyddoniaeth", "Meistr yn y Celfyddydau",
+        "Meistr mewn Gwyddoniaeth", "Doethur mewn Athroniaeth"],
+    fields_of_study: &["Cyfrifiadureg", "Peirianneg", "Economeg", "Seicoleg", "Bioleg", "Mathemateg", "Hanes",
+        "Y Gyfraith", "Meddygaeth", "Ffiseg", "Cerddoriaeth", "Cemeg"],
+};
+
+/// Returns the word lists of `locale`, falling back to `EN` like [`FakeGenerator`](crate::FakeGenerator).
+fn words(locale: &str) -> &'static EducationWords {
+    match LocalesKeys::from(locale) {
+        LocalesKeys::En => &EN,
+        LocalesKeys::FrFr => &FR_FR,
+        LocalesKeys::ItIt => &IT_IT,
+        LocalesKeys::JaJp => &JA_JP,
+        LocalesKeys::DeDe => &DE_DE,
+        LocalesKeys::PtBr => &PT_BR,
+        LocalesKeys::ArSa => &AR_SA,
+        LocalesKeys::CyGb => &CY_GB,
+    }
+}
+
+/// Generates `education.university`, such as `Universität Hamburg` in `DE_DE`.
+pub(crate) fn university(locale: &str, rng: &mut StdRng) -> Value {
+    Value::String(pick(words(locale).universities, rng).to_string())
+}
+
+/// Generates `education.degree`, such as `Master of Science`.
+pub(crate) fn degree(locale: &str, rng: &mut StdRng) -> Value {
+    Value::String(pick(words(locale).degrees, rng).to_string())
+}
+
+/// Generates `education.fieldOfStudy`, such as `Computer Science`.
+pub(crate) fn field_of_study(locale: &str, rng: &mut StdRng) -> Value {
+    Value::String(pick(words(locale).fields_of_study, rng).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_education_words_per_locale() {
+        let mut rng = StdRng::seed_from_u64(2);
+        assert!(IT_IT.universities.contains(&university("IT_IT", &mut rng).as_str().unwrap()));
+        assert!(PT_BR.degrees.contains(&degree("PT_BR", &mut rng).as_str().unwrap()));
+        assert!(EN.fields_of_study.contains(&field_of_study("EN_AU", &mut rng).as_str().unwrap()));
+    }
+}
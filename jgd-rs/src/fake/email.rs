@@ -0,0 +1,68 @@
+//! Domains of the email keys: the domain pinned by `internet.email(domain)` and the
+//! reserved top-level domain of the `safeEmails` schema option.
+
+use serde_json::Value;
+
+/// Top-level domain that replaces the real-looking ones with `safeEmails`.
+const SAFE_TLD: &str = "test";
+
+/// Top-level domains reserved by RFC 2606 and RFC 6761, which never deliver mail.
+const RESERVED_TLDS: &[&str] = &["test", "example", "invalid", "localhost"];
+
+/// Second-level domains reserved by RFC 2606.
+const RESERVED_DOMAINS: &[&str] = &["example.com", "example.net", "example.org"];
+
+/// Checks that the argument of an email key is a domain such as `example.test`.
+pub(crate) fn is_domain(value: &str) -> bool {
+    !value.is_empty() && value.split('.').all(|label| {
+        !label.is_empty() && !label.starts_with('-') && !label.ends_with('-')
+            && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+    })
+}
+
+/// Whether mail to `domain` can never reach a real mailbox.
+fn is_reserved(domain: &str) -> bool {
+    let domain = domain.to_lowercase();
+    let tld = domain.rsplit('.').next().unwrap_or_default();
+    RESERVED_TLDS.contains(&tld) || RESERVED_DOMAINS.contains(&domain.as_str())
+}
+
+/// Replaces the domain of a generated email with `domain`, and with `safe` the
+/// top-level domain of an unreserved domain with `.test` (`jane@gmail.com` →
+/// `jane@gmail.test`).
+pub(crate) fn email(email: Value, domain: Option<&str>, safe: bool) -> Value {
+    let Value::String(email) = email else {
+        return email;
+    };
+    let Some((local, generated)) = email.split_once('@') else {
+        return Value::String(email);
+    };
+
+    let domain = domain.unwrap_or(generated);
+    let domain = match safe && !is_reserved(domain) {
+        true => match domain.rsplit_once('.') {
+            Some((name, _)) => format!("{}.{}", name, SAFE_TLD),
+            None => format!("{}.{}", domain, SAFE_TLD),
+        },
+        false => domain.to_string(),
+    };
+    Value::String(format!("{}@{}", local, domain))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_email_domains() {
+        assert_eq!(email(json!("jane@gmail.com"), Some("acme.test"), false), json!("jane@acme.test"));
+        assert_eq!(email(json!("jane@gmail.com"), None, true), json!("jane@gmail.test"));
+        assert_eq!(email(json!("jane@mail.co.uk"), Some("corp"), true), json!("jane@corp.test"));
+        assert_eq!(email(json!("jane@example.org"), None, true), json!("jane@example.org"));
+        assert_eq!(email(json!("jane@acme.invalid"), None, true), json!("jane@acme.invalid"));
+        assert!(is_domain("example.test") && is_domain("mail-1.example.com"));
+        assert!(!is_domain("bad domain") && !is_domain("a..b") && !is_domain("-a.com") && !is_domain(""));
+    }
+}
@@ -0,0 +1,89 @@
+use rand::rngs::StdRng;
+
+use crate::{apply_transforms_with, fake::{FakeGenerator, FAKE_KEYS}, Jgd, JgdErrorKind, JgdGeneratorError, Replacer, ResultValue};
+
+/// Evaluates a single fake key without building a JGD document.
+///
+/// `pattern` is the content of a template placeholder: a key with optional arguments
+/// and transforms, e.g. `name.firstName`, `lorem.words(3)` or `name.lastName|upper`.
+/// Keys registered with [`Jgd::add_custom_key`] are evaluated too. Context keys such
+/// as `index` need a generation context and are reported as unknown.
+///
+/// # Examples
+///
+/// ```rust
+/// use jgd_rs::evaluate_key;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let name = evaluate_key("name.firstName|upper", "EN", &mut rng).unwrap();
+/// assert_eq!(name.as_str().unwrap(), name.as_str().unwrap().to_uppercase());
+///
+/// assert!(evaluate_key("name.unknown", "EN", &mut rng).is_err());
+/// ```
+pub fn evaluate_key(pattern: &str, locale: &str, rng: &mut StdRng) -> ResultValue {
+    let replacer = Replacer::from(format!("${{{}}}", pattern).as_str());
+    let error = |message: String| JgdGeneratorError {
+        message,
+        kind: JgdErrorKind::Template,
+        key: Some(replacer.tag.as_str().into()),
+        ..Default::default()
+    };
+
+    let transforms = replacer.transforms.clone().map_err(error)?;
+    let value = if let Some(func) = Jgd::get_custom_key(&replacer.key) {
+        func(replacer.arguments.clone())
+    } else if FAKE_KEYS.contains_key(&replacer.key) {
+        FakeGenerator::new(locale).generate_by_key(&replacer, rng)
+    } else {
+        Err(FAKE_KEYS.unknown_key_message(&replacer.tag, &replacer.key))
+    };
+
+    Ok(apply_transforms_with(&transforms, value.map_err(error)?, locale, rng))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FakeKeys;
+    use rand::SeedableRng;
+    use serde_json::Value;
+
+    #[test]
+    fn test_evaluate_key_is_deterministic_per_seed() {
+        let first = evaluate_key("lorem.words(3)", "FR_FR", &mut StdRng::seed_from_u64(7)).unwrap();
+        let second = evaluate_key("lorem.words(3)", "FR_FR", &mut StdRng::seed_from_u64(7)).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_evaluate_key_reports_unknown_keys() {
+        let error = evaluate_key("index", "EN", &mut StdRng::seed_from_u64(1)).unwrap_err();
+        assert_eq!(error.message, "Error to process the pattern ${index}");
+        assert_eq!(error.key.as_deref(), Some("${index}"));
+
+        let error = evaluate_key("name.firstName|shout", "EN", &mut StdRng::seed_from_u64(1)).unwrap_err();
+        assert_eq!(error.kind, JgdErrorKind::Template);
+
+        let error = evaluate_key("lorem.wrods(3)", "EN", &mut StdRng::seed_from_u64(1)).unwrap_err();
+        assert_eq!(error.message, "Error to process the pattern ${lorem.wrods(3)}, did you mean `lorem.words`?");
+    }
+
+    #[test]
+    fn test_all_keys_evaluate_in_their_locales() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for info in FakeKeys::all() {
+            // choice has nothing to pick from without arguments
+            let key = if info.key == FakeKeys::CHOICE { "choice(a, b)" } else { info.key };
+            for locale in info.locales {
+                assert!(evaluate_key(key, locale, &mut rng).is_ok(), "{} in {}", key, locale);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fake_keys_constants_are_evaluated() {
+        let value = evaluate_key(FakeKeys::BOOLEAN_BOOLEAN, "EN", &mut StdRng::seed_from_u64(1)).unwrap();
+        assert!(matches!(value, Value::Bool(_)));
+    }
+}
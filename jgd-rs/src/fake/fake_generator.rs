@@ -1,16 +1,33 @@
-use chrono::{DateTime, Utc};
-use rand::rngs::StdRng;
+use chrono::{DateTime, Datelike, Utc};
+use rand::{rngs::StdRng, Rng};
 use serde_json::Value;
 
-use crate::{fake::{fake_keys::FakeKeys, fake_locale_generator::{FakeGeneratorArSa, FakeGeneratorCyGb, FakeGeneratorDeDe, FakeGeneratorEn, FakeGeneratorFrFr, FakeGeneratorItIt, FakeGeneratorJaJp, FakeGeneratorPtBr, FakeLocaleGenerator}}, locales_keys::LocalesKeys, Replacer};
+use crate::{fake::{fake_keys::{FakeKeys, FAKE_KEYS}, fake_locale_generator::{FakeGeneratorArSa, FakeGeneratorCyGb, FakeGeneratorDeDe, FakeGeneratorEn, FakeGeneratorFrFr, FakeGeneratorItIt, FakeGeneratorJaJp, FakeGeneratorPtBr, FakeLocaleGenerator}}, locales_keys::LocalesKeys, type_spec::{unquote, weighted_index}, Jgd, Replacer, ValueProvider};
+use crate::fake::{business_hours::BusinessHours, commerce, crypto, education, email, iso, media, money, palette, strings, user_agent, vehicle, LocaleFormat};
 
+/// Generates the values of the built-in fake keys for one locale.
+///
+/// This is the engine behind template placeholders; [`evaluate_key`](crate::evaluate_key)
+/// is the simplest way to use it directly.
 pub struct FakeGenerator {
-    locale_generator: Box<dyn FakeLocaleGenerator>
+    locale: String,
+    locale_generator: Box<dyn FakeLocaleGenerator>,
+    locale_format: &'static LocaleFormat,
+    reference_date: Option<DateTime<Utc>>,
+    safe_emails: bool,
 }
 
 impl FakeGenerator {
+    /// Creates a generator for `locale` (e.g. `EN`, `FR_FR`), falling back to `EN` for
+    /// unsupported locales. Keys with a data pack registered for `locale` (see
+    /// [`Jgd::add_data_pack`]) pick from the pack instead.
     pub fn new(locale: &str) -> Self {
         let locale_keys = LocalesKeys::from(locale);
+        let resolved: &str = LocalesKeys::from(locale).into();
+        if resolved != locale {
+            trace_event!(warn, requested = locale, resolved, "unsupported locale, falling back");
+        }
+
         let locale_generator: Box<dyn FakeLocaleGenerator> = match locale_keys {
             LocalesKeys::En => Box::new(FakeGeneratorEn),
             LocalesKeys::FrFr => Box::new(FakeGeneratorFrFr),
@@ -22,10 +39,48 @@ impl FakeGenerator {
             LocalesKeys::CyGb => Box::new(FakeGeneratorCyGb),
         };
 
-        Self { locale_generator }
+        Self { locale: locale.to_string(), locale_generator, locale_format: LocaleFormat::of(locale), reference_date: None, safe_emails: false }
+    }
+
+    /// Uses `reference_date` instead of the current time as "now" of the relative dates,
+    /// such as the default of `chrono.dateTimeBefore` or the last year of `chrono.dateTimeBetween`.
+    pub fn with_reference_date(mut self, reference_date: DateTime<Utc>) -> Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    /// With `safe_emails`, the email keys replace real-looking top-level domains with
+    /// the reserved `.test`, so generated addresses never reach a real mailbox.
+    pub fn with_safe_emails(mut self, safe_emails: bool) -> Self {
+        self.safe_emails = safe_emails;
+        self
+    }
+
+    /// The reference date, or the current time when none is set.
+    fn now(&self) -> DateTime<Utc> {
+        self.reference_date.unwrap_or_else(Utc::now)
+    }
+
+    /// [`FakeGenerator::now`] for the keys of the `time` crate.
+    fn now_utc(&self) -> time::OffsetDateTime {
+        let now = self.now();
+        time::OffsetDateTime::from_unix_timestamp(now.timestamp())
+            .map(|time| time + time::Duration::nanoseconds(now.timestamp_subsec_nanos() as i64))
+            .unwrap_or_else(|_| time::OffsetDateTime::now_utc())
     }
 
+    /// Generates the value of the fake key of `replacer`, using its arguments.
+    ///
+    /// Returns an error message when the key is not a [`FakeKeys`] key.
     pub fn generate_by_key(&self, replacer: &Replacer, rng: &mut StdRng) -> Result<Value, String> {
+        FakeKeys::validate_arguments(&replacer.key, &replacer.arguments).map_err(|expected| {
+            format!("Error to process the pattern {}: invalid arguments, expected {}", replacer.tag, expected)
+        })?;
+
+        if let Some(pack) = Jgd::get_data_pack(&self.locale, &replacer.key) {
+            return Ok(pack[rng.random_range(0..pack.len())].clone());
+        }
+
         match replacer.key.as_str() {
             // Address
             FakeKeys::ADDRESS_CITY_PREFIX => Ok(self.locale_generator.address_city_prefix(rng)),
@@ -68,6 +123,7 @@ impl FakeGenerator {
             FakeKeys::COLOR_HSL_COLOR => Ok(self.locale_generator.color_hsl_color(rng)),
             FakeKeys::COLOR_HSLA_COLOR => Ok(self.locale_generator.color_hsla_color(rng)),
             FakeKeys::COLOR_COLOR => Ok(self.locale_generator.color_color(rng)),
+            FakeKeys::COLOR_PALETTE => Ok(palette::palette(&replacer.arguments, rng)),
 
             // Chrono
             FakeKeys::CHRONO_TIME => Ok(self.locale_generator.chrono_time(rng)),
@@ -77,23 +133,31 @@ impl FakeGenerator {
             // Chrono with arguments
             FakeKeys::CHRONO_DATE_TIME_BEFORE => {
                 // Parse datetime argument or use current time as default
-                let dt = replacer.arguments.get_datetime(chrono::Utc::now());
+                let dt = replacer.arguments.get_datetime(self.now());
                 Ok(self.locale_generator.chrono_date_time_before(rng, dt))
             },
             FakeKeys::CHRONO_DATE_TIME_AFTER => {
                 // Parse datetime argument or use current time as default
-                let dt = replacer.arguments.get_datetime(chrono::Utc::now());
+                let dt = replacer.arguments.get_datetime(self.now());
                 Ok(self.locale_generator.chrono_date_time_after(rng, dt))
             },
             FakeKeys::CHRONO_DATE_TIME_BETWEEN => {
                 // For between, we need two datetime arguments or use defaults
-                let now: DateTime<Utc> = chrono::Utc::now();
+                let now = self.now();
                 let (start, end) = replacer.arguments
                     .get_datetime_range(now - chrono::Duration::days(365), now);
 
                 // Default: past year to now
                 Ok(self.locale_generator.chrono_date_time_between(rng, start, end))
             },
+            FakeKeys::CHRONO_BUSINESS_DATE_TIME => {
+                // Whole days of the past year
+                let now = self.now();
+                BusinessHours::from_arguments(&replacer.arguments)
+                    .date_time_between(rng, now - chrono::Duration::days(365), now)
+                    .map(|dt| Value::String(dt.to_rfc3339()))
+                    .ok_or_else(|| format!("Error to process the pattern {}: no business time in the last year", replacer.tag))
+            },
 
             // Time
             FakeKeys::TIME_TIME => Ok(self.locale_generator.time_time(rng)),
@@ -102,15 +166,15 @@ impl FakeGenerator {
             FakeKeys::TIME_DURATION => Ok(self.locale_generator.time_duration(rng)),
             // Time with arguments
             FakeKeys::TIME_DATE_TIME_BEFORE => {
-                let dt = replacer.arguments.get_time(time::OffsetDateTime::now_utc());
+                let dt = replacer.arguments.get_time(self.now_utc());
                 Ok(self.locale_generator.time_date_time_before(rng, dt))
             },
             FakeKeys::TIME_DATE_TIME_AFTER => {
-                let dt = replacer.arguments.get_time(time::OffsetDateTime::now_utc());
+                let dt = replacer.arguments.get_time(self.now_utc());
                 Ok(self.locale_generator.time_date_time_after(rng, dt))
             },
             FakeKeys::TIME_DATE_TIME_BETWEEN => {
-                let now = time::OffsetDateTime::now_utc();
+                let now = self.now_utc();
 
                 let (start, end) = replacer.arguments
                     .get_time_range(now - time::Duration::days(365), now);
@@ -143,8 +207,14 @@ impl FakeGenerator {
             // Internet
             FakeKeys::INTERNET_FREE_EMAIL_PROVIDER => Ok(self.locale_generator.internet_free_email_provider(rng)),
             FakeKeys::INTERNET_DOMAIN_SUFFIX => Ok(self.locale_generator.internet_domain_suffix(rng)),
-            FakeKeys::INTERNET_FREE_EMAIL => Ok(self.locale_generator.internet_free_email(rng)),
-            FakeKeys::INTERNET_SAFE_EMAIL => Ok(self.locale_generator.internet_safe_email(rng)),
+            FakeKeys::INTERNET_FREE_EMAIL => {
+                let free_email = self.locale_generator.internet_free_email(rng);
+                Ok(email::email(free_email, replacer.arguments.get_str(0), self.safe_emails))
+            },
+            FakeKeys::INTERNET_SAFE_EMAIL | FakeKeys::INTERNET_EMAIL => {
+                let safe_email = self.locale_generator.internet_safe_email(rng);
+                Ok(email::email(safe_email, replacer.arguments.get_str(0), self.safe_emails))
+            },
             FakeKeys::INTERNET_USERNAME => Ok(self.locale_generator.internet_username(rng)),
             FakeKeys::INTERNET_PASSWORD => {
                 let range = replacer.arguments.get_number_range(8, 16);
@@ -154,7 +224,8 @@ impl FakeGenerator {
             FakeKeys::INTERNET_I_PV6 => Ok(self.locale_generator.internet_i_pv6(rng)),
             FakeKeys::INTERNET_IP => Ok(self.locale_generator.internet_ip(rng)),
             FakeKeys::INTERNET_MAC_ADDRESS => Ok(self.locale_generator.internet_mac_address(rng)),
-            FakeKeys::INTERNET_USER_AGENT => Ok(self.locale_generator.internet_user_agent(rng)),
+            FakeKeys::INTERNET_USER_AGENT if replacer.arguments.is_empty() => Ok(self.locale_generator.internet_user_agent(rng)),
+            FakeKeys::INTERNET_USER_AGENT => Ok(user_agent::user_agent(&replacer.arguments, rng)),
 
             // Job
             FakeKeys::JOB_SENIORITY => Ok(self.locale_generator.job_seniority(rng)),
@@ -224,6 +295,7 @@ impl FakeGenerator {
                 let format = replacer.arguments.get_string("###-###-####");
                 Ok(self.locale_generator.number_number_with_format(rng, format))
             },
+            FakeKeys::NUMBER_DECIMAL => Ok(self.decimal(replacer, rng)),
 
             // Phone Number
             FakeKeys::PHONE_NUMBER_PHONE_NUMBER => Ok(self.locale_generator.phone_number_phone_number(rng)),
@@ -239,6 +311,18 @@ impl FakeGenerator {
             FakeKeys::FILESYSTEM_SEMVER_STABLE => Ok(self.locale_generator.filesystem_semver_stable(rng)),
             FakeKeys::FILESYSTEM_SEMVER_UNSTABLE => Ok(self.locale_generator.filesystem_semver_unstable(rng)),
 
+            // File and image
+            FakeKeys::FILE_S3_URL => Ok(media::s3_url(rng)),
+            FakeKeys::FILE_SIZE_BYTES => Ok(media::size_bytes(&replacer.arguments, rng)),
+            FakeKeys::FILE_METADATA => Ok(media::metadata(rng)),
+            FakeKeys::IMAGE_URL => Ok(media::image_url(&replacer.arguments, rng)),
+            FakeKeys::IMAGE_DATA_URI => Ok(media::image_data_uri(&replacer.arguments, rng)),
+
+            // String
+            FakeKeys::STRING_UNICODE => Ok(strings::unicode(&replacer.arguments, rng)),
+            FakeKeys::STRING_EDGE => Ok(strings::edge(&replacer.arguments, rng)),
+            FakeKeys::STRING_RANDOM => Ok(strings::random(&replacer.arguments, rng)),
+
             // Currency
             FakeKeys::CURRENCY_CURRENCY_CODE => Ok(self.locale_generator.currency_currency_code(rng)),
             FakeKeys::CURRENCY_CURRENCY_NAME => Ok(self.locale_generator.currency_currency_name(rng)),
@@ -247,16 +331,60 @@ impl FakeGenerator {
             // Finance
             FakeKeys::FINANCE_BIC => Ok(self.locale_generator.finance_bic(rng)),
             FakeKeys::FINANCE_ISIN => Ok(self.locale_generator.finance_isin(rng)),
+            FakeKeys::FINANCE_AMOUNT => Ok(self.amount(replacer, rng, true)),
+            FakeKeys::FINANCE_AMOUNT_VALUE => Ok(self.amount(replacer, rng, false)),
 
             // Administrative
             FakeKeys::ADMINISTRATIVE_HEALTH_INSURANCE_CODE => Ok(self.locale_generator.administrative_health_insurance_code(rng)),
 
             // Automotive
             FakeKeys::AUTOMOTIVE_LICENCE_PLATE => Ok(self.locale_generator.automotive_licence_plate(rng)),
+            FakeKeys::AUTOMOTIVE_MAKE => Ok(vehicle::make(rng)),
+            FakeKeys::AUTOMOTIVE_MODEL => Ok(vehicle::model(rng)),
+            FakeKeys::AUTOMOTIVE_VIN => Ok(vehicle::vin(rng)),
+            FakeKeys::AUTOMOTIVE_YEAR => {
+                let min = replacer.arguments.get_num(0).unwrap_or(1990);
+                let max = replacer.arguments.get_num(1).unwrap_or_else(|| self.now().year());
+                Ok(vehicle::year(min, max, rng))
+            },
+
+            // Commerce
+            FakeKeys::COMMERCE_PRODUCT_NAME => Ok(commerce::product_name(&self.locale, rng)),
+            FakeKeys::COMMERCE_DEPARTMENT => Ok(commerce::department(&self.locale, rng)),
+            FakeKeys::COMMERCE_SKU => Ok(commerce::sku(rng)),
+            FakeKeys::COMMERCE_PRICE_TIER => Ok(commerce::price_tier(&self.locale, rng)),
+
+            // Crypto
+            FakeKeys::CRYPTO_ETH_ADDRESS => Ok(crypto::eth_address(rng)),
+            FakeKeys::CRYPTO_BTC_ADDRESS => Ok(crypto::btc_address(rng)),
+            FakeKeys::CRYPTO_TX_HASH => Ok(crypto::tx_hash(rng)),
+
+            // Education
+            FakeKeys::EDUCATION_UNIVERSITY => Ok(education::university(&self.locale, rng)),
+            FakeKeys::EDUCATION_DEGREE => Ok(education::degree(&self.locale, rng)),
+            FakeKeys::EDUCATION_FIELD_OF_STUDY => Ok(education::field_of_study(&self.locale, rng)),
+
+            // ISO
+            FakeKeys::ISO_LANGUAGE_CODE => Ok(iso::language_code(rng)),
+            FakeKeys::ISO_SUBDIVISION => Ok(iso::subdivision(replacer.arguments.get_str(0), &self.locale, rng)),
+            FakeKeys::ISO_LOCALE_TAG => Ok(iso::locale_tag(rng)),
+            FakeKeys::ISO_CHARSET => Ok(iso::charset(rng)),
+
+            // Healthcare
+            #[cfg(feature = "healthcare")]
+            FakeKeys::HEALTHCARE_ICD10_CODE => Ok(crate::fake::healthcare::icd10_code(rng)),
+            #[cfg(feature = "healthcare")]
+            FakeKeys::HEALTHCARE_MEDICATION => Ok(crate::fake::healthcare::medication(rng)),
+            #[cfg(feature = "healthcare")]
+            FakeKeys::HEALTHCARE_HOSPITAL_DEPARTMENT => Ok(crate::fake::healthcare::hospital_department(rng)),
+
+            // Literals
+            FakeKeys::CHOICE => Ok(Self::choice(replacer, rng)),
 
             //IDs
             FakeKeys::UUID_V4 => {
-                let id = uuid::Uuid::new_v4();
+                // Drawn from the seeded generator so seeded runs (and resumed runs) repeat the ids
+                let id = uuid::Builder::from_random_bytes(rng.random()).into_uuid();
                 Ok(Value::String(id.to_string()))
             }
             FakeKeys::ULID => {
@@ -267,6 +395,80 @@ impl FakeGenerator {
             _ => Err(format!("Error to generate unknown key {}", replacer.tag)),
         }
     }
+
+    /// Generates `number.decimal(min..max, pattern)`: a number in the range, formatted with the
+    /// decimals of the pattern (`#,##0.00` has two) and the locale separators. The thousands
+    /// are grouped when the pattern has a `,`.
+    fn decimal(&self, replacer: &Replacer, rng: &mut StdRng) -> Value {
+        let arguments = &replacer.arguments;
+        let min = arguments.get_num(0).unwrap_or(0.0);
+        let max = arguments.get_num(1).unwrap_or(1000.0);
+        let pattern = arguments.get_str(2).unwrap_or("#,##0.00");
+
+        let decimals = pattern.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+        let value: f64 = if min < max { rng.random_range(min..max) } else { min };
+        let number = format!("{:.*}", decimals, value);
+
+        let formatted = self.locale_format.format_number(&number, pattern.contains(','));
+        Value::String(formatted.unwrap_or(number))
+    }
+
+    /// Generates `finance.amount(min..max, currency)`: an amount in the range, rounded to the
+    /// decimals of the currency and formatted with the locale separators and symbol placement
+    /// (`$1,234.50`, `1.234,50 €`). The currency defaults to the locale currency. With
+    /// `formatted` unset, as for `finance.amountValue`, the rounded amount is a number.
+    fn amount(&self, replacer: &Replacer, rng: &mut StdRng, formatted: bool) -> Value {
+        let arguments = &replacer.arguments;
+        let min = arguments.get_num(0).unwrap_or(0.0);
+        let max = arguments.get_num(1).unwrap_or(1000.0);
+        let currency = arguments.get_str(2).filter(|currency| !currency.is_empty())
+            .map_or_else(|| self.locale_format.currency().to_string(), str::to_uppercase);
+
+        let decimals = money::minor_units(&currency);
+        let value: f64 = if min < max { rng.random_range(min..=max) } else { min };
+        let number = format!("{:.*}", decimals as usize, value);
+
+        match formatted {
+            true => Value::String(self.locale_format.format_money(&number, &currency).unwrap_or(number)),
+            false if decimals == 0 => Value::from(number.parse::<i64>().unwrap_or_default()),
+            false => Value::from(number.parse::<f64>().unwrap_or(value)),
+        }
+    }
+
+    /// Generates `choice(red:3, green:1, blue)`: one of the literals, picked by weight. A
+    /// literal without a numeric `:weight` suffix weighs 1; literals containing `:` are
+    /// quoted and weighted, as in `'12:30':1`.
+    fn choice(replacer: &Replacer, rng: &mut StdRng) -> Value {
+        let options: Vec<(String, f64)> = replacer.arguments.values().iter()
+            .map(|value| match value.rsplit_once(':').map(|(literal, weight)| (literal, weight.trim().parse::<f64>())) {
+                Some((literal, Ok(weight))) => (unquote(literal), weight),
+                _ => (value.clone(), 1.0),
+            })
+            .collect();
+
+        let weights: Vec<f64> = options.iter().map(|(_, weight)| *weight).collect();
+        let literal = weighted_index(&weights, rng).map(|index| options[index].0.clone());
+        Value::String(literal.unwrap_or_default())
+    }
+}
+
+impl ValueProvider for FakeGenerator {
+    fn provides(&self, key: &str) -> bool {
+        FAKE_KEYS.contains_key(key)
+    }
+
+    fn generate_by_key(&self, replacer: &Replacer, rng: &mut StdRng) -> Result<Value, String> {
+        FakeGenerator::generate_by_key(self, replacer, rng)
+    }
+
+    fn has_locale_data(&self, key: &str) -> bool {
+        FakeKeys::has_locale_data(key, &self.locale)
+    }
+
+    fn street_and_number(&self, rng: &mut StdRng) -> (Value, Value) {
+        let street = self.locale_generator.address_street_name(rng);
+        (street, self.locale_generator.address_building_number(rng))
+    }
 }
 
 #[cfg(test)]
@@ -390,6 +592,12 @@ mod tests {
 
         let result = generator.generate_by_key(&Replacer::from("${chrono.dateTimeBetween(2024-01-01 00:00:00, 2024-12-31T23:59:59)}"), &mut rng);
         assert!(matches!(result, Ok(Value::String(_))));
+
+        let result = generator.generate_by_key(&Replacer::from("${chrono.businessDateTime(9..17, Mon..Fri, 2024-12-25)}"), &mut rng);
+        assert!(matches!(result, Ok(Value::String(_))));
+
+        let result = generator.generate_by_key(&Replacer::from("${chrono.businessDateTime(9..17, Someday)}"), &mut rng);
+        assert!(result.unwrap_err().contains("invalid arguments"));
     }
 
     #[test]
@@ -725,5 +933,99 @@ mod tests {
         assert!(matches!(result2, Ok(Value::Bool(_))));
     }
 
+    #[test]
+    fn test_generate_by_key_decimal_uses_locale_separators() {
+        let mut rng = create_test_rng();
+
+        let value = FakeGenerator::new("DE_DE")
+            .generate_by_key(&Replacer::from("${number.decimal(1000..9999, \"#,##0.00\")}"), &mut rng)
+            .unwrap();
+        let value = value.as_str().unwrap();
+        assert_eq!(value.len(), "1.000,00".len());
+        assert_eq!(value.find('.'), Some(1));
+        assert_eq!(value.find(','), Some(5));
+
+        let value = create_test_generator()
+            .generate_by_key(&Replacer::from("${number.decimal(5, 5, 0.0)}"), &mut rng)
+            .unwrap();
+        assert_eq!(value, Value::String("5.0".to_string()));
+
+        let result = create_test_generator().generate_by_key(&Replacer::from("${number.decimal(low, 2)}"), &mut rng);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate_by_key_amount_uses_locale_symbol() {
+        let mut rng = create_test_rng();
+
+        let value = create_test_generator()
+            .generate_by_key(&Replacer::from("${finance.amount(1000..5000, USD)}"), &mut rng)
+            .unwrap();
+        let value = value.as_str().unwrap();
+        assert!(value.starts_with('$') && value.find(',') == Some(2) && value.len() == "$1,000.00".len(), "{}", value);
+
+        let value = FakeGenerator::new("DE_DE")
+            .generate_by_key(&Replacer::from("${finance.amount(10..99)}"), &mut rng)
+            .unwrap();
+        let value = value.as_str().unwrap();
+        assert!(value.ends_with(" €") && value.find(',') == Some(2), "{}", value);
+
+        let value = create_test_generator()
+            .generate_by_key(&Replacer::from("${finance.amountValue(10..5000, jpy)}"), &mut rng)
+            .unwrap();
+        assert!(value.is_i64());
+
+        let value = create_test_generator()
+            .generate_by_key(&Replacer::from("${finance.amountValue(2.5..2.5, BHD)}"), &mut rng)
+            .unwrap();
+        assert_eq!(value, serde_json::json!(2.5));
+
+        let result = create_test_generator().generate_by_key(&Replacer::from("${finance.amount(1..5, dollars)}"), &mut rng);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate_by_key_rejects_invalid_arguments() {
+        let generator = create_test_generator();
+        let mut rng = create_test_rng();
+
+        let result = generator.generate_by_key(&Replacer::from("${lorem.words(a..b)}"), &mut rng);
+        assert_eq!(result.unwrap_err(),
+            "Error to process the pattern ${lorem.words(a..b)}: invalid arguments, expected min..max words, default 3..8");
+
+        let result = generator.generate_by_key(&Replacer::from("${boolean.boolean(1, 2)}"), &mut rng);
+        assert!(result.unwrap_err().contains("expected percentage of true values"));
+
+        let result = generator.generate_by_key(&Replacer::from("${chrono.dateTimeBefore(yesterday)}"), &mut rng);
+        assert!(result.is_err());
+
+        let result = generator.generate_by_key(&Replacer::from("${name.firstName(3)}"), &mut rng);
+        assert!(result.unwrap_err().ends_with("invalid arguments, expected no arguments"));
+
+        let result = generator.generate_by_key(&Replacer::from("${number.numberWithFormat(###-###)}"), &mut rng);
+        assert!(matches!(result, Ok(Value::String(_))));
+
+        let result = generator.generate_by_key(&Replacer::from("${choice}"), &mut rng);
+        assert!(result.unwrap_err().ends_with("expected one or more literal or literal:weight values, default weight 1"));
+    }
+
+    #[test]
+    fn test_generate_by_key_choice_picks_by_weight() {
+        let generator = create_test_generator();
+        let mut rng = create_test_rng();
+
+        let replacer = Replacer::from("${choice(red:3, green:1, blue, never:0)}");
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..500 {
+            let value = generator.generate_by_key(&replacer, &mut rng).unwrap();
+            *counts.entry(value.as_str().unwrap().to_string()).or_insert(0) += 1;
+        }
+        assert!(counts["red"] > counts["green"] + counts["blue"], "{:?}", counts);
+        assert!(counts.contains_key("blue"));
+        assert!(!counts.contains_key("never"));
+
+        let value = generator.generate_by_key(&Replacer::from("${choice('12:30':1, 'a, b':0)}"), &mut rng).unwrap();
+        assert_eq!(value, Value::String("12:30".to_string()));
+    }
 }
 
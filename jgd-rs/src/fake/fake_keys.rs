@@ -1,5 +1,185 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::LazyLock};
 
+use crate::{fake::{business_hours::HOLIDAY_FORMAT, email::is_domain, iso::is_subdivision_country, media::parse_size, money::is_currency_code, strings::{is_edge_kind, is_random_argument, is_unicode_kind}, user_agent::is_target}, locales_keys::LOCALES, Arguments, Jgd};
+
+/// Shape of the arguments a fake key accepts.
+#[derive(Clone, Copy)]
+enum ArgumentKind {
+    /// A single integer.
+    Number,
+    /// One or two integers, `min..max`.
+    Range,
+    /// A single date time.
+    DateTime,
+    /// One or two date times, `start..end`.
+    DateTimeRange,
+    /// A single Unix timestamp.
+    Time,
+    /// One or two Unix timestamps, `start..end`.
+    TimeRange,
+    /// Two decimal numbers, `min..max`, and a format pattern.
+    Decimal,
+    /// Two decimal numbers, `min..max`, and a currency code.
+    Amount,
+    /// `startHour..endHour`, `firstDay..lastDay` and holiday dates.
+    BusinessHours,
+    /// One or two sizes such as `1KB`, `min..max`.
+    SizeRange,
+    /// A device and a browser or crawler, in any order.
+    UserAgent,
+    /// A domain such as `example.test`.
+    Domain,
+    /// An ISO 3166-1 alpha-2 country code with known subdivisions.
+    Country,
+    /// A kind of `string.unicode`.
+    UnicodeKind,
+    /// A kind of `string.edge`.
+    EdgeKind,
+    /// A length or `min..max` lengths, then a charset.
+    RandomString,
+    /// Any text.
+    Text,
+    /// One or more literals, each optionally followed by `:weight`.
+    Choice,
+}
+
+/// Arguments accepted by the fake keys that take any, with their defaults.
+const KEY_ARGUMENTS: &[(&str, ArgumentKind, &str)] = &[
+    (FakeKeys::ADDRESS_GEOHASH, ArgumentKind::Number, "precision, default 5"),
+    (FakeKeys::BOOLEAN_BOOLEAN, ArgumentKind::Number, "percentage of true values, default 5"),
+    (FakeKeys::COLOR_PALETTE, ArgumentKind::Number, "number of colors, default 5"),
+    (FakeKeys::CHRONO_DATE_TIME_BEFORE, ArgumentKind::DateTime, "date time, default now"),
+    (FakeKeys::CHRONO_DATE_TIME_AFTER, ArgumentKind::DateTime, "date time, default now"),
+    (FakeKeys::CHRONO_DATE_TIME_BETWEEN, ArgumentKind::DateTimeRange, "start..end date times, default the last year"),
+    (FakeKeys::CHRONO_BUSINESS_DATE_TIME, ArgumentKind::BusinessHours, "startHour..endHour, firstDay..lastDay, holiday dates, default 9..17, Mon..Fri"),
+    (FakeKeys::TIME_DATE_TIME_BEFORE, ArgumentKind::Time, "Unix timestamp, default now"),
+    (FakeKeys::TIME_DATE_TIME_AFTER, ArgumentKind::Time, "Unix timestamp, default now"),
+    (FakeKeys::TIME_DATE_TIME_BETWEEN, ArgumentKind::TimeRange, "start..end Unix timestamps, default the last year"),
+    (FakeKeys::INTERNET_USER_AGENT, ArgumentKind::UserAgent, "desktop, mobile or bot and chrome, firefox, safari, edge or a crawler such as googlebot, default any"),
+    (FakeKeys::INTERNET_EMAIL, ArgumentKind::Domain, "domain, default example.com, example.net or example.org"),
+    (FakeKeys::INTERNET_FREE_EMAIL, ArgumentKind::Domain, "domain, default a free email provider"),
+    (FakeKeys::INTERNET_SAFE_EMAIL, ArgumentKind::Domain, "domain, default example.com, example.net or example.org"),
+    (FakeKeys::INTERNET_PASSWORD, ArgumentKind::Range, "min..max length, default 8..16"),
+    (FakeKeys::LOREM_WORDS, ArgumentKind::Range, "min..max words, default 3..8"),
+    (FakeKeys::LOREM_SENTENCE, ArgumentKind::Range, "min..max words, default 4..18"),
+    (FakeKeys::LOREM_SENTENCES, ArgumentKind::Range, "min..max sentences, default 2..6"),
+    (FakeKeys::LOREM_PARAGRAPH, ArgumentKind::Range, "min..max sentences, default 3..10"),
+    (FakeKeys::LOREM_PARAGRAPHS, ArgumentKind::Range, "min..max paragraphs, default 2..5"),
+    (FakeKeys::MARKDOWN_BULLET_POINTS, ArgumentKind::Range, "min..max items, default 3..8"),
+    (FakeKeys::MARKDOWN_LIST_ITEMS, ArgumentKind::Range, "min..max items, default 3..8"),
+    (FakeKeys::MARKDOWN_BLOCK_QUOTE_SINGLE_LINE, ArgumentKind::Range, "min..max words, default 4..18"),
+    (FakeKeys::MARKDOWN_BLOCK_QUOTE_MULTI_LINE, ArgumentKind::Range, "min..max lines, default 2..6"),
+    (FakeKeys::MARKDOWN_CODE, ArgumentKind::Range, "min..max lines, default 3..8"),
+    (FakeKeys::FILE_SIZE_BYTES, ArgumentKind::SizeRange, "min..max sizes such as 1KB..10MB, default 1KB..10MB"),
+    (FakeKeys::IMAGE_URL, ArgumentKind::Range, "width, height, default 640, 480"),
+    (FakeKeys::IMAGE_DATA_URI, ArgumentKind::Range, "width, height, default 640, 480"),
+    (FakeKeys::STRING_UNICODE, ArgumentKind::UnicodeKind, "emoji, rtl, cjk or zalgo, default any"),
+    (FakeKeys::STRING_RANDOM, ArgumentKind::RandomString, "length or min..max lengths, alpha, alnum, lower, upper, numeric, hex, base64url or custom characters, default 16, alnum"),
+    (FakeKeys::STRING_EDGE, ArgumentKind::EdgeKind, "empty, whitespace, sqlLike or htmlInjection, default any"),
+    (FakeKeys::NUMBER_NUMBER_WITH_FORMAT, ArgumentKind::Text, "format, default ###-###-####"),
+    (FakeKeys::NUMBER_DECIMAL, ArgumentKind::Decimal, "min..max, format pattern, default 0..1000, #,##0.00"),
+    (FakeKeys::FINANCE_AMOUNT, ArgumentKind::Amount, "min..max, currency code, default 0..1000, the locale currency"),
+    (FakeKeys::FINANCE_AMOUNT_VALUE, ArgumentKind::Amount, "min..max, currency code, default 0..1000, the locale currency"),
+    (FakeKeys::ISO_SUBDIVISION, ArgumentKind::Country, "AU, BR, CA, DE, ES, FR, GB, IT, JP, MX, SA or US, default the locale country"),
+    (FakeKeys::AUTOMOTIVE_YEAR, ArgumentKind::Range, "min..max years, default 1990..the current year"),
+    (FakeKeys::CHOICE, ArgumentKind::Choice, "one or more literal or literal:weight values, default weight 1"),
+];
+
+/// Locales with commerce word lists.
+const COMMERCE_LOCALES: &[&str] = &["EN", "FR_FR", "IT_IT", "JA_JP", "DE_DE", "PT_BR", "AR_SA"];
+
+/// Keys whose data depends on the locale, with the locales the `fake` crate has data
+/// for. Other locales generate the English data. Keys missing here generate the same
+/// kind of value in every locale (numbers, ids, colors, dates, lorem ipsum, ...).
+const LOCALIZED_KEYS: &[(&str, &[&str])] = &[
+    (FakeKeys::ADDRESS_CITY_PREFIX, &["EN", "IT_IT", "DE_DE"]),
+    (FakeKeys::ADDRESS_CITY_SUFFIX, &["EN", "IT_IT", "DE_DE"]),
+    (FakeKeys::ADDRESS_CITY_NAME, &["EN", "FR_FR", "IT_IT", "DE_DE", "CY_GB"]),
+    (FakeKeys::ADDRESS_COUNTRY_NAME, &["EN", "FR_FR", "IT_IT", "DE_DE", "CY_GB"]),
+    (FakeKeys::ADDRESS_STREET_SUFFIX, &["EN", "FR_FR", "IT_IT", "DE_DE", "PT_BR", "CY_GB"]),
+    (FakeKeys::ADDRESS_STREET_NAME, &["EN", "FR_FR", "IT_IT", "DE_DE", "PT_BR", "CY_GB"]),
+    (FakeKeys::ADDRESS_TIME_ZONE, &["EN", "FR_FR", "IT_IT"]),
+    (FakeKeys::ADDRESS_STATE_NAME, &["EN", "IT_IT", "DE_DE", "PT_BR"]),
+    (FakeKeys::ADDRESS_STATE_ABBR, &["EN", "IT_IT", "DE_DE", "PT_BR"]),
+    (FakeKeys::ADDRESS_SECONDARY_ADDRESS_TYPE, &["EN", "IT_IT", "DE_DE"]),
+    (FakeKeys::ADDRESS_SECONDARY_ADDRESS, &["EN", "IT_IT", "DE_DE", "CY_GB"]),
+    (FakeKeys::ADDRESS_ZIP_CODE, &["EN", "FR_FR", "IT_IT", "DE_DE"]),
+    (FakeKeys::ADDRESS_POST_CODE, &["EN", "FR_FR", "IT_IT", "PT_BR"]),
+    (FakeKeys::ADDRESS_BUILDING_NUMBER, &["EN", "IT_IT"]),
+    (FakeKeys::COMPANY_COMPANY_SUFFIX, &["EN", "IT_IT", "JA_JP", "DE_DE", "PT_BR"]),
+    (FakeKeys::COMPANY_COMPANY_NAME, &["EN", "IT_IT", "JA_JP", "DE_DE", "PT_BR", "CY_GB"]),
+    (FakeKeys::COMPANY_BUZZWORD, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_BUZZWORD_MIDDLE, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_BUZZWORD_TAIL, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_CATCH_PHRASE, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_BS_VERB, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_BS_ADJ, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_BS_NOUN, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_BS, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMPANY_PROFESSION, &["EN"]),
+    (FakeKeys::COMPANY_INDUSTRY, &["EN"]),
+    (FakeKeys::CURRENCY_CURRENCY_NAME, &["EN", "IT_IT", "CY_GB"]),
+    (FakeKeys::COMMERCE_PRODUCT_NAME, COMMERCE_LOCALES),
+    (FakeKeys::COMMERCE_DEPARTMENT, COMMERCE_LOCALES),
+    (FakeKeys::COMMERCE_PRICE_TIER, COMMERCE_LOCALES),
+    (FakeKeys::EDUCATION_UNIVERSITY, LOCALES),
+    (FakeKeys::EDUCATION_DEGREE, LOCALES),
+    (FakeKeys::EDUCATION_FIELD_OF_STUDY, LOCALES),
+    (FakeKeys::INTERNET_FREE_EMAIL_PROVIDER, &["EN", "FR_FR", "IT_IT", "PT_BR", "CY_GB"]),
+    (FakeKeys::INTERNET_DOMAIN_SUFFIX, &["EN", "IT_IT", "PT_BR", "CY_GB"]),
+    (FakeKeys::INTERNET_FREE_EMAIL, &["EN", "FR_FR", "IT_IT", "PT_BR", "CY_GB"]),
+    (FakeKeys::JOB_SENIORITY, &["EN", "IT_IT", "JA_JP", "CY_GB"]),
+    (FakeKeys::JOB_FIELD, &["EN", "IT_IT", "JA_JP", "CY_GB"]),
+    (FakeKeys::JOB_POSITION, &["EN", "IT_IT", "JA_JP", "CY_GB"]),
+    (FakeKeys::JOB_TITLE, &["EN", "IT_IT", "JA_JP", "CY_GB"]),
+    (FakeKeys::NAME_FIRST_NAME, LOCALES),
+    (FakeKeys::NAME_LAST_NAME, LOCALES),
+    (FakeKeys::NAME_TITLE, &["EN", "FR_FR", "IT_IT", "JA_JP", "DE_DE", "CY_GB"]),
+    (FakeKeys::NAME_SUFFIX, &["EN", "IT_IT"]),
+    (FakeKeys::NAME_NAME, LOCALES),
+    (FakeKeys::NAME_NAME_WITH_TITLE, &["EN", "FR_FR", "IT_IT", "JA_JP", "DE_DE", "CY_GB"]),
+    (FakeKeys::PHONE_NUMBER_PHONE_NUMBER, &["EN", "FR_FR", "IT_IT", "JA_JP", "PT_BR", "CY_GB"]),
+    (FakeKeys::PHONE_NUMBER_CELL_NUMBER, &["EN", "FR_FR", "IT_IT", "JA_JP", "PT_BR", "CY_GB"]),
+];
+
+/// Maximum edit distance for a key to be suggested in place of an unknown one.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != *b_char);
+            current[j + 1] = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Metadata of a built-in fake key, as returned by [`FakeKeys::all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeKeyInfo {
+    /// The key used in templates, e.g. `lorem.words`.
+    pub key: &'static str,
+    /// The key prefix, e.g. `lorem`.
+    pub category: &'static str,
+    /// The accepted arguments and their defaults, for keys that take any.
+    pub arguments: Option<&'static str>,
+    /// The locales with their own data for the key; other locales generate the
+    /// English data. Locale-neutral keys list every supported locale.
+    pub locales: &'static [&'static str],
+}
+
+/// The built-in keys, for lookups that do not need a `FakeKeys` of their own.
+pub(crate) static FAKE_KEYS: LazyLock<FakeKeys> = LazyLock::new(FakeKeys::new);
+
+/// The built-in fake keys, as constants (`FakeKeys::NAME_FIRST_NAME`) and as a set.
 pub struct FakeKeys {
     pub sets: HashSet<&'static str>,
 }
@@ -34,6 +214,7 @@ impl FakeKeys {
     pub const BARCODE_ISBN13: &'static str = "barcode.isbn13";
     pub const BOOLEAN_BOOLEAN: &'static str = "boolean.boolean";
     pub const COLOR_HEX_COLOR: &'static str = "color.hexColor";
+    pub const COLOR_PALETTE: &'static str = "color.palette";
     pub const COLOR_RGB_COLOR: &'static str = "color.rgbColor";
     pub const COLOR_RGBA_COLOR: &'static str = "color.rgbaColor";
     pub const COLOR_HSL_COLOR: &'static str = "color.hslColor";
@@ -46,6 +227,7 @@ impl FakeKeys {
     pub const CHRONO_DATE_TIME_BEFORE: &'static str = "chrono.dateTimeBefore";
     pub const CHRONO_DATE_TIME_AFTER: &'static str = "chrono.dateTimeAfter";
     pub const CHRONO_DATE_TIME_BETWEEN: &'static str = "chrono.dateTimeBetween";
+    pub const CHRONO_BUSINESS_DATE_TIME: &'static str = "chrono.businessDateTime";
     pub const TIME_TIME: &'static str = "time.time";
     pub const TIME_DATE: &'static str = "time.date";
     pub const TIME_DATE_TIME: &'static str = "time.dateTime";
@@ -72,6 +254,7 @@ impl FakeKeys {
     pub const INTERNET_DOMAIN_SUFFIX: &'static str = "internet.domainSuffix";
     pub const INTERNET_FREE_EMAIL: &'static str = "internet.freeEmail";
     pub const INTERNET_SAFE_EMAIL: &'static str = "internet.safeEmail";
+    pub const INTERNET_EMAIL: &'static str = "internet.email";
     pub const INTERNET_USERNAME: &'static str = "internet.username";
     pub const INTERNET_PASSWORD: &'static str = "internet.password";
     pub const INTERNET_I_PV4: &'static str = "internet.IPv4";
@@ -105,6 +288,7 @@ impl FakeKeys {
     pub const NAME_NAME_WITH_TITLE: &'static str = "name.nameWithTitle";
     pub const NUMBER_DIGIT: &'static str = "number.digit";
     pub const NUMBER_NUMBER_WITH_FORMAT: &'static str = "number.numberWithFormat";
+    pub const NUMBER_DECIMAL: &'static str = "number.decimal";
     pub const PHONE_NUMBER_PHONE_NUMBER: &'static str = "phone_number.phoneNumber";
     pub const PHONE_NUMBER_CELL_NUMBER: &'static str = "phone_number.cellNumber";
     pub const FILESYSTEM_FILE_PATH: &'static str = "filesystem.filePath";
@@ -115,14 +299,49 @@ impl FakeKeys {
     pub const FILESYSTEM_SEMVER: &'static str = "filesystem.semver";
     pub const FILESYSTEM_SEMVER_STABLE: &'static str = "filesystem.semverStable";
     pub const FILESYSTEM_SEMVER_UNSTABLE: &'static str = "filesystem.semverUnstable";
+    pub const FILE_S3_URL: &'static str = "file.s3Url";
+    pub const FILE_SIZE_BYTES: &'static str = "file.sizeBytes";
+    pub const FILE_METADATA: &'static str = "file.metadata";
+    pub const IMAGE_URL: &'static str = "image.url";
+    pub const IMAGE_DATA_URI: &'static str = "image.dataUri";
+    pub const STRING_UNICODE: &'static str = "string.unicode";
+    pub const STRING_EDGE: &'static str = "string.edge";
+    pub const STRING_RANDOM: &'static str = "string.random";
     pub const CURRENCY_CURRENCY_CODE: &'static str = "currency.currencyCode";
     pub const CURRENCY_CURRENCY_NAME: &'static str = "currency.currencyName";
     pub const CURRENCY_CURRENCY_SYMBOL: &'static str = "currency.currencySymbol";
     pub const FINANCE_BIC: &'static str = "finance.bic";
     pub const FINANCE_ISIN: &'static str = "finance.isin";
+    pub const FINANCE_AMOUNT: &'static str = "finance.amount";
+    pub const FINANCE_AMOUNT_VALUE: &'static str = "finance.amountValue";
     pub const ADMINISTRATIVE_HEALTH_INSURANCE_CODE: &'static str = "administrative.healthInsuranceCode";
     pub const AUTOMOTIVE_LICENCE_PLATE: &'static str = "automotive.licencePlate";
+    pub const AUTOMOTIVE_MAKE: &'static str = "automotive.make";
+    pub const AUTOMOTIVE_MODEL: &'static str = "automotive.model";
+    pub const AUTOMOTIVE_VIN: &'static str = "automotive.vin";
+    pub const AUTOMOTIVE_YEAR: &'static str = "automotive.year";
+    pub const COMMERCE_PRODUCT_NAME: &'static str = "commerce.productName";
+    pub const COMMERCE_DEPARTMENT: &'static str = "commerce.department";
+    pub const COMMERCE_SKU: &'static str = "commerce.sku";
+    pub const COMMERCE_PRICE_TIER: &'static str = "commerce.priceTier";
+    pub const CRYPTO_ETH_ADDRESS: &'static str = "crypto.ethAddress";
+    pub const CRYPTO_BTC_ADDRESS: &'static str = "crypto.btcAddress";
+    pub const CRYPTO_TX_HASH: &'static str = "crypto.txHash";
+    pub const EDUCATION_UNIVERSITY: &'static str = "education.university";
+    pub const EDUCATION_DEGREE: &'static str = "education.degree";
+    pub const EDUCATION_FIELD_OF_STUDY: &'static str = "education.fieldOfStudy";
+    pub const ISO_LANGUAGE_CODE: &'static str = "iso.languageCode";
+    pub const ISO_SUBDIVISION: &'static str = "iso.subdivision";
+    pub const ISO_LOCALE_TAG: &'static str = "iso.localeTag";
+    pub const ISO_CHARSET: &'static str = "iso.charset";
+    #[cfg(feature = "healthcare")]
+    pub const HEALTHCARE_ICD10_CODE: &'static str = "healthcare.icd10Code";
+    #[cfg(feature = "healthcare")]
+    pub const HEALTHCARE_MEDICATION: &'static str = "healthcare.medication";
+    #[cfg(feature = "healthcare")]
+    pub const HEALTHCARE_HOSPITAL_DEPARTMENT: &'static str = "healthcare.hospitalDepartment";
 
+    pub const CHOICE: &'static str = "choice";
     pub const ULID: &'static str = "ulid";
     pub const UUID_V4: &'static str = "uuid.v4";
 
@@ -159,6 +378,7 @@ impl FakeKeys {
 
         // Color constants
         sets.insert(Self::COLOR_HEX_COLOR);
+        sets.insert(Self::COLOR_PALETTE);
         sets.insert(Self::COLOR_RGB_COLOR);
         sets.insert(Self::COLOR_RGBA_COLOR);
         sets.insert(Self::COLOR_HSL_COLOR);
@@ -173,6 +393,7 @@ impl FakeKeys {
         sets.insert(Self::CHRONO_DATE_TIME_BEFORE);
         sets.insert(Self::CHRONO_DATE_TIME_AFTER);
         sets.insert(Self::CHRONO_DATE_TIME_BETWEEN);
+        sets.insert(Self::CHRONO_BUSINESS_DATE_TIME);
 
         // Time constants
         sets.insert(Self::TIME_TIME);
@@ -209,6 +430,7 @@ impl FakeKeys {
         sets.insert(Self::INTERNET_DOMAIN_SUFFIX);
         sets.insert(Self::INTERNET_FREE_EMAIL);
         sets.insert(Self::INTERNET_SAFE_EMAIL);
+        sets.insert(Self::INTERNET_EMAIL);
         sets.insert(Self::INTERNET_USERNAME);
         sets.insert(Self::INTERNET_PASSWORD);
         sets.insert(Self::INTERNET_I_PV4);
@@ -252,6 +474,7 @@ impl FakeKeys {
         // Number constants
         sets.insert(Self::NUMBER_DIGIT);
         sets.insert(Self::NUMBER_NUMBER_WITH_FORMAT);
+        sets.insert(Self::NUMBER_DECIMAL);
 
         // Phone number constants
         sets.insert(Self::PHONE_NUMBER_PHONE_NUMBER);
@@ -267,6 +490,18 @@ impl FakeKeys {
         sets.insert(Self::FILESYSTEM_SEMVER_STABLE);
         sets.insert(Self::FILESYSTEM_SEMVER_UNSTABLE);
 
+        // File and image constants
+        sets.insert(Self::FILE_S3_URL);
+        sets.insert(Self::FILE_SIZE_BYTES);
+        sets.insert(Self::FILE_METADATA);
+        sets.insert(Self::IMAGE_URL);
+        sets.insert(Self::IMAGE_DATA_URI);
+
+        // String constants
+        sets.insert(Self::STRING_UNICODE);
+        sets.insert(Self::STRING_EDGE);
+        sets.insert(Self::STRING_RANDOM);
+
         // Currency constants
         sets.insert(Self::CURRENCY_CURRENCY_CODE);
         sets.insert(Self::CURRENCY_CURRENCY_NAME);
@@ -275,12 +510,51 @@ impl FakeKeys {
         // Finance constants
         sets.insert(Self::FINANCE_BIC);
         sets.insert(Self::FINANCE_ISIN);
+        sets.insert(Self::FINANCE_AMOUNT);
+        sets.insert(Self::FINANCE_AMOUNT_VALUE);
 
         // Administrative constants
         sets.insert(Self::ADMINISTRATIVE_HEALTH_INSURANCE_CODE);
 
         // Automotive constants
         sets.insert(Self::AUTOMOTIVE_LICENCE_PLATE);
+        sets.insert(Self::AUTOMOTIVE_MAKE);
+        sets.insert(Self::AUTOMOTIVE_MODEL);
+        sets.insert(Self::AUTOMOTIVE_VIN);
+        sets.insert(Self::AUTOMOTIVE_YEAR);
+
+        // Commerce constants
+        sets.insert(Self::COMMERCE_PRODUCT_NAME);
+        sets.insert(Self::COMMERCE_DEPARTMENT);
+        sets.insert(Self::COMMERCE_SKU);
+        sets.insert(Self::COMMERCE_PRICE_TIER);
+
+        // Crypto constants
+        sets.insert(Self::CRYPTO_ETH_ADDRESS);
+        sets.insert(Self::CRYPTO_BTC_ADDRESS);
+        sets.insert(Self::CRYPTO_TX_HASH);
+
+        // Education constants
+        sets.insert(Self::EDUCATION_UNIVERSITY);
+        sets.insert(Self::EDUCATION_DEGREE);
+        sets.insert(Self::EDUCATION_FIELD_OF_STUDY);
+
+        // ISO constants
+        sets.insert(Self::ISO_LANGUAGE_CODE);
+        sets.insert(Self::ISO_SUBDIVISION);
+        sets.insert(Self::ISO_LOCALE_TAG);
+        sets.insert(Self::ISO_CHARSET);
+
+        // Healthcare constants
+        #[cfg(feature = "healthcare")]
+        {
+            sets.insert(Self::HEALTHCARE_ICD10_CODE);
+            sets.insert(Self::HEALTHCARE_MEDICATION);
+            sets.insert(Self::HEALTHCARE_HOSPITAL_DEPARTMENT);
+        }
+
+        // Literals
+        sets.insert(Self::CHOICE);
 
         // IDs
         sets.insert(Self::ULID);
@@ -289,7 +563,137 @@ impl FakeKeys {
         Self { sets }
     }
 
+    /// Checks if `key` is a built-in fake key.
     pub fn contains_key(&self, key: &str) -> bool {
         self.sets.contains(key)
     }
+
+    /// Lists the built-in fake keys with their metadata, sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jgd_rs::FakeKeys;
+    ///
+    /// let words = FakeKeys::all().into_iter().find(|info| info.key == "lorem.words").unwrap();
+    /// assert_eq!(words.category, "lorem");
+    /// assert_eq!(words.arguments, Some("min..max words, default 3..8"));
+    /// assert!(words.locales.contains(&"PT_BR"));
+    /// ```
+    pub fn all() -> Vec<FakeKeyInfo> {
+        let mut keys: Vec<FakeKeyInfo> = Self::new().sets
+            .into_iter()
+            .map(|key| FakeKeyInfo {
+                key,
+                category: key.split('.').next().unwrap_or(key),
+                arguments: KEY_ARGUMENTS.iter().find(|(name, ..)| *name == key).map(|(.., arguments)| *arguments),
+                locales: Self::locales_of(key),
+            })
+            .collect();
+
+        keys.sort_by_key(|info| info.key);
+        keys
+    }
+
+    /// Locales with their own data for `key`: every supported locale for locale-neutral
+    /// keys, or the locales the `fake` crate has localized data for.
+    pub fn locales_of(key: &str) -> &'static [&'static str] {
+        LOCALIZED_KEYS.iter()
+            .find(|(name, _)| *name == key)
+            .map_or(LOCALES, |(_, locales)| *locales)
+    }
+
+    /// Checks if `key` generates data of `locale`, rather than falling back to English:
+    /// the `fake` crate has data for it, or a data pack was registered with
+    /// [`Jgd::add_data_pack`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jgd_rs::FakeKeys;
+    ///
+    /// assert!(FakeKeys::has_locale_data("name.firstName", "PT_BR"));
+    /// assert!(!FakeKeys::has_locale_data("name.title", "PT_BR"));
+    /// assert!(FakeKeys::has_locale_data("uuid.v4", "PT_BR"));
+    /// ```
+    pub fn has_locale_data(key: &str, locale: &str) -> bool {
+        Self::locales_of(key).contains(&locale) || Jgd::get_data_pack(locale, key).is_some()
+    }
+
+    /// Returns the closest built-in key to an unknown `key`, if it is only a typo away.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jgd_rs::FakeKeys;
+    ///
+    /// assert_eq!(FakeKeys::new().suggest("lorem.wrods"), Some("lorem.words"));
+    /// assert_eq!(FakeKeys::new().suggest("completely.different"), None);
+    /// ```
+    pub fn suggest(&self, key: &str) -> Option<&'static str> {
+        self.sets.iter()
+            .map(|candidate| (edit_distance(key, candidate), *candidate))
+            .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+            .min()
+            .map(|(_, candidate)| candidate)
+    }
+
+    /// Error message for a template `tag` whose `key` could not be resolved.
+    pub(crate) fn unknown_key_message(&self, tag: &str, key: &str) -> String {
+        match self.suggest(key) {
+            Some(suggestion) => format!("Error to process the pattern {}, did you mean `{}`?", tag, suggestion),
+            None => format!("Error to process the pattern {}", tag),
+        }
+    }
+
+    /// Checks that `arguments` fit the arguments accepted by `key`.
+    ///
+    /// Returns a description of the expected arguments when they do not.
+    pub(crate) fn validate_arguments(key: &str, arguments: &Arguments) -> Result<(), String> {
+        let values = arguments.values();
+        let Some((_, kind, description)) = KEY_ARGUMENTS.iter().find(|(name, ..)| *name == key) else {
+            return match values.is_empty() {
+                true => Ok(()),
+                false => Err("no arguments".to_string()),
+            };
+        };
+
+        let (max_values, valid): (usize, fn(usize, &str) -> bool) = match kind {
+            ArgumentKind::Number => (1, |_, value| value.parse::<u8>().is_ok()),
+            ArgumentKind::Range => (2, |_, value| value.parse::<usize>().is_ok()),
+            ArgumentKind::DateTime => (1, |_, value| Arguments::try_parse_datetime(value).is_some()),
+            ArgumentKind::DateTimeRange => (2, |_, value| Arguments::try_parse_datetime(value).is_some()),
+            ArgumentKind::Time => (1, |_, value| Arguments::try_parse_time(value).is_some()),
+            ArgumentKind::TimeRange => (2, |_, value| Arguments::try_parse_time(value).is_some()),
+            ArgumentKind::BusinessHours => (usize::MAX, |index, value| value.is_empty() || match index {
+                0 | 1 => value.parse::<u32>().is_ok_and(|hour| hour <= 24),
+                2 | 3 => value.parse::<chrono::Weekday>().is_ok(),
+                _ => chrono::NaiveDate::parse_from_str(value, HOLIDAY_FORMAT).is_ok(),
+            }),
+            ArgumentKind::SizeRange => (2, |_, value| parse_size(value).is_some()),
+            ArgumentKind::UserAgent => (2, |_, value| is_target(value)),
+            ArgumentKind::Domain => (1, |_, value| is_domain(value)),
+            ArgumentKind::Country => (1, |_, value| is_subdivision_country(value)),
+            ArgumentKind::UnicodeKind => (1, |_, value| is_unicode_kind(value)),
+            ArgumentKind::EdgeKind => (1, |_, value| is_edge_kind(value)),
+            ArgumentKind::RandomString => (3, is_random_argument),
+            ArgumentKind::Decimal => (3, |index, value| index == 2 || value.is_empty() || value.parse::<f64>().is_ok()),
+            ArgumentKind::Amount => (3, |index, value| value.is_empty() || match index {
+                2 => is_currency_code(value),
+                _ => value.parse::<f64>().is_ok(),
+            }),
+            ArgumentKind::Text => (2, |_, _| true),
+            ArgumentKind::Choice => (usize::MAX, |_, _| true),
+        };
+
+        if matches!(kind, ArgumentKind::Choice) && values.is_empty() {
+            return Err(description.to_string());
+        }
+
+        if values.len() > max_values || !values.iter().enumerate().all(|(index, value)| valid(index, value)) {
+            return Err(description.to_string());
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,77 @@
+//! Healthcare test data of the `healthcare.*` keys, behind the `healthcare` feature.
+//!
+//! These values are synthetic and only meant for testing software: ICD-10 codes follow
+//! the format of the classification (`J45.9`) but are random, so many of them do not
+//! exist and none describes a real diagnosis, and medications are generic names picked
+//! without any dose or indication. Never use them for clinical purposes.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::Value;
+
+/// First letters of the ICD-10 chapters; `U` is reserved for special purposes.
+const ICD10_LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTVWXYZ";
+
+/// Generic names (INN) from the WHO Model List of Essential Medicines.
+const MEDICATIONS: &[&str] = &[
+    "amoxicillin", "azithromycin", "ceftriaxone", "ciprofloxacin", "doxycycline", "metronidazole",
+    "paracetamol", "ibuprofen", "morphine", "acetylsalicylic acid", "metformin", "insulin glargine",
+    "gliclazide", "amlodipine", "atorvastatin", "bisoprolol", "enalapril", "furosemide",
+    "hydrochlorothiazide", "losartan", "simvastatin", "warfarin", "heparin", "omeprazole", "ondansetron",
+    "salbutamol", "budesonide", "prednisolone", "levothyroxine", "fluoxetine", "sertraline", "diazepam",
+    "haloperidol", "carbamazepine", "valproic acid", "levetiracetam", "lamotrigine", "loratadine",
+    "fluconazole", "aciclovir", "tenofovir", "dolutegravir", "artemether", "chloroquine", "isoniazid",
+    "rifampicin", "ferrous sulfate", "folic acid",
+];
+
+/// Hospital departments and units, in English in every locale.
+const HOSPITAL_DEPARTMENTS: &[&str] = &[
+    "Emergency", "Intensive Care Unit", "Cardiology", "Neurology", "Oncology", "Pediatrics", "Obstetrics",
+    "Gynecology", "Orthopedics", "Radiology", "Anesthesiology", "General Surgery", "Internal Medicine",
+    "Dermatology", "Psychiatry", "Nephrology", "Gastroenterology", "Pulmonology", "Urology",
+    "Ophthalmology", "Otolaryngology", "Endocrinology", "Geriatrics", "Neonatal Intensive Care",
+    "Physical Therapy", "Pharmacy", "Pathology", "Outpatient Clinic",
+];
+
+/// Generates `healthcare.icd10Code`: a chapter letter and two digits, with a
+/// subcategory digit half of the time, such as `K52` or `J45.9`.
+pub(crate) fn icd10_code(rng: &mut StdRng) -> Value {
+    let letter = char::from(ICD10_LETTERS[rng.random_range(0..ICD10_LETTERS.len())]);
+    let category = rng.random_range(0..100);
+    let code = match rng.random_bool(0.5) {
+        true => format!("{}{:02}.{}", letter, category, rng.random_range(0..10)),
+        false => format!("{}{:02}", letter, category),
+    };
+    Value::String(code)
+}
+
+/// Generates `healthcare.medication`, such as `amoxicillin`.
+pub(crate) fn medication(rng: &mut StdRng) -> Value {
+    Value::String(MEDICATIONS[rng.random_range(0..MEDICATIONS.len())].to_string())
+}
+
+/// Generates `healthcare.hospitalDepartment`, such as `Cardiology`.
+pub(crate) fn hospital_department(rng: &mut StdRng) -> Value {
+    Value::String(HOSPITAL_DEPARTMENTS[rng.random_range(0..HOSPITAL_DEPARTMENTS.len())].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_icd10_codes_follow_the_format() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..50 {
+            let code = icd10_code(&mut rng);
+            let code = code.as_str().unwrap();
+            let (category, subcategory) = code.split_once('.').unwrap_or((code, "0"));
+            assert!(category.len() == 3 && subcategory.len() == 1, "{}", code);
+            assert!(category.starts_with(|char: char| char.is_ascii_uppercase() && char != 'U'), "{}", code);
+            assert!(category[1..].bytes().chain(subcategory.bytes()).all(|byte| byte.is_ascii_digit()), "{}", code);
+        }
+        assert!(MEDICATIONS.contains(&medication(&mut rng).as_str().unwrap()));
+        assert!(HOSPITAL_DEPARTMENTS.contains(&hospital_department(&mut rng).as_str().unwrap()));
+    }
+}
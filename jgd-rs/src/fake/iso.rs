@@ -0,0 +1,108 @@
+//! Standard codes of the `iso.*` keys: ISO 639-1 languages, ISO 3166-2 subdivisions,
+//! BCP 47 locale tags and IANA character sets.
+
+use rand::rngs::StdRng;
+use serde_json::Value;
+
+use crate::fake::{address_data, pick};
+
+/// ISO 639-1 codes of widely spoken languages.
+const LANGUAGES: &[&str] = &[
+    "ar", "bn", "cs", "cy", "da", "de", "el", "en", "es", "fa", "fi", "fr", "he", "hi", "hu", "id", "it", "ja",
+    "ko", "ms", "nl", "no", "pl", "pt", "ro", "ru", "sv", "sw", "ta", "th", "tr", "uk", "ur", "vi", "zh",
+];
+
+/// BCP 47 tags of common locales, a language and a region.
+const LOCALE_TAGS: &[&str] = &[
+    "ar-EG", "ar-SA", "cy-GB", "de-AT", "de-CH", "de-DE", "en-AU", "en-CA", "en-GB", "en-IN", "en-US", "es-AR",
+    "es-ES", "es-MX", "fr-BE", "fr-CA", "fr-CH", "fr-FR", "hi-IN", "it-CH", "it-IT", "ja-JP", "ko-KR", "nl-BE",
+    "nl-NL", "pl-PL", "pt-BR", "pt-PT", "ru-RU", "sv-SE", "tr-TR", "zh-CN", "zh-TW",
+];
+
+/// Character sets registered by IANA for MIME, with their preferred names.
+const CHARSETS: &[&str] = &[
+    "UTF-8", "UTF-16", "UTF-16BE", "UTF-16LE", "US-ASCII", "ISO-8859-1", "ISO-8859-2", "ISO-8859-15",
+    "windows-1251", "windows-1252", "KOI8-R", "Shift_JIS", "EUC-JP", "ISO-2022-JP", "GB2312", "GBK", "Big5",
+    "EUC-KR",
+];
+
+/// ISO 3166-2 subdivision codes (after the `XX-` country prefix) of the supported countries.
+const SUBDIVISIONS: &[(&str, &[&str])] = &[
+    ("AU", &["ACT", "NSW", "NT", "QLD", "SA", "TAS", "VIC", "WA"]),
+    ("BR", &["AC", "AL", "AM", "AP", "BA", "CE", "DF", "ES", "GO", "MA", "MG", "MS", "MT", "PA", "PB", "PE", "PI",
+        "PR", "RJ", "RN", "RO", "RR", "RS", "SC", "SE", "SP", "TO"]),
+    ("CA", &["AB", "BC", "MB", "NB", "NL", "NS", "NT", "NU", "ON", "PE", "QC", "SK", "YT"]),
+    ("DE", &["BB", "BE", "BW", "BY", "HB", "HE", "HH", "MV", "NI", "NW", "RP", "SH", "SL", "SN", "ST", "TH"]),
+    ("ES", &["AN", "AR", "AS", "CB", "CE", "CL", "CM", "CN", "CT", "EX", "GA", "IB", "MC", "MD", "ML", "NC", "PV",
+        "RI", "VC"]),
+    ("FR", &["20R", "ARA", "BFC", "BRE", "CVL", "GES", "HDF", "IDF", "NAQ", "NOR", "OCC", "PAC", "PDL"]),
+    ("GB", &["ENG", "NIR", "SCT", "WLS"]),
+    ("IT", &["21", "23", "25", "32", "34", "36", "42", "45", "52", "55", "57", "62", "65", "67", "72", "75", "77",
+        "78", "82", "88"]),
+    ("JP", &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12", "13", "14", "15", "16", "17",
+        "18", "19", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "30", "31", "32", "33", "34", "35",
+        "36", "37", "38", "39", "40", "41", "42", "43", "44", "45", "46", "47"]),
+    ("MX", &["AGU", "BCN", "BCS", "CAM", "CHH", "CHP", "CMX", "COA", "COL", "DUR", "GRO", "GUA", "HID", "JAL",
+        "MEX", "MIC", "MOR", "NAY", "NLE", "OAX", "PUE", "QUE", "ROO", "SIN", "SLP", "SON", "TAB", "TAM", "TLA",
+        "VER", "YUC", "ZAC"]),
+    ("SA", &["01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12", "14"]),
+    ("US", &["AK", "AL", "AR", "AZ", "CA", "CO", "CT", "DC", "DE", "FL", "GA", "HI", "IA", "ID", "IL", "IN", "KS",
+        "KY", "LA", "MA", "MD", "ME", "MI", "MN", "MO", "MS", "MT", "NC", "ND", "NE", "NH", "NJ", "NM", "NV", "NY",
+        "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VA", "VT", "WA", "WI", "WV", "WY"]),
+];
+
+fn subdivisions(country: &str) -> Option<&'static [&'static str]> {
+    SUBDIVISIONS.iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(country))
+        .map(|(_, subdivisions)| *subdivisions)
+}
+
+/// Checks that the argument of `iso.subdivision` is a country with known subdivisions.
+pub(crate) fn is_subdivision_country(value: &str) -> bool {
+    subdivisions(value).is_some()
+}
+
+/// Generates `iso.languageCode`, such as `fr`.
+pub(crate) fn language_code(rng: &mut StdRng) -> Value {
+    Value::String(pick(LANGUAGES, rng).to_string())
+}
+
+/// Generates `iso.localeTag`, such as `pt-BR`.
+pub(crate) fn locale_tag(rng: &mut StdRng) -> Value {
+    Value::String(pick(LOCALE_TAGS, rng).to_string())
+}
+
+/// Generates `iso.charset`, such as `UTF-8`.
+pub(crate) fn charset(rng: &mut StdRng) -> Value {
+    Value::String(pick(CHARSETS, rng).to_string())
+}
+
+/// Generates `iso.subdivision(country)`, such as `US-CA`. The country defaults to the
+/// country of `locale`.
+pub(crate) fn subdivision(country: Option<&str>, locale: &str, rng: &mut StdRng) -> Value {
+    let country = country.unwrap_or_else(|| address_data::records(locale)[0].country_code).to_uppercase();
+    let code = subdivisions(&country).map_or_else(String::new, |subdivisions| pick(subdivisions, rng).to_string());
+    Value::String(format!("{}-{}", country, code))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_subdivisions_of_country_or_locale() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let code = subdivision(Some("us"), "FR_FR", &mut rng);
+        let (country, state) = code.as_str().unwrap().split_once('-').unwrap();
+        assert!(country == "US" && subdivisions("US").unwrap().contains(&state), "{}", code);
+        assert!(subdivision(None, "JA_JP", &mut rng).as_str().unwrap().starts_with("JP-"));
+        assert!(subdivision(None, "CY_GB", &mut rng).as_str().unwrap().starts_with("GB-"));
+        assert!(is_subdivision_country("br") && !is_subdivision_country("XX"));
+
+        assert_eq!(language_code(&mut rng).as_str().unwrap().len(), 2);
+        assert!(LOCALE_TAGS.contains(&locale_tag(&mut rng).as_str().unwrap()));
+    }
+}
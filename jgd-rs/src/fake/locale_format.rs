@@ -0,0 +1,248 @@
+//! Locale conventions for formatting numbers and dates.
+//!
+//! Fake data is localized by the `fake` crate, but formats are not: dates are ISO 8601
+//! and numbers use `.` as decimal separator in every locale. [`LocaleFormat`] holds the
+//! separators, month names, date, address and money patterns and currency of each supported
+//! locale, used by the `localeFormat` transform, the `number.decimal` and
+//! `finance.amount` keys and the `address` and `money` fields.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime};
+
+use crate::{fake::money::symbol, locales_keys::LocalesKeys};
+
+/// Number and date conventions of a locale.
+pub(crate) struct LocaleFormat {
+    decimal: char,
+    group: &'static str,
+    months: [&'static str; 12],
+    /// Date pattern with `{day}`, `{month}` (name), `{m}` (number) and `{year}` placeholders.
+    date: &'static str,
+    /// One-line address pattern with placeholders named after the `address` field parts.
+    address: &'static str,
+    /// ISO 4217 code of the local currency.
+    currency: &'static str,
+    /// Money pattern with `{amount}` and `{symbol}` placeholders.
+    money: &'static str,
+}
+
+const EN: LocaleFormat = LocaleFormat {
+    decimal: '.',
+    group: ",",
+    months: ["January", "February", "March", "April", "May", "June", "July", "August", "September",
+        "October", "November", "December"],
+    date: "{month} {day}, {year}",
+    address: "{number} {street}, {city}, {state} {zip}, {country}",
+    currency: "USD",
+    money: "{symbol}{amount}",
+};
+
+const FR_FR: LocaleFormat = LocaleFormat {
+    decimal: ',',
+    group: " ",
+    months: ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre",
+        "octobre", "novembre", "décembre"],
+    date: "{day} {month} {year}",
+    address: "{number} {street}, {zip} {city}, {country}",
+    currency: "EUR",
+    money: "{amount} {symbol}",
+};
+
+const IT_IT: LocaleFormat = LocaleFormat {
+    decimal: ',',
+    group: ".",
+    months: ["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre",
+        "ottobre", "novembre", "dicembre"],
+    date: "{day} {month} {year}",
+    address: "{street} {number}, {zip} {city}, {country}",
+    currency: "EUR",
+    money: "{amount} {symbol}",
+};
+
+const JA_JP: LocaleFormat = LocaleFormat {
+    decimal: '.',
+    group: ",",
+    months: ["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"],
+    date: "{year}年{m}月{day}日",
+    address: "〒{zip} {state}{city}{street}{number}",
+    currency: "JPY",
+    money: "{symbol}{amount}",
+};
+
+const DE_DE: LocaleFormat = LocaleFormat {
+    decimal: ',',
+    group: ".",
+    months: ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+        "Oktober", "November", "Dezember"],
+    date: "{day}. {month} {year}",
+    address: "{street} {number}, {zip} {city}, {country}",
+    currency: "EUR",
+    money: "{amount} {symbol}",
+};
+
+const PT_BR: LocaleFormat = LocaleFormat {
+    decimal: ',',
+    group: ".",
+    months: ["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro",
+        "outubro", "novembro", "dezembro"],
+    date: "{day} de {month} de {year}",
+    address: "{street}, {number}, {city} - {state}, {zip}, {country}",
+    currency: "BRL",
+    money: "{symbol} {amount}",
+};
+
+/// Arabic month names with Latin digits, as in the `ar-SA-u-nu-latn` convention.
+const AR_SA: LocaleFormat = LocaleFormat {
+    decimal: '.',
+    group: ",",
+    months: ["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر",
+        "نوفمبر", "ديسمبر"],
+    date: "{day} {month} {year}",
+    address: "{number} {street}، {city} {zip}، {country}",
+    currency: "SAR",
+    money: "{amount} {symbol}",
+};
+
+const CY_GB: LocaleFormat = LocaleFormat {
+    decimal: '.',
+    group: ",",
+    months: ["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi",
+        "Hydref", "Tachwedd", "Rhagfyr"],
+    date: "{day} {month} {year}",
+    address: "{number} {street}, {city}, {zip}, {country}",
+    currency: "GBP",
+    money: "{symbol}{amount}",
+};
+
+impl LocaleFormat {
+    /// Returns the conventions of `locale`, falling back to `EN` like [`FakeGenerator`](crate::FakeGenerator).
+    pub(crate) fn of(locale: &str) -> &'static LocaleFormat {
+        match LocalesKeys::from(locale) {
+            LocalesKeys::En => &EN,
+            LocalesKeys::FrFr => &FR_FR,
+            LocalesKeys::ItIt => &IT_IT,
+            LocalesKeys::JaJp => &JA_JP,
+            LocalesKeys::DeDe => &DE_DE,
+            LocalesKeys::PtBr => &PT_BR,
+            LocalesKeys::ArSa => &AR_SA,
+            LocalesKeys::CyGb => &CY_GB,
+        }
+    }
+
+    /// Localizes a plain decimal number like `-1234.5`: groups the thousands and
+    /// replaces the decimal point. Returns `None` for anything else.
+    pub(crate) fn format_decimal(&self, number: &str) -> Option<String> {
+        self.format_number(number, true)
+    }
+
+    /// Localizes a plain decimal number, grouping the thousands only when `grouping` is set.
+    pub(crate) fn format_number(&self, number: &str, grouping: bool) -> Option<String> {
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", number),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+
+        let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+        if !is_digits(integer) || !fraction.is_none_or(is_digits) {
+            return None;
+        }
+
+        let mut formatted = sign.to_string();
+        for (index, digit) in integer.chars().enumerate() {
+            if grouping && index > 0 && (integer.len() - index) % 3 == 0 {
+                formatted.push_str(self.group);
+            }
+            formatted.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal);
+            formatted.push_str(fraction);
+        }
+
+        Some(formatted)
+    }
+
+    /// ISO 4217 code of the local currency, used by `money` fields with `"currency": "locale"`.
+    pub(crate) fn currency(&self) -> &'static str {
+        self.currency
+    }
+
+    /// Formats a plain decimal amount with the locale separators and the symbol of
+    /// `currency`, placed as the locale places it (`$1,234.50`, `1.234,50 €`).
+    /// Returns `None` when the amount is not a plain decimal number.
+    pub(crate) fn format_money(&self, amount: &str, currency: &str) -> Option<String> {
+        let amount = self.format_decimal(amount)?;
+        Some(self.money.replace("{amount}", &amount).replace("{symbol}", symbol(currency)))
+    }
+
+    /// Formats a date with the locale pattern and month names.
+    pub(crate) fn format_date(&self, date: NaiveDate) -> String {
+        self.date
+            .replace("{day}", &date.day().to_string())
+            .replace("{month}", self.months[date.month0() as usize])
+            .replace("{m}", &date.month().to_string())
+            .replace("{year}", &date.year().to_string())
+    }
+
+    /// Formats an address from its parts (`street`, `number`, `city`, `state`, `zip`, `country`).
+    pub(crate) fn format_address(&self, parts: &serde_json::Map<String, serde_json::Value>) -> String {
+        parts.iter().fold(self.address.to_string(), |address, (name, value)| {
+            address.replace(&format!("{{{}}}", name), value.as_str().unwrap_or_default())
+        })
+    }
+
+    /// Formats a date (`2024-03-01`), date time (RFC 3339 or `2024-03-01 10:00:00`) or
+    /// plain decimal number. Returns `None` for other values.
+    pub(crate) fn format_str(&self, value: &str) -> Option<String> {
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Some(self.format_date(date));
+        }
+
+        let date_time = DateTime::parse_from_rfc3339(value).map(|date_time| date_time.naive_local())
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S"));
+        if let Ok(date_time) = date_time {
+            return Some(format!("{} {}", self.format_date(date_time.date()), date_time.format("%H:%M:%S")));
+        }
+
+        self.format_decimal(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_per_locale() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(LocaleFormat::of("EN").format_date(date), "March 5, 2024");
+        assert_eq!(LocaleFormat::of("DE_DE").format_date(date), "5. März 2024");
+        assert_eq!(LocaleFormat::of("JA_JP").format_date(date), "2024年3月5日");
+        assert_eq!(LocaleFormat::of("PT_BR").format_date(date), "5 de março de 2024");
+
+        assert_eq!(LocaleFormat::of("EN").format_decimal("-1234567.50").as_deref(), Some("-1,234,567.50"));
+        assert_eq!(LocaleFormat::of("FR_FR").format_decimal("1234.5").as_deref(), Some("1 234,5"));
+        assert_eq!(LocaleFormat::of("DE_DE").format_decimal("999").as_deref(), Some("999"));
+        assert_eq!(LocaleFormat::of("EN").format_decimal("1e10"), None);
+        assert_eq!(LocaleFormat::of("EN").format_decimal("1."), None);
+        assert_eq!(LocaleFormat::of("PT_BR").format_number("1234.5", false).as_deref(), Some("1234,5"));
+
+        assert_eq!(LocaleFormat::of("EN").format_money("1234.50", "USD").as_deref(), Some("$1,234.50"));
+        assert_eq!(LocaleFormat::of("DE_DE").format_money("1234.50", "EUR").as_deref(), Some("1.234,50 €"));
+        assert_eq!(LocaleFormat::of("PT_BR").format_money("99.90", "BRL").as_deref(), Some("R$ 99,90"));
+        assert_eq!(LocaleFormat::of("FR_FR").format_money("5", "SEK").as_deref(), Some("5 SEK"));
+    }
+
+    #[test]
+    fn test_format_str_detects_dates_and_numbers() {
+        let format = LocaleFormat::of("IT_IT");
+        assert_eq!(format.format_str("2024-12-01").as_deref(), Some("1 dicembre 2024"));
+        assert_eq!(format.format_str("2024-12-01T08:30:00+00:00").as_deref(), Some("1 dicembre 2024 08:30:00"));
+        assert_eq!(format.format_str("10500.25").as_deref(), Some("10.500,25"));
+        assert_eq!(format.format_str("Roma"), None);
+    }
+}
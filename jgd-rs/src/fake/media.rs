@@ -0,0 +1,152 @@
+//! File metadata and image placeholder values of the `file.*` and `image.*` keys.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::{Map, Value};
+
+use super::pick;
+use crate::Arguments;
+
+/// File extensions with their MIME type.
+const FILE_TYPES: &[(&str, &str)] = &[
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("svg", "image/svg+xml"),
+    ("mp4", "video/mp4"),
+    ("mp3", "audio/mpeg"),
+    ("csv", "text/csv"),
+    ("txt", "text/plain"),
+    ("json", "application/json"),
+    ("zip", "application/zip"),
+    ("docx", "application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+    ("xlsx", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+];
+
+const FILE_STEMS: &[&str] = &[
+    "invoice", "report", "avatar", "photo", "contract", "receipt", "statement", "backup", "export", "presentation",
+    "screenshot", "resume", "notes", "summary", "banner",
+];
+
+const S3_BUCKETS: &[&str] = &["app-uploads", "user-content", "media-assets", "documents-prod", "static-files"];
+
+const S3_REGIONS: &[&str] = &["us-east-1", "us-west-2", "eu-west-1", "eu-central-1", "ap-southeast-2", "sa-east-1"];
+
+const S3_FOLDERS: &[&str] = &["uploads", "documents", "images", "exports", "attachments"];
+
+/// Default range of `file.sizeBytes`: 1KB to 10MB.
+const DEFAULT_SIZES: (u64, u64) = (1 << 10, 10 << 20);
+
+/// Default size of the `image.*` placeholders.
+const DEFAULT_IMAGE_SIZE: (usize, usize) = (640, 480);
+
+/// Parses a size such as `512`, `1KB` or `2.5 MB` into bytes; units are powers of 1024.
+pub(crate) fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let split = size.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let factor: u64 = match unit.trim().to_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" => 1 << 10,
+        "MB" => 1 << 20,
+        "GB" => 1 << 30,
+        "TB" => 1 << 40,
+        _ => return None,
+    };
+    number.parse::<f64>().ok().map(|number| (number * factor as f64).round() as u64)
+}
+
+/// Generates `file.sizeBytes(min..max)`: a size in bytes within the range.
+pub(crate) fn size_bytes(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let min = arguments.get_str(0).and_then(parse_size).unwrap_or(DEFAULT_SIZES.0);
+    let max = arguments.get_str(1).and_then(parse_size).unwrap_or(DEFAULT_SIZES.1.max(min));
+    Value::from(if min < max { rng.random_range(min..=max) } else { min })
+}
+
+/// Generates `file.s3Url`: the URL of an object in an S3 bucket.
+pub(crate) fn s3_url(rng: &mut StdRng) -> Value {
+    let (extension, _) = FILE_TYPES[rng.random_range(0..FILE_TYPES.len())];
+    Value::String(s3_object_url(&format!("{}.{}", object_id(rng), extension), rng))
+}
+
+/// Generates `file.metadata`: the name, extension, MIME type, size and URL of a file.
+pub(crate) fn metadata(rng: &mut StdRng) -> Value {
+    let (extension, mime_type) = FILE_TYPES[rng.random_range(0..FILE_TYPES.len())];
+    let name = format!("{}-{}.{}", pick(FILE_STEMS, rng), rng.random_range(1..10_000), extension);
+    let url = s3_object_url(&format!("{}/{}", object_id(rng), name), rng);
+
+    let mut file = Map::new();
+    file.insert("name".to_string(), Value::String(name));
+    file.insert("extension".to_string(), Value::String(extension.to_string()));
+    file.insert("mimeType".to_string(), Value::String(mime_type.to_string()));
+    file.insert("sizeBytes".to_string(), size_bytes(&Arguments::default(), rng));
+    file.insert("url".to_string(), Value::String(url));
+    Value::Object(file)
+}
+
+fn object_id(rng: &mut StdRng) -> String {
+    uuid::Builder::from_random_bytes(rng.random()).into_uuid().simple().to_string()
+}
+
+fn s3_object_url(key: &str, rng: &mut StdRng) -> String {
+    format!("https://{}.s3.{}.amazonaws.com/{}/{}", pick(S3_BUCKETS, rng), pick(S3_REGIONS, rng), pick(S3_FOLDERS, rng), key)
+}
+
+/// Width and height of an `image.*` key, `(640, 480)` by default.
+fn image_size(arguments: &Arguments) -> (usize, usize) {
+    let width = arguments.get_num(0).unwrap_or(DEFAULT_IMAGE_SIZE.0);
+    (width, arguments.get_num(1).unwrap_or(if arguments.is_empty() { DEFAULT_IMAGE_SIZE.1 } else { width }))
+}
+
+fn background(rng: &mut StdRng) -> String {
+    format!("{:06x}", rng.random_range(0..0x100_0000))
+}
+
+/// Generates `image.url(width, height)`: a placeholder image URL from placehold.co.
+pub(crate) fn image_url(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let (width, height) = image_size(arguments);
+    Value::String(format!("https://placehold.co/{}x{}/{}/ffffff/png", width, height, background(rng)))
+}
+
+/// Generates `image.dataUri(width, height)`: an inline SVG placeholder image.
+pub(crate) fn image_data_uri(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let (width, height) = image_size(arguments);
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='{w}' height='{h}'><rect width='100%' height='100%' fill='#{}'/></svg>",
+        background(rng), w = width, h = height,
+    );
+    let encoded = svg.replace('%', "%25").replace('<', "%3C").replace('>', "%3E").replace('#', "%23").replace(' ', "%20");
+    Value::String(format!("data:image/svg+xml,{}", encoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_file_and_image_values() {
+        assert_eq!((parse_size("512"), parse_size("1KB"), parse_size("2.5 mb"), parse_size("1XB")), (Some(512), Some(1024), Some(2_621_440), None));
+
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let size = size_bytes(&Arguments::from("(1KB..2KB)"), &mut rng).as_u64().unwrap();
+            assert!((1024..=2048).contains(&size));
+        }
+
+        let file = metadata(&mut rng);
+        let name = file["name"].as_str().unwrap();
+        assert!(name.ends_with(&format!(".{}", file["extension"].as_str().unwrap())));
+        assert!(FILE_TYPES.iter().any(|(extension, mime)| file["extension"] == *extension && file["mimeType"] == *mime));
+        assert!(file["url"].as_str().unwrap().starts_with("https://") && file["url"].as_str().unwrap().ends_with(name));
+        assert!(s3_url(&mut rng).as_str().unwrap().contains(".amazonaws.com/"));
+
+        let url = image_url(&Arguments::from("(300, 200)"), &mut rng);
+        assert!(url.as_str().unwrap().starts_with("https://placehold.co/300x200/"));
+        assert!(image_url(&Arguments::default(), &mut rng).as_str().unwrap().contains("/640x480/"));
+        let data_uri = image_data_uri(&Arguments::from("(64)"), &mut rng);
+        assert!(data_uri.as_str().unwrap().starts_with("data:image/svg+xml,%3Csvg") && data_uri.as_str().unwrap().contains("width='64'%20height='64'"));
+    }
+}
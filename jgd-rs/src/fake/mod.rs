@@ -1,6 +1,32 @@
+pub(crate) mod address_data;
+mod business_hours;
+mod commerce;
+mod crypto;
+mod education;
 mod fake_generator;
 mod fake_locale_generator;
 mod fake_keys;
+mod email;
+mod evaluate_key;
+#[cfg(feature = "healthcare")]
+mod healthcare;
+mod iso;
+mod locale_format;
+pub(crate) mod media;
+pub(crate) mod money;
+mod palette;
+pub(crate) mod strings;
+mod user_agent;
+mod vehicle;
 
 pub use fake_generator::FakeGenerator;
-pub use fake_keys::*;
\ No newline at end of file
+pub use fake_keys::*;
+pub use evaluate_key::evaluate_key;
+pub(crate) use locale_format::LocaleFormat;
+
+use rand::{rngs::StdRng, Rng};
+
+/// Picks a random element of a non-empty `list`.
+pub(crate) fn pick<'a, T>(list: &'a [T], rng: &mut StdRng) -> &'a T {
+    &list[rng.random_range(0..list.len())]
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+//! Currencies of the `money` field and the `finance.amount` keys: the decimals (ISO 4217
+//! minor units) and symbol of each currency.
+
+/// Currencies picked when no currency is set, with their minor units.
+pub(crate) const CURRENCIES: &[(&str, u32)] = &[
+    ("USD", 2), ("EUR", 2), ("GBP", 2), ("JPY", 0), ("BRL", 2), ("SAR", 2), ("CHF", 2), ("CAD", 2),
+    ("AUD", 2), ("CNY", 2), ("INR", 2), ("MXN", 2), ("KRW", 0), ("BHD", 3), ("KWD", 3),
+];
+
+/// Other currencies whose minor units are not 2.
+const OTHER_MINOR_UNITS: &[(&str, u32)] = &[
+    ("CLP", 0), ("ISK", 0), ("VND", 0), ("UGX", 0), ("PYG", 0), ("XOF", 0), ("XAF", 0),
+    ("IQD", 3), ("JOD", 3), ("LYD", 3), ("OMR", 3), ("TND", 3),
+];
+
+/// Symbols of the common currencies; dollars and yuan other than `USD` carry a prefix
+/// so that they are not mistaken for it.
+const SYMBOLS: &[(&str, &str)] = &[
+    ("USD", "$"), ("EUR", "€"), ("GBP", "£"), ("JPY", "¥"), ("BRL", "R$"), ("SAR", "ر.س"),
+    ("CHF", "CHF"), ("CAD", "CA$"), ("AUD", "A$"), ("CNY", "CN¥"), ("INR", "₹"), ("MXN", "MX$"),
+    ("KRW", "₩"), ("BHD", "BD"), ("KWD", "KD"),
+];
+
+/// Decimals of a currency amount; 2 for currencies not listed.
+pub(crate) fn minor_units(currency: &str) -> u32 {
+    CURRENCIES.iter().chain(OTHER_MINOR_UNITS)
+        .find(|(code, _)| code.eq_ignore_ascii_case(currency))
+        .map_or(2, |(_, units)| *units)
+}
+
+/// Symbol of a currency; the code itself for currencies without a listed symbol.
+pub(crate) fn symbol(currency: &str) -> &str {
+    SYMBOLS.iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(currency))
+        .map_or(currency, |(_, symbol)| symbol)
+}
+
+/// Checks that the argument of a `finance.amount` key is a currency code such as `USD`.
+pub(crate) fn is_currency_code(value: &str) -> bool {
+    value.len() == 3 && value.chars().all(|char| char.is_ascii_alphabetic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_units_and_symbols() {
+        assert_eq!((minor_units("JPY"), minor_units("bhd"), minor_units("EUR"), minor_units("XYZ")), (0, 3, 2, 2));
+        assert_eq!((symbol("usd"), symbol("CAD"), symbol("SEK")), ("$", "CA$", "SEK"));
+        assert!(is_currency_code("eur") && !is_currency_code("EURO") && !is_currency_code("U$D"));
+    }
+}
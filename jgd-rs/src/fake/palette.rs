@@ -0,0 +1,66 @@
+//! Harmonious colors of the `color.palette` key.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::Value;
+
+use crate::Arguments;
+
+/// Converts a hue (degrees), saturation and lightness (0 to 1) to a `#rrggbb` color.
+fn hsl_to_hex(hue: f64, saturation: f64, lightness: f64) -> String {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |value: f64| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(r), channel(g), channel(b))
+}
+
+/// Generates `color.palette(count)`: `count` hex colors (5 by default) of one harmony.
+///
+/// The palette is analogous (neighbour hues), complementary (a hue and its opposite),
+/// evenly spread around the color wheel, or monochromatic (one hue, several lightnesses).
+pub(crate) fn palette(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let count: usize = arguments.get_num(0).unwrap_or(5);
+    let base = rng.random_range(0.0..360.0);
+    let saturation = rng.random_range(0.45..0.8);
+    let harmony = rng.random_range(0..4);
+
+    let colors = (0..count).map(|index| {
+        let position = index as f64;
+        let (hue, lightness) = match harmony {
+            0 => (base + position * 25.0, rng.random_range(0.4..0.6)),
+            1 => (base + (index % 2) as f64 * 180.0, 0.3 + 0.4 * position / count.max(2) as f64),
+            2 => (base + position * 360.0 / count as f64, rng.random_range(0.4..0.6)),
+            _ => (base, 0.2 + 0.6 * position / (count.max(2) - 1) as f64),
+        };
+        Value::String(hsl_to_hex(hue, saturation, lightness))
+    });
+    Value::Array(colors.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_palette_colors() {
+        assert_eq!((hsl_to_hex(0.0, 1.0, 0.5), hsl_to_hex(120.0, 1.0, 0.5), hsl_to_hex(240.0, 1.0, 0.25)), ("#ff0000".to_string(), "#00ff00".to_string(), "#000080".to_string()));
+
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..20 {
+            let palette = palette(&Arguments::from("(3)"), &mut rng);
+            assert_eq!(palette.as_array().unwrap().len(), 3);
+        }
+        assert_eq!(palette(&Arguments::default(), &mut rng).as_array().unwrap().len(), 5);
+    }
+}
@@ -0,0 +1,205 @@
+//! Adversarial strings of the `string.unicode(kind)` and `string.edge(kind)` keys, for
+//! robustness testing of parsers, databases and user interfaces, and the random
+//! strings of `string.random(length, charset)` and `randomString` fields.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::Value;
+
+use super::pick;
+use crate::Arguments;
+
+/// Kinds of `string.unicode`.
+const UNICODE_KINDS: &[&str] = &["emoji", "rtl", "cjk", "zalgo"];
+
+/// Kinds of `string.edge`.
+const EDGE_KINDS: &[&str] = &["empty", "whitespace", "sqllike", "htmlinjection"];
+
+/// Named character sets of random strings.
+const CHARSETS: &[(&str, &str)] = &[
+    ("alpha", "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"),
+    ("alnum", "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789"),
+    ("lower", "abcdefghijklmnopqrstuvwxyz"),
+    ("upper", "ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+    ("numeric", "0123456789"),
+    ("hex", "0123456789abcdef"),
+    ("base64url", "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"),
+];
+
+/// Default charset of random strings.
+pub(crate) const DEFAULT_CHARSET: &str = "alnum";
+
+/// Default length of `string.random`.
+const DEFAULT_LENGTH: usize = 16;
+
+/// Emoji, including ZWJ sequences, skin tones, flags and variation selectors.
+const EMOJI: &[&str] = &[
+    "😀", "😂", "🥲", "😍", "🤔", "🙃", "🔥", "✨", "🎉", "🚀", "💩", "🦀", "🍕", "🌈",
+    "👍🏽", "👋🏿", "🧑🏻‍💻", "👨‍👩‍👧‍👦", "🏳️‍🌈", "❤️", "☺️", "🇯🇵", "🇧🇷", "🇺🇳", "1️⃣", "#️⃣",
+];
+
+/// Right-to-left phrases, some mixed with left-to-right text or bidirectional controls.
+const RTL: &[&str] = &[
+    "مرحبا بالعالم", "شكرا جزيلا", "שלום עולם", "תודה רבה", "سلام دنیا", "خوش آمدید",
+    "Order #123 تم الشحن", "שם משתמש: admin", "\u{202E}txt.exe", "abc \u{202B}עברית\u{202C} def",
+    "\u{200F}مرحبا\u{200E} hello",
+];
+
+/// Chinese, Japanese and Korean text, including fullwidth forms.
+const CJK: &[&str] = &[
+    "你好世界", "数据生成器", "測試資料", "こんにちは世界", "テストデータ", "カタカナとひらがな",
+    "안녕하세요 세계", "테스트 데이터", "ＦＵＬＬＷＩＤＴＨ１２３", "日本語テキスト、句読点。", "𠜎𠜱𠝹𠱓",
+];
+
+/// Base words of zalgo text.
+const ZALGO_WORDS: &[&str] = &["hello", "zalgo", "lorem", "ipsum", "he comes", "test data"];
+
+/// Whitespace-only strings, with invisible and non-ASCII spaces.
+const WHITESPACE: &[&str] = &[
+    " ", "   ", "\t", "\n", "\r\n", " \t \n ", "\u{00A0}", "\u{200B}", "\u{2003}", "\u{3000}",
+    "\u{FEFF}", "\u{2028}", " \u{00A0}\u{200B} ",
+];
+
+/// Strings that look like SQL injection.
+const SQL_LIKE: &[&str] = &[
+    "' OR '1'='1", "' OR 1=1 --", "admin'--", "'; DROP TABLE users; --", "\" OR \"\"=\"",
+    "1' UNION SELECT NULL, NULL--", "1; SELECT * FROM information_schema.tables", "O'Reilly",
+    "%' AND 1=0 UNION ALL SELECT 'x", "\\'; --",
+];
+
+/// Strings that look like HTML or script injection.
+const HTML_INJECTION: &[&str] = &[
+    "<script>alert(1)</script>", "<img src=x onerror=alert(1)>", "\"><svg onload=alert(1)>",
+    "javascript:alert(1)", "<iframe src=\"javascript:alert(1)\"></iframe>", "<b>bold</b>",
+    "&lt;script&gt;alert(1)&lt;/script&gt;", "{{7*7}}", "${7*7}", "<a href=\"#\" onclick=\"alert(1)\">click</a>",
+];
+
+/// Checks that the argument of `string.unicode` is a kind or `any`.
+pub(crate) fn is_unicode_kind(value: &str) -> bool {
+    is_kind(UNICODE_KINDS, value)
+}
+
+/// Checks that the argument of `string.edge` is a kind or `any`.
+pub(crate) fn is_edge_kind(value: &str) -> bool {
+    is_kind(EDGE_KINDS, value)
+}
+
+fn is_kind(kinds: &[&str], value: &str) -> bool {
+    let value = value.to_lowercase();
+    value == "any" || kinds.contains(&value.as_str())
+}
+
+/// The kind among the arguments, or a random one when missing or `any`.
+fn kind(kinds: &[&'static str], arguments: &Arguments, rng: &mut StdRng) -> &'static str {
+    let requested = arguments.get_str(0).map(str::to_lowercase);
+    kinds.iter().copied()
+        .find(|kind| requested.as_deref() == Some(*kind))
+        .unwrap_or_else(|| *pick(kinds, rng))
+}
+
+/// Characters of a named charset, or the characters of `charset` itself for custom sets.
+pub(crate) fn charset(charset: &str) -> Vec<char> {
+    CHARSETS.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(charset))
+        .map_or(charset, |(_, chars)| chars)
+        .chars()
+        .collect()
+}
+
+/// A string of `length` characters picked from `chars`, which must not be empty.
+pub(crate) fn random_string(chars: &[char], length: usize, rng: &mut StdRng) -> String {
+    (0..length).map(|_| chars[rng.random_range(0..chars.len())]).collect()
+}
+
+/// Checks an argument of `string.random`: a length first, then a length or a charset.
+pub(crate) fn is_random_argument(index: usize, value: &str) -> bool {
+    match index {
+        0 => value.parse::<usize>().is_ok(),
+        _ => !value.is_empty(),
+    }
+}
+
+/// Generates `string.random(length, charset)` or `string.random(min..max, charset)`.
+///
+/// A second number is the largest length, so custom charsets made of digits only
+/// need the `min..max` form.
+pub(crate) fn random(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let min = arguments.get_num(0).unwrap_or(DEFAULT_LENGTH);
+    let (max, charset_index) = match arguments.get_num::<usize>(1) {
+        Some(max) => (max, 2),
+        None => (min, 1),
+    };
+    let chars = charset(arguments.get_str(charset_index).unwrap_or(DEFAULT_CHARSET));
+    let length = if min < max { rng.random_range(min..=max) } else { min };
+    Value::String(random_string(&chars, length, rng))
+}
+
+/// Adds 1 to 8 combining marks above, through and below each letter of a word.
+fn zalgo(rng: &mut StdRng) -> String {
+    let mut text = String::new();
+    for letter in pick(ZALGO_WORDS, rng).chars() {
+        text.push(letter);
+        if letter == ' ' {
+            continue;
+        }
+        for _ in 0..rng.random_range(1..=8) {
+            text.push(char::from_u32(rng.random_range(0x0300..=0x036F)).unwrap_or('\u{0300}'));
+        }
+    }
+    text
+}
+
+/// Generates `string.unicode(kind)`: emoji, right-to-left, CJK or zalgo text.
+pub(crate) fn unicode(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let text = match kind(UNICODE_KINDS, arguments, rng) {
+        "emoji" => (0..rng.random_range(1..=5)).map(|_| *pick(EMOJI, rng)).collect(),
+        "rtl" => pick(RTL, rng).to_string(),
+        "cjk" => pick(CJK, rng).to_string(),
+        _ => zalgo(rng),
+    };
+    Value::String(text)
+}
+
+/// Generates `string.edge(kind)`: an empty, whitespace-only, SQL-like or HTML injection string.
+pub(crate) fn edge(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let text = match kind(EDGE_KINDS, arguments, rng) {
+        "empty" => "",
+        "whitespace" => *pick(WHITESPACE, rng),
+        "sqllike" => *pick(SQL_LIKE, rng),
+        _ => *pick(HTML_INJECTION, rng),
+    };
+    Value::String(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn text(value: Value) -> String {
+        value.as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_adversarial_strings() {
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..20 {
+            assert!(!text(unicode(&Arguments::from("(emoji)"), &mut rng)).is_ascii());
+            assert!(text(unicode(&Arguments::from("(zalgo)"), &mut rng)).chars().any(|c| ('\u{0300}'..='\u{036F}').contains(&c)));
+            assert!(CJK.contains(&text(unicode(&Arguments::from("(CJK)"), &mut rng)).as_str()));
+            assert_eq!(text(edge(&Arguments::from("(empty)"), &mut rng)), "");
+            assert!(text(edge(&Arguments::from("(whitespace)"), &mut rng)).trim().chars().all(|c| !c.is_alphanumeric()));
+            assert!(SQL_LIKE.contains(&text(edge(&Arguments::from("(sqlLike)"), &mut rng)).as_str()));
+            assert!(HTML_INJECTION.contains(&text(edge(&Arguments::from("(htmlInjection)"), &mut rng)).as_str()));
+        }
+        let hex = text(random(&Arguments::from("(12, hex)"), &mut rng));
+        assert!(hex.len() == 12 && hex.chars().all(|c| c.is_ascii_hexdigit()), "{}", hex);
+        let custom = text(random(&Arguments::from("(4..6, 'xy')"), &mut rng));
+        assert!((4..=6).contains(&custom.len()) && custom.chars().all(|c| c == 'x' || c == 'y'), "{}", custom);
+        assert!(text(random(&Arguments::default(), &mut rng)).chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_eq!(charset("NUMERIC").len(), 10);
+
+        assert!(is_unicode_kind("RTL") && is_unicode_kind("any") && !is_unicode_kind("latin"));
+        assert!(is_edge_kind("sqlLike") && !is_edge_kind("xss"));
+    }
+}
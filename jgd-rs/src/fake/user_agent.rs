@@ -0,0 +1,111 @@
+//! User agents of the `internet.userAgent(device, browser)` key.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::Value;
+
+use super::pick;
+use crate::Arguments;
+
+/// Devices accepted as arguments.
+const DEVICES: &[&str] = &["desktop", "mobile", "bot"];
+
+/// Browsers accepted as arguments, for desktop and mobile user agents.
+const BROWSERS: &[&str] = &["chrome", "firefox", "safari", "edge"];
+
+/// Crawlers accepted as arguments, with their user agent.
+const BOTS: &[(&str, &str)] = &[
+    ("googlebot", "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"),
+    ("bingbot", "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)"),
+    ("duckduckbot", "DuckDuckBot/1.1; (+http://duckduckgo.com/duckduckbot.html)"),
+    ("yandexbot", "Mozilla/5.0 (compatible; YandexBot/3.0; +http://yandex.com/bots)"),
+];
+
+const DESKTOP_SYSTEMS: &[&str] = &["Windows NT 10.0; Win64; x64", "Macintosh; Intel Mac OS X 10_15_7", "X11; Linux x86_64"];
+
+const ANDROID_MODELS: &[&str] = &["Pixel 8", "SM-S918B", "SM-A546B", "2201116SG", "K"];
+
+/// Checks that an argument of `internet.userAgent` is a device, a browser, a crawler or `any`.
+pub(crate) fn is_target(value: &str) -> bool {
+    let value = value.to_lowercase();
+    value == "any" || DEVICES.contains(&value.as_str()) || BROWSERS.contains(&value.as_str())
+        || BOTS.iter().any(|(bot, _)| *bot == value)
+}
+
+/// Generates a user agent of the device and browser among the arguments, in any order.
+///
+/// Missing targets are picked at random: a desktop or mobile device (crawlers only when
+/// asked for) and one of the browsers.
+pub(crate) fn user_agent(arguments: &Arguments, rng: &mut StdRng) -> Value {
+    let targets: Vec<String> = arguments.values().iter().map(|value| value.to_lowercase()).collect();
+    let find = |values: &[&'static str]| values.iter().copied().find(|value| targets.iter().any(|target| target == value));
+
+    if let Some((_, agent)) = BOTS.iter().find(|(bot, _)| targets.iter().any(|target| target == bot)) {
+        return Value::String(agent.to_string());
+    }
+    let device = find(DEVICES).unwrap_or_else(|| *pick(&DEVICES[..2], rng));
+    if device == "bot" {
+        return Value::String(BOTS[rng.random_range(0..BOTS.len())].1.to_string());
+    }
+    let browser = find(BROWSERS).unwrap_or_else(|| *pick(BROWSERS, rng));
+
+    let chrome = rng.random_range(118..=131);
+    let firefox = rng.random_range(115..=133);
+    let (safari, minor) = (rng.random_range(15..=18), rng.random_range(0..=6));
+    let agent = match (device, browser) {
+        ("mobile", "chrome") => format!(
+            "Mozilla/5.0 (Linux; Android {}; {}) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.0.0 Mobile Safari/537.36",
+            rng.random_range(11..=15), pick(ANDROID_MODELS, rng), chrome,
+        ),
+        ("mobile", "firefox") => format!(
+            "Mozilla/5.0 (Android {}; Mobile; rv:{v}.0) Gecko/{v}.0 Firefox/{v}.0", rng.random_range(11..=15), v = firefox,
+        ),
+        ("mobile", "safari") => format!(
+            "Mozilla/5.0 (iPhone; CPU iPhone OS {v}_{m} like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{v}.{m} Mobile/15E148 Safari/604.1",
+            v = safari, m = minor,
+        ),
+        ("mobile", _) => format!(
+            "Mozilla/5.0 (Linux; Android {}; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{v}.0.0.0 Mobile Safari/537.36 EdgA/{v}.0.0.0",
+            rng.random_range(11..=15), v = chrome,
+        ),
+        (_, "chrome") => format!(
+            "Mozilla/5.0 ({}) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.0.0 Safari/537.36", pick(DESKTOP_SYSTEMS, rng), chrome,
+        ),
+        (_, "firefox") => format!(
+            "Mozilla/5.0 ({}; rv:{v}.0) Gecko/20100101 Firefox/{v}.0", pick(DESKTOP_SYSTEMS, rng).replace("10_15_7", "10.15"), v = firefox,
+        ),
+        (_, "safari") => format!(
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{}.{} Safari/605.1.15", safari, minor,
+        ),
+        _ => format!(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{v}.0.0.0 Safari/537.36 Edg/{v}.0.0.0", v = chrome,
+        ),
+    };
+    Value::String(agent)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn agent(arguments: &str, rng: &mut StdRng) -> String {
+        user_agent(&Arguments::from(arguments), rng).as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_user_agent_targets() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..20 {
+            let mobile_chrome = agent("(mobile, chrome)", &mut rng);
+            assert!(mobile_chrome.contains("Android") && mobile_chrome.contains("Chrome/") && !mobile_chrome.contains("EdgA"));
+            assert!(agent("(safari, mobile)", &mut rng).contains("iPhone"));
+            assert!(agent("(desktop, firefox)", &mut rng).contains("Gecko/20100101 Firefox/"));
+            assert!(agent("(edge)", &mut rng).contains("Edg"));
+            assert!(agent("(bot)", &mut rng).to_lowercase().contains("bot"));
+            assert!(!agent("(chrome)", &mut rng).contains("bot"));
+        }
+        assert!(agent("(googlebot)", &mut rng).contains("Googlebot"));
+        assert!(is_target("Mobile") && is_target("any") && !is_target("opera"));
+    }
+}
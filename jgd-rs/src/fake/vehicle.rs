@@ -0,0 +1,107 @@
+//! Vehicles of the `automotive.make`, `automotive.model`, `automotive.vin` and
+//! `automotive.year` keys.
+
+use rand::{rngs::StdRng, Rng};
+use serde_json::Value;
+
+use super::pick;
+
+/// Makes with some of their models.
+const VEHICLES: &[(&str, &[&str])] = &[
+    ("Toyota", &["Corolla", "Camry", "RAV4", "Yaris", "Prius", "Hilux"]),
+    ("Volkswagen", &["Golf", "Polo", "Passat", "Tiguan", "ID.4"]),
+    ("Ford", &["Focus", "Fiesta", "Mustang", "F-150", "Explorer"]),
+    ("Honda", &["Civic", "Accord", "CR-V", "Jazz"]),
+    ("Renault", &["Clio", "Mégane", "Captur", "Zoe"]),
+    ("Fiat", &["500", "Panda", "Tipo", "Punto"]),
+    ("BMW", &["3 Series", "5 Series", "X3", "X5", "i4"]),
+    ("Mercedes-Benz", &["A-Class", "C-Class", "E-Class", "GLC"]),
+    ("Hyundai", &["i30", "Tucson", "Kona", "Elantra"]),
+    ("Chevrolet", &["Malibu", "Silverado", "Onix", "Equinox"]),
+    ("Nissan", &["Micra", "Qashqai", "Leaf", "Altima"]),
+    ("Peugeot", &["208", "308", "3008", "5008"]),
+    ("Tesla", &["Model 3", "Model S", "Model X", "Model Y"]),
+    ("Kia", &["Rio", "Sportage", "Ceed", "Niro"]),
+];
+
+/// Characters of a VIN: digits and capital letters but `I`, `O` and `Q`.
+const VIN_CHARS: &[u8] = b"0123456789ABCDEFGHJKLMNPRSTUVWXYZ";
+
+/// Characters of the model year, the tenth of a VIN.
+const VIN_YEAR_CHARS: &[u8] = b"ABCDEFGHJKLMNPRSTVWXY123456789";
+
+/// Weights of the VIN positions in the check digit, which is the ninth character.
+const VIN_WEIGHTS: [u32; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Value of a VIN character in the check digit (ISO 3779 transliteration).
+fn vin_value(char: u8) -> u32 {
+    match char {
+        b'0'..=b'9' => u32::from(char - b'0'),
+        b'A'..=b'H' => u32::from(char - b'A') + 1,
+        b'J'..=b'R' => [1, 2, 3, 4, 5, 0, 7, 0, 9][usize::from(char - b'J')],
+        _ => u32::from(char - b'S') + 2,
+    }
+}
+
+/// Check digit of a VIN, `0` to `9` or `X` for 10.
+fn vin_check_digit(vin: &[u8]) -> u8 {
+    let sum: u32 = vin.iter().zip(VIN_WEIGHTS).map(|(char, weight)| vin_value(*char) * weight).sum();
+    match sum % 11 {
+        10 => b'X',
+        digit => b'0' + digit as u8,
+    }
+}
+
+/// Generates `automotive.make`, such as `Toyota`.
+pub(crate) fn make(rng: &mut StdRng) -> Value {
+    Value::String(pick(VEHICLES, rng).0.to_string())
+}
+
+/// Generates `automotive.model`, such as `Corolla`, of any make.
+pub(crate) fn model(rng: &mut StdRng) -> Value {
+    let (_, models) = pick(VEHICLES, rng);
+    Value::String(pick(models, rng).to_string())
+}
+
+/// Generates `automotive.vin`: a 17 character Vehicle Identification Number with a
+/// valid check digit, a model year character and a numeric serial number.
+pub(crate) fn vin(rng: &mut StdRng) -> Value {
+    let mut vin: Vec<u8> = (0..17).map(|_| *pick(VIN_CHARS, rng)).collect();
+    vin[9] = *pick(VIN_YEAR_CHARS, rng);
+    for char in &mut vin[11..] {
+        *char = b'0' + rng.random_range(0..10u8);
+    }
+    vin[8] = vin_check_digit(&vin);
+    Value::String(vin.into_iter().map(char::from).collect())
+}
+
+/// Generates `automotive.year(min..max)`: a model year in the range, both included.
+pub(crate) fn year(min: i32, max: i32, rng: &mut StdRng) -> Value {
+    Value::from(if min < max { rng.random_range(min..=max) } else { min })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_vins_have_a_valid_check_digit() {
+        assert_eq!(vin_check_digit(b"1M8GDM9AXKP042788"), b'X');
+        assert_eq!(vin_check_digit(b"11111111111111111"), b'1');
+
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..50 {
+            let vin = vin(&mut rng);
+            let vin = vin.as_str().unwrap().as_bytes();
+            assert_eq!(vin.len(), 17);
+            assert!(!vin.iter().any(|char| b"IOQ".contains(char)));
+            assert_eq!(vin[8], vin_check_digit(vin));
+        }
+
+        let generated = make(&mut rng);
+        assert!(VEHICLES.iter().any(|(name, _)| generated == *name));
+        assert_eq!(year(2001, 2001, &mut rng), Value::from(2001));
+    }
+}
@@ -3,10 +3,21 @@ use std::path::PathBuf;
 use serde_json::Value;
 
 pub use crate::type_spec::*;
+pub use crate::fake::{evaluate_key, FakeGenerator, FakeKeyInfo, FakeKeys};
+#[cfg(feature = "derive")]
+pub use jgd_derive::JgdSchema;
 
+// Lets the code generated by `#[derive(JgdSchema)]` refer to `::jgd_rs` inside this crate
+extern crate self as jgd_rs;
+
+#[macro_use]
+mod macros;
 mod type_spec;
 mod fake;
 mod locales_keys;
+pub mod testing;
+#[doc(hidden)]
+pub mod derive_support;
 
 pub fn generate_jgd_from_str(value: &str) -> Result<Value, JgdGeneratorError> {
     Jgd::from(value)
@@ -29,10 +40,10 @@ mod tests {
     #[test]
     fn all_keys() {
         Jgd::add_custom_key("custom.key", Arc::new(|args: Arguments| {
-            let value = match args {
-                Arguments::None => "Empty Custom Key".to_string(),
-                Arguments::Fixed(_) => format!("Fixed Custom Key with value {}", args.get_string("")),
-                Arguments::Range(_, _) => format!("Range Custom Key with values {:?}", args.get_string_tuple("0", "1")),
+            let value = match args.len() {
+                0 => "Empty Custom Key".to_string(),
+                1 => format!("Fixed Custom Key with value {}", args.get_string("")),
+                _ => format!("Range Custom Key with values {:?}", args.values()),
             };
 
             Ok(Value::String(value))
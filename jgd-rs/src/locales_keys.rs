@@ -1,3 +1,6 @@
+/// Codes of the supported locales.
+pub(crate) const LOCALES: &[&str] = &["EN", "FR_FR", "IT_IT", "JA_JP", "DE_DE", "PT_BR", "AR_SA", "CY_GB"];
+
 pub enum LocalesKeys {
     En,
     FrFr,
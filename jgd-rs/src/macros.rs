@@ -0,0 +1,80 @@
+//! Internal tracing helpers.
+//!
+//! When the `tracing` feature is enabled these forward to the `tracing` crate; otherwise
+//! they expand to nothing, so instrumentation points cost nothing in default builds.
+
+/// Emits a `tracing` event at the given level (`trace`, `debug`, `info`, `warn`, `error`).
+#[cfg(feature = "tracing")]
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        tracing::$level!($($arg)+)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {};
+}
+
+/// Enters a `tracing` span (`debug_span`, `trace_span`, ...) and returns its guard.
+///
+/// Bind the result (`let _span = trace_span!(...)`) so the span lives until the end of scope.
+#[cfg(feature = "tracing")]
+macro_rules! trace_span {
+    ($span:ident, $($arg:tt)+) => {
+        tracing::$span!($($arg)+).entered()
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_span {
+    ($span:ident, $($arg:tt)+) => {
+        ()
+    };
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::{io, sync::{Arc, Mutex}};
+
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    /// Shared buffer collecting the formatted spans and events.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_generation_emits_spans_and_events() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let jgd: Jgd = serde_json::from_value(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 1,
+            "entities": { "users": { "count": 2, "fields": { "name": "${name.firstName}" } } }
+        })).unwrap();
+        tracing::subscriber::with_default(subscriber, || jgd.generate().unwrap());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("entity{name=users}"), "{}", output);
+        assert!(output.contains("generating entity items count=2"), "{}", output);
+    }
+}
@@ -0,0 +1,131 @@
+//! Fixture helpers for unit tests.
+//!
+//! Loads a JGD schema, generates it with an optional seed override and deserializes
+//! the result into your own types, so tests don't repeat the same boilerplate.
+//!
+//! ```rust,no_run
+//! use jgd_rs::generate_fixture;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct User {
+//!     name: String,
+//! }
+//!
+//! // Relative paths are resolved from the calling crate's manifest directory
+//! let users: Vec<User> = generate_fixture!("fixtures/users.jgd", seed = 42);
+//! ```
+//!
+//! The helpers panic with a descriptive message on any failure, which is the expected
+//! behavior inside tests.
+
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::Jgd;
+
+fn deserialize<T: DeserializeOwned>(jgd: Jgd, seed: Option<u64>, source: &str) -> T {
+    let mut jgd = jgd;
+    if seed.is_some() {
+        jgd.seed = seed;
+    }
+
+    jgd.generate_as()
+        .unwrap_or_else(|error| panic!("Error to generate the fixture {}: {}", source, error))
+}
+
+/// Generates the schema at `path` and deserializes the result into `T`.
+///
+/// `seed` overrides the schema seed when set.
+///
+/// # Panics
+///
+/// Panics when the file cannot be read or parsed, when generation fails, or when the
+/// generated JSON does not match `T`.
+pub fn fixture<T: DeserializeOwned>(path: impl AsRef<Path>, seed: Option<u64>) -> T {
+    let path = PathBuf::from(path.as_ref());
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Error to read the fixture {}: {}", path.display(), error));
+    let jgd: Jgd = serde_json::from_str(&content)
+        .unwrap_or_else(|error| panic!("Error to parse the fixture {}: {}", path.display(), error));
+
+    deserialize(jgd, seed, &path.display().to_string())
+}
+
+/// Generates an inline schema (a JSON string or `serde_json::Value`) and deserializes the result into `T`.
+///
+/// # Panics
+///
+/// Panics when the schema is invalid, when generation fails, or when the generated JSON
+/// does not match `T`.
+pub fn fixture_from<T: DeserializeOwned>(schema: impl Into<Value>, seed: Option<u64>) -> T {
+    let schema = match schema.into() {
+        Value::String(content) => serde_json::from_str(&content),
+        value => serde_json::from_value(value),
+    };
+    let jgd: Jgd = schema.unwrap_or_else(|error| panic!("Error to parse the inline fixture: {}", error));
+
+    deserialize(jgd, seed, "(inline)")
+}
+
+/// Generates a fixture file and deserializes it into the inferred type.
+///
+/// Relative paths are resolved from the `CARGO_MANIFEST_DIR` of the calling crate, so
+/// the fixture is found no matter where `cargo test` runs from.
+///
+/// ```rust,ignore
+/// let users: Vec<User> = generate_fixture!("fixtures/users.jgd");
+/// let users: Vec<User> = generate_fixture!("fixtures/users.jgd", seed = 42);
+/// ```
+#[macro_export]
+macro_rules! generate_fixture {
+    ($path:expr) => {
+        $crate::testing::fixture(::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path), None)
+    };
+    ($path:expr, seed = $seed:expr) => {
+        $crate::testing::fixture(::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path), Some($seed))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Product {
+        name: String,
+        price: i64,
+    }
+
+    #[test]
+    fn test_fixture_macro_resolves_manifest_dir() {
+        let user: Value = generate_fixture!("../examples/root-user.jgd", seed = 7);
+        let again: Value = generate_fixture!("../examples/root-user.jgd", seed = 7);
+
+        assert!(user["name"].is_string());
+        assert_eq!(user["name"], again["name"]);
+    }
+
+    #[test]
+    fn test_fixture_from_inline_schema() {
+        let products: Vec<Product> = fixture_from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "count": 2, "fields": { "name": "Widget ${index}", "price": 10 } }
+        }), None);
+
+        assert_eq!(products[1], Product { name: "Widget 2".to_string(), price: 10 });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error to deserialize the generated value")]
+    fn test_fixture_type_mismatch_panics() {
+        let _: Vec<Product> = fixture_from(r#"{
+            "$format": "jgd/v1", "version": "1.0", "root": { "count": 1, "fields": { "name": 1 } }
+        }"#, None);
+    }
+}
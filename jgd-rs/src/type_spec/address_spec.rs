@@ -0,0 +1,107 @@
+//! # Address Specification Module
+//!
+//! Complete addresses whose parts agree with each other. Building an address from the
+//! individual `address.*` keys mixes cities, states and zip codes of different places;
+//! an `address` field takes the city, state, zip code and country from a single place
+//! of the locale address dataset:
+//!
+//! ```json
+//! {
+//!   "shipping": { "address": { "formatted": true } }
+//! }
+//! ```
+//!
+//! generates, for the `EN` locale:
+//!
+//! ```json
+//! {
+//!   "shipping": {
+//!     "street": "Main Street",
+//!     "number": "42",
+//!     "city": "Austin",
+//!     "state": "Texas",
+//!     "zip": "78712",
+//!     "country": "United States",
+//!     "formatted": "42 Main Street, Austin, Texas 78712, United States"
+//!   }
+//! }
+//! ```
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{fake::{address_data, FakeKeys, LocaleFormat}, type_spec::JsonGenerator, GeneratorConfig, JgdGeneratorError, LocalConfig};
+
+/// Specification of an address object with consistent parts.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct AddressSpec {
+    /// Also emits a `formatted` one-line address, following the locale conventions.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub formatted: bool,
+}
+
+impl JsonGenerator for AddressSpec {
+    /// Generates `{street, number, city, state, zip, country}` from one place of the
+    /// configuration locale, plus `formatted` when requested.
+    fn generate(&self, config: &mut GeneratorConfig, _local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let records = address_data::records(&config.locale);
+        let record = &records[config.rng.random_range(0..records.len())];
+        let (street, number) = config.provider.street_and_number(&mut config.rng);
+        let zip = record.value(FakeKeys::ADDRESS_ZIP_CODE, &mut config.rng).unwrap_or_default();
+
+        let mut address = Map::new();
+        address.insert("street".to_string(), street);
+        address.insert("number".to_string(), number);
+        address.insert("city".to_string(), Value::String(record.city.to_string()));
+        address.insert("state".to_string(), Value::String(record.state.to_string()));
+        address.insert("zip".to_string(), Value::String(zip));
+        address.insert("country".to_string(), Value::String(record.country.to_string()));
+
+        if self.formatted {
+            let formatted = LocaleFormat::of(&config.locale).format_address(&address);
+            address.insert("formatted".to_string(), Value::String(formatted));
+        }
+
+        Ok(Value::Object(address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_parts_come_from_one_place() {
+        let mut config = GeneratorConfig::new("PT_BR", Some(5));
+        let spec = AddressSpec { formatted: true };
+
+        for _ in 0..10 {
+            let address = spec.generate(&mut config, None).unwrap();
+            let record = address_data::records("PT_BR").iter()
+                .find(|record| record.city == address["city"])
+                .unwrap();
+            assert_eq!(address["state"], record.state);
+            assert_eq!(address["country"], "Brasil");
+            assert!(record.matches(FakeKeys::ADDRESS_ZIP_CODE, address["zip"].as_str().unwrap()));
+
+            let expected = format!("{}, {}, {} - {}, {}, Brasil", address["street"].as_str().unwrap(),
+                address["number"].as_str().unwrap(), record.city, record.state, address["zip"].as_str().unwrap());
+            assert_eq!(address["formatted"], expected);
+        }
+    }
+
+    #[test]
+    fn test_address_field_in_schema() {
+        let jgd = crate::Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "home": { "address": {} } } }
+        }));
+
+        let home = jgd.generate().unwrap()["home"].clone();
+        let keys: Vec<&String> = home.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["street", "number", "city", "state", "zip", "country"]);
+    }
+}
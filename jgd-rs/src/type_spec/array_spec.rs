@@ -1,6 +1,7 @@
-use serde::Deserialize;
+use rand::Rng;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
-use crate::{type_spec::{Count, Field, GetCount, JsonGenerator}, JgdGeneratorError, LocalConfig};
+use crate::{fake::media::parse_size, type_spec::{Count, Field, GetCount, JsonGenerator}, compact_size, JgdGeneratorError, LocalConfig};
 
 /// Specification for generating JSON arrays in JGD (JSON Generator Definition) schemas.
 ///
@@ -77,6 +78,9 @@ use crate::{type_spec::{Count, Field, GetCount, JsonGenerator}, JgdGeneratorErro
 /// let string_array = ArraySpec {
 ///     of: Box::new(Field::Str("${name.firstName}".to_string())),
 ///     count: Some(Count::Fixed(5)),
+///     prob_null: None,
+///     prob_empty: None,
+///     target_size: None,
 /// };
 ///
 /// let result = string_array.generate(&mut config);
@@ -88,6 +92,9 @@ use crate::{type_spec::{Count, Field, GetCount, JsonGenerator}, JgdGeneratorErro
 ///         number: NumberSpec::new_integer(1.0, 100.0)
 ///     }),
 ///     count: Some(Count::Range((2, 6))),
+///     prob_null: None,
+///     prob_empty: None,
+///     target_size: None,
 /// };
 ///
 /// let result = number_array.generate(&mut config);
@@ -107,7 +114,7 @@ use crate::{type_spec::{Count, Field, GetCount, JsonGenerator}, JgdGeneratorErro
 /// - **Transaction Records**: Generate sequences of financial transactions
 /// - **Test Data**: Create realistic datasets for application testing
 /// - **Mock APIs**: Provide dynamic array responses for API development
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ArraySpec {
     /// The specification for elements that will populate the array.
     ///
@@ -185,8 +192,85 @@ pub struct ArraySpec {
     ///   }
     /// }
     /// ```
-    #[serde(default)]
-    pub count: Option<Count>
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<Count>,
+
+    /// Probability (0.0 to 1.0) that the array is `null` instead of generated, for
+    /// APIs that omit empty collections.
+    ///
+    /// ```json
+    /// { "array": { "of": "${lorem.word}", "count": [1, 3], "probNull": 0.1 } }
+    /// ```
+    #[serde(default, rename = "probNull", skip_serializing_if = "Option::is_none")]
+    pub prob_null: Option<f64>,
+
+    /// Probability (0.0 to 1.0) that the array is empty (`[]`) when it is not `null`,
+    /// whatever its `count`.
+    #[serde(default, rename = "probEmpty", skip_serializing_if = "Option::is_none")]
+    pub prob_empty: Option<f64>,
+
+    /// Approximate size in bytes of the array serialized as compact JSON, for payloads
+    /// of a given size in performance and limit tests. Items are generated until the
+    /// array reaches the size, so it is exceeded by less than one item; `count` is ignored.
+    ///
+    /// Reads a number of bytes or a size with a unit, `B`, `KB`, `MB` or `GB` (powers of 1024):
+    ///
+    /// ```json
+    /// { "array": { "of": { "fields": { "id": "${uuid.v4}" } }, "targetSize": "5MB" } }
+    /// ```
+    #[serde(default, rename = "targetSize", deserialize_with = "deserialize_size", skip_serializing_if = "Option::is_none")]
+    pub target_size: Option<u64>,
+}
+
+/// Reads `targetSize` from a number of bytes or a text such as `512KB`.
+fn deserialize_size<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        Value::Number(number) => number.as_u64().map(Some)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid targetSize {}", number))),
+        Value::String(size) => parse_size(&size).map(Some)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid targetSize {:?}", size))),
+        other => Err(serde::de::Error::custom(format!("invalid targetSize {}", other))),
+    }
+}
+
+impl ArraySpec {
+    /// Draws whether the array is `null` or empty; no random number is drawn for an
+    /// unset probability, so schemas without them generate the same data as before.
+    fn shortcut(&self, config: &mut super::GeneratorConfig) -> Option<Value> {
+        let mut happens = |prob: Option<f64>| prob.is_some_and(|prob| config.rng.random::<f64>() < prob);
+        if happens(self.prob_null) {
+            return Some(Value::Null);
+        }
+        happens(self.prob_empty).then(|| Value::Array(vec![]))
+    }
+
+    /// Generates items until the compact JSON size of the array, brackets and commas
+    /// included, reaches `target`.
+    fn generate_to_size(&self, target: u64, config: &mut super::GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        config.limits.enter_level(&mut config.usage, local_config.as_deref())?;
+
+        let mut arr = Vec::new();
+        let mut size = 2;
+        let mut local_config = LocalConfig::from_current_with_config(None, None, local_config);
+
+        while size < target {
+            config.limits.reserve_records(&mut config.usage, 1, Some(&local_config))?;
+            local_config.set_index(arr.len());
+            let item = self.of.generate(config, Some(&mut local_config))
+                .map_err(|error| error.within(&["array", "of"]))?;
+            size += compact_size(&item) + u64::from(!arr.is_empty());
+            arr.push(item);
+        }
+
+        config.limits.exit_level(&mut config.usage);
+
+        Ok(Value::Array(arr))
+    }
 }
 
 impl JsonGenerator for ArraySpec {
@@ -241,6 +325,9 @@ impl JsonGenerator for ArraySpec {
     /// let spec = ArraySpec {
     ///     of: Box::new(Field::Number(NumberSpec::new_integer(1.0, 100.0))),
     ///     count: Some(Count::Fixed(3)),
+    ///     prob_null: None,
+    ///     prob_empty: None,
+    ///     target_size: None,
     /// };
     ///
     /// let result = spec.generate(&mut config);
@@ -274,7 +361,18 @@ impl JsonGenerator for ArraySpec {
     /// Invalid configurations may result in panics or unexpected behavior.
     fn generate(&self, config: &mut super::GeneratorConfig, local_config: Option<&mut LocalConfig>
         ) -> Result<Value, JgdGeneratorError> {
+        if let Some(value) = self.shortcut(config) {
+            return Ok(value);
+        }
+        if let Some(target) = self.target_size {
+            return self.generate_to_size(target, config, local_config);
+        }
+
         let count_items = self.count.count(config);
+
+        config.limits.reserve_records(&mut config.usage, count_items, local_config.as_deref())?;
+        config.limits.enter_level(&mut config.usage, local_config.as_deref())?;
+
         let mut arr = Vec::with_capacity(count_items as usize);
 
         let mut local_config =
@@ -282,10 +380,13 @@ impl JsonGenerator for ArraySpec {
 
         for i in 0..count_items {
             local_config.set_index(i as usize);
-            let item = self.of.generate(config, Some(&mut local_config))?;
+            let item = self.of.generate(config, Some(&mut local_config))
+                .map_err(|error| error.within(&["array", "of"]))?;
             arr.push(item);
         }
 
+        config.limits.exit_level(&mut config.usage);
+
         Ok(Value::Array(arr))
     }
 }
@@ -308,6 +409,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 10.0)
             }),
             count: Some(Count::Fixed(3)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = spec.generate(&mut config, None);
@@ -335,6 +439,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 100.0)
             }),
             count: Some(Count::Range((2, 5))),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = spec.generate(&mut config, None);
@@ -362,6 +469,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 100.0)
             }),
             count: None, // Should default to 1
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = spec.generate(&mut config, None);
@@ -387,6 +497,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 100.0)
             }),
             count: Some(Count::Fixed(0)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = spec.generate(&mut config, None);
@@ -409,6 +522,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 100.0)
             }),
             count: Some(Count::Fixed(3)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let mut config1 = create_test_config(Some(42));
@@ -434,6 +550,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 100.0)
             }),
             count: Some(Count::Range((3, 5))),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let mut config1 = create_test_config(Some(42));
@@ -461,6 +580,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 100.0)
             }),
             count: Some(Count::Fixed(2)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let cloned_spec = spec.clone();
@@ -487,6 +609,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 100.0)
             }),
             count: Some(Count::Fixed(3)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         // Test that Debug is implemented (should not panic)
@@ -503,6 +628,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 10.0)
             }),
             count: Some(Count::Fixed(100)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = spec.generate(&mut config, None);
@@ -532,6 +660,9 @@ mod tests {
                 number: NumberSpec::new_integer(1.0, 1000.0)
             }),
             count: Some(Count::Fixed(10)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = spec.generate(&mut config, None);
@@ -564,6 +695,9 @@ mod tests {
         let spec = ArraySpec {
             of: Box::new(Field::Str("test_value".to_string())),
             count: Some(Count::Fixed(2)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = spec.generate(&mut config, None);
@@ -590,6 +724,9 @@ mod tests {
         let bool_spec = ArraySpec {
             of: Box::new(Field::Bool(true)),
             count: Some(Count::Fixed(1)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = bool_spec.generate(&mut config, None);
@@ -609,6 +746,9 @@ mod tests {
         let null_spec = ArraySpec {
             of: Box::new(Field::Null),
             count: Some(Count::Fixed(1)),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let result = null_spec.generate(&mut config, None);
@@ -624,4 +764,41 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_array_spec_null_and_empty_probabilities() {
+        let mut config = create_test_config(Some(11));
+        let spec: ArraySpec = serde_json::from_value(serde_json::json!({
+            "of": "x", "count": [1, 3], "probNull": 0.2, "probEmpty": 0.3
+        })).unwrap();
+
+        let values: Vec<Value> = (0..1000).map(|_| spec.generate(&mut config, None).unwrap()).collect();
+        let nulls = values.iter().filter(|value| value.is_null()).count();
+        let empties = values.iter().filter(|value| value.as_array().is_some_and(Vec::is_empty)).count();
+        assert!((150..=250).contains(&nulls), "nulls: {}", nulls);
+        assert!((190..=290).contains(&empties), "empties: {}", empties);
+
+        let always: ArraySpec = serde_json::from_value(serde_json::json!({ "of": "x", "count": 3, "probEmpty": 1.0 })).unwrap();
+        assert_eq!(always.generate(&mut config, None).unwrap(), serde_json::json!([]));
+        assert_eq!(serde_json::to_value(&always).unwrap()["probEmpty"], 1.0);
+        assert!(serde_json::to_value(&always).unwrap().get("probNull").is_none());
+    }
+
+    #[test]
+    fn test_array_spec_target_size() {
+        let mut config = create_test_config(Some(5));
+        let spec: ArraySpec = serde_json::from_value(serde_json::json!({
+            "of": "${lorem.sentence}", "count": 1, "targetSize": "2KB"
+        })).unwrap();
+        assert_eq!(spec.target_size, Some(2048));
+
+        let value = spec.generate(&mut config, None).unwrap();
+        let size = serde_json::to_string(&value).unwrap().len();
+        let last = serde_json::to_string(value.as_array().unwrap().last().unwrap()).unwrap().len();
+        assert!(size >= 2048 && size < 2048 + last + 1, "size: {}", size);
+
+        let bytes: ArraySpec = serde_json::from_value(serde_json::json!({ "of": 1, "targetSize": 9 })).unwrap();
+        assert_eq!(bytes.generate(&mut config, None).unwrap(), serde_json::json!([1, 1, 1, 1]));
+        assert!(serde_json::from_value::<ArraySpec>(serde_json::json!({ "of": 1, "targetSize": "5 parsecs" })).is_err());
+    }
 }
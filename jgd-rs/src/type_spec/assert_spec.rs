@@ -0,0 +1,196 @@
+//! # Assert Specification Module
+//!
+//! Constraints that generated values must satisfy, for fake keys whose output does
+//! not always pass the validation rules of the application under test:
+//!
+//! ```json
+//! {
+//!   "phone": {
+//!     "of": "${phone_number.phoneNumber}",
+//!     "assert": { "pattern": "^[0-9 ()+.-]+$", "minLength": 8, "maxLength": 20 }
+//!   },
+//!   "age": {
+//!     "of": { "number": { "min": 0, "max": 150, "integer": true } },
+//!     "assert": { "min": 18, "max": 99, "retries": 50 }
+//!   }
+//! }
+//! ```
+//!
+//! A value that breaks a constraint is generated again, up to `retries` times, and
+//! generation fails when none of the attempts passes.
+
+use std::{fmt, sync::OnceLock};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{JgdErrorKind, JgdGeneratorError};
+
+fn default_retries() -> usize {
+    10
+}
+
+/// Constraints checked on the values of an asserted field.
+///
+/// - `pattern` must match strings, and the JSON text of other values
+/// - `minLength` and `maxLength` bound the characters of strings and the items of arrays
+/// - `min` and `max` bound numbers; other values break them
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssertSpec {
+    /// Regular expression the value must match (unanchored, like `regex::Regex::is_match`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Smallest length of strings and arrays.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// Largest length of strings and arrays.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Smallest number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+
+    /// Largest number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+
+    /// Values generated again after a failed check before giving up, 10 by default.
+    #[serde(default = "default_retries")]
+    pub retries: usize,
+
+    /// `pattern`, compiled on first use.
+    #[serde(skip)]
+    regex: OnceLock<Result<Regex, String>>,
+}
+
+impl AssertSpec {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to satisfy the assert of the field: {}", details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+
+    fn regex(&self) -> Result<Option<&Regex>, JgdGeneratorError> {
+        let Some(pattern) = &self.pattern else {
+            return Ok(None);
+        };
+        self.regex.get_or_init(|| Regex::new(pattern).map_err(|error| error.to_string()))
+            .as_ref()
+            .map(Some)
+            .map_err(|error| self.error(&format!("invalid pattern {}: {}", pattern, error)))
+    }
+
+    /// Returns the first constraint `value` breaks, or `None` when it satisfies all of them.
+    pub(crate) fn violation(&self, value: &Value) -> Result<Option<String>, JgdGeneratorError> {
+        if let Some(regex) = self.regex()? {
+            let text = match value {
+                Value::String(text) => text.clone(),
+                value => value.to_string(),
+            };
+            if !regex.is_match(&text) {
+                return Ok(Some(format!("{} does not match {}", value, regex)));
+            }
+        }
+
+        if self.min_length.is_some() || self.max_length.is_some() {
+            let length = match value {
+                Value::String(text) => text.chars().count(),
+                Value::Array(items) => items.len(),
+                value => return Ok(Some(format!("{} has no length", value))),
+            };
+            if self.min_length.is_some_and(|min| length < min) || self.max_length.is_some_and(|max| length > max) {
+                return Ok(Some(format!("{} has length {}", value, length)));
+            }
+        }
+
+        if self.min.is_some() || self.max.is_some() {
+            let Some(number) = value.as_f64() else {
+                return Ok(Some(format!("{} is not a number", value)));
+            };
+            if self.min.is_some_and(|min| number < min) || self.max.is_some_and(|max| number > max) {
+                return Ok(Some(format!("{} is out of range", value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Checks a value, returning an error after the last attempt.
+    pub(crate) fn check(&self, value: &Value, attempt: usize) -> Result<bool, JgdGeneratorError> {
+        match self.violation(value)? {
+            None => Ok(true),
+            Some(violation) if attempt >= self.retries => {
+                Err(self.error(&format!("{} after {} attempts", violation, attempt + 1)))
+            },
+            Some(_) => Ok(false),
+        }
+    }
+}
+
+impl fmt::Display for AssertSpec {
+    /// Lists the constraints, e.g. `/^[a-z]+$/ length 3..=10 number 1..=5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bound = |bound: Option<String>| bound.unwrap_or_default();
+        let mut constraints = Vec::new();
+        if let Some(pattern) = &self.pattern {
+            constraints.push(format!("/{}/", pattern));
+        }
+        if self.min_length.is_some() || self.max_length.is_some() {
+            constraints.push(format!("length {}..={}", bound(self.min_length.map(|min| min.to_string())), bound(self.max_length.map(|max| max.to_string()))));
+        }
+        if self.min.is_some() || self.max.is_some() {
+            constraints.push(format!("number {}..={}", bound(self.min.map(|min| min.to_string())), bound(self.max.map(|max| max.to_string()))));
+        }
+        write!(f, "{}", constraints.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    #[test]
+    fn test_assert_checks_and_retries() {
+        let spec: AssertSpec = serde_json::from_value(json!({ "pattern": "^[a-z]+$", "minLength": 2, "maxLength": 4 })).unwrap();
+        assert_eq!(spec.violation(&json!("abc")).unwrap(), None);
+        assert!(spec.violation(&json!("ABC")).unwrap().unwrap().contains("does not match"));
+        assert!(spec.violation(&json!("abcde")).unwrap().unwrap().contains("length 5"));
+        assert_eq!(spec.to_string(), "/^[a-z]+$/ length 2..=4");
+
+        let bounds: AssertSpec = serde_json::from_value(json!({ "min": 1, "max": 5 })).unwrap();
+        assert!(bounds.violation(&json!(6)).unwrap().is_some());
+        assert!(bounds.violation(&json!("3")).unwrap().unwrap().contains("not a number"));
+
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 30,
+                "fields": {
+                    "even": { "of": { "number": { "min": 0, "max": 9, "integer": true } }, "assert": { "pattern": "[02468]$", "retries": 200 } }
+                }
+            }
+        }));
+        for item in jgd.generate().unwrap().as_array().unwrap() {
+            assert_eq!(item["even"].as_i64().unwrap() % 2, 0);
+        }
+
+        let impossible = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "code": { "of": "abc", "assert": { "maxLength": 2, "retries": 3 } } } }
+        }));
+        let error = impossible.generate().unwrap_err();
+        assert!(error.message.contains("\"abc\" has length 3 after 4 attempts"), "{}", error.message);
+        assert!(error.to_string().contains("code"), "{}", error);
+    }
+}
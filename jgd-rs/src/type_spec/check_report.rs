@@ -0,0 +1,301 @@
+//! Consistency checks of generated data, run over the final document.
+//!
+//! [`Jgd::check`](crate::Jgd::check) verifies the invariants implied by the schema, that
+//! every `ref` value is found in the field it points to and that the items of an entity
+//! with `uniqueBy` are unique, and the invariants declared under `checks`:
+//!
+//! ```json
+//! {
+//!   "checks": [
+//!     { "unique": "users.email" },
+//!     { "ref": "orders.buyer", "to": "users.email" },
+//!     { "in": "orders", "sum": "lines.amount", "equals": "total" }
+//!   ]
+//! }
+//! ```
+//!
+//! Paths are dot-notation paths of the generated document, like `ref` paths: arrays are
+//! crossed item by item, so `orders.lines.amount` holds the amount of every line of every
+//! order. Declared paths use the output keys, after `keyCase`; in root mode they start at
+//! the root value.
+
+use std::{collections::{HashMap, HashSet}, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{type_spec::{Entity, Field, Root}, Jgd};
+
+/// Relative tolerance of the `sum` checks, for float rounding.
+const SUM_TOLERANCE: f64 = 1e-9;
+
+/// An invariant of the generated document, declared in the schema `checks`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Check {
+    /// The numbers at `sum` of each item at `in` add up to the number at `equals`;
+    /// both paths are relative to the item, and an empty `in` checks the document itself.
+    Sum {
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        r#in: String,
+        sum: String,
+        equals: String,
+    },
+
+    /// Every non-null value at `ref` is found among the values at `to`.
+    Ref { r#ref: String, to: String },
+
+    /// The non-null values at `unique` are all different.
+    Unique { unique: String },
+}
+
+/// Outcome of the consistency checks of a generated document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CheckReport {
+    /// Number of invariants checked, implied and declared.
+    pub checks: usize,
+
+    /// One message per violation, empty when the document is consistent.
+    pub violations: Vec<String>,
+}
+
+impl CheckReport {
+    /// Returns `true` when no invariant is violated.
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Runs a check, recording its violations.
+    fn run(&mut self, violations: Vec<String>) {
+        self.checks += 1;
+        self.violations.extend(violations);
+    }
+}
+
+impl Display for CheckReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} checks, {} violations", self.checks, self.violations.len())?;
+        for violation in &self.violations {
+            writeln!(f, "  {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every value reachable through a dot-notation path, crossing arrays item by item.
+fn values<'a>(document: &'a Value, path: &str) -> Vec<&'a Value> {
+    let items = |values: Vec<&'a Value>| -> Vec<&'a Value> {
+        values.into_iter()
+            .flat_map(|value| match value {
+                Value::Array(items) => items.iter().collect(),
+                _ => vec![value],
+            })
+            .collect()
+    };
+
+    let mut current = vec![document];
+    for key in path.split('.').filter(|key| !key.is_empty()) {
+        current = items(current).into_iter()
+            .filter_map(|value| value.get(key))
+            .collect();
+    }
+    items(current)
+}
+
+fn check_unique(document: &Value, path: &str) -> Vec<String> {
+    let mut seen = HashMap::new();
+    for value in values(document, path).into_iter().filter(|value| !value.is_null()) {
+        *seen.entry(value.to_string()).or_insert(0) += 1;
+    }
+
+    let mut duplicates: Vec<_> = seen.into_iter().filter(|(_, times)| *times > 1).collect();
+    duplicates.sort();
+    duplicates.into_iter()
+        .map(|(value, times)| format!("{}: the value {} appears {} times", path, value, times))
+        .collect()
+}
+
+fn check_ref(document: &Value, path: &str, to: &str) -> Vec<String> {
+    let targets: HashSet<String> = values(document, to).into_iter().map(Value::to_string).collect();
+    let mut missing = Vec::new();
+    for value in values(document, path).into_iter().filter(|value| !value.is_null()) {
+        let value = value.to_string();
+        if !targets.contains(&value) && !missing.contains(&value) {
+            missing.push(value);
+        }
+    }
+
+    missing.into_iter()
+        .map(|value| format!("{}: the value {} is not found in {}", path, value, to))
+        .collect()
+}
+
+fn check_sum(document: &Value, path: &str, sum: &str, equals: &str) -> Vec<String> {
+    let mut violations = Vec::new();
+    for (index, item) in values(document, path).into_iter().enumerate() {
+        let total: f64 = values(item, sum).into_iter().filter_map(Value::as_f64).sum();
+        let expected = values(item, equals).into_iter().find_map(Value::as_f64);
+        let location = if path.is_empty() { format!("[{}]", index) } else { format!("{}[{}]", path, index) };
+        match expected {
+            Some(expected) if (total - expected).abs() <= SUM_TOLERANCE * expected.abs().max(1.0) => {},
+            Some(expected) => violations.push(format!("{}: the sum of {} is {}, but {} is {}", location, sum, total, equals, expected)),
+            None => violations.push(format!("{}: {} is not a number", location, equals)),
+        }
+    }
+    violations
+}
+
+/// Checks that the items of an entity are unique by its `uniqueBy` fields.
+fn check_unique_by(document: &Value, path: &str, fields: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut violations = Vec::new();
+    for item in values(document, path) {
+        let key: Vec<&Value> = fields.iter().map(|field| item.get(field).unwrap_or(&Value::Null)).collect();
+        let key = serde_json::to_string(&key).unwrap_or_default();
+        if !seen.insert(key.clone()) {
+            let name = if path.is_empty() { "root" } else { path };
+            violations.push(format!("{}: more than one item has the uniqueBy ({}) value {}", name, fields.join(", "), key));
+        }
+    }
+    violations
+}
+
+/// Dot-notation paths of the `ref` fields of an entity, with the paths they point to.
+fn entity_refs(entity: &Entity, path: &str, refs: &mut Vec<(String, String)>) {
+    let fields = entity.fields.iter()
+        .chain(entity.variants.iter().flat_map(|variant| variant.fields.iter()));
+    for (name, field) in fields {
+        match field {
+            Field::Entity(inner) if inner.flatten => entity_refs(inner, path, refs),
+            _ => field_refs(field, &format!("{}.{}", path, name), refs),
+        }
+    }
+}
+
+fn field_refs(field: &Field, path: &str, refs: &mut Vec<(String, String)>) {
+    match field {
+        Field::Ref { r#ref, .. } => refs.push((path.to_string(), r#ref.clone())),
+        Field::Array { array } => field_refs(&array.of, path, refs),
+        Field::Optional { optional } => field_refs(&optional.of, path, refs),
+        Field::Tagged { of, .. } | Field::Limited { of, .. } | Field::Asserted { of, .. } => field_refs(of, path, refs),
+        Field::Entity(entity) => entity_refs(entity, path, refs),
+        _ => {},
+    }
+}
+
+impl Jgd {
+    /// Converts a field name to the schema `keyCase`.
+    fn output_key(&self, key: &str) -> String {
+        self.key_case.map_or_else(|| key.to_string(), |key_case| key_case.convert(key))
+    }
+
+    /// Converts the field names of a path to the schema `keyCase`, keeping the entity name.
+    fn output_path(&self, path: &str) -> String {
+        let mut keys = path.split('.');
+        keys.next().into_iter().map(str::to_string)
+            .chain(keys.map(|key| self.output_key(key)))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Checks the `uniqueBy` of an entity whose items are at `path`.
+    fn check_entity_unique_by(&self, document: &Value, path: &str, entity: &Entity, report: &mut CheckReport) {
+        if !entity.unique_by.is_empty() {
+            let fields: Vec<String> = entity.unique_by.iter().map(|field| self.output_key(field)).collect();
+            report.run(check_unique_by(document, path, &fields));
+        }
+    }
+
+    /// Checks the invariants of a document generated from this schema: the implied ones
+    /// (`ref` values are found, `uniqueBy` holds) and the declared `checks`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "checks": [{ "unique": "users.id" }],
+    ///   "entities": {
+    ///     "users": { "count": 3, "fields": { "id": "${index}" } },
+    ///     "posts": { "count": 5, "fields": { "userId": { "ref": "users.id" } } }
+    ///   }
+    /// }"#);
+    /// let report = jgd.check(&jgd.generate().unwrap());
+    /// assert!(report.is_valid());
+    /// assert_eq!(report.checks, 2);
+    /// ```
+    pub fn check(&self, document: &Value) -> CheckReport {
+        let mut report = CheckReport::default();
+
+        if let Some(entities) = &self.entities {
+            for (name, entity) in entities {
+                let mut refs = Vec::new();
+                entity_refs(entity, name, &mut refs);
+                for (path, target) in refs {
+                    report.run(check_ref(document, &self.output_path(&path), &self.output_path(&target)));
+                }
+                self.check_entity_unique_by(document, name, entity, &mut report);
+            }
+        }
+        if let Some(Root::Entity(root)) = &self.root {
+            self.check_entity_unique_by(document, "", root, &mut report);
+        }
+
+        for check in &self.checks {
+            report.run(match check {
+                Check::Sum { r#in, sum, equals } => check_sum(document, r#in, sum, equals),
+                Check::Ref { r#ref, to } => check_ref(document, r#ref, to),
+                Check::Unique { unique } => check_unique(document, unique),
+            });
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_check_reports_violations() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "keyCase": "snake_case",
+            "checks": [
+                { "unique": "users.user_name" },
+                { "in": "orders", "sum": "lines.amount", "equals": "total" }
+            ],
+            "entities": {
+                "users": { "count": 2, "uniqueBy": ["userName"], "fields": { "id": "${index}", "userName": "user${index}" } },
+                "orders": {
+                    "count": 2,
+                    "fields": {
+                        "buyerId": { "ref": "users.id" },
+                        "lines": { "count": 2, "fields": { "amount": 2.5 } },
+                        "total": 5
+                    }
+                }
+            }
+        }));
+
+        let mut document = jgd.generate().unwrap();
+        let report = jgd.check(&document);
+        assert_eq!(report, CheckReport { checks: 4, violations: vec![] });
+
+        document["users"][1]["user_name"] = json!("user1");
+        document["orders"][1]["buyer_id"] = json!(9);
+        document["orders"][1]["total"] = json!(4);
+        assert_eq!(jgd.check(&document).violations, vec![
+            "users: more than one item has the uniqueBy (user_name) value [\"user1\"]",
+            "orders.buyer_id: the value 9 is not found in users.id",
+            "users.user_name: the value \"user1\" appears 2 times",
+            "orders[1]: the sum of lines.amount is 5, but total is 4",
+        ]);
+    }
+}
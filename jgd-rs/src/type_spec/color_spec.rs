@@ -0,0 +1,101 @@
+//! # Color Specification Module
+//!
+//! Colors as objects of numbers, for design-tool and charting fixtures that read the
+//! channels of a color instead of parsing the strings of the `color.*` keys:
+//!
+//! ```json
+//! {
+//!   "fill": { "color": {} },
+//!   "stroke": { "color": { "format": "hsl", "alpha": true } }
+//! }
+//! ```
+//!
+//! generates values such as `{ "r": 18, "g": 164, "b": 230 }` and
+//! `{ "h": 212, "s": 64, "l": 47, "a": 0.8 }`.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{type_spec::JsonGenerator, GeneratorConfig, JgdGeneratorError, LocalConfig};
+
+/// Channels of a generated color.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorFormat {
+    /// `{ "r", "g", "b" }`, each from 0 to 255.
+    #[default]
+    Rgb,
+
+    /// `{ "h", "s", "l" }`: hue from 0 to 359, saturation and lightness from 0 to 100.
+    Hsl,
+}
+
+/// Specification of a color object.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct ColorSpec {
+    /// Channels of the color, `rgb` by default.
+    #[serde(default)]
+    pub format: ColorFormat,
+
+    /// Adds an `a` channel, an opacity from 0 to 1 with two decimals.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub alpha: bool,
+}
+
+impl JsonGenerator for ColorSpec {
+    /// Generates a random color as an object of numeric channels.
+    fn generate(&self, config: &mut GeneratorConfig, _local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let rng = &mut config.rng;
+        let channels: [(&str, u32); 3] = match self.format {
+            ColorFormat::Rgb => [("r", rng.random_range(0..=255)), ("g", rng.random_range(0..=255)), ("b", rng.random_range(0..=255))],
+            ColorFormat::Hsl => [("h", rng.random_range(0..360)), ("s", rng.random_range(0..=100)), ("l", rng.random_range(0..=100))],
+        };
+
+        let mut color: Map<String, Value> = channels.iter()
+            .map(|(name, value)| (name.to_string(), Value::from(*value)))
+            .collect();
+        if self.alpha {
+            color.insert("a".to_string(), Value::from(rng.random_range(0..=100) as f64 / 100.0));
+        }
+        Ok(Value::Object(color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    #[test]
+    fn test_color_objects() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 20,
+                "fields": {
+                    "fill": { "color": {} },
+                    "stroke": { "color": { "format": "hsl", "alpha": true } },
+                    "palette": "${color.palette(4)}"
+                }
+            }
+        }));
+
+        for item in jgd.generate().unwrap().as_array().unwrap() {
+            let fill = item["fill"].as_object().unwrap();
+            assert_eq!(fill.keys().collect::<Vec<_>>(), ["r", "g", "b"]);
+            assert!(fill.values().all(|channel| channel.as_u64().unwrap() <= 255));
+
+            let stroke = &item["stroke"];
+            assert!(stroke["h"].as_u64().unwrap() < 360 && stroke["l"].as_u64().unwrap() <= 100);
+            assert!((0.0..=1.0).contains(&stroke["a"].as_f64().unwrap()));
+
+            let palette = item["palette"].as_array().unwrap();
+            assert_eq!(palette.len(), 4);
+            assert!(palette.iter().all(|color| color.as_str().unwrap().len() == 7 && color.as_str().unwrap().starts_with('#')));
+        }
+    }
+}
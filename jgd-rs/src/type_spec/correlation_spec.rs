@@ -0,0 +1,143 @@
+//! # Correlation Specification Module
+//!
+//! Keeps independently generated fields consistent with each other, so a generated
+//! city belongs to the generated country and the zip code fits the generated state.
+//!
+//! A correlation maps entity fields to the address value they hold, in dependency
+//! order. Values come from the locale address dataset:
+//!
+//! ```json
+//! {
+//!   "fields": {
+//!     "country": "${address.countryName}",
+//!     "state": "${address.stateName}",
+//!     "city": "${address.cityName}",
+//!     "zip": "${address.zipCode}"
+//!   },
+//!   "correlations": [
+//!     { "country": "address.countryName", "state": "address.stateName", "city": "address.cityName", "zip": "address.zipCode" }
+//!   ]
+//! }
+//! ```
+
+use indexmap::IndexMap;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{fake::address_data::{self, AddressRecord, ATTRIBUTES}, GeneratorConfig, JgdErrorKind, JgdGeneratorError};
+
+/// Fields correlated through the locale address dataset.
+///
+/// Fields are checked in order: each value that belongs to a dataset place shared
+/// with the previous fields is kept, and the first one that does not is replaced,
+/// along with every field after it, by the values of one of those places. So the
+/// first field acts as the anchor, e.g. the cities follow the generated country.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct CorrelationSpec {
+    /// Entity field names and the address value each holds, e.g. `"city": "address.cityName"`.
+    pub fields: IndexMap<String, String>,
+}
+
+impl CorrelationSpec {
+    /// Replaces the fields of `item` that contradict the previous ones.
+    pub(crate) fn apply(&self, item: &mut Map<String, Value>, config: &mut GeneratorConfig
+        ) -> Result<(), JgdGeneratorError> {
+        if let Some((field, attribute)) = self.fields.iter().find(|(_, attribute)| !ATTRIBUTES.contains(&attribute.as_str())) {
+            return Err(JgdGeneratorError {
+                message: format!(
+                    "Error to correlate the field {}: unknown address value {}, expected one of {}",
+                    field, attribute, ATTRIBUTES.join(", ")
+                ),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            });
+        }
+
+        let mut candidates: Vec<&AddressRecord> = address_data::all_records().collect();
+        for (position, (field, attribute)) in self.fields.iter().enumerate() {
+            let value = item.get(field).and_then(Value::as_str).unwrap_or_default();
+            let matching: Vec<&AddressRecord> = candidates.iter().copied()
+                .filter(|record| record.matches(attribute, value))
+                .collect();
+
+            if matching.is_empty() {
+                // Without an anchor, replace everything with a place of the current locale.
+                if position == 0 {
+                    candidates = address_data::records(&config.locale).iter().collect();
+                }
+                let record = candidates[config.rng.random_range(0..candidates.len())];
+                for (field, attribute) in self.fields.iter().skip(position) {
+                    if let Some(value) = record.value(attribute, &mut config.rng) {
+                        item.insert(field.clone(), Value::String(value));
+                    }
+                }
+                return Ok(());
+            }
+
+            candidates = matching;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    #[test]
+    fn test_correlated_fields_follow_the_anchor() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 11,
+            "defaultLocale": "DE_DE",
+            "root": {
+                "count": 20,
+                "fields": {
+                    "country": "Deutschland",
+                    "state": "${address.stateName}",
+                    "city": "${address.cityName}",
+                    "zip": "${address.zipCode}"
+                },
+                "correlations": [
+                    { "country": "address.countryName", "state": "address.stateName", "city": "address.cityName", "zip": "address.zipCode" }
+                ]
+            }
+        }));
+
+        let places = [("Berlin", "Berlin", "10"), ("Bayern", "München", "80"), ("Hamburg", "Hamburg", "20"),
+            ("Hessen", "Frankfurt am Main", "60"), ("Nordrhein-Westfalen", "Köln", "50")];
+        for item in jgd.generate().unwrap().as_array().unwrap() {
+            assert_eq!(item["country"], "Deutschland");
+            let (state, city, zip) = (item["state"].as_str().unwrap(), item["city"].as_str().unwrap(), item["zip"].as_str().unwrap());
+            assert!(places.iter().any(|place| place.0 == state && place.1 == city && zip.starts_with(place.2)), "{:?}", item);
+        }
+    }
+
+    #[test]
+    fn test_correlation_keeps_consistent_values_and_rejects_unknown_ones() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "fields": { "state": "Texas", "city": "Austin" },
+                "correlations": [{ "state": "address.stateName", "city": "address.cityName" }]
+            }
+        }));
+        assert_eq!(jgd.generate().unwrap(), json!({ "state": "Texas", "city": "Austin" }));
+
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "city": "Austin" }, "correlations": [{ "city": "address.town" }] }
+        }));
+        let error = jgd.generate().unwrap_err();
+        assert!(error.message.starts_with("Error to correlate the field city: unknown address value address.town"));
+        assert_eq!(error.path.as_deref(), Some("/root/correlations/0"));
+    }
+}
@@ -0,0 +1,222 @@
+//! # Corruption Specification Module
+//!
+//! This module provides deliberately dirty data through the `CorruptionSpec` struct.
+//! Data-quality checks and deduplication pipelines need realistic defects to test against;
+//! a corruption layer on an entity injects them after the clean items are generated.
+//!
+//! ## Defects
+//!
+//! Every rate is a probability between 0.0 and 1.0 (default 0.0, meaning disabled):
+//! - **`duplicates`**: chance that an item is an exact copy of an earlier item
+//! - **`nulls`**: chance that each field is replaced by `null`
+//! - **`typos`**: chance that each string field gets a typo (swap, drop, repeat or replace a character)
+//! - **`outliers`**: chance that each numeric field falls outside its `number` range
+//!
+//! ```json
+//! {
+//!   "customers": {
+//!     "count": 1000,
+//!     "corruption": { "duplicates": 0.02, "nulls": 0.05, "typos": 0.03, "outliers": 0.01 },
+//!     "fields": { ... }
+//!   }
+//! }
+//! ```
+//!
+//! Corruption is applied after `unique_by`, so duplicates are injected on purpose even
+//! for unique entities. The item count is preserved: duplicates replace fresh items.
+
+use indexmap::IndexMap;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::type_spec::{Field, GeneratorConfig};
+
+/// Maximum number of earlier items kept as duplicate candidates.
+const DUPLICATE_POOL_SIZE: usize = 1024;
+
+/// Multiplier of the range span used to push numbers out of range.
+const OUTLIER_SPAN: f64 = 10.0;
+
+/// Per-entity rates of injected data defects.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct CorruptionSpec {
+    #[serde(default)]
+    pub duplicates: f64,
+    #[serde(default)]
+    pub nulls: f64,
+    #[serde(default)]
+    pub typos: f64,
+    #[serde(default)]
+    pub outliers: f64,
+}
+
+/// Earlier items that duplicates are copied from, sampled as a bounded reservoir.
+#[derive(Default)]
+pub(crate) struct CorruptionState {
+    pool: Vec<Value>,
+    seen: u64,
+}
+
+fn chance(config: &mut GeneratorConfig, rate: f64) -> bool {
+    rate > 0.0 && config.rng.random_bool(rate.min(1.0))
+}
+
+pub(crate) fn typo(value: &str, config: &mut GeneratorConfig) -> String {
+    let mut chars: Vec<char> = value.chars().collect();
+    if chars.is_empty() {
+        return value.to_string();
+    }
+
+    let position = config.rng.random_range(0..chars.len());
+    match config.rng.random_range(0..4) {
+        0 if position + 1 < chars.len() => chars.swap(position, position + 1),
+        1 if chars.len() > 1 => {
+            chars.remove(position);
+        },
+        2 => chars.insert(position, chars[position]),
+        _ => chars[position] = config.rng.random_range('a'..='z'),
+    }
+
+    chars.into_iter().collect()
+}
+
+pub(crate) fn outlier(value: &Value, field: Option<&Field>, config: &mut GeneratorConfig) -> Value {
+    let Some(number) = value.as_f64() else {
+        return value.clone();
+    };
+
+    let (min, max, integer) = match field {
+        Some(Field::Number { number: spec }) => (spec.min, spec.max, spec.integer),
+        _ => (number, number, value.is_i64() || value.is_u64()),
+    };
+
+    let span = (max - min).abs().max(1.0) * config.rng.random_range(1.0..=OUTLIER_SPAN);
+    let result = if config.rng.random_bool(0.5) { max + span } else { min - span };
+
+    if integer {
+        Value::from(result.round() as i64)
+    } else {
+        serde_json::Number::from_f64(result).map(Value::Number).unwrap_or_else(|| value.clone())
+    }
+}
+
+impl CorruptionSpec {
+    /// Applies the configured defects to a freshly generated item.
+    pub(crate) fn apply(
+        &self,
+        item: Value,
+        fields: &IndexMap<String, Field>,
+        config: &mut GeneratorConfig,
+        state: &mut CorruptionState,
+    ) -> Value {
+        if !state.pool.is_empty() && chance(config, self.duplicates) {
+            let index = config.rng.random_range(0..state.pool.len());
+            return state.pool[index].clone();
+        }
+
+        let item = match item {
+            Value::Object(map) => Value::Object(map.into_iter()
+                .map(|(key, value)| {
+                    let value = self.corrupt_value(value, fields.get(&key), config);
+                    (key, value)
+                })
+                .collect()),
+            item => item,
+        };
+
+        if self.duplicates > 0.0 {
+            state.remember(&item, config);
+        }
+
+        item
+    }
+
+    fn corrupt_value(&self, value: Value, field: Option<&Field>, config: &mut GeneratorConfig) -> Value {
+        if chance(config, self.nulls) {
+            return Value::Null;
+        }
+
+        match &value {
+            Value::String(text) if chance(config, self.typos) => Value::String(typo(text, config)),
+            Value::Number(_) if chance(config, self.outliers) => outlier(&value, field, config),
+            _ => value,
+        }
+    }
+}
+
+impl CorruptionState {
+    fn remember(&mut self, item: &Value, config: &mut GeneratorConfig) {
+        self.seen += 1;
+        if self.pool.len() < DUPLICATE_POOL_SIZE {
+            self.pool.push(item.clone());
+            return;
+        }
+
+        let slot = config.rng.random_range(0..self.seen) as usize;
+        if slot < DUPLICATE_POOL_SIZE {
+            self.pool[slot] = item.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_spec::NumberSpec;
+    use serde_json::json;
+
+    fn fields() -> IndexMap<String, Field> {
+        let mut fields = IndexMap::new();
+        fields.insert("age".to_string(), Field::Number { number: NumberSpec::new_integer(18.0, 65.0) });
+        fields
+    }
+
+    #[test]
+    fn test_disabled_corruption_keeps_items() {
+        let mut config = GeneratorConfig::new("EN", Some(1));
+        let mut state = CorruptionState::default();
+        let item = json!({ "name": "Alice", "age": 30 });
+
+        let result = CorruptionSpec::default().apply(item.clone(), &fields(), &mut config, &mut state);
+        assert_eq!(result, item);
+    }
+
+    #[test]
+    fn test_outliers_leave_number_range() {
+        let mut config = GeneratorConfig::new("EN", Some(1));
+        let mut state = CorruptionState::default();
+        let spec = CorruptionSpec { outliers: 1.0, ..Default::default() };
+
+        for _ in 0..20 {
+            let result = spec.apply(json!({ "age": 30 }), &fields(), &mut config, &mut state);
+            let age = result["age"].as_i64().unwrap();
+            assert!(!(18..=65).contains(&age));
+        }
+    }
+
+    #[test]
+    fn test_nulls_and_typos() {
+        let mut config = GeneratorConfig::new("EN", Some(1));
+        let mut state = CorruptionState::default();
+
+        let spec = CorruptionSpec { nulls: 1.0, ..Default::default() };
+        let result = spec.apply(json!({ "name": "Alice" }), &fields(), &mut config, &mut state);
+        assert_eq!(result, json!({ "name": null }));
+
+        let spec = CorruptionSpec { typos: 1.0, ..Default::default() };
+        let result = spec.apply(json!({ "name": "Alexander" }), &fields(), &mut config, &mut state);
+        assert_ne!(result["name"], json!("Alexander"));
+    }
+
+    #[test]
+    fn test_duplicates_copy_earlier_items() {
+        let mut config = GeneratorConfig::new("EN", Some(1));
+        let mut state = CorruptionState::default();
+        let spec = CorruptionSpec { duplicates: 1.0, ..Default::default() };
+
+        let first = spec.apply(json!({ "id": 1 }), &fields(), &mut config, &mut state);
+        let second = spec.apply(json!({ "id": 2 }), &fields(), &mut config, &mut state);
+        assert_eq!(first, json!({ "id": 1 }));
+        assert_eq!(second, first);
+    }
+}
@@ -1,5 +1,6 @@
-use rand::Rng;
-use serde::Deserialize;
+use rand::{rngs::StdRng, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::type_spec::GeneratorConfig;
 
@@ -21,12 +22,20 @@ use crate::type_spec::GeneratorConfig;
 ///
 /// - **Fixed(u64)**: Generates exactly the specified number of items
 /// - **Range((u64, u64))**: Generates a random number of items within the range (inclusive)
+/// - **Bounds**: Same as `Range` with named `min` and `max`, optionally excluding `max`
+/// - **Choices**: Picks one of several counts according to their weights
+/// - **Poisson**: Draws the count from a Poisson distribution with the given mean
+/// - **PerParent**: Draws a count per item generated for another entity and sums them
 ///
 /// # Serialization Format
 ///
 /// The enum uses `#[serde(untagged)]` for natural JSON representation:
 /// - Fixed count: `42` (just a number)
-/// - Range count: `[5, 10]` (array with min and max values)
+/// - Range count: `[5, 10]` (array with min and max values, both inclusive)
+/// - Bounds count: `{"min": 5, "max": 10}`, or `{"min": 5, "max": 10, "exclusive": true}` for 5 to 9
+/// - Weighted choices: `{"choices": [{"value": 0, "weight": 5}, {"value": 3, "weight": 1}]}`
+/// - Poisson distribution: `{"poisson": 1.5}`
+/// - Per parent: `{"perParent": {"entity": "orders", "range": [1, 5]}}`
 ///
 /// # Examples
 ///
@@ -64,7 +73,7 @@ use crate::type_spec::GeneratorConfig;
 ///   }
 /// }
 /// ```
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(untagged)]
 pub enum Count {
     /// A fixed count that always generates exactly the specified number of items.
@@ -97,7 +106,189 @@ pub enum Count {
     /// - Realistic data generation with natural variation
     /// - Stress testing with variable load sizes
     /// - Simulating real-world data patterns
-    Range((u64,u64))
+    Range((u64,u64)),
+
+    /// A range count with named bounds, inclusive unless `exclusive` is set.
+    ///
+    /// With `exclusive`, `max` itself is never drawn, like a Rust `min..max` range,
+    /// so `{ "min": 0, "max": 3, "exclusive": true }` gives 0, 1 or 2.
+    ///
+    /// # JSON Representation
+    /// ```json
+    /// { "min": 5, "max": 15 }
+    /// ```
+    Bounds {
+        min: u64,
+        max: u64,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        exclusive: bool,
+    },
+
+    /// A weighted discrete choice between several counts.
+    ///
+    /// Each choice is picked with a probability proportional to its `weight`
+    /// (default 1). Useful for skewed cardinalities, e.g. most users having no
+    /// orders and a few having many.
+    ///
+    /// # JSON Representation
+    /// ```json
+    /// { "choices": [{ "value": 0, "weight": 5 }, { "value": 1, "weight": 3 }, { "value": 10, "weight": 1 }] }
+    /// ```
+    Choices {
+        choices: Vec<CountChoice>
+    },
+
+    /// A count drawn from a Poisson distribution with mean `lambda`.
+    ///
+    /// # JSON Representation
+    /// ```json
+    /// { "poisson": 1.5 }
+    /// ```
+    Poisson {
+        poisson: f64
+    },
+
+    /// A count that scales with the number of items generated for another entity.
+    ///
+    /// A count within `range` is drawn for each item of `entity` and the counts are
+    /// summed, e.g. 1 to 5 order items per order however many orders were generated.
+    /// The other entity is generated first.
+    ///
+    /// # JSON Representation
+    /// ```json
+    /// { "perParent": { "entity": "orders", "range": [1, 5] } }
+    /// ```
+    PerParent {
+        #[serde(rename = "perParent")]
+        per_parent: Box<PerParentCount>
+    },
+}
+
+/// Count of a [`Count::PerParent`] count.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct PerParentCount {
+    /// Name of the parent entity.
+    pub entity: String,
+
+    /// Inclusive bounds of the count drawn for each parent item.
+    pub range: (u64, u64),
+}
+
+impl Count {
+    /// Checks that a range count can produce a value, so that `[5, 2]` fails when the
+    /// schema is loaded instead of when it is generated.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        let (min, max, exclusive) = match self {
+            Count::Range((min, max)) => (*min, *max, false),
+            Count::Bounds { min, max, exclusive } => (*min, *max, *exclusive),
+            Count::PerParent { per_parent } => (per_parent.range.0, per_parent.range.1, false),
+            _ => return Ok(()),
+        };
+
+        if min > max {
+            return Err(format!("the range {} has a min greater than its max", self));
+        }
+        if exclusive && min == max {
+            return Err(format!("the exclusive range {} is empty", self));
+        }
+        Ok(())
+    }
+}
+
+impl PerParentCount {
+    /// Number of items generated for the parent entity: 0 before it is generated and
+    /// 1 for a single object.
+    fn parents(&self, config: &GeneratorConfig) -> u64 {
+        match config.gen_value.get(&self.entity) {
+            Some(Value::Array(items)) => items.len() as u64,
+            Some(Value::Null) | None => 0,
+            Some(_) => 1,
+        }
+    }
+}
+
+/// One option of a weighted [`Count::Choices`] count.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct CountChoice {
+    /// The count produced when this choice is picked.
+    pub value: u64,
+
+    /// Relative weight of this choice. Negative weights count as zero.
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+/// Mean above which the Poisson distribution is approximated by a normal one.
+const POISSON_NORMAL_THRESHOLD: f64 = 30.0;
+
+/// Picks an index proportionally to `weights`, where negative weights count as zero.
+/// Falls back to a uniform pick when no weight is positive, and to `None` when there
+/// are no weights.
+pub(crate) fn weighted_index(weights: &[f64], rng: &mut StdRng) -> Option<usize> {
+    let total: f64 = weights.iter().map(|weight| weight.max(0.0)).sum();
+    if total <= 0.0 {
+        return match weights.len() {
+            0 => None,
+            len => Some(rng.random_range(0..len)),
+        };
+    }
+
+    let mut target = rng.random_range(0.0..total);
+    for (index, weight) in weights.iter().enumerate() {
+        let weight = weight.max(0.0);
+        if target < weight {
+            return Some(index);
+        }
+        target -= weight;
+    }
+
+    weights.iter().rposition(|weight| *weight > 0.0)
+}
+
+/// Picks a choice value proportionally to the weights, or 0 when there are no choices.
+fn weighted_choice(choices: &[CountChoice], config: &mut GeneratorConfig) -> u64 {
+    let weights: Vec<f64> = choices.iter().map(|choice| choice.weight).collect();
+    weighted_index(&weights, &mut config.rng)
+        .map(|index| choices[index].value)
+        .unwrap_or(0)
+}
+
+/// Draws a count within `min..=max`; a single possible count is returned without
+/// drawing, so `[0, 0]` behaves exactly like `0`.
+fn inclusive(min: u64, max: u64, config: &mut GeneratorConfig) -> u64 {
+    match min < max {
+        true => config.rng.random_range(min..=max),
+        false => min,
+    }
+}
+
+/// Samples a Poisson distribution (Knuth's algorithm for small means, a normal
+/// approximation for large ones).
+fn poisson(lambda: f64, config: &mut GeneratorConfig) -> u64 {
+    if !lambda.is_finite() || lambda <= 0.0 {
+        return 0;
+    }
+
+    if lambda > POISSON_NORMAL_THRESHOLD {
+        let u1: f64 = 1.0 - config.rng.random::<f64>();
+        let u2: f64 = config.rng.random::<f64>();
+        let normal = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        return (lambda + lambda.sqrt() * normal).round().max(0.0) as u64;
+    }
+
+    let limit = (-lambda).exp();
+    let mut product = config.rng.random::<f64>();
+    let mut count = 0;
+    while product > limit {
+        count += 1;
+        product *= config.rng.random::<f64>();
+    }
+
+    count
 }
 
 /// Trait for extracting count values from count specifications.
@@ -214,7 +405,38 @@ impl GetCount for Count {
     fn count(&self, config: &mut GeneratorConfig) -> u64 {
         match self {
             Count::Fixed(n) => *n,
-            Count::Range((a, b)) => config.rng.random_range(*a..=*b),
+            Count::Range((a, b)) => inclusive(*a, *b, config),
+            Count::Bounds { min, max, exclusive: false } => inclusive(*min, *max, config),
+            Count::Bounds { min, max, exclusive: true } => inclusive(*min, max.saturating_sub(1), config),
+            Count::Choices { choices } => weighted_choice(choices, config),
+            Count::Poisson { poisson: lambda } => poisson(*lambda, config),
+            Count::PerParent { per_parent } => {
+                let (min, max) = per_parent.range;
+                (0..per_parent.parents(config)).map(|_| inclusive(min, max, config)).sum()
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Count {
+    /// Formats the count the way it reads in a schema review: `5`, `1..=10`,
+    /// `0 (w5) | 3 (w1)` or `poisson(1.5)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Count::Fixed(n) => write!(f, "{}", n),
+            Count::Range((a, b)) => write!(f, "{}..={}", a, b),
+            Count::Bounds { min, max, exclusive: false } => write!(f, "{}..={}", min, max),
+            Count::Bounds { min, max, exclusive: true } => write!(f, "{}..{}", min, max),
+            Count::Choices { choices } => {
+                let choices: Vec<String> = choices.iter()
+                    .map(|choice| format!("{} (w{})", choice.value, choice.weight))
+                    .collect();
+                write!(f, "{}", choices.join(" | "))
+            },
+            Count::Poisson { poisson } => write!(f, "poisson({})", poisson),
+            Count::PerParent { per_parent } => {
+                write!(f, "{}..={} per {}", per_parent.range.0, per_parent.range.1, per_parent.entity)
+            },
         }
     }
 }
@@ -427,7 +649,7 @@ mod tests {
 
         match count {
             Count::Fixed(n) => assert_eq!(n, 42),
-            Count::Range(_) => panic!("Expected Fixed variant"),
+            _ => panic!("Expected Fixed variant"),
         }
     }
 
@@ -443,7 +665,7 @@ mod tests {
                 assert_eq!(a, 5);
                 assert_eq!(b, 10);
             },
-            Count::Fixed(_) => panic!("Expected Range variant"),
+            _ => panic!("Expected Range variant"),
         }
     }
 
@@ -508,4 +730,97 @@ mod tests {
 
         assert_eq!(count.count(&mut config), large_value);
     }
+
+    #[test]
+    fn test_count_weighted_choices() {
+        let mut config = create_test_config(Some(42));
+        let count: Count = serde_json::from_str(
+            r#"{"choices": [{"value": 0, "weight": 9}, {"value": 5, "weight": 1}, {"value": 7, "weight": 0}]}"#
+        ).unwrap();
+
+        let values: Vec<u64> = (0..1000).map(|_| count.count(&mut config)).collect();
+        let zeros = values.iter().filter(|value| **value == 0).count();
+        assert!(values.iter().all(|value| *value == 0 || *value == 5));
+        assert!((800..=980).contains(&zeros), "zeros: {}", zeros);
+        assert_eq!(count.to_string(), "0 (w9) | 5 (w1) | 7 (w0)");
+    }
+
+    #[test]
+    fn test_count_choice_default_weight() {
+        let count: Count = serde_json::from_str(r#"{"choices": [{"value": 4}]}"#).unwrap();
+        assert_eq!(count.count(&mut create_test_config(Some(1))), 4);
+    }
+
+    #[test]
+    fn test_count_poisson_mean() {
+        let mut config = create_test_config(Some(7));
+        for lambda in [1.5, 50.0] {
+            let count: Count = serde_json::from_value(serde_json::json!({ "poisson": lambda })).unwrap();
+            let total: u64 = (0..2000).map(|_| count.count(&mut config)).sum();
+            let mean = total as f64 / 2000.0;
+            assert!((mean - lambda).abs() < lambda * 0.1, "lambda {} mean {}", lambda, mean);
+        }
+
+        assert_eq!(Count::Poisson { poisson: -1.0 }.count(&mut config), 0);
+    }
+
+    #[test]
+    fn test_count_per_parent_scales_with_parents() {
+        let jgd = crate::Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 7,
+            "entities": {
+                "orderItems": {
+                    "count": { "perParent": { "entity": "orders", "range": [2, 2] } },
+                    "fields": { "orderId": { "ref": "orders.id" } }
+                },
+                "orders": { "count": [1, 20], "fields": { "id": "${index}" } }
+            }
+        }));
+
+        let generated = jgd.generate().unwrap();
+        let orders = generated["orders"].as_array().unwrap().len();
+        assert_eq!(generated["orderItems"].as_array().unwrap().len(), orders * 2);
+        assert!(jgd.explain().warnings.is_empty());
+        assert_eq!(Count::PerParent { per_parent: Box::new(PerParentCount { entity: "orders".into(), range: (1, 5) }) }.to_string(),
+            "1..=5 per orders");
+    }
+
+    #[test]
+    fn test_count_bounds_and_empty_ranges() {
+        let mut config = create_test_config(Some(3));
+        let exclusive: Count = serde_json::from_str(r#"{"min": 0, "max": 3, "exclusive": true}"#).unwrap();
+        let values: Vec<u64> = (0..200).map(|_| exclusive.count(&mut config)).collect();
+        assert!(values.iter().all(|value| *value < 3) && values.contains(&2));
+        assert_eq!(exclusive.to_string(), "0..3");
+        let inclusive: Count = serde_json::from_str(r#"{"min": 4, "max": 4}"#).unwrap();
+        assert_eq!(inclusive.count(&mut config), 4);
+
+        assert!(Count::Range((2, 2)).validate().is_ok());
+        assert_eq!(Count::Range((5, 2)).validate().unwrap_err(), "the range 5..=2 has a min greater than its max");
+        assert_eq!(Count::Bounds { min: 2, max: 2, exclusive: true }.validate().unwrap_err(), "the exclusive range 2..2 is empty");
+
+        let error = serde_json::from_value::<crate::Jgd>(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": { "users": { "count": 2, "fields": { "tags": { "array": { "count": [5, 2], "of": "x" } } } } }
+        })).unwrap_err();
+        assert!(error.to_string().contains("Error to load the count of users.tags: the range 5..=2"), "{}", error);
+
+        let empty = crate::Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 1,
+            "root": { "count": 2, "fields": { "tags": { "array": { "count": [0, 0], "of": "x" } }, "n": { "number": { "min": 0, "max": 1000 } } } }
+        }));
+        let zero = crate::Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 1,
+            "root": { "count": 2, "fields": { "tags": { "array": { "count": 0, "of": "x" } }, "n": { "number": { "min": 0, "max": 1000 } } } }
+        }));
+        assert_eq!(empty.generate().unwrap(), zero.generate().unwrap());
+        assert_eq!(zero.generate().unwrap()[0]["tags"], serde_json::json!([]));
+    }
 }
@@ -0,0 +1,151 @@
+//! # Cumulative Specification Module
+//!
+//! Running totals over the items of a generated array, for order-dependent fixtures
+//! such as an account balance after each transaction or a stock level after each
+//! movement.
+//!
+//! A cumulative field sums a sibling numeric field, declared before it in the same
+//! object, across the items generated so far:
+//!
+//! ```json
+//! {
+//!   "root": {
+//!     "count": 5,
+//!     "fields": {
+//!       "amount": { "number": { "min": -50, "max": 100, "integer": true } },
+//!       "balance": { "cumulative": { "of": "amount", "initial": 1000 } }
+//!     }
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{type_spec::JsonGenerator, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig, SpecId};
+
+/// Specification of a running total of a sibling field.
+///
+/// The total starts at `initial` and is kept per generated array: each entity or root
+/// array with a `count` starts its own totals. `null` sibling values add nothing.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CumulativeSpec {
+    /// Name of the sibling field to sum; it must be declared before the cumulative field.
+    pub of: String,
+
+    /// Value of the total before the first item.
+    #[serde(default)]
+    pub initial: f64,
+
+    /// Decimal places the total is rounded to, to avoid floating point noise such as `0.30000000000000004`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u32>,
+
+    /// Key of the running state of this field; not part of the schema.
+    #[serde(skip)]
+    pub id: SpecId,
+}
+
+impl CumulativeSpec {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to accumulate the field {}: {}", self.of, details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+}
+
+impl JsonGenerator for CumulativeSpec {
+    /// Adds the sibling value to the running total of this field and returns the new total.
+    ///
+    /// The total is an integer when the sibling values and `initial` are integers.
+    fn generate(&self, _config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let Some(local_config) = local_config else {
+            return Err(self.error("cumulative fields must be fields of an object"));
+        };
+
+        let (increment, integer) = match local_config.siblings.get(&self.of) {
+            Some(Value::Null) => (0.0, true),
+            Some(Value::Number(number)) => (number.as_f64().unwrap_or_default(), number.is_i64() || number.is_u64()),
+            Some(_) => return Err(self.error("the field is not a number")),
+            None => return Err(self.error("the field must be declared before the cumulative field")),
+        };
+
+        let mut state = local_config.array_state.borrow_mut();
+        let total = state.totals.entry(self.id).or_insert(self.initial);
+        *total += increment;
+        if let Some(decimals) = self.decimals {
+            let factor = 10f64.powi(decimals as i32);
+            *total = (*total * factor).round() / factor;
+        }
+
+        if integer && total.fract() == 0.0 && self.initial.fract() == 0.0 {
+            return Ok(Value::Number((*total as i64).into()));
+        }
+
+        serde_json::Number::from_f64(*total)
+            .map(Value::Number)
+            .ok_or_else(|| self.error("the total is not a finite number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    #[test]
+    fn test_cumulative_running_balance() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 4,
+                "fields": {
+                    "amount": "${index}",
+                    "balance": { "cumulative": { "of": "amount", "initial": 100 } },
+                    "fee": 0.1,
+                    "fees": { "cumulative": { "of": "fee", "decimals": 2 } },
+                    "lines": { "count": 2, "fields": { "qty": 1, "total": { "cumulative": { "of": "qty" } } } }
+                }
+            }
+        }));
+
+        let value = jgd.generate().unwrap();
+        let balances: Vec<&serde_json::Value> = value.as_array().unwrap().iter().map(|item| &item["balance"]).collect();
+        assert_eq!(balances, [&json!(101), &json!(103), &json!(106), &json!(110)]);
+        assert_eq!(value[2]["fees"], json!(0.3));
+        assert_eq!(value[3]["lines"], json!([{ "qty": 1, "total": 1 }, { "qty": 1, "total": 2 }]));
+    }
+
+    #[test]
+    fn test_cumulative_total_follows_the_spec_and_its_clones() {
+        let spec: CumulativeSpec = serde_json::from_value(json!({ "of": "amount" })).unwrap();
+        let other: CumulativeSpec = serde_json::from_value(json!({ "of": "amount" })).unwrap();
+        let mut config = GeneratorConfig::new("EN", Some(1));
+        let mut local_config = LocalConfig::new(None);
+        local_config.siblings.insert("amount".to_string(), json!(2));
+
+        assert_eq!(spec.generate(&mut config, Some(&mut local_config)).unwrap(), json!(2));
+        let moved = Box::new(spec.clone());
+        assert_eq!(moved.generate(&mut config, Some(&mut local_config)).unwrap(), json!(4));
+        assert_eq!(other.generate(&mut config, Some(&mut local_config)).unwrap(), json!(2));
+    }
+
+    #[test]
+    fn test_cumulative_requires_a_previous_numeric_sibling() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "total": { "cumulative": { "of": "amount" } }, "amount": 1 } }
+        }));
+
+        let error = jgd.generate().unwrap_err();
+        assert_eq!(error.message, "Error to accumulate the field amount: the field must be declared before the cumulative field");
+        assert_eq!(error.path.as_deref(), Some("/root/fields/total"));
+    }
+}
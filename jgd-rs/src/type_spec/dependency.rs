@@ -0,0 +1,160 @@
+//! Generation order of the entities of a schema.
+//!
+//! An entity depends on another when one of its fields (at any depth) is a `ref`
+//! to it, when its `relation` pairs values of it, or when its `count` is `perParent`
+//! of it. Entities are generated in
+//! dependency order, so a `posts` entity referencing `users` may be declared
+//! before it. Entities without dependencies between them keep their declaration
+//! order, and circular dependencies are reported as errors.
+
+use indexmap::IndexMap;
+
+use crate::{type_spec::{Count, Entity, Field}, JgdErrorKind, JgdGeneratorError};
+
+/// Returns the entity name a ref path starts with (`users.id` → `users`).
+fn ref_target(path: &str) -> &str {
+    path.split('.').next().unwrap_or_default()
+}
+
+fn field_refs<'a>(field: &'a Field, refs: &mut Vec<&'a str>) {
+    match field {
+        Field::Ref { r#ref, .. } => refs.push(ref_target(r#ref)),
+        Field::Array { array } => field_refs(&array.of, refs),
+        Field::Tuple { tuple } => tuple.iter().for_each(|field| field_refs(field, refs)),
+        Field::Map { map } => {
+            field_refs(&map.key, refs);
+            field_refs(&map.value, refs);
+        },
+        Field::Trace { trace } => {
+            trace.name.iter().for_each(|name| field_refs(name, refs));
+            trace.attributes.values().for_each(|field| field_refs(field, refs));
+        },
+        Field::GeoJson { geojson } => geojson.properties.values().for_each(|field| field_refs(field, refs)),
+        Field::Optional { optional } => field_refs(&optional.of, refs),
+        Field::JsonString { json_string } => field_refs(&json_string.of, refs),
+        Field::Transformed { of, .. } | Field::Formatted { of, .. } | Field::Limited { of, .. } | Field::Asserted { of, .. } | Field::Tagged { of, .. } => field_refs(of, refs),
+        Field::Entity(entity) => entity_refs_into(entity, refs),
+        _ => {},
+    }
+}
+
+fn entity_refs_into<'a>(entity: &'a Entity, refs: &mut Vec<&'a str>) {
+    if let Some(relation) = &entity.relation {
+        refs.push(ref_target(&relation.from));
+        refs.push(ref_target(&relation.to));
+    }
+    if let Some(Count::PerParent { per_parent }) = &entity.count {
+        refs.push(&per_parent.entity);
+    }
+
+    for field in entity.all_fields() {
+        field_refs(field, refs);
+    }
+}
+
+/// Returns the names of the entities referenced by `entity`, in first-use order.
+pub(crate) fn entity_refs(entity: &Entity) -> Vec<&str> {
+    let mut refs = Vec::new();
+    entity_refs_into(entity, &mut refs);
+
+    let mut unique: Vec<&str> = Vec::with_capacity(refs.len());
+    for name in refs {
+        if !unique.contains(&name) {
+            unique.push(name);
+        }
+    }
+    unique
+}
+
+/// Orders the entities so that every entity comes after the entities it references.
+///
+/// References to unknown entities and to the entity itself are ignored here; they
+/// are reported when the ref is resolved.
+pub(crate) fn generation_order(entities: &IndexMap<String, Entity>
+    ) -> Result<Vec<(&String, &Entity)>, JgdGeneratorError> {
+    let dependencies: Vec<Vec<usize>> = entities.iter()
+        .enumerate()
+        .map(|(index, (_, entity))| entity_refs(entity)
+            .into_iter()
+            .filter_map(|name| entities.get_index_of(name))
+            .filter(|dependency| *dependency != index)
+            .collect())
+        .collect();
+
+    let mut done = vec![false; entities.len()];
+    let mut order = Vec::with_capacity(entities.len());
+    while order.len() < entities.len() {
+        let next = (0..entities.len()).find(|index| {
+            !done[*index] && dependencies[*index].iter().all(|dependency| done[*dependency])
+        });
+
+        let Some(index) = next else {
+            let pending: Vec<&str> = entities.keys()
+                .enumerate()
+                .filter(|(index, _)| !done[*index])
+                .map(|(_, name)| name.as_str())
+                .collect();
+            return Err(JgdGeneratorError {
+                message: format!(
+                    "Error to order the entities: circular ref dependency between {}", pending.join(", ")
+                ),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            });
+        };
+
+        done[index] = true;
+        order.push(entities.get_index(index).unwrap());
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entities(value: serde_json::Value) -> IndexMap<String, Entity> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn names(entities: &IndexMap<String, Entity>) -> Vec<String> {
+        generation_order(entities).unwrap().into_iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    #[test]
+    fn test_order_follows_refs() {
+        let entities = entities(json!({
+            "comments": { "fields": { "post": { "array": { "of": { "ref": "posts.id" } } } } },
+            "posts": { "fields": { "author": { "fields": { "id": { "ref": "users.id" } } } } },
+            "tags": { "fields": { "name": "tag" } },
+            "users": { "fields": { "id": 1, "manager": { "ref": "users.id" } } }
+        }));
+
+        assert_eq!(names(&entities), vec!["tags", "users", "posts", "comments"]);
+    }
+
+    #[test]
+    fn test_order_follows_relations() {
+        let entities = entities(json!({
+            "memberships": { "relation": { "from": "users.id", "to": "groups.id" } },
+            "groups": { "fields": { "id": 1 } },
+            "users": { "fields": { "id": 1 } }
+        }));
+
+        assert_eq!(names(&entities), vec!["groups", "users", "memberships"]);
+    }
+
+    #[test]
+    fn test_order_rejects_cycles() {
+        let entities = entities(json!({
+            "a": { "fields": { "b": { "ref": "b.id" } } },
+            "b": { "fields": { "a": { "ref": "a.id" } } },
+            "c": { "fields": { "id": 1 } }
+        }));
+
+        let error = generation_order(&entities).unwrap_err();
+        assert_eq!(error.message, "Error to order the entities: circular ref dependency between a, b");
+    }
+}
@@ -1,10 +1,96 @@
-use std::collections::{HashMap, HashSet};
+use std::{collections::{HashMap, HashSet}, rc::Rc};
 
 use indexmap::IndexMap;
 use rand::{rngs::StdRng, SeedableRng};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
-use crate::{type_spec::{Count, Field, GetCount, JsonGenerator}, JgdGeneratorError, LocalConfig};
+use crate::{type_spec::{corruption_spec::CorruptionState, dependency, count::weighted_index, field::generate_fields_with, relation_spec::RelationPool, ArraySpec, CorrelationSpec, Count, CorruptionSpec, Field, GeneratorConfig, GetCount, JsonGenerator, LocaleFrom, LocaleMix, RelationSpec, VariantSpec}, JgdErrorKind, JgdGeneratorError, LocalConfig, Transform};
+
+/// Whether `field` reads already generated items (`${previous.field}`, `${items[0].field}`),
+/// looking into nested fields but not into nested entity arrays, which keep their own items.
+fn uses_item_keys(field: &Field) -> bool {
+    match field {
+        Field::Template(template) => template.collection.iter().any(|replacer| LocalConfig::is_item_key(&replacer.key)),
+        Field::Array { array } => uses_item_keys(&array.of),
+        Field::Tuple { tuple } => tuple.iter().any(uses_item_keys),
+        Field::Map { map } => uses_item_keys(&map.key) || uses_item_keys(&map.value),
+        Field::Trace { trace } => trace.name.as_deref().is_some_and(uses_item_keys) || trace.attributes.values().any(uses_item_keys),
+        Field::GeoJson { geojson } => geojson.properties.values().any(uses_item_keys),
+        Field::Optional { optional } => uses_item_keys(&optional.of),
+        Field::JsonString { json_string } => uses_item_keys(&json_string.of),
+        Field::Transformed { of, .. } | Field::Formatted { of, .. } | Field::Limited { of, .. } | Field::Asserted { of, .. } | Field::Tagged { of, .. } => uses_item_keys(of),
+        Field::Entity(entity) if entity.is_single() => entity.all_fields().any(uses_item_keys),
+        _ => false,
+    }
+}
+
+/// Named field groups of a schema, see [`Entity::include`].
+pub(crate) type Mixins = IndexMap<String, IndexMap<String, Field>>;
+
+/// Expands the `include` of the entities nested in `field`.
+pub(crate) fn include_in_field(field: &mut Field, mixins: &Mixins, path: &str, active: &mut Vec<String>) -> Result<(), String> {
+    match field {
+        Field::Entity(entity) => entity.include_mixins(mixins, path, active),
+        Field::Array { array } => include_in_field(&mut array.of, mixins, path, active),
+        Field::Optional { optional } => include_in_field(&mut optional.of, mixins, path, active),
+        Field::JsonString { json_string } => include_in_field(&mut json_string.of, mixins, path, active),
+        Field::Map { map } => include_in_field(&mut map.value, mixins, path, active),
+        Field::Trace { trace } => trace.attributes.values_mut().try_for_each(|field| include_in_field(field, mixins, path, active)),
+        Field::GeoJson { geojson } => geojson.properties.values_mut().try_for_each(|field| include_in_field(field, mixins, path, active)),
+        Field::Tuple { tuple } => tuple.iter_mut().try_for_each(|field| include_in_field(field, mixins, path, active)),
+        Field::Transformed { of, .. } | Field::Formatted { of, .. } | Field::Limited { of, .. } | Field::Asserted { of, .. } | Field::Tagged { of, .. } => {
+            include_in_field(of, mixins, path, active)
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Checks a range count, naming `path` in the error.
+fn check_count(count: Option<&Count>, path: &str) -> Result<(), String> {
+    match count {
+        Some(count) => count.validate().map_err(|details| format!("Error to load the count of {}: {}", path, details)),
+        None => Ok(()),
+    }
+}
+
+/// Checks the range counts of an array and of the fields nested in its items.
+pub(crate) fn check_counts_in_array(array: &ArraySpec, path: &str) -> Result<(), String> {
+    check_count(array.count.as_ref(), path)?;
+    check_counts_in_field(&array.of, path)
+}
+
+/// Checks the range counts of `field` and of the fields nested in it.
+pub(crate) fn check_counts_in_field(field: &Field, path: &str) -> Result<(), String> {
+    let check = |count: Option<&Count>| check_count(count, path);
+
+    match field {
+        Field::Entity(entity) => entity.check_counts(path),
+        Field::Array { array } => check_counts_in_array(array, path),
+        Field::Map { map } => {
+            check(map.count.as_ref())?;
+            check_counts_in_field(&map.key, path)?;
+            check_counts_in_field(&map.value, path)
+        },
+        Field::Partition { partition } => check(Some(&partition.count)),
+        Field::RandomString { random_string } => check(Some(&random_string.length)),
+        Field::Trace { trace } => {
+            check(Some(&trace.spans))?;
+            trace.name.as_deref().into_iter().chain(trace.attributes.values()).try_for_each(|field| check_counts_in_field(field, path))
+        },
+        Field::GeoJson { geojson } => {
+            check(geojson.count.as_ref())?;
+            check(Some(&geojson.vertices))?;
+            geojson.properties.values().try_for_each(|field| check_counts_in_field(field, path))
+        },
+        Field::Optional { optional } => check_counts_in_field(&optional.of, path),
+        Field::JsonString { json_string } => check_counts_in_field(&json_string.of, path),
+        Field::Tuple { tuple } => tuple.iter().try_for_each(|field| check_counts_in_field(field, path)),
+        Field::Transformed { of, .. } | Field::Formatted { of, .. } | Field::Limited { of, .. } | Field::Asserted { of, .. } | Field::Tagged { of, .. } => {
+            check_counts_in_field(of, path)
+        },
+        _ => Ok(()),
+    }
+}
 
 /// Creates a fingerprint for uniqueness checking based on specified fields.
 ///
@@ -69,7 +155,7 @@ fn fingerprint(obj: &Value, unique_fields: &[String]) -> String {
 /// {
 ///   "entity": {
 ///     "count": 10,
-///     "unique_by": ["id", "email"],
+///     "uniqueBy": ["id", "email"],
 ///     "fields": {
 ///       "id": "${uuid}",
 ///       "name": "${name.fullName}",
@@ -88,7 +174,7 @@ fn fingerprint(obj: &Value, unique_fields: &[String]) -> String {
 ///
 /// # Uniqueness Constraints
 ///
-/// The `unique_by` field allows specifying which field combinations must be unique
+/// The `uniqueBy` property (`unique_by` in Rust) allows specifying which field combinations must be unique
 /// across all generated entities. This is useful for:
 /// - Primary key constraints (IDs, usernames, emails)
 /// - Composite uniqueness (user + project combinations)
@@ -113,12 +199,16 @@ fn fingerprint(obj: &Value, unique_fields: &[String]) -> String {
 ///     seed: None,
 ///     unique_by: vec!["email".to_string()],
 ///     fields,
+///     ..Default::default()
 /// };
 ///
 /// let result = entity.generate(&mut config);
 /// // Generates an array of 5 user objects with unique emails
 /// ```
-#[derive(Debug, Deserialize, Clone)]
+// Derived as a remote of itself so the `Deserialize` impl below can require `fields`
+// unless the entity is a relation
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(remote = "Self")]
 pub struct Entity {
     /// Optional count specification for the number of entities to generate.
     ///
@@ -148,6 +238,7 @@ pub struct Entity {
     ///   }
     /// }
     /// ```
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<Count>,
 
     /// Optional seed for deterministic entity generation.
@@ -172,7 +263,7 @@ pub struct Entity {
     ///   }
     /// }
     /// ```
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub seed: Option<u64>,
 
     /// Fields that must be unique across all generated entities.
@@ -198,7 +289,7 @@ pub struct Entity {
     /// ```json
     /// {
     ///   "entity": {
-    ///     "unique_by": ["id"],           // Single field uniqueness
+    ///     "uniqueBy": ["id"],           // Single field uniqueness
     ///     "fields": { ... }
     ///   }
     /// }
@@ -208,7 +299,7 @@ pub struct Entity {
     /// ```json
     /// {
     ///   "entity": {
-    ///     "unique_by": ["user_id", "project_id"], // Composite uniqueness
+    ///     "uniqueBy": ["user_id", "project_id"], // Composite uniqueness
     ///     "fields": { ... }
     ///   }
     /// }
@@ -223,7 +314,7 @@ pub struct Entity {
     /// // Ensure user+project combination uniqueness
     /// unique_by: vec!["user_id".to_string(), "project_id".to_string()]
     /// ```
-    #[serde(default)]
+    #[serde(default, rename = "uniqueBy", alias = "unique_by", skip_serializing_if = "Vec::is_empty")]
     pub unique_by: Vec<String>,
 
     /// The collection of fields that make up the entity structure.
@@ -275,7 +366,458 @@ pub struct Entity {
     ///   }
     /// }
     /// ```
+    ///
+    /// Required, except for relation entities, which only need the generated pair.
+    #[serde(default)]
     pub fields: IndexMap<String, Field>,
+
+    /// Names of schema `mixins` whose fields are added after `fields` when the schema
+    /// is loaded. Fields declared in the entity take precedence over included ones.
+    ///
+    /// ```json
+    /// {
+    ///   "users": {
+    ///     "include": ["auditFields", "softDelete"],
+    ///     "fields": { ... }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
+    /// Merges the fields of this embedded entity into the parent object instead of
+    /// nesting them under the field name. Fields declared by the parent take precedence.
+    ///
+    /// ```json
+    /// {
+    ///   "users": {
+    ///     "fields": {
+    ///       "id": "${ulid}",
+    ///       "audit": { "flatten": true, "fields": { "createdAt": "${chrono.dateTime}" } }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// generates `{"id": "...", "createdAt": "..."}`. Only single objects, without
+    /// `count`, can be flattened.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub flatten: bool,
+
+    /// Optional many-to-many relation that turns the entity into join records.
+    ///
+    /// Each item gets a pair of values picked from previously generated entities,
+    /// followed by the regular `fields`. Relation entities always generate an array.
+    ///
+    /// # JSON Schema Mapping
+    ///
+    /// ```json
+    /// {
+    ///   "follows": {
+    ///     "relation": { "from": "users.id", "to": "users.id", "count": 100, "noSelfLoops": true, "unique": true }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation: Option<Box<RelationSpec>>,
+
+    /// Post-generation transforms applied to every string field of each item.
+    ///
+    /// ```json
+    /// {
+    ///   "entity": {
+    ///     "transforms": ["trim", "lower"],
+    ///     "fields": { ... }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transforms: Vec<Transform>,
+
+    /// Static values injected verbatim into each item, after the generated fields.
+    ///
+    /// When set, replaces the schema-level `constants` for this entity; an empty object
+    /// opts out of them. Unlike the schema-level ones, entity constants also apply to
+    /// nested entities. Generated fields with the same name take precedence.
+    ///
+    /// ```json
+    /// {
+    ///   "entity": {
+    ///     "constants": { "source": "import" },
+    ///     "fields": { ... }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constants: Option<Box<serde_json::Map<String, Value>>>,
+
+    /// Optional defects injected into the generated items for data-quality testing.
+    ///
+    /// ```json
+    /// {
+    ///   "entity": {
+    ///     "corruption": { "duplicates": 0.02, "nulls": 0.05, "typos": 0.03, "outliers": 0.01 },
+    ///     "fields": { ... }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corruption: Option<Box<CorruptionSpec>>,
+
+    /// Groups of fields kept consistent with each other through the locale address dataset.
+    ///
+    /// ```json
+    /// {
+    ///   "entity": {
+    ///     "correlations": [{ "country": "address.countryName", "city": "address.cityName" }],
+    ///     "fields": { ... }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub correlations: Vec<CorrelationSpec>,
+
+    /// Optional locales picked by weight for each item, instead of the schema locale.
+    ///
+    /// ```json
+    /// {
+    ///   "users": {
+    ///     "localeMix": { "EN": 0.6, "PT_BR": 0.3, "JA_JP": 0.1 },
+    ///     "fields": { ... }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, rename = "localeMix", skip_serializing_if = "Option::is_none")]
+    pub locale_mix: Option<Box<LocaleMix>>,
+
+    /// Optional field of each item whose value picks the locale of the fields after it.
+    ///
+    /// ```json
+    /// {
+    ///   "customers": {
+    ///     "localeFrom": { "field": "self.countryCode", "locales": { "BR": "PT_BR", "DE": "DE_DE" } },
+    ///     "fields": { "countryCode": "${choice(BR, DE, US)}", "name": "${name.name}" }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, rename = "localeFrom", skip_serializing_if = "Option::is_none")]
+    pub locale_from: Option<Box<LocaleFrom>>,
+
+    /// Kinds of items, picked by weight for each item, whose fields are added after the
+    /// entity `fields`. Used for heterogeneous arrays such as event streams.
+    ///
+    /// ```json
+    /// {
+    ///   "events": {
+    ///     "fields": { "id": "${uuid.v4}" },
+    ///     "variants": [
+    ///       { "weight": 70, "fields": { "type": "pageView" } },
+    ///       { "weight": 30, "fields": { "type": "click" } }
+    ///     ]
+    ///   }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<VariantSpec>,
+}
+
+impl<'de> Deserialize<'de> for Entity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        if value.get("fields").is_none() && value.get("relation").is_none() {
+            return Err(serde::de::Error::missing_field("fields"));
+        }
+        Entity::deserialize(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for Entity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Entity::serialize(self, serializer)
+    }
+}
+
+impl Entity {
+    /// Generates the entity items one by one, handing each to `on_item` as soon as it is ready.
+    ///
+    /// This is the streaming counterpart of [`JsonGenerator::generate`]: it applies the same
+    /// count, seed and `unique_by` rules, but never collects the items, so callers can
+    /// write them out (see [`crate::Jgd::generate_to_writer`]) without building the array.
+    /// When `count` is not set, exactly one item is produced.
+    pub fn generate_each<F>(
+        &self,
+        config: &mut GeneratorConfig,
+        local_config: Option<&mut LocalConfig>,
+        mut on_item: F,
+    ) -> Result<(), JgdGeneratorError>
+    where
+        F: FnMut(&mut GeneratorConfig, Value) -> Result<(), JgdGeneratorError>,
+    {
+        let pool = match &self.relation {
+            Some(relation) => Some(relation.pool(config, local_config.as_deref())?),
+            None => None,
+        };
+
+        let count_items = match (&self.relation, &pool) {
+            (Some(relation), Some(pool)) => {
+                let count = relation.count.as_ref().or(self.count.as_ref()).cloned().count(config);
+                relation.cap_count(pool, count)
+            },
+            _ => self.count.count(config),
+        };
+        trace_event!(debug, count = count_items, "generating entity items");
+
+        config.limits.reserve_records(&mut config.usage, count_items, local_config.as_deref())?;
+        config.limits.enter_level(&mut config.usage, local_config.as_deref())?;
+
+        let result = self.generate_items(config, local_config, count_items, pool.as_ref(), &mut on_item);
+        config.limits.exit_level(&mut config.usage);
+
+        result
+    }
+
+    /// Checks the range counts of the entity, its relation and its fields, reporting the
+    /// first empty range (`[5, 2]`) with its path.
+    pub(crate) fn check_counts(&self, path: &str) -> Result<(), String> {
+        let counts = self.count.iter().chain(self.relation.iter().filter_map(|relation| relation.count.as_ref()));
+        for count in counts {
+            check_count(Some(count), path)?;
+        }
+
+        let fields = self.fields.iter()
+            .chain(self.variants.iter().flat_map(|variant| variant.fields.iter()));
+        for (name, field) in fields {
+            check_counts_in_field(field, &format!("{}.{}", path, name))?;
+        }
+        Ok(())
+    }
+
+    /// Adds the fields of the included mixins after the entity fields, skipping the
+    /// names the entity already declares, and does the same for nested entities.
+    ///
+    /// `path` names the entity in error messages; `active` holds the mixins being
+    /// included, to reject a mixin that includes itself.
+    pub(crate) fn include_mixins(&mut self, mixins: &Mixins, path: &str, active: &mut Vec<String>) -> Result<(), String> {
+        let fields = self.fields.iter_mut()
+            .chain(self.variants.iter_mut().flat_map(|variant| variant.fields.iter_mut()));
+        for (name, field) in fields {
+            include_in_field(field, mixins, &format!("{}.{}", path, name), active)?;
+        }
+
+        for mixin in &self.include {
+            let error = |details: &str| format!("Error to include the mixin {} in {}: {}", mixin, path, details);
+            if active.contains(mixin) {
+                return Err(error("the mixin includes itself"));
+            }
+            let fields = mixins.get(mixin).ok_or_else(|| error("it is not defined in mixins"))?;
+
+            active.push(mixin.clone());
+            for (name, field) in fields {
+                if !self.fields.contains_key(name) {
+                    let mut field = field.clone();
+                    include_in_field(&mut field, mixins, &format!("{}.{}", path, name), active)?;
+                    self.fields.insert(name.clone(), field);
+                }
+            }
+            active.pop();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the entity fields followed by the fields of every variant.
+    pub(crate) fn all_fields(&self) -> impl Iterator<Item = &Field> {
+        self.fields.values().chain(self.variants.iter().flat_map(|variant| variant.fields.values()))
+    }
+
+    /// Whether the entity generates a single object rather than an array.
+    pub(crate) fn is_single(&self) -> bool {
+        self.count.is_none() && self.relation.is_none()
+    }
+
+    /// Generates the entity fields and the fields of a variant, applies the correlations
+    /// and the entity-level transforms and appends the constants, switching to the locale
+    /// picked by `localeFrom` once its field is generated.
+    fn generate_fields(&self, config: &mut GeneratorConfig, local_config: &mut LocalConfig
+        ) -> Result<Value, JgdGeneratorError> {
+        let Some(locale_from) = &self.locale_from else {
+            return self.generate_localized_fields(config, local_config, |_, _, _| {});
+        };
+        if !self.fields.contains_key(locale_from.field()) {
+            return Err(JgdGeneratorError {
+                message: format!("Error to read the locale of the item: the field {} of localeFrom is not in fields", locale_from.field()),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            }.within(&["localeFrom"]));
+        }
+
+        let mut schema_locale = None;
+        let result = self.generate_localized_fields(config, local_config, |key, value, config| {
+            if let Some(locale) = locale_from.locale(key, value) {
+                schema_locale = Some(config.switch_locale(locale));
+            }
+        });
+        if let Some(schema_locale) = schema_locale {
+            config.restore_locale(schema_locale);
+        }
+        result
+    }
+
+    /// Generates the item of [`Entity::generate_fields`], calling `on_field` after each
+    /// entity field.
+    fn generate_localized_fields<F>(&self, config: &mut GeneratorConfig, local_config: &mut LocalConfig, on_field: F
+        ) -> Result<Value, JgdGeneratorError>
+    where
+        F: FnMut(&str, &Value, &mut GeneratorConfig),
+    {
+        let value = generate_fields_with(&self.fields, config, Some(local_config), on_field)?;
+
+        let Value::Object(mut map) = value else {
+            return Ok(value);
+        };
+
+        let weights: Vec<f64> = self.variants.iter().map(|variant| variant.weight).collect();
+        if let Some(index) = weighted_index(&weights, &mut config.rng) {
+            let variant = self.variants[index].fields.generate(config, Some(local_config))
+                .map_err(|error| error.within(&["variants", &index.to_string()]))?;
+            if let Value::Object(variant) = variant {
+                map.extend(variant);
+            }
+        }
+
+        for (index, correlation) in self.correlations.iter().enumerate() {
+            correlation.apply(&mut map, config)
+                .map_err(|error| error.within(&["correlations", &index.to_string()]))?;
+        }
+
+        if !self.transforms.is_empty() {
+            map = map.into_iter()
+                .map(|(key, value)| (key, crate::apply_transforms_with(&self.transforms, value, &config.locale, &mut config.rng)))
+                .collect();
+        }
+
+        // Schema-level constants only reach top-level records (depth 1)
+        let constants = match &self.constants {
+            Some(constants) => Some(constants.as_ref()),
+            None if config.usage.depth == 1 => Some(&config.constants),
+            None => None,
+        };
+        for (key, value) in constants.into_iter().flatten() {
+            if !map.contains_key(key) {
+                map.insert(key.clone(), value.clone());
+            }
+        }
+
+        Ok(Value::Object(map))
+    }
+
+    fn generate_items<F>(
+        &self,
+        config: &mut GeneratorConfig,
+        local_config: Option<&mut LocalConfig>,
+        count_items: u64,
+        pool: Option<&RelationPool>,
+        on_item: &mut F,
+    ) -> Result<(), JgdGeneratorError>
+    where
+        F: FnMut(&mut GeneratorConfig, Value) -> Result<(), JgdGeneratorError>,
+    {
+        let mut unique_sets: HashMap<String, HashSet<String>> = HashMap::new();
+
+        let mut corruption_state = CorruptionState::default();
+
+        let mut unique_by = self.unique_by.clone();
+        if let Some(relation) = self.relation.as_ref().filter(|relation| relation.unique) {
+            unique_by.push(relation.from_field.clone());
+            unique_by.push(relation.to_field.clone());
+        }
+
+        let rng = self.seed.map(StdRng::seed_from_u64);
+
+        let mut local_config =
+            LocalConfig::from_current_with_config(rng, Some(count_items), local_config);
+
+        // Single objects read the items and totals of the enclosing array; arrays keep
+        // their own, storing items only when a template reads them.
+        let keep_items = !self.is_single() && self.all_fields().any(uses_item_keys);
+        if !self.is_single() {
+            local_config.items = Rc::default();
+            local_config.array_state = Rc::default();
+        }
+
+        const MAX_ATTEMPTS: usize = 1000; // Prevent infinite loops
+
+        for i in 0..count_items {
+            let mut obj = None;
+            local_config.set_index(i as usize);
+
+            let mixed_locale = self.locale_mix.as_ref()
+                .and_then(|locale_mix| locale_mix.pick(&mut config.rng).map(str::to_string));
+            let schema_locale = mixed_locale.map(|locale| config.switch_locale(&locale));
+
+            // Try to generate a unique object
+            for _ in 0..MAX_ATTEMPTS {
+                let candidate = match (&self.relation, pool) {
+                    (Some(relation), Some(pool)) => {
+                        let Some((from, to)) = relation.pick(pool, config) else {
+                            break;
+                        };
+                        let mut item = serde_json::Map::new();
+                        item.insert(relation.from_field.clone(), from);
+                        item.insert(relation.to_field.clone(), to);
+                        if let Value::Object(fields) = self.generate_fields(config, &mut local_config)? {
+                            item.extend(fields);
+                        }
+                        Value::Object(item)
+                    },
+                    _ => self.generate_fields(config, &mut local_config)?,
+                };
+
+                if !unique_by.is_empty() {
+                    let fp = fingerprint(&candidate, &unique_by);
+                    let set = unique_sets.entry(unique_by.join("|"))
+                        .or_default();
+
+                    if !set.contains(&fp) {
+                        set.insert(fp);
+                        obj = Some(candidate);
+                        break;
+                    }
+                    // If fingerprint already exists, try again
+                    trace_event!(trace, index = i, fingerprint = %fp, "duplicate entity for unique_by, retrying");
+                } else {
+                    // No uniqueness constraints
+                    obj = Some(candidate);
+                    break;
+                }
+            }
+
+            if let Some(schema_locale) = schema_locale {
+                config.restore_locale(schema_locale);
+            }
+
+            if let Some(generated_obj) = obj {
+                let generated_obj = match &self.corruption {
+                    Some(corruption) => corruption.apply(generated_obj, &self.fields, config, &mut corruption_state),
+                    None => generated_obj,
+                };
+                config.limits.account_output(&mut config.usage, &generated_obj, Some(&local_config))?;
+                if keep_items {
+                    Rc::make_mut(&mut local_config.items).push(generated_obj.clone());
+                }
+                on_item(config, generated_obj)?;
+            } else {
+                // Failed to generate a unique object after MAX_ATTEMPTS
+                // This can happen if the uniqueness constraints are too restrictive
+                // relative to the possible value space
+                trace_event!(warn, index = i, attempts = MAX_ATTEMPTS, "failed to generate unique entity");
+                eprintln!("Warning: Failed to generate unique entity after {} attempts. Uniqueness constraints may be too restrictive.", MAX_ATTEMPTS);
+                break;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl JsonGenerator for Entity {
@@ -336,6 +878,7 @@ impl JsonGenerator for Entity {
     ///     seed: None,
     ///     unique_by: vec![],
     ///     fields,
+    ///     ..Default::default()
     /// };
     ///
     /// let result = entity.generate(&mut config);
@@ -346,6 +889,7 @@ impl JsonGenerator for Entity {
     ///     count: Some(Count::Fixed(3)),
     ///     unique_by: vec!["name".to_string()],
     ///     fields: fields.clone(),
+    ///     ..Default::default()
     /// };
     ///
     /// let result = entity_array.generate(&mut config);
@@ -367,58 +911,14 @@ impl JsonGenerator for Entity {
     /// - **Template Variety**: Ensure fake data templates provide sufficient variation
     fn generate(&self, config: &mut super::GeneratorConfig, local_config: Option<&mut LocalConfig>
         ) -> Result<Value, JgdGeneratorError> {
-        let count_items = self.count.count(config);
-
-        let mut items = Vec::with_capacity(count_items as usize);
-        let mut unique_sets: HashMap<String, HashSet<String>> = HashMap::new();
-
-        let rng = self.seed.map(StdRng::seed_from_u64);
-
-        let mut local_config =
-            LocalConfig::from_current_with_config(rng, Some(count_items), local_config);
-
-        let mut _attempts = 0;
-        const MAX_ATTEMPTS: usize = 1000; // Prevent infinite loops
-
-        for i in 0..count_items {
-            let mut obj = None;
-            local_config.set_index(i as usize);
-
-            // Try to generate a unique object
-            for _ in 0..MAX_ATTEMPTS {
-                _attempts += 1;
-                let candidate = self.fields.generate(config, Some(&mut local_config))?;
-
-                if !self.unique_by.is_empty() {
-                    let fp = fingerprint(&candidate, &self.unique_by);
-                    let set = unique_sets.entry(self.unique_by.join("|"))
-                        .or_default();
-
-                    if !set.contains(&fp) {
-                        set.insert(fp);
-                        obj = Some(candidate);
-                        break;
-                    }
-                    // If fingerprint already exists, try again
-                } else {
-                    // No uniqueness constraints
-                    obj = Some(candidate);
-                    break;
-                }
-            }
-
-            if let Some(generated_obj) = obj {
-                if self.count.is_none() {
-                    return Ok(generated_obj);
-                }
-                items.push(generated_obj);
-            } else {
-                // Failed to generate a unique object after MAX_ATTEMPTS
-                // This can happen if the uniqueness constraints are too restrictive
-                // relative to the possible value space
-                eprintln!("Warning: Failed to generate unique entity after {} attempts. Uniqueness constraints may be too restrictive.", MAX_ATTEMPTS);
-                break;
-            }
+        let mut items = Vec::new();
+        self.generate_each(config, local_config, |_, item| {
+            items.push(item);
+            Ok(())
+        })?;
+
+        if self.is_single() && items.len() == 1 {
+            return Ok(items.remove(0));
         }
 
         Ok(Value::Array(items))
@@ -487,7 +987,7 @@ impl JsonGenerator for IndexMap<String, Entity> {
     /// # Examples
     ///
     /// ```rust,ignore
-    /// use jgd_rs::{Entity, Field, JsonGenerator, GeneratorConfig};
+    /// use jgd_rs::{Entity, Field, JsonGenerator, GeneratorConfig, RefDistribution, SpecId};
     /// use indexmap::IndexMap;
     ///
     /// let mut config = GeneratorConfig::new("EN", Some(42));
@@ -503,13 +1003,16 @@ impl JsonGenerator for IndexMap<String, Entity> {
     ///     seed: None,
     ///     unique_by: vec!["id".to_string()],
     ///     fields: user_fields,
+    ///     ..Default::default()
     /// });
     ///
     /// // Define post entity that references users
     /// let mut post_fields = IndexMap::new();
     /// post_fields.insert("title".to_string(), Field::Str("${lorem.sentence}".to_string()));
     /// post_fields.insert("author_id".to_string(), Field::Ref {
-    ///     r#ref: "users.id".to_string()
+    ///     r#ref: "users.id".to_string(),
+    ///     distribution: RefDistribution::Uniform,
+    ///     id: SpecId::default(),
     /// });
     ///
     /// entities.insert("posts".to_string(), Entity {
@@ -517,6 +1020,7 @@ impl JsonGenerator for IndexMap<String, Entity> {
     ///     seed: None,
     ///     unique_by: vec![],
     ///     fields: post_fields,
+    ///     ..Default::default()
     /// });
     ///
     /// let result = entities.generate(&mut config);
@@ -528,7 +1032,8 @@ impl JsonGenerator for IndexMap<String, Entity> {
     ///
     /// # Performance Notes
     ///
-    /// - **Sequential Generation**: Entities are generated in insertion order
+    /// - **Dependency Order**: Entities are generated after the entities they `ref`,
+    ///   otherwise in insertion order; the output follows the generation order
     /// - **Reference Storage**: Each entity is cloned for storage in gen_value
     /// - **Memory Usage**: Stores both final result and reference copies
     /// - **Order Dependency**: Earlier entities can be referenced by later ones
@@ -538,9 +1043,11 @@ impl JsonGenerator for IndexMap<String, Entity> {
             LocalConfig::from_current_with_config(None, None, local_config);
 
         let mut map = serde_json::Map::new();
-        for (name, entity) in self {
+        for (name, entity) in dependency::generation_order(self)? {
+            let _span = trace_span!(debug_span, "entity", name = %name);
             local_config.entity_name = Some(name.clone());
-            let generated = entity.generate(config, Some(&mut local_config))?;
+            let generated = entity.generate(config, Some(&mut local_config))
+                .map_err(|error| error.within(&["entities", name]))?;
             map.insert(name.clone(), generated.clone());
 
             config.gen_value.insert(name.clone(), generated);
@@ -630,6 +1137,7 @@ mod tests {
             seed: None,
             unique_by: vec![],
             fields,
+            ..Default::default()
         };
 
         let result = entity.generate(&mut config, None);
@@ -659,6 +1167,7 @@ mod tests {
             seed: None,
             unique_by: vec![],
             fields,
+            ..Default::default()
         };
 
         let result = entity.generate(&mut config, None);
@@ -695,6 +1204,7 @@ mod tests {
             seed: None,
             unique_by: vec!["id".to_string()],
             fields,
+            ..Default::default()
         };
 
         let result = entity.generate(&mut config, None);
@@ -739,6 +1249,7 @@ mod tests {
             seed: None,
             unique_by: vec!["category".to_string(), "subcategory".to_string()],
             fields,
+            ..Default::default()
         };
 
         let result = entity.generate(&mut config, None);
@@ -784,6 +1295,7 @@ mod tests {
             seed: None,
             unique_by: vec![],
             fields: user_fields,
+            ..Default::default()
         });
 
         // Second entity
@@ -795,6 +1307,7 @@ mod tests {
             seed: None,
             unique_by: vec![],
             fields: post_fields,
+            ..Default::default()
         });
 
         let result = entities.generate(&mut config, None);
@@ -831,6 +1344,7 @@ mod tests {
             seed: None,
             unique_by: vec![],
             fields: user_fields,
+            ..Default::default()
         });
 
         let _ = entities.generate(&mut config, None);
@@ -846,4 +1360,155 @@ mod tests {
             _ => panic!("Expected stored user to be an object"),
         }
     }
+
+    #[test]
+    fn test_entity_generation_limits() {
+        let mut config = create_test_config(Some(42));
+        config.limits.max_records = Some(3);
+
+        let mut fields = IndexMap::new();
+        fields.insert("name".to_string(), Field::Str("TestUser".to_string()));
+
+        let entity = Entity {
+            count: Some(Count::Fixed(5)),
+            seed: None,
+            unique_by: vec![],
+            fields,
+            ..Default::default()
+        };
+
+        let error = entity.generate(&mut config, None).unwrap_err();
+        assert!(error.message.contains("maxRecords is 3"));
+        assert_eq!(config.usage.depth, 0);
+    }
+
+    #[test]
+    fn test_entity_transforms_apply_to_string_fields() {
+        let mut config = create_test_config(Some(42));
+        let entity: Entity = serde_json::from_value(serde_json::json!({
+            "transforms": ["upper"],
+            "fields": { "name": "alice", "age": 30 }
+        })).unwrap();
+
+        let result = entity.generate(&mut config, None).unwrap();
+        assert_eq!(result, serde_json::json!({ "name": "ALICE", "age": 30 }));
+    }
+
+    #[test]
+    fn test_entity_templates_read_previous_items() {
+        let entity: Entity = serde_json::from_value(serde_json::json!({
+            "count": 3,
+            "fields": {
+                "n": "${index}",
+                "previous": "${previous.n}",
+                "first": "${items[0].n}",
+                "label": "after ${items[-1].n}",
+                "meta": { "fields": { "previous": "${previous.n}" } },
+                "lines": { "count": 1, "fields": { "previous": "${previous.n}" } }
+            }
+        })).unwrap();
+
+        let mut config = create_test_config(Some(1));
+        let result = entity.generate(&mut config, None).unwrap();
+
+        assert_eq!(result[0]["previous"], Value::Null);
+        assert_eq!(result[0]["label"], "after null");
+        assert_eq!(result[2]["previous"], 2);
+        assert_eq!(result[2]["first"], 1);
+        assert_eq!(result[2]["label"], "after 2");
+        assert_eq!(result[2]["meta"]["previous"], 2);
+        assert_eq!(result[2]["lines"][0]["previous"], Value::Null);
+    }
+
+    #[test]
+    fn test_entity_includes_mixins() {
+        let jgd = crate::Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "mixins": {
+                "audit": { "createdBy": { "ref": "admins.id" }, "status": "active" },
+                "softDelete": { "deleted": false }
+            },
+            "entities": {
+                "posts": {
+                    "count": 2,
+                    "include": ["audit", "softDelete"],
+                    "fields": {
+                        "id": "${index}",
+                        "status": "draft",
+                        "author": { "include": ["audit"], "fields": { "name": "${name.name}" } }
+                    }
+                },
+                "admins": { "count": 1, "fields": { "id": "${ulid}" } }
+            }
+        }));
+
+        let posts = &jgd.entities.as_ref().unwrap()["posts"];
+        assert_eq!(posts.fields.keys().collect::<Vec<_>>(), vec!["id", "status", "author", "createdBy", "deleted"]);
+
+        // The ref of the mixin puts admins first
+        let generated = jgd.generate().unwrap();
+        let admin = &generated["admins"][0]["id"];
+        assert_eq!(generated["posts"][0]["status"], "draft");
+        assert_eq!(&generated["posts"][1]["createdBy"], admin);
+        assert_eq!(&generated["posts"][1]["author"]["createdBy"], admin);
+        assert_eq!(generated["posts"][1]["author"]["status"], "active");
+
+        let error = crate::Jgd::from_value_strict(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "include": ["audit"], "fields": {} }
+        })).unwrap_err();
+        assert!(error.message.contains("Error to include the mixin audit in root: it is not defined in mixins"));
+
+        let error = crate::Jgd::from_value_strict(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "mixins": { "tree": { "child": { "include": ["tree"], "fields": {} } } },
+            "root": { "include": ["tree"], "fields": {} }
+        })).unwrap_err();
+        assert!(error.message.contains("the mixin includes itself"));
+    }
+
+    #[test]
+    fn test_entity_flatten_merges_into_parent() {
+        let jgd = crate::Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "fields": {
+                    "id": 7,
+                    "audit": {
+                        "flatten": true,
+                        "fields": { "id": 0, "createdBy": "admin", "meta": { "fields": { "source": "import" } } }
+                    }
+                }
+            }
+        }));
+        assert_eq!(jgd.generate().unwrap(), serde_json::json!({
+            "id": 7,
+            "createdBy": "admin",
+            "meta": { "source": "import" }
+        }));
+
+        let many = crate::Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "lines": { "flatten": true, "count": 2, "fields": { "sku": 1 } } } }
+        }));
+        assert!(many.generate().unwrap_err().message.contains("Error to flatten the field lines: only single objects"));
+    }
+
+    #[test]
+    fn test_entity_requires_fields_unless_relation() {
+        let misspelled = serde_json::json!({ "$format": "jgd/v1", "version": "1.0", "root": { "feilds": {} } });
+        assert!(serde_json::from_value::<crate::Jgd>(misspelled).is_err());
+        assert!(serde_json::from_value::<Field>(serde_json::json!({ "oneOf": ["a", "b"] })).is_err());
+        assert!(serde_json::from_value::<Entity>(serde_json::json!({ "fields": {} })).is_ok());
+
+        let relation: Entity = serde_json::from_value(serde_json::json!({
+            "relation": { "from": "users.id", "to": "groups.id" }
+        })).unwrap();
+        assert!(relation.relation.is_some() && relation.fields.is_empty());
+    }
 }
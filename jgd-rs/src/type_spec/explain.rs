@@ -0,0 +1,623 @@
+//! # Explain Module
+//!
+//! Builds a human-readable generation plan from a JGD schema without generating any data.
+//! The plan lists the entities in generation order with their counts, the resolved locale,
+//! the dependency edges created by `ref` fields and the template keys used by each field,
+//! flagging keys that cannot be resolved and keys without data in the schema locale.
+//!
+//! ```rust
+//! # use jgd_rs::Jgd;
+//! let jgd = Jgd::from(r#"{
+//!   "$format": "jgd/v1",
+//!   "version": "1.0",
+//!   "entities": {
+//!     "users": { "count": 2, "fields": { "id": "${ulid}" } },
+//!     "posts": { "count": [1, 5], "fields": { "userId": { "ref": "users.id" } } }
+//!   }
+//! }"#);
+//! let plan = jgd.explain();
+//! assert_eq!(plan.entities[1].depends_on, vec!["users".to_string()]);
+//! println!("{}", plan);
+//! ```
+
+use std::fmt::Display;
+
+use indexmap::IndexMap;
+
+use crate::{fake::FakeKeys, locales_keys::LocalesKeys, type_spec::{dependency, ArraySpec, ColorFormat, Count, Entity, Field, GeometryKind, Root}, Jgd, LocalConfig, LocaleFallback, ReplacerCollection};
+
+/// How a template key is resolved during generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyKind {
+    /// Built-in fake data key (e.g. `name.firstName`).
+    Fake,
+    /// Key registered with [`Jgd::add_custom_key`].
+    Custom,
+    /// Generation context key (`index`, `count`, `entity.name`, `field.name`).
+    Context,
+    /// Key that cannot be resolved; generation will fail on it.
+    Unknown,
+}
+
+/// A template key used by a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyUsage {
+    pub key: String,
+    pub kind: KeyKind,
+}
+
+/// Generation plan of a single field (nested fields are flattened with dot paths).
+#[derive(Debug, Clone)]
+pub struct FieldPlan {
+    /// Dot path of the field inside the entity; array items are suffixed with `[]`.
+    pub path: String,
+    /// Short description of the field type (`template`, `number`, `ref`, ...).
+    pub kind: String,
+    /// Template keys used by the field.
+    pub keys: Vec<KeyUsage>,
+    /// Paths referenced through `ref`.
+    pub refs: Vec<String>,
+}
+
+/// Generation plan of a top-level entity.
+#[derive(Debug, Clone)]
+pub struct EntityPlan {
+    pub name: String,
+    /// Count description (`5`, `1..=10` or `single object`).
+    pub count: String,
+    /// Entities this entity references, in first-use order.
+    pub depends_on: Vec<String>,
+    pub fields: Vec<FieldPlan>,
+}
+
+/// Human-readable plan of what a schema will generate.
+///
+/// Built by [`Jgd::explain`]; use its `Display` implementation to print the plan.
+#[derive(Debug, Clone)]
+pub struct ExplainPlan {
+    /// `root` or `entities`.
+    pub mode: String,
+    /// Locale requested in the schema.
+    pub locale: String,
+    /// Locale that will actually be used by the fake generators.
+    pub resolved_locale: String,
+    pub seed: Option<u64>,
+    /// Entities in generation order.
+    pub entities: Vec<EntityPlan>,
+    /// Problems found while building the plan (unknown keys, unresolvable refs, and keys
+    /// without data in the locale when `localeFallback` is `error`).
+    pub warnings: Vec<String>,
+    /// Keys without data in the locale, which generate English data.
+    pub fallbacks: Vec<String>,
+}
+
+/// Number of items of an array, or its target size.
+fn array_length(array: &ArraySpec) -> String {
+    match (array.target_size, &array.count) {
+        (Some(size), _) => format!("~{} bytes", size),
+        (None, Some(count)) => count.to_string(),
+        (None, None) => "1".to_string(),
+    }
+}
+
+struct PlanBuilder {
+    fake_keys: FakeKeys,
+    entity_names: Vec<String>,
+    /// The schema locale, or `None` when it is not supported at all.
+    locale: Option<String>,
+    locale_fallback: LocaleFallback,
+    warnings: Vec<String>,
+    fallbacks: Vec<String>,
+}
+
+impl PlanBuilder {
+    fn classify(&self, key: &str) -> KeyKind {
+        if LocalConfig::is_context_key(key) {
+            KeyKind::Context
+        } else if Jgd::get_custom_key(key).is_some() {
+            KeyKind::Custom
+        } else if self.fake_keys.contains_key(key) {
+            KeyKind::Fake
+        } else {
+            KeyKind::Unknown
+        }
+    }
+
+    fn template_keys(&self, template: &ReplacerCollection) -> Vec<KeyUsage> {
+        template.collection
+            .iter()
+            .map(|replacer| KeyUsage {
+                key: replacer.key.clone(),
+                kind: self.classify(&replacer.key),
+            })
+            .collect()
+    }
+
+    fn entity(&mut self, name: &str, entity: &Entity, position: usize) -> EntityPlan {
+        let mut fields = Vec::new();
+
+        let count = if let Some(relation) = &entity.relation {
+            for (path, r#ref) in [(&relation.from_field, &relation.from), (&relation.to_field, &relation.to)] {
+                fields.push(FieldPlan {
+                    path: path.clone(),
+                    kind: "relation".to_string(),
+                    keys: vec![],
+                    refs: vec![r#ref.clone()],
+                });
+            }
+            relation.count.as_ref().or(entity.count.as_ref())
+                .map(|count| format!("{} pairs", count))
+                .unwrap_or_else(|| "1 pair".to_string())
+        } else {
+            entity.count.as_ref()
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| "single object".to_string())
+        };
+
+        self.entity_fields(entity, "", &mut fields);
+        let mut depends_on = self.dependencies(name, &fields, position);
+
+        if let Some(Count::PerParent { per_parent }) = &entity.count {
+            match self.entity_names.iter().position(|entity_name| *entity_name == per_parent.entity) {
+                Some(index) if index < position => {
+                    if !depends_on.contains(&per_parent.entity) {
+                        depends_on.push(per_parent.entity.clone());
+                    }
+                },
+                _ => self.warnings.push(format!(
+                    "{}: perParent count entity '{}' is not generated before it", name, per_parent.entity
+                )),
+            }
+        }
+
+        EntityPlan { name: name.to_string(), count, depends_on, fields }
+    }
+
+    /// Plans the root entity, the items of an array root under `[]`, or a value root.
+    fn root(&mut self, root: &Root) -> EntityPlan {
+        let (count, path, field) = match root {
+            Root::Entity(entity) => return self.entity("root", entity, 0),
+            Root::Array { array } => (format!("array ({})", array_length(array)), "[]", array.of.as_ref()),
+            Root::Value(field) => ("single value".to_string(), "value", field),
+        };
+
+        let mut fields = Vec::new();
+        self.field(path, field, &mut fields);
+        let depends_on = self.dependencies("root", &fields, 0);
+        EntityPlan { name: "root".to_string(), count, depends_on, fields }
+    }
+
+    /// Reports the unknown keys, locale fallbacks and bad refs of the fields of an
+    /// entity, and returns the entities it references.
+    fn dependencies(&mut self, name: &str, fields: &[FieldPlan], position: usize) -> Vec<String> {
+        let mut depends_on: Vec<String> = Vec::new();
+        for field in fields {
+            for key in field.keys.iter().filter(|key| key.kind == KeyKind::Unknown) {
+                self.warnings.push(format!("{}.{}: unknown key '{}'", name, field.path, key.key));
+            }
+
+            if let Some(locale) = &self.locale {
+                let fallbacks = field.keys.iter()
+                    .filter(|key| key.kind == KeyKind::Fake && !FakeKeys::has_locale_data(&key.key, locale))
+                    .map(|key| format!("{}.{}: key '{}' has no {} data, falling back to EN", name, field.path, key.key, locale));
+                match self.locale_fallback {
+                    LocaleFallback::Warn => self.fallbacks.extend(fallbacks),
+                    LocaleFallback::Error => self.warnings.extend(fallbacks),
+                }
+            }
+
+            for r#ref in &field.refs {
+                let target = r#ref.split('.').next().unwrap_or_default().to_string();
+                match self.entity_names.iter().position(|entity_name| *entity_name == target) {
+                    Some(index) if index < position => {
+                        if !depends_on.contains(&target) {
+                            depends_on.push(target);
+                        }
+                    },
+                    Some(_) => self.warnings.push(format!(
+                        "{}.{}: ref '{}' points to an entity generated later", name, field.path, r#ref
+                    )),
+                    None => self.warnings.push(format!(
+                        "{}.{}: ref '{}' does not match any entity", name, field.path, r#ref
+                    )),
+                }
+            }
+        }
+        depends_on
+    }
+
+    fn fields(&self, fields: &IndexMap<String, Field>, prefix: &str, plans: &mut Vec<FieldPlan>) {
+        for (name, field) in fields {
+            self.field(&format!("{}{}", prefix, name), field, plans);
+        }
+    }
+
+    /// Plans the entity fields, then the fields of each variant under `variants[n].`.
+    fn entity_fields(&self, entity: &Entity, prefix: &str, plans: &mut Vec<FieldPlan>) {
+        self.fields(&entity.fields, prefix, plans);
+        for (index, variant) in entity.variants.iter().enumerate() {
+            self.fields(&variant.fields, &format!("{}variants[{}].", prefix, index), plans);
+        }
+    }
+
+    fn field(&self, path: &str, field: &Field, plans: &mut Vec<FieldPlan>) {
+        let plan = |kind: &str, keys: Vec<KeyUsage>, refs: Vec<String>| FieldPlan {
+            path: path.to_string(),
+            kind: kind.to_string(),
+            keys,
+            refs,
+        };
+
+        match field {
+            Field::Array { array } => {
+                plans.push(plan(&format!("array ({})", array_length(array)), vec![], vec![]));
+                self.field(&format!("{}[]", path), &array.of, plans);
+            },
+            Field::Raw { .. } => plans.push(plan("raw", vec![], vec![])),
+            Field::Tuple { tuple } => {
+                plans.push(plan(&format!("tuple ({})", tuple.len()), vec![], vec![]));
+                for (position, field) in tuple.iter().enumerate() {
+                    self.field(&format!("{}[{}]", path, position), field, plans);
+                }
+            },
+            Field::Map { map } => {
+                let count = map.count.as_ref().map(|count| count.to_string()).unwrap_or_else(|| "1".to_string());
+                plans.push(plan(&format!("map ({})", count), vec![], vec![]));
+                self.field(&format!("{}{{key}}", path), &map.key, plans);
+                self.field(&format!("{}{{}}", path), &map.value, plans);
+            },
+            Field::Entity(entity) => {
+                let kind = match &entity.count {
+                    Some(count) => format!("object ({})", count),
+                    None if entity.flatten => "object (flattened)".to_string(),
+                    None => "object".to_string(),
+                };
+                plans.push(plan(&kind, vec![], vec![]));
+                self.entity_fields(entity, &format!("{}.", path), plans);
+            },
+            Field::Number { number } => {
+                let kind = if number.integer { "integer" } else { "float" };
+                plans.push(plan(&format!("{} ({}..={})", kind, number.min, number.max), vec![], vec![]));
+            },
+            Field::Optional { optional } => {
+                plans.push(plan(&format!("optional (prob {})", optional.prob), vec![], vec![]));
+                self.field(&format!("{}?", path), &optional.of, plans);
+            },
+            Field::Ref { r#ref, .. } => plans.push(plan("ref", vec![], vec![r#ref.clone()])),
+            Field::Address { address } => {
+                let kind = if address.formatted { "address (formatted)" } else { "address" };
+                plans.push(plan(kind, vec![], vec![]));
+            },
+            Field::Money { money } => {
+                let kind = if money.formatted { "money (formatted)" } else { "money" };
+                plans.push(plan(kind, vec![], vec![]));
+            },
+            Field::Partition { partition } => {
+                plans.push(plan(&format!("partition ({})", partition.count), vec![], vec![]));
+            },
+            Field::Color { color } => {
+                let format = match color.format {
+                    ColorFormat::Rgb => "rgb",
+                    ColorFormat::Hsl => "hsl",
+                };
+                let kind = if color.alpha { format!("color ({} with alpha)", format) } else { format!("color ({})", format) };
+                plans.push(plan(&kind, vec![], vec![]));
+            },
+            Field::Cumulative { cumulative } => {
+                plans.push(plan(&format!("cumulative of {}", cumulative.of), vec![], vec![]));
+            },
+            Field::IdFrom(id_from) => {
+                plans.push(plan(&format!("id from {}", id_from.id_from.join(", ")), vec![], vec![]));
+            },
+            Field::StateMachine { state_machine } => {
+                plans.push(plan(&format!("state machine (from {})", state_machine.initial), vec![], vec![]));
+            },
+            Field::Timeline { timeline } => {
+                plans.push(plan(&format!("timeline (+{}..{})", timeline.min_gap, timeline.max_gap), vec![], vec![]));
+            },
+            Field::Trace { trace } => {
+                plans.push(plan(&format!("trace ({} spans, {} services)", trace.spans, trace.services.len()), vec![], vec![]));
+                if let Some(name) = &trace.name {
+                    self.field(&format!("{}{{span}}.name", path), name, plans);
+                }
+                for (key, field) in &trace.attributes {
+                    self.field(&format!("{}{{span}}.{}", path, key), field, plans);
+                }
+            },
+            Field::GeoJson { geojson } => {
+                let count = geojson.count.as_ref().map(|count| count.to_string()).unwrap_or_else(|| "1".to_string());
+                let geometry = match geojson.geometry {
+                    GeometryKind::Point => "points",
+                    GeometryKind::Polygon => "polygons",
+                };
+                plans.push(plan(&format!("geojson ({} {})", count, geometry), vec![], vec![]));
+                for (key, field) in &geojson.properties {
+                    self.field(&format!("{}{{feature}}.{}", path, key), field, plans);
+                }
+            },
+            Field::RandomString { random_string } => {
+                plans.push(plan(&format!("random string ({}, {})", random_string.length, random_string.charset), vec![], vec![]));
+            },
+            Field::JsonString { json_string } => {
+                plans.push(plan("json string", vec![], vec![]));
+                self.field(&format!("{}{{json}}", path), &json_string.of, plans);
+            },
+            Field::Transformed { of, transforms } => {
+                let start = plans.len();
+                self.field(path, of, plans);
+                if let Some(inner) = plans.get_mut(start) {
+                    let names: Vec<String> = transforms.iter().map(|transform| transform.to_string()).collect();
+                    inner.kind = format!("{} | {}", inner.kind, names.join(" | "));
+                }
+            },
+            Field::Formatted { format, of } => {
+                let start = plans.len();
+                self.field(path, of, plans);
+                if let Some(inner) = plans.get_mut(start) {
+                    inner.kind = format!("{} | format {}", inner.kind, format);
+                }
+            },
+            Field::Limited { of, length } => {
+                let start = plans.len();
+                self.field(path, of, plans);
+                if let Some(inner) = plans.get_mut(start) {
+                    let bound = |bound: Option<usize>| bound.map(|bound| bound.to_string()).unwrap_or_default();
+                    inner.kind = format!("{} | length {}..={}", inner.kind, bound(length.min), bound(length.max));
+                }
+            },
+            Field::Asserted { of, assert } => {
+                let start = plans.len();
+                self.field(path, of, plans);
+                if let Some(inner) = plans.get_mut(start) {
+                    inner.kind = format!("{} | assert {}", inner.kind, assert);
+                }
+            },
+            Field::Tagged { of, pii, tags } => {
+                let start = plans.len();
+                self.field(path, of, plans);
+                if let Some(inner) = plans.get_mut(start) {
+                    inner.kind = format!("{} [{}]", inner.kind, super::tag_report::field_tags(*pii, tags).join(", "));
+                }
+            },
+            Field::Template(template) => plans.push(plan("template", self.template_keys(template), vec![])),
+            Field::Str(value) => {
+                let keys = self.template_keys(&ReplacerCollection::new(value.clone()));
+                let kind = if keys.is_empty() { "string" } else { "template" };
+                plans.push(plan(kind, keys, vec![]));
+            },
+            Field::Bool(_) => plans.push(plan("boolean", vec![], vec![])),
+            Field::I64(_) | Field::F64(_) => plans.push(plan("constant number", vec![], vec![])),
+            Field::Null => plans.push(plan("null", vec![], vec![])),
+        }
+    }
+}
+
+impl ExplainPlan {
+    pub(crate) fn new(jgd: &Jgd) -> Self {
+        let resolved: &str = LocalesKeys::from(jgd.default_locale.as_str()).into();
+
+        // An unsupported locale falls back as a whole, which is reported once below
+        let mut builder = PlanBuilder {
+            fake_keys: FakeKeys::new(),
+            entity_names: vec![],
+            locale: (resolved == jgd.default_locale).then(|| jgd.default_locale.clone()),
+            locale_fallback: jgd.locale_fallback,
+            warnings: vec![],
+            fallbacks: vec![],
+        };
+
+        let mut entities = Vec::new();
+        let mode = if let Some(root) = &jgd.root {
+            builder.entity_names.push("root".to_string());
+            entities.push(builder.root(root));
+            "root"
+        } else if let Some(schema_entities) = &jgd.entities {
+            let order = match dependency::generation_order(schema_entities) {
+                Ok(order) => order,
+                Err(error) => {
+                    builder.warnings.push(error.message);
+                    schema_entities.iter().collect()
+                },
+            };
+
+            builder.entity_names = order.iter().map(|(name, _)| (*name).clone()).collect();
+            for (position, (name, entity)) in order.into_iter().enumerate() {
+                entities.push(builder.entity(name, entity, position));
+            }
+            "entities"
+        } else {
+            "empty"
+        };
+
+        if resolved != jgd.default_locale {
+            builder.warnings.push(format!(
+                "locale '{}' is not supported, falling back to '{}'", jgd.default_locale, resolved
+            ));
+        }
+
+        Self {
+            mode: mode.to_string(),
+            locale: jgd.default_locale.clone(),
+            resolved_locale: resolved.to_string(),
+            seed: jgd.seed,
+            entities,
+            warnings: builder.warnings,
+            fallbacks: builder.fallbacks,
+        }
+    }
+}
+
+impl Display for KeyUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            KeyKind::Fake => write!(f, "{}", self.key),
+            KeyKind::Custom => write!(f, "{} (custom)", self.key),
+            KeyKind::Context => write!(f, "{} (context)", self.key),
+            KeyKind::Unknown => write!(f, "{} (UNKNOWN)", self.key),
+        }
+    }
+}
+
+impl Display for ExplainPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Mode: {}", self.mode)?;
+        if self.locale == self.resolved_locale {
+            writeln!(f, "Locale: {}", self.resolved_locale)?;
+        } else {
+            writeln!(f, "Locale: {} (requested {})", self.resolved_locale, self.locale)?;
+        }
+        match self.seed {
+            Some(seed) => writeln!(f, "Seed: {}", seed)?,
+            None => writeln!(f, "Seed: random")?,
+        }
+
+        for (position, entity) in self.entities.iter().enumerate() {
+            writeln!(f)?;
+            writeln!(f, "{}. {} [count: {}]", position + 1, entity.name, entity.count)?;
+            if !entity.depends_on.is_empty() {
+                writeln!(f, "   depends on: {}", entity.depends_on.join(", "))?;
+            }
+            for field in &entity.fields {
+                write!(f, "   - {}: {}", field.path, field.kind)?;
+                if !field.keys.is_empty() {
+                    let keys: Vec<String> = field.keys.iter().map(|key| key.to_string()).collect();
+                    write!(f, " [{}]", keys.join(", "))?;
+                }
+                if !field.refs.is_empty() {
+                    write!(f, " -> {}", field.refs.join(", "))?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        if !self.fallbacks.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Locale fallbacks:")?;
+            for fallback in &self.fallbacks {
+                writeln!(f, "   ~ {}", fallback)?;
+            }
+        }
+
+        if !self.warnings.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Warnings:")?;
+            for warning in &self.warnings {
+                writeln!(f, "   ! {}", warning)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn plan(schema: serde_json::Value) -> ExplainPlan {
+        Jgd::from(schema).explain()
+    }
+
+    #[test]
+    fn test_explain_entities_order_counts_and_dependencies() {
+        let plan = plan(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "users": { "count": 3, "fields": { "id": "${ulid}" } },
+                "posts": { "count": [1, 5], "fields": { "userId": { "ref": "users.id" } } }
+            }
+        }));
+
+        assert_eq!(plan.mode, "entities");
+        assert_eq!(plan.entities.len(), 2);
+        assert_eq!(plan.entities[0].name, "users");
+        assert_eq!(plan.entities[0].count, "3");
+        assert_eq!(plan.entities[1].count, "1..=5");
+        assert_eq!(plan.entities[1].depends_on, vec!["users".to_string()]);
+        assert!(plan.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_explain_flags_unknown_keys_and_bad_refs() {
+        let plan = plan(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "posts": { "fields": { "userId": { "ref": "users.id" }, "title": "${not.a.key} #${index}" } },
+                "users": { "fields": { "id": "${ulid}" } }
+            }
+        }));
+
+        // posts is declared first but generated after the users it references
+        assert_eq!(plan.entities[0].name, "users");
+        let title = &plan.entities[1].fields[1];
+        assert_eq!(title.kind, "template");
+        assert_eq!(title.keys[0].kind, KeyKind::Unknown);
+        assert_eq!(title.keys[1].kind, KeyKind::Context);
+        assert_eq!(plan.warnings.len(), 1);
+        assert!(plan.to_string().contains("not.a.key (UNKNOWN)"));
+    }
+
+    #[test]
+    fn test_explain_flags_circular_refs() {
+        let plan = plan(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "a": { "fields": { "b": { "ref": "b.id" } } },
+                "b": { "fields": { "a": { "ref": "a.id" } } }
+            }
+        }));
+
+        assert_eq!(plan.warnings[0], "Error to order the entities: circular ref dependency between a, b");
+        assert!(plan.warnings[1].contains("points to an entity generated later"));
+    }
+
+    #[test]
+    fn test_explain_nested_fields_and_locale_fallback() {
+        let plan = plan(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "defaultLocale": "XX",
+            "root": {
+                "fields": {
+                    "tags": { "array": { "count": [1, 3], "of": "${lorem.word}" } },
+                    "address": { "fields": { "city": "${address.cityName}" } }
+                }
+            }
+        }));
+
+        let paths: Vec<&str> = plan.entities[0].fields.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["tags", "tags[]", "address", "address.city"]);
+        assert_eq!(plan.resolved_locale, "EN");
+        assert_eq!(plan.warnings.len(), 1);
+        assert!(plan.fallbacks.is_empty());
+    }
+
+    #[test]
+    fn test_explain_lists_locale_fallbacks() {
+        let schema = |locale_fallback: &str| json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "defaultLocale": "PT_BR",
+            "localeFallback": locale_fallback,
+            "root": { "fields": { "name": "${name.title} ${name.firstName}", "id": "${uuid.v4}" } }
+        });
+
+        let warn = plan(schema("warn"));
+        assert_eq!(warn.fallbacks, ["root.name: key 'name.title' has no PT_BR data, falling back to EN"]);
+        assert!(warn.warnings.is_empty());
+        assert!(warn.to_string().contains("Locale fallbacks:"));
+
+        let error = plan(schema("error"));
+        assert!(error.fallbacks.is_empty());
+        assert_eq!(error.warnings.len(), 1);
+
+        let generated = Jgd::from(schema("error")).generate().unwrap_err();
+        assert_eq!(generated.message, "Error to process the pattern ${name.title}: name.title has no PT_BR data and localeFallback is error");
+        assert_eq!(generated.path.as_deref(), Some("/root/fields/name"));
+        assert!(Jgd::from(schema("warn")).generate().is_ok());
+    }
+}
@@ -19,9 +19,35 @@
 //! - Function calls with arguments: `"${lorem.sentence(5)}"`
 
 use indexmap::IndexMap;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
-use crate::{type_spec::{ArraySpec, Entity, GeneratorConfig, JsonGenerator, NumberSpec, OptionalSpec, ReplacerCollection}, JgdGeneratorError, LocalConfig};
+use crate::{type_spec::{AddressSpec, ArraySpec, AssertSpec, LengthSpec, ColorSpec, MoneySpec, PartitionSpec, CumulativeSpec, TimelineSpec, TraceSpec, GeoJsonSpec, JsonStringSpec, RandomStringSpec, Entity, FormatSpec, IdFromSpec, GeneratorConfig, JsonGenerator, MapSpec, NumberSpec, OptionalSpec, RefDistribution, ReplacerCollection, StateMachineSpec}, apply_transforms_with, JgdErrorKind, JgdGeneratorError, LocalConfig, SpecId, Transform};
+
+/// Deserializes a string into a compiled template, rejecting strings without
+/// placeholders so the untagged `Field` falls back to `Field::Str`.
+fn deserialize_template<'de, D>(deserializer: D) -> Result<ReplacerCollection, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    let template = ReplacerCollection::new(value);
+    if template.is_empty() {
+        return Err(serde::de::Error::custom("string has no template placeholders"));
+    }
+
+    Ok(template)
+}
+
+/// Keys of the wrappers around an `of` field; an object may only use one of them.
+const WRAPPER_KEYS: &[&[&str]] = &[&["transforms"], &["format"], &["length"], &["assert"], &["pii", "tags"]];
+
+/// Writes a pre-compiled template back as its original string.
+fn serialize_template<S>(template: &ReplacerCollection, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&template.value)
+}
 
 /// A field specification that can generate any JSON value type.
 ///
@@ -39,7 +65,28 @@ use crate::{type_spec::{ArraySpec, Entity, GeneratorConfig, JsonGenerator, Numbe
 ///
 /// ## Dynamic Types
 /// - **`Ref`**: References values from other generated entities
-/// - **`Str`**: Template strings with placeholder substitution support
+/// - **`Tuple`**: Fixed-length arrays with a specification per position
+/// - **`Raw`**: JSON subtrees embedded verbatim
+/// - **`Map`**: Objects with a variable number of generated keys
+/// - **`Address`**: Complete address whose city, state, zip code and country agree
+/// - **`Money`**: Amount rounded to the decimals of its currency, with the currency
+/// - **`Partition`**: Numbers that add up to a fixed total or to a sibling field
+/// - **`Color`**: Color object of numeric channels (`{r,g,b}` or `{h,s,l}`)
+/// - **`Cumulative`**: Running total of a sibling numeric field across generated items
+/// - **`StateMachine`**: States that follow allowed transitions across generated items
+/// - **`Timeline`**: Timestamps that increase strictly across generated items
+/// - **`Trace`**: OpenTelemetry trace of nested spans in the OTLP JSON encoding
+/// - **`GeoJson`**: GeoJSON feature collection of points or polygons
+/// - **`JsonString`**: Another field serialized to a JSON string
+/// - **`RandomString`**: Random string of a length and a character set
+/// - **`Transformed`**: Applies post-generation transforms (`upper`, `slugify`, ...) to another field
+/// - **`Formatted`**: Formats another field with a format string (`INV-{:06}`)
+/// - **`Limited`**: Truncates or generates another field again until its strings fit a `length`
+/// - **`Asserted`**: Generates another field again until it satisfies an `assert` (pattern, length, bounds)
+/// - **`Tagged`**: Classifies another field (`pii`, `tags`) for the [`TagReport`](crate::TagReport)
+/// - **`IdFrom`**: Stable id hashed from sibling fields (`idFrom`, `algo`)
+/// - **`Template`**: Template strings, parsed once when the schema is loaded
+/// - **`Str`**: Literal strings (templates are still substituted when built programmatically)
 ///
 /// ## Primitive Types
 /// - **`Number`**: Generates numbers within specified ranges
@@ -82,12 +129,39 @@ use crate::{type_spec::{ArraySpec, Entity, GeneratorConfig, JsonGenerator, Numbe
 /// - Objects with `"number"` key → `Field::Number`
 /// - Objects with `"optional"` key → `Field::Optional`
 /// - Objects with `"ref"` key → `Field::Ref`
+/// - Objects with `"map"` key → `Field::Map`
+/// - Objects with `"tuple"` key → `Field::Tuple`
+/// - Objects with `"raw"` or `"literal"` key → `Field::Raw`
+/// - Objects with `"address"` key → `Field::Address`
+/// - Objects with `"money"` key → `Field::Money`
+/// - Objects with `"partition"` key → `Field::Partition`
+/// - Objects with `"color"` key → `Field::Color`
+/// - Objects with `"cumulative"` key → `Field::Cumulative`
+/// - Objects with `"stateMachine"` key → `Field::StateMachine`
+/// - Objects with `"timeline"` key → `Field::Timeline`
+/// - Objects with `"trace"` key → `Field::Trace`
+/// - Objects with `"geojson"` key → `Field::GeoJson`
+/// - Objects with `"jsonString"` key → `Field::JsonString`
+/// - Objects with `"randomString"` key → `Field::RandomString`
+/// - Objects with `"of"` and `"transforms"` keys → `Field::Transformed`
+/// - Objects with `"format"` and `"of"` keys → `Field::Formatted`
+/// - Objects with `"of"` and `"length"` keys → `Field::Limited`
+/// - Objects with `"of"` and `"assert"` keys → `Field::Asserted`
+/// - Other objects with an `"of"` key (and `"pii"` or `"tags"`) → `Field::Tagged`
+/// - Objects with an `"idFrom"` key → `Field::IdFrom`
+/// - Other objects (`"fields"`, `"relation"`, ...) → `Field::Entity`
+/// - Strings with `${...}` placeholders → `Field::Template`
 /// - Plain strings → `Field::Str`
 /// - Plain numbers → `Field::I64` or `Field::F64`
 /// - Plain booleans → `Field::Bool`
 /// - `null` → `Field::Null`
-#[derive(Debug, Deserialize, Clone)]
-#[serde(untagged)]
+///
+/// An `of` object combining the keys of several wrappers, such as `transforms` and
+/// `pii`, is rejected instead of dropping all but one of them: nest the wrappers,
+/// e.g. `{ "of": { "of": "${lorem.word}", "transforms": ["upper"] }, "pii": true }`.
+// Derived as a remote of itself so the `Deserialize` impl below can reject combined wrappers
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(untagged, remote = "Self")]
 pub enum Field {
     /// Array field that generates JSON arrays.
     ///
@@ -97,12 +171,6 @@ pub enum Field {
         array: ArraySpec
     },
 
-    /// Entity field that generates nested JSON objects.
-    ///
-    /// Embeds a complete `Entity` specification for generating complex nested structures.
-    /// Entities can contain multiple fields and support uniqueness constraints.
-    Entity(Entity),
-
     /// Number field that generates numeric values within ranges.
     ///
     /// Wraps a `NumberSpec` that defines the range and type (integer/float) for number generation.
@@ -122,11 +190,187 @@ pub enum Field {
     /// Reference field that links to other generated entities.
     ///
     /// Contains a dot-notation path string for accessing values from previously generated
-    /// entities. Enables cross-referencing and relational data generation.
+    /// entities. Enables cross-referencing and relational data generation. The optional
+    /// `distribution` controls how the referenced items are picked, and `id` keys the
+    /// position of a round-robin ref.
     Ref {
-        r#ref: String
+        r#ref: String,
+        #[serde(default, skip_serializing_if = "RefDistribution::is_uniform")]
+        distribution: RefDistribution,
+        #[serde(skip)]
+        id: SpecId,
+    },
+
+    /// Object with a variable number of generated keys and values.
+    ///
+    /// Wraps a `MapSpec`, e.g. `{ "map": { "count": [3, 6], "key": "${lorem.word}", "value": 1 } }`.
+    Map {
+        map: MapSpec
+    },
+
+    /// Fixed-length array whose positions each have their own specification.
+    ///
+    /// E.g. `{ "tuple": ["${address.longitude}", "${address.latitude}"] }` for `[lon, lat]` pairs.
+    Tuple {
+        tuple: Vec<Field>
+    },
+
+    /// JSON subtree embedded verbatim, without template scanning or interpretation.
+    ///
+    /// E.g. `{ "raw": { "fields": ["a"], "note": "${not a template}" } }`; `literal` is an alias of `raw`.
+    Raw {
+        #[serde(alias = "literal")]
+        raw: Value
+    },
+
+    /// Complete address object with consistent parts.
+    ///
+    /// Wraps an `AddressSpec`, e.g. `{ "address": { "formatted": true } }`.
+    Address {
+        address: AddressSpec
+    },
+
+    /// Amount of money with a currency, rounded to the decimals of the currency.
+    ///
+    /// Wraps a `MoneySpec`, e.g. `{ "money": { "min": 1, "max": 500, "currency": "locale" } }`.
+    Money {
+        money: MoneySpec
+    },
+
+    /// Array of numbers that add up to a total.
+    ///
+    /// Wraps a `PartitionSpec`, e.g. `{ "partition": { "count": 4, "total": 100 } }`.
+    Partition {
+        partition: PartitionSpec
+    },
+
+    /// Color as an object of numeric channels.
+    ///
+    /// Wraps a `ColorSpec`, e.g. `{ "color": { "format": "hsl", "alpha": true } }`.
+    Color {
+        color: ColorSpec
     },
 
+    /// Running total of a sibling numeric field across the generated items.
+    ///
+    /// Wraps a `CumulativeSpec`, e.g. `{ "cumulative": { "of": "amount", "initial": 1000 } }`.
+    Cumulative {
+        cumulative: CumulativeSpec
+    },
+
+    /// State following the previous state of the sequence across the generated items.
+    ///
+    /// Wraps a `StateMachineSpec`, e.g. `{ "stateMachine": { "initial": "created", "transitions": { ... } } }`.
+    StateMachine {
+        #[serde(rename = "stateMachine")]
+        state_machine: StateMachineSpec
+    },
+
+    /// Timestamp later than the one of the previous generated item.
+    ///
+    /// Wraps a `TimelineSpec`, e.g. `{ "timeline": { "start": "2025-01-01", "minGap": "1m", "maxGap": "2h" } }`.
+    Timeline {
+        timeline: TimelineSpec
+    },
+
+    /// OpenTelemetry trace of nested spans, as an OTLP JSON export request.
+    ///
+    /// Wraps a `TraceSpec`, e.g. `{ "trace": { "services": ["api", "db"], "spans": [3, 8] } }`.
+    Trace {
+        trace: TraceSpec
+    },
+
+    /// GeoJSON `FeatureCollection` of generated features.
+    ///
+    /// Wraps a `GeoJsonSpec`, e.g. `{ "geojson": { "count": 10, "geometry": "polygon", "properties": { ... } } }`.
+    GeoJson {
+        geojson: GeoJsonSpec
+    },
+
+    /// Field whose generated value is serialized to JSON text.
+    ///
+    /// Wraps a `JsonStringSpec`, e.g. `{ "jsonString": { "of": { "fields": { ... } }, "pretty": true } }`.
+    JsonString {
+        #[serde(rename = "jsonString")]
+        json_string: JsonStringSpec
+    },
+
+    /// Random string of a length and a character set.
+    ///
+    /// Wraps a `RandomStringSpec`, e.g. `{ "randomString": { "length": [8, 12], "charset": "hex" } }`.
+    RandomString {
+        #[serde(rename = "randomString")]
+        random_string: RandomStringSpec
+    },
+
+    /// Field whose generated value goes through post-generation transforms.
+    ///
+    /// Generates `of` and applies `transforms` in order, e.g.
+    /// `{ "of": "${lorem.words(3)}", "transforms": ["slugify", "truncate(20)"] }`.
+    Transformed {
+        of: Box<Field>,
+        transforms: Vec<Transform>,
+    },
+
+    /// Field whose generated value is written into a format string.
+    ///
+    /// Generates `of` and formats it with a `FormatSpec`, e.g.
+    /// `{ "format": "INV-{:06}", "of": { "number": { "min": 1, "max": 9999, "integer": true } } }`.
+    Formatted {
+        format: FormatSpec,
+        of: Box<Field>,
+    },
+
+    /// Field whose generated strings must fit length limits.
+    ///
+    /// Truncates or generates `of` again following the `LengthSpec`, e.g.
+    /// `{ "of": "${lorem.sentence}", "length": { "max": 50 } }`.
+    Limited {
+        of: Box<Field>,
+        length: LengthSpec,
+    },
+
+    /// Field whose generated values must satisfy constraints.
+    ///
+    /// Generates `of` again while the value breaks the `AssertSpec`, e.g.
+    /// `{ "of": "${phone_number.phoneNumber}", "assert": { "pattern": "^[0-9 ()+.-]+$", "maxLength": 20 } }`.
+    Asserted {
+        of: Box<Field>,
+        assert: AssertSpec,
+    },
+
+    /// Field classified for compliance checks; the value is generated from `of` unchanged.
+    ///
+    /// E.g. `{ "of": "${internet.safeEmail}", "pii": true, "tags": ["contact"] }`. The
+    /// output paths of tagged fields are listed by [`Jgd::tag_report`](crate::Jgd::tag_report).
+    Tagged {
+        of: Box<Field>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        pii: bool,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+    },
+
+    /// Stable id hashed from sibling fields declared before it.
+    ///
+    /// Wraps an `IdFromSpec`, e.g. `{ "idFrom": ["email"], "algo": "xxhash" }`.
+    IdFrom(IdFromSpec),
+
+    /// Entity field that generates nested JSON objects.
+    ///
+    /// Embeds a complete `Entity` specification for generating complex nested structures.
+    /// Entities can contain multiple fields and support uniqueness constraints.
+    ///
+    /// Tried after the keyed variants above, since an entity's `fields` may be omitted.
+    Entity(Entity),
+
+    /// Pre-compiled template string.
+    ///
+    /// Strings containing `${...}` placeholders are parsed once at schema-load time,
+    /// so the placeholder scan is not repeated for every generated instance.
+    #[serde(deserialize_with = "deserialize_template", serialize_with = "serialize_template")]
+    Template(ReplacerCollection),
+
     /// String field with template support.
     ///
     /// Can be a literal string or contain `${...}` placeholders for dynamic content generation.
@@ -154,14 +398,39 @@ pub enum Field {
     Null,
 }
 
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        if let Some(object) = value.as_object().filter(|object| object.contains_key("of")) {
+            let wrappers: Vec<&str> = WRAPPER_KEYS.iter()
+                .filter_map(|keys| keys.iter().find(|key| object.contains_key(**key)).copied())
+                .collect();
+            if wrappers.len() > 1 {
+                return Err(serde::de::Error::custom(format!(
+                    "the keys {} cannot be combined in one field, nest one of them in `of`", wrappers.join(" and ")
+                )));
+            }
+        }
+        Field::deserialize(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for Field {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Field::serialize(self, serializer)
+    }
+}
+
 impl Field {
     /// Resolves a reference path to retrieve a value from generated entities.
     ///
     /// This method handles cross-reference resolution by looking up values in the
-    /// generator configuration's `gen_value` map using dot-notation paths.
+    /// generator configuration's `gen_value` map using dot-notation paths. When the path
+    /// goes through arrays, one of the reachable values is picked with `distribution`.
     ///
     /// # Parameters
     /// - `r#ref`: The dot-notation path to resolve (e.g., "users.name", "posts.0.title")
+    /// - `distribution`: How to pick among the values reachable through the path
     /// - `config`: Mutable reference to the generator configuration containing generated values
     ///
     /// # Returns
@@ -178,21 +447,25 @@ impl Field {
     ///
     /// ```rust,ignore
     /// // Reference a user's name from a posts entity
-    /// let user_ref = Field::Ref { r#ref: "users.name".to_string() };
-    /// let resolved_value = user_ref.generate_for_ref("users.name", &mut config);
+    /// let user_ref = Field::Ref { r#ref: "users.name".to_string(), distribution: RefDistribution::Uniform, id: SpecId::default() };
+    /// let resolved_value = user_ref.generate_for_ref("users.name", RefDistribution::Uniform, SpecId::default(), &mut config);
     ///
-    /// // Reference an ID from an entity array
-    /// let id_ref = Field::Ref { r#ref: "users.0.id".to_string() };
-    /// let user_id = id_ref.generate_for_ref("users.0.id", &mut config);
+    /// // Reference an ID from an entity array, favoring the first users
+    /// let id_ref = Field::Ref { r#ref: "users.id".to_string(), distribution: RefDistribution::Zipf, id: SpecId::default() };
+    /// let user_id = id_ref.generate_for_ref("users.id", RefDistribution::Zipf, SpecId::default(), &mut config);
     /// ```
-    fn generate_for_ref(&self, r#ref: &str, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
-        ) -> Result<Value, JgdGeneratorError> {
-        let value = config.get_value_from_path(r#ref.to_string());
-
-        if let Some(value) = value {
-            return Ok(value.clone());
+    fn generate_for_ref(&self, r#ref: &str, distribution: RefDistribution, id: SpecId, config: &mut GeneratorConfig,
+        local_config: Option<&mut LocalConfig>) -> Result<Value, JgdGeneratorError> {
+        let len = config.get_values_from_path(r#ref).len();
+        // A mixin included into several entities keeps one cursor per entity
+        let entity = local_config.as_ref().and_then(|local_config| local_config.entity_name.clone());
+
+        if let Some(position) = distribution.pick(len, (entity, id), config) {
+            return Ok(config.get_values_from_path(r#ref)[position].clone());
         }
 
+        trace_event!(debug, path = %r#ref, "ref path not found");
+
         let (entity_name, field_name) = if let Some(local_config) = local_config {
             let entity_name = local_config.entity_name.clone();
             let field_name = local_config.field_name.clone();
@@ -205,8 +478,22 @@ impl Field {
             message: format!("The path {} is not found", r#ref),
             entity: entity_name,
             field: field_name,
+            kind: JgdErrorKind::Reference,
+            path: None,
+            key: Some(r#ref.into()),
         })
     }
+
+    /// Generates a fixed-length array, one value per position specification.
+    fn generate_for_tuple(&self, tuple: &[Field], config: &mut GeneratorConfig, mut local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        tuple.iter()
+            .enumerate()
+            .map(|(position, field)| field.generate(config, local_config.as_deref_mut())
+                .map_err(|error| error.within(&["tuple", &position.to_string()])))
+            .collect::<Result<Vec<Value>, JgdGeneratorError>>()
+            .map(Value::Array)
+    }
 }
 
 impl JsonGenerator for Field {
@@ -229,6 +516,26 @@ impl JsonGenerator for Field {
     /// - **Number**: Delegates to `NumberSpec::generate()` for numeric value generation
     /// - **Optional**: Delegates to `OptionalSpec::generate()` for probability-based generation
     /// - **Ref**: Resolves cross-references using `generate_for_ref()`
+    /// - **Tuple**: Generates each position in order using `generate_for_tuple()`
+    /// - **Raw**: Returns the embedded JSON unchanged
+    /// - **Map**: Generates an object with generated keys using `MapSpec::generate()`
+    /// - **Address**: Generates a consistent address object with `AddressSpec::generate()`
+    /// - **Money**: Generates an amount and its currency with `MoneySpec::generate()`
+    /// - **Partition**: Splits the total into numbers with `PartitionSpec::generate()`
+    /// - **Color**: Generates the channels of a color with `ColorSpec::generate()`
+    /// - **Cumulative**: Adds the sibling value to the running total with `CumulativeSpec::generate()`
+    /// - **StateMachine**: Moves the sequence to its next state with `StateMachineSpec::generate()`
+    /// - **Timeline**: Moves the timeline forward by a random gap with `TimelineSpec::generate()`
+    /// - **Trace**: Generates a span tree of one trace with `TraceSpec::generate()`
+    /// - **GeoJson**: Generates a feature collection with `GeoJsonSpec::generate()`
+    /// - **JsonString**: Generates the wrapped field and serializes it with `JsonStringSpec::generate()`
+    /// - **RandomString**: Picks the characters of a string with `RandomStringSpec::generate()`
+    /// - **Transformed**: Generates the wrapped field and applies its transforms
+    /// - **Formatted**: Generates the wrapped field and formats it with `FormatSpec::format()`
+    /// - **Limited**: Generates the wrapped field until `LengthSpec::fit()` fits it
+    /// - **Asserted**: Generates the wrapped field until it satisfies `AssertSpec::check()`
+    /// - **Tagged**: Generates the wrapped field
+    /// - **IdFrom**: Hashes the sibling values with `IdFromSpec::generate()`
     /// - **Str**: Processes template strings with placeholder replacement
     /// - **Bool/I64/F64/Null**: Direct conversion to corresponding JSON values
     ///
@@ -260,7 +567,59 @@ impl JsonGenerator for Field {
             Field::Entity(entity) => entity.generate(config, local_config),
             Field::Number { number } => number.generate(config, local_config),
             Field::Optional { optional } => optional.generate(config, local_config),
-            Field::Ref { r#ref } => self.generate_for_ref(r#ref, config, local_config),
+            Field::Ref { r#ref, distribution, id } => self.generate_for_ref(r#ref, *distribution, *id, config, local_config),
+            Field::Map { map } => map.generate(config, local_config),
+            Field::Tuple { tuple } => self.generate_for_tuple(tuple, config, local_config),
+            Field::Raw { raw } => Ok(raw.clone()),
+            Field::Address { address } => address.generate(config, local_config),
+            Field::Money { money } => money.generate(config, local_config),
+            Field::Partition { partition } => partition.generate(config, local_config),
+            Field::Color { color } => color.generate(config, local_config),
+            Field::Cumulative { cumulative } => cumulative.generate(config, local_config),
+            Field::IdFrom(id_from) => id_from.generate(config, local_config),
+            Field::StateMachine { state_machine } => state_machine.generate(config, local_config),
+            Field::Timeline { timeline } => timeline.generate(config, local_config),
+            Field::Trace { trace } => trace.generate(config, local_config),
+            Field::GeoJson { geojson } => geojson.generate(config, local_config),
+            Field::JsonString { json_string } => json_string.generate(config, local_config),
+            Field::RandomString { random_string } => random_string.generate(config, local_config),
+            Field::Transformed { of, transforms } => {
+                of.generate(config, local_config)
+                    .map(|value| apply_transforms_with(transforms, value, &config.locale, &mut config.rng))
+                    .map_err(|error| error.within(&["of"]))
+            },
+            Field::Formatted { format, of } => {
+                let value = of.generate(config, local_config).map_err(|error| error.within(&["of"]))?;
+                format.format(&value).map(Value::String).map_err(|message| JgdGeneratorError {
+                    message,
+                    kind: JgdErrorKind::Schema,
+                    ..Default::default()
+                })
+            },
+            Field::Limited { of, length } => {
+                let mut local_config = local_config;
+                let mut attempt = 0;
+                loop {
+                    let value = of.generate(config, local_config.as_deref_mut()).map_err(|error| error.within(&["of"]))?;
+                    if let Some(value) = length.fit(value, attempt)? {
+                        break Ok(value);
+                    }
+                    attempt += 1;
+                }
+            },
+            Field::Asserted { of, assert } => {
+                let mut local_config = local_config;
+                let mut attempt = 0;
+                loop {
+                    let value = of.generate(config, local_config.as_deref_mut()).map_err(|error| error.within(&["of"]))?;
+                    if assert.check(&value, attempt)? {
+                        break Ok(value);
+                    }
+                    attempt += 1;
+                }
+            },
+            Field::Tagged { of, .. } => of.generate(config, local_config).map_err(|error| error.within(&["of"])),
+            Field::Template(template) => template.replace(config, local_config),
             Field::Str(value) => value.generate(config, local_config),
             Field::Bool(value) => Ok(Value::Bool(*value)),
             Field::I64(value) => Ok(Value::Number(serde_json::Number::from(*value))),
@@ -310,18 +669,50 @@ impl JsonGenerator for IndexMap<String, Field> {
     /// - Nested object creation within complex field hierarchies
     fn generate(&self, config: &mut super::GeneratorConfig, local_config: Option<&mut LocalConfig>
         ) -> Result<Value, JgdGeneratorError> {
+        generate_fields_with(self, config, local_config, |_, _, _| {})
+    }
+}
 
-        let mut local_config = LocalConfig::from_current_with_config(None, None, local_config);
-
-        let mut map = serde_json::Map::new();
-        for (key, field) in self {
-            local_config.field_name = Some(key.clone());
-            let generated = field.generate(config, Some(&mut local_config))?;
-            map.insert(key.clone(), generated);
+/// Generates the fields of an object like [`JsonGenerator::generate`], calling `on_field`
+/// with each field name and value as soon as it is generated, before the next field.
+pub(crate) fn generate_fields_with<F>(fields: &IndexMap<String, Field>, config: &mut GeneratorConfig,
+    local_config: Option<&mut LocalConfig>, mut on_field: F) -> Result<Value, JgdGeneratorError>
+where
+    F: FnMut(&str, &Value, &mut GeneratorConfig),
+{
+    let mut local_config = LocalConfig::from_current_with_config(None, None, local_config);
+
+    for (key, field) in fields {
+        let _span = trace_span!(trace_span, "field", name = %key);
+        local_config.field_name = Some(key.clone());
+        let generated = field.generate(config, Some(&mut local_config))
+            .map_err(|error| error.within(&["fields", key]))?;
+        on_field(key, &generated, config);
+        match field {
+            Field::Entity(entity) if entity.flatten => {
+                let Value::Object(object) = generated else {
+                    return Err(JgdGeneratorError {
+                        message: format!("Error to flatten the field {}: only single objects, without count, can be flattened", key),
+                        kind: JgdErrorKind::Schema,
+                        ..Default::default()
+                    }.within(&["fields", key]));
+                };
+                for (name, value) in object.into_iter().filter(|(name, _)| !fields.contains_key(name)) {
+                    local_config.siblings.entry(name).or_insert(value);
+                }
+            },
+            _ => {
+                local_config.siblings.insert(key.clone(), generated);
+            },
         }
+    }
 
-        Ok(Value::Object(map))
+    let mut map = std::mem::take(&mut local_config.siblings);
+    config.fuzz.apply(&mut map, fields, &mut config.rng);
+    if let Some(realism) = config.realism {
+        realism.apply(&mut map, fields, config);
     }
+    Ok(Value::Object(map))
 }
 
 impl JsonGenerator for String {
@@ -411,6 +802,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_field_str_with_escaped_placeholder() {
+        let mut config = create_test_config(Some(42));
+        let field: Field = serde_json::from_value(json!("echo $${HOME} ${name.firstName}")).unwrap();
+
+        let result = field.generate(&mut config, None).unwrap();
+        let result = result.as_str().unwrap();
+        assert!(result.starts_with("echo ${HOME} "));
+        assert!(!result.contains("${name"));
+
+        let field: Field = serde_json::from_value(json!("$${literal}")).unwrap();
+        assert_eq!(field.generate(&mut config, None).unwrap(), json!("${literal}"));
+    }
+
     #[test]
     fn test_field_bool_true() {
         let mut config = create_test_config(Some(42));
@@ -505,6 +910,9 @@ mod tests {
         let array_spec = ArraySpec {
             count: Some(Count::Fixed(3)),
             of: Box::new(Field::Str("test".to_string())),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
         let field = Field::Array { array: array_spec };
 
@@ -533,7 +941,7 @@ mod tests {
             "name": "John Doe"
         }));
 
-        let field = Field::Ref { r#ref: "users.name".to_string() };
+        let field = Field::Ref { r#ref: "users.name".to_string(), distribution: RefDistribution::Uniform, id: SpecId::default() };
         let result = field.generate(&mut config, None);
         assert!(result.is_ok());
 
@@ -545,7 +953,7 @@ mod tests {
     #[test]
     fn test_field_ref_missing_path() {
         let mut config = create_test_config(Some(42));
-        let field = Field::Ref { r#ref: "nonexistent.path".to_string() };
+        let field = Field::Ref { r#ref: "nonexistent.path".to_string(), distribution: RefDistribution::Uniform, id: SpecId::default() };
 
         let result = field.generate(&mut config, None);
         assert!(result.is_err());
@@ -555,6 +963,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_field_tuple() {
+        let mut config = create_test_config(Some(42));
+        let field: Field = serde_json::from_value(json!({
+            "tuple": [{ "number": { "min": -180, "max": 180 } }, "EUR", { "ref": "currencies.label" }]
+        })).unwrap();
+        config.gen_value.insert("currencies".to_string(), json!({ "label": "Euro" }));
+
+        let result = field.generate(&mut config, None).unwrap();
+        let values = result.as_array().unwrap();
+        assert_eq!(values.len(), 3);
+        assert!(values[0].is_f64());
+        assert_eq!(values[1..], [json!("EUR"), json!("Euro")]);
+
+        let field: Field = serde_json::from_value(json!({ "tuple": [1, { "ref": "missing.id" }] })).unwrap();
+        let error = field.generate(&mut config, None).unwrap_err();
+        assert_eq!(error.path.as_deref(), Some("/tuple/1"));
+    }
+
+    #[test]
+    fn test_field_raw_is_not_interpreted() {
+        let mut config = create_test_config(Some(42));
+        let payload = json!({ "fields": { "id": "${uuid}" }, "count": [1, 2], "ref": "users.id" });
+
+        for key in ["raw", "literal"] {
+            let field: Field = serde_json::from_value(json!({ key: payload.clone() })).unwrap();
+            assert!(matches!(field, Field::Raw { .. }));
+            assert_eq!(field.generate(&mut config, None).unwrap(), payload);
+        }
+    }
+
     #[test]
     fn test_field_entity() {
         let mut config = create_test_config(Some(42));
@@ -568,6 +1007,7 @@ mod tests {
             seed: None,
             unique_by: vec![],
             fields,
+            ..Default::default()
         };
 
         let field = Field::Entity(entity);
@@ -707,6 +1147,7 @@ mod tests {
             seed: None,
             unique_by: vec![],
             fields: inner_fields,
+            ..Default::default()
         };
 
         let mut outer_fields = IndexMap::new();
@@ -748,7 +1189,7 @@ mod tests {
             Field::F64(123.45), // Using arbitrary float to avoid clippy warnings
             Field::Null,
             Field::Number { number: NumberSpec::new_integer(1.0, 10.0) },
-            Field::Ref { r#ref: "test.path".to_string() },
+            Field::Ref { r#ref: "test.path".to_string(), distribution: RefDistribution::Uniform, id: SpecId::default() },
         ];
 
         for field in variants {
@@ -762,4 +1203,30 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_field_template_compiled_on_deserialize() {
+        let mut config = create_test_config(Some(42));
+
+        let field: Field = serde_json::from_value(json!("${name.firstName} ${name.lastName}")).unwrap();
+        assert!(matches!(&field, Field::Template(template) if template.collection.len() == 2));
+
+        let literal: Field = serde_json::from_value(json!("plain text")).unwrap();
+        assert!(matches!(literal, Field::Str(ref value) if value == "plain text"));
+
+        let result = field.generate(&mut config, None);
+        assert!(matches!(result, Ok(Value::String(_))));
+    }
+
+    #[test]
+    fn test_field_transformed() {
+        let mut config = create_test_config(Some(42));
+        let field: Field = serde_json::from_value(serde_json::json!({
+            "of": "  Hello World  ",
+            "transforms": ["trim", "slugify"]
+        })).unwrap();
+
+        assert!(matches!(field, Field::Transformed { .. }));
+        assert_eq!(field.generate(&mut config, None).unwrap(), Value::String("hello-world".to_string()));
+    }
 }
@@ -0,0 +1,374 @@
+//! # Format Specification Module
+//!
+//! Formats generated values as strings with Rust-like format strings, for zero-padded
+//! ids, prefixed codes and fixed-decimal amounts:
+//!
+//! ```json
+//! {
+//!   "invoice": { "format": "INV-{:06}", "of": { "number": { "min": 1, "max": 999999, "integer": true } } },
+//!   "amount": { "format": "{:.2}", "of": { "number": { "min": 1, "max": 500 } } },
+//!   "color": { "format": "#{:06X}", "of": { "number": { "min": 0, "max": 16777215, "integer": true } } }
+//! }
+//! ```
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+/// Alignment of a padded value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// Radix of an integer written with the `x`, `X`, `o` or `b` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Radix {
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+}
+
+/// One `{...}` placeholder: `{:[[fill]align][+][0][width][.precision][type]}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Placeholder {
+    fill: char,
+    align: Option<Align>,
+    plus: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    radix: Option<Radix>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Placeholder(Placeholder),
+}
+
+/// A generated value as seen by a placeholder.
+enum Formattable {
+    Integer(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl From<&Value> for Formattable {
+    /// Numbers, and strings holding a number (such as `${number.digit}`), are formatted
+    /// as numbers; other values as their text.
+    fn from(value: &Value) -> Self {
+        let text = match value {
+            Value::String(text) => text.clone(),
+            Value::Number(_) => value.to_string(),
+            Value::Null | Value::Bool(_) | Value::Array(_) | Value::Object(_) => return Formattable::Text(value.to_string()),
+        };
+
+        if let Ok(integer) = text.trim().parse::<i64>() {
+            Formattable::Integer(integer)
+        } else if let Ok(float) = text.trim().parse::<f64>() {
+            Formattable::Float(float)
+        } else {
+            Formattable::Text(text)
+        }
+    }
+}
+
+fn parse_placeholder(spec: &str, pattern: &str) -> Result<Placeholder, String> {
+    let error = |details: &str| format!("Error to parse the format {}: {}", pattern, details);
+    let Some(spec) = spec.strip_prefix(':').or(spec.is_empty().then_some("")) else {
+        return Err(error("placeholders are {} or {:spec}"));
+    };
+
+    let align_of = |char: char| match char {
+        '<' => Some(Align::Left),
+        '^' => Some(Align::Center),
+        '>' => Some(Align::Right),
+        _ => None,
+    };
+
+    let chars: Vec<char> = spec.chars().collect();
+    let mut placeholder = Placeholder {
+        fill: ' ', align: None, plus: false, zero: false, width: 0, precision: None, radix: None,
+    };
+    let mut position = 0;
+
+    if let Some(align) = chars.get(1).and_then(|char| align_of(*char)) {
+        placeholder.fill = chars[0];
+        placeholder.align = Some(align);
+        position = 2;
+    } else if let Some(align) = chars.first().and_then(|char| align_of(*char)) {
+        placeholder.align = Some(align);
+        position = 1;
+    }
+
+    if chars.get(position) == Some(&'+') {
+        placeholder.plus = true;
+        position += 1;
+    }
+    if chars.get(position) == Some(&'0') {
+        placeholder.zero = true;
+        position += 1;
+    }
+
+    let digits = |position: &mut usize| {
+        let start = *position;
+        while chars.get(*position).is_some_and(char::is_ascii_digit) {
+            *position += 1;
+        }
+        chars[start..*position].iter().collect::<String>()
+    };
+
+    let width = digits(&mut position);
+    placeholder.width = if width.is_empty() { 0 } else { width.parse().map_err(|_| error("invalid width"))? };
+
+    if chars.get(position) == Some(&'.') {
+        position += 1;
+        let precision = digits(&mut position);
+        placeholder.precision = Some(precision.parse().map_err(|_| error("expected a precision after ."))?);
+    }
+
+    placeholder.radix = match chars.get(position) {
+        Some('x') => Some(Radix::LowerHex),
+        Some('X') => Some(Radix::UpperHex),
+        Some('o') => Some(Radix::Octal),
+        Some('b') => Some(Radix::Binary),
+        _ => None,
+    };
+    if placeholder.radix.is_some() {
+        position += 1;
+    }
+
+    if position < chars.len() {
+        return Err(error(&format!("unsupported format spec :{}", spec)));
+    }
+
+    Ok(placeholder)
+}
+
+fn parse(pattern: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            },
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            },
+            '{' => {
+                let spec: String = chars.by_ref().take_while(|char| *char != '}').collect();
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Placeholder(parse_placeholder(&spec, pattern)?));
+            },
+            '}' => return Err(format!("Error to parse the format {}: unmatched }}, use }}}} for a literal }}", pattern)),
+            char => literal.push(char),
+        }
+    }
+
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+
+    if !pieces.iter().any(|piece| matches!(piece, Piece::Placeholder(_))) {
+        return Err(format!("Error to parse the format {}: expected a {{}} placeholder", pattern));
+    }
+
+    Ok(pieces)
+}
+
+impl Placeholder {
+    fn pad(&self, body: String, numeric: bool) -> String {
+        let len = body.chars().count();
+        if len >= self.width {
+            return body;
+        }
+
+        let padding = self.width - len;
+        if numeric && self.zero && self.align.is_none() {
+            let (sign, digits) = match body.strip_prefix(['+', '-']) {
+                Some(digits) => (&body[..1], digits),
+                None => ("", body.as_str()),
+            };
+            return format!("{}{}{}", sign, "0".repeat(padding), digits);
+        }
+
+        let fill = |count: usize| self.fill.to_string().repeat(count);
+        match self.align.unwrap_or(if numeric { Align::Right } else { Align::Left }) {
+            Align::Left => format!("{}{}", body, fill(padding)),
+            Align::Right => format!("{}{}", fill(padding), body),
+            Align::Center => format!("{}{}{}", fill(padding / 2), body, fill(padding - padding / 2)),
+        }
+    }
+
+    fn format(&self, value: &Value, pattern: &str) -> Result<String, String> {
+        let formattable = Formattable::from(value);
+        let signed = |body: String, negative: bool| match self.plus && !negative {
+            true => format!("+{}", body),
+            false => body,
+        };
+
+        let (body, numeric) = match (formattable, self.radix) {
+            (Formattable::Integer(integer), Some(radix)) => {
+                let body = match radix {
+                    Radix::LowerHex => format!("{:x}", integer),
+                    Radix::UpperHex => format!("{:X}", integer),
+                    Radix::Octal => format!("{:o}", integer),
+                    Radix::Binary => format!("{:b}", integer),
+                };
+                (signed(body, integer < 0), true)
+            },
+            (_, Some(_)) => return Err(format!("Error to format the value {} with {}: expected an integer", value, pattern)),
+            (Formattable::Integer(integer), None) => match self.precision {
+                Some(precision) => (signed(format!("{:.*}", precision, integer as f64), integer < 0), true),
+                None => (signed(integer.to_string(), integer < 0), true),
+            },
+            (Formattable::Float(float), None) => match self.precision {
+                Some(precision) => (signed(format!("{:.*}", precision, float), float < 0.0), true),
+                None => (signed(float.to_string(), float < 0.0), true),
+            },
+            (Formattable::Text(text), None) => match self.precision {
+                Some(precision) => (text.chars().take(precision).collect(), false),
+                None => (text, false),
+            },
+        };
+
+        Ok(self.pad(body, numeric))
+    }
+}
+
+/// A format string applied to the value generated by a field.
+///
+/// Placeholders follow the Rust syntax `{:[[fill]align][+][0][width][.precision][type]}`,
+/// where `type` is `x`, `X`, `o` or `b` for integers in hex, octal or binary. Numbers,
+/// and strings holding a number, are formatted as numbers: `{:06}` zero-pads and `{:.2}`
+/// fixes the decimals. Other values are formatted as text, where `.precision` truncates.
+/// `{{` and `}}` are literal braces.
+///
+/// The format string is parsed when the schema is loaded; an invalid one is reported when
+/// the field is generated.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "String")]
+pub struct FormatSpec {
+    /// The format string, e.g. `INV-{:06}`.
+    pub pattern: String,
+    pieces: Result<Vec<Piece>, String>,
+}
+
+impl From<String> for FormatSpec {
+    fn from(pattern: String) -> Self {
+        let pieces = parse(&pattern);
+        Self { pattern, pieces }
+    }
+}
+
+impl From<&str> for FormatSpec {
+    fn from(pattern: &str) -> Self {
+        FormatSpec::from(pattern.to_string())
+    }
+}
+
+impl Serialize for FormatSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.pattern)
+    }
+}
+
+impl Display for FormatSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+impl FormatSpec {
+    /// Formats `value`, writing it in place of each placeholder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jgd_rs::FormatSpec;
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(FormatSpec::from("INV-{:06}").format(&json!(42)).unwrap(), "INV-000042");
+    /// assert_eq!(FormatSpec::from("{:.2}").format(&json!("3.14159")).unwrap(), "3.14");
+    /// assert_eq!(FormatSpec::from("#{:06X}").format(&json!(48879)).unwrap(), "#00BEEF");
+    /// ```
+    pub fn format(&self, value: &Value) -> Result<String, String> {
+        let pieces = self.pieces.as_ref().map_err(|message| message.clone())?;
+
+        let mut formatted = String::new();
+        for piece in pieces {
+            match piece {
+                Piece::Literal(literal) => formatted.push_str(literal),
+                Piece::Placeholder(placeholder) => formatted.push_str(&placeholder.format(value, &self.pattern)?),
+            }
+        }
+        Ok(formatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn format(pattern: &str, value: Value) -> Result<String, String> {
+        FormatSpec::from(pattern).format(&value)
+    }
+
+    #[test]
+    fn test_format_numbers_and_text() {
+        assert_eq!(format("INV-{:06}", json!(42)).unwrap(), "INV-000042");
+        assert_eq!(format("{:06}", json!(-42)).unwrap(), "-00042");
+        assert_eq!(format("{:+.2}", json!(2.5)).unwrap(), "+2.50");
+        assert_eq!(format("{:.1}", json!(7)).unwrap(), "7.0");
+        assert_eq!(format("{:x}-{:08b}", json!("10")).unwrap(), "a-00001010");
+        assert_eq!(format("[{:>5}|{:*<5}|{:^6}]", json!("ab")).unwrap(), "[   ab|ab***|  ab  ]");
+        assert_eq!(format("{:.3}", json!("abcdef")).unwrap(), "abc");
+        assert_eq!(format("{{{}}}", json!(true)).unwrap(), "{true}");
+    }
+
+    #[test]
+    fn test_format_errors() {
+        assert_eq!(format("{:x}", json!(1.5)).unwrap_err(), "Error to format the value 1.5 with {:x}: expected an integer");
+        assert_eq!(format("{0}", json!(1)).unwrap_err(), "Error to parse the format {0}: placeholders are {} or {:spec}");
+        assert!(format("{:06q}", json!(1)).unwrap_err().contains("unsupported format spec :06q"));
+        assert!(format("no placeholder", json!(1)).unwrap_err().contains("expected a {} placeholder"));
+    }
+
+    #[test]
+    fn test_formatted_field() {
+        let jgd = crate::Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 3,
+                "fields": {
+                    "id": { "format": "INV-{:06}", "of": "${index}" },
+                    "bad": { "format": "{:x}", "of": "${name.firstName}" }
+                }
+            }
+        }));
+
+        let error = jgd.generate().unwrap_err();
+        assert!(error.message.starts_with("Error to format the value"), "{}", error.message);
+        assert_eq!(error.path.as_deref(), Some("/root/fields/bad"));
+
+        let mut jgd = jgd;
+        jgd.root.as_mut().and_then(crate::Root::entity_mut).unwrap().fields.shift_remove("bad");
+        let ids: Vec<Value> = jgd.generate().unwrap().as_array().unwrap().iter().map(|item| item["id"].clone()).collect();
+        assert_eq!(ids, [json!("INV-000001"), json!("INV-000002"), json!("INV-000003")]);
+    }
+}
@@ -0,0 +1,215 @@
+//! # GeoJSON Specification Module
+//!
+//! GeoJSON `FeatureCollection`s (RFC 7946) of points or polygons, for map-based
+//! applications that load fixtures straight into a map layer:
+//!
+//! ```json
+//! {
+//!   "stores": {
+//!     "geojson": {
+//!       "count": 25,
+//!       "geometry": "polygon",
+//!       "bbox": [-74.05, 40.68, -73.90, 40.88],
+//!       "radius": 0.5,
+//!       "properties": {
+//!         "name": "${company.name}",
+//!         "visits": { "number": { "min": 0, "max": 1000, "integer": true } }
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+
+use std::f64::consts::TAU;
+
+use indexmap::IndexMap;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{type_spec::{Count, Field, GetCount, JsonGenerator}, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Kilometers in a degree of latitude.
+const KM_PER_DEGREE: f64 = 111.32;
+
+/// Decimals of the coordinates, about 10cm.
+const COORDINATE_DECIMALS: i32 = 6;
+
+/// Geometry of the generated features.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GeometryKind {
+    /// A `Point` anywhere in the bounding box.
+    #[default]
+    Point,
+
+    /// A `Polygon` with one closed, counterclockwise ring around a point of the bounding box.
+    Polygon,
+}
+
+fn default_bbox() -> [f64; 4] {
+    [-180.0, -90.0, 180.0, 90.0]
+}
+
+fn default_vertices() -> Count {
+    Count::Range((3, 8))
+}
+
+fn default_radius() -> f64 {
+    1.0
+}
+
+/// Specification of a GeoJSON feature collection.
+///
+/// Features are points or polygons inside `bbox`. Polygon vertices are spread around
+/// the center at distances between half of `radius` and `radius`. `properties` are
+/// generated once per feature, with `${index}` set to the feature position.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GeoJsonSpec {
+    /// Number of features; defaults to 1 like [`ArraySpec`](crate::ArraySpec).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<Count>,
+
+    /// Geometry of the features, `point` by default.
+    #[serde(default)]
+    pub geometry: GeometryKind,
+
+    /// Bounding box of the feature centers: `[west, south, east, north]` in degrees.
+    #[serde(default = "default_bbox")]
+    pub bbox: [f64; 4],
+
+    /// Number of vertices of each polygon, `[3, 8]` by default.
+    #[serde(default = "default_vertices")]
+    pub vertices: Count,
+
+    /// Largest distance of the polygon vertices from their center, in kilometers.
+    #[serde(default = "default_radius")]
+    pub radius: f64,
+
+    /// Specifications of the feature properties, by property name.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub properties: IndexMap<String, Field>,
+}
+
+fn round(coordinate: f64) -> f64 {
+    let factor = 10f64.powi(COORDINATE_DECIMALS);
+    (coordinate * factor).round() / factor
+}
+
+impl GeoJsonSpec {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to generate the GeoJSON: {}", details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+
+    fn validate(&self) -> Result<(), JgdGeneratorError> {
+        let [west, south, east, north] = self.bbox;
+        if !(-180.0..=180.0).contains(&west) || !(-180.0..=180.0).contains(&east) || west > east {
+            return Err(self.error(&format!("invalid longitudes {}..{} in bbox", west, east)));
+        }
+        if !(-90.0..=90.0).contains(&south) || !(-90.0..=90.0).contains(&north) || south > north {
+            return Err(self.error(&format!("invalid latitudes {}..{} in bbox", south, north)));
+        }
+        if self.radius.is_nan() || self.radius <= 0.0 {
+            return Err(self.error("radius must be greater than zero"));
+        }
+        Ok(())
+    }
+
+    /// A random `[longitude, latitude]` inside the bounding box.
+    fn position(&self, config: &mut GeneratorConfig) -> (f64, f64) {
+        let [west, south, east, north] = self.bbox;
+        (config.rng.random_range(west..=east), config.rng.random_range(south..=north))
+    }
+
+    fn geometry(&self, config: &mut GeneratorConfig) -> Value {
+        let (longitude, latitude) = self.position(config);
+        if self.geometry == GeometryKind::Point {
+            return json!({ "type": "Point", "coordinates": [round(longitude), round(latitude)] });
+        }
+
+        let vertices = self.vertices.count(config).max(3);
+        let step = TAU / vertices as f64;
+        let longitude_scale = latitude.to_radians().cos().max(0.01);
+        let mut ring: Vec<Value> = (0..vertices)
+            .map(|vertex| {
+                let angle = step * (vertex as f64 + config.rng.random_range(0.0..0.5));
+                let distance = config.rng.random_range(self.radius / 2.0..=self.radius) / KM_PER_DEGREE;
+                let x = (longitude + distance * angle.cos() / longitude_scale).clamp(-180.0, 180.0);
+                let y = (latitude + distance * angle.sin()).clamp(-90.0, 90.0);
+                json!([round(x), round(y)])
+            })
+            .collect();
+        ring.push(ring[0].clone());
+        json!({ "type": "Polygon", "coordinates": [ring] })
+    }
+}
+
+impl JsonGenerator for GeoJsonSpec {
+    /// Generates a `FeatureCollection` of `count` features with generated properties.
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        self.validate()?;
+        let count_items = self.count.count(config);
+
+        config.limits.reserve_records(&mut config.usage, count_items, local_config.as_deref())?;
+        config.limits.enter_level(&mut config.usage, local_config.as_deref())?;
+
+        let mut features = Vec::with_capacity(count_items as usize);
+        let mut local_config =
+            LocalConfig::from_current_with_config(None, Some(count_items), local_config);
+
+        for i in 0..count_items {
+            local_config.set_index(i as usize);
+
+            let properties = self.properties.generate(config, Some(&mut local_config))
+                .map_err(|error| error.within(&["geojson", "properties"]))?;
+            let geometry = self.geometry(config);
+            features.push(json!({ "type": "Feature", "geometry": geometry, "properties": properties }));
+        }
+
+        config.limits.exit_level(&mut config.usage);
+
+        Ok(json!({ "type": "FeatureCollection", "features": features }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geojson_features_stay_in_bbox() {
+        let mut config = GeneratorConfig::new("EN", Some(5));
+        let points: GeoJsonSpec = serde_json::from_value(json!({
+            "count": 30,
+            "bbox": [2.2, 48.8, 2.4, 48.9],
+            "properties": { "id": "store-${index}" }
+        })).unwrap();
+
+        let collection = points.generate(&mut config, None).unwrap();
+        assert_eq!(collection["type"], "FeatureCollection");
+        let features = collection["features"].as_array().unwrap();
+        assert_eq!(features.len(), 30);
+        assert_eq!(features[0]["properties"]["id"], "store-1");
+        for feature in features {
+            assert_eq!(feature["geometry"]["type"], "Point");
+            let coordinates = feature["geometry"]["coordinates"].as_array().unwrap();
+            assert!((2.2..=2.4).contains(&coordinates[0].as_f64().unwrap()));
+            assert!((48.8..=48.9).contains(&coordinates[1].as_f64().unwrap()));
+        }
+
+        let polygons: GeoJsonSpec = serde_json::from_value(json!({ "count": 10, "geometry": "polygon", "vertices": 5 })).unwrap();
+        for feature in polygons.generate(&mut config, None).unwrap()["features"].as_array().unwrap() {
+            let ring = feature["geometry"]["coordinates"][0].as_array().unwrap();
+            assert_eq!(ring.len(), 6);
+            assert_eq!(ring.first(), ring.last());
+        }
+
+        let invalid: GeoJsonSpec = serde_json::from_value(json!({ "bbox": [10, 0, -10, 5] })).unwrap();
+        assert!(invalid.generate(&mut config, None).unwrap_err().message.contains("invalid longitudes"));
+    }
+}
@@ -0,0 +1,150 @@
+//! # Id From Specification Module
+//!
+//! Stable ids derived from the content of a record, so regenerated datasets keep the
+//! same ids for logically identical records even when the seed or the record order
+//! changes.
+//!
+//! An `idFrom` field hashes sibling fields, declared before it in the same object:
+//!
+//! ```json
+//! {
+//!   "root": {
+//!     "count": 5,
+//!     "fields": {
+//!       "email": "${internet.safeEmail}",
+//!       "id": { "idFrom": ["email"], "algo": "uuid" }
+//!     }
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use xxhash_rust::xxh3::{xxh3_128, xxh3_64};
+
+use crate::{type_spec::JsonGenerator, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Hash algorithm of an `idFrom` field, and the shape of the generated id.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HashAlgo {
+    /// 64 bits XXH3 hash, as 16 hex digits.
+    #[default]
+    Xxhash,
+
+    /// SHA-256 hash, as 64 hex digits.
+    Sha256,
+
+    /// 128 bits XXH3 hash, as a version 8 UUID.
+    Uuid,
+}
+
+impl HashAlgo {
+    /// Whether this is the default, `xxhash` algorithm.
+    pub fn is_xxhash(&self) -> bool {
+        *self == HashAlgo::Xxhash
+    }
+
+    fn hash(&self, content: &[u8]) -> String {
+        match self {
+            HashAlgo::Xxhash => format!("{:016x}", xxh3_64(content)),
+            HashAlgo::Sha256 => Sha256::digest(content).iter().map(|byte| format!("{:02x}", byte)).collect(),
+            HashAlgo::Uuid => uuid::Builder::from_custom_bytes(xxh3_128(content).to_be_bytes()).into_uuid().to_string(),
+        }
+    }
+}
+
+/// Specification of an id hashed from sibling fields.
+///
+/// The hash covers the JSON values of the `idFrom` fields, in the listed order, so
+/// records with the same values get the same id.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IdFromSpec {
+    /// Names of the sibling fields to hash; they must be declared before the id field.
+    #[serde(rename = "idFrom")]
+    pub id_from: Vec<String>,
+
+    /// Hash algorithm, `xxhash` by default.
+    #[serde(default, skip_serializing_if = "HashAlgo::is_xxhash")]
+    pub algo: HashAlgo,
+}
+
+impl IdFromSpec {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to derive the id from {}: {}", self.id_from.join(", "), details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+}
+
+impl JsonGenerator for IdFromSpec {
+    /// Hashes the serialized sibling values and returns the id as a string.
+    fn generate(&self, _config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let Some(local_config) = local_config else {
+            return Err(self.error("idFrom fields must be fields of an object"));
+        };
+        if self.id_from.is_empty() {
+            return Err(self.error("idFrom must list at least one field"));
+        }
+
+        let mut values = Vec::with_capacity(self.id_from.len());
+        for name in &self.id_from {
+            match local_config.siblings.get(name) {
+                Some(value) => values.push(value),
+                None => return Err(self.error(&format!("the field {} must be declared before the id field", name))),
+            }
+        }
+
+        let content = serde_json::to_vec(&values).map_err(|e| self.error(&e.to_string()))?;
+        Ok(Value::String(self.algo.hash(&content)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    #[test]
+    fn test_id_from_is_stable_across_seeds() {
+        let schema = |seed: u64| Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": seed,
+            "root": {
+                "count": 3,
+                "fields": {
+                    "email": "user${index}@example.com",
+                    "name": "${name.firstName}",
+                    "id": { "idFrom": ["email"] },
+                    "key": { "idFrom": ["email"], "algo": "sha256" },
+                    "uuid": { "idFrom": ["email"], "algo": "uuid" }
+                }
+            }
+        }));
+
+        let first = schema(1).generate().unwrap();
+        let second = schema(2).generate().unwrap();
+        for (a, b) in first.as_array().unwrap().iter().zip(second.as_array().unwrap()) {
+            assert_eq!(a["id"], b["id"]);
+            assert_eq!(a["key"], b["key"]);
+            assert_eq!(a["uuid"], b["uuid"]);
+        }
+        assert_ne!(first[0]["id"], first[1]["id"]);
+        assert_eq!(first[0]["id"].as_str().unwrap().len(), 16);
+        assert_eq!(first[0]["key"].as_str().unwrap().len(), 64);
+        assert_eq!(uuid::Uuid::parse_str(first[0]["uuid"].as_str().unwrap()).unwrap().get_version_num(), 8);
+
+        let missing = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "id": { "idFrom": ["email"] }, "email": "a@b.c" } }
+        }));
+        assert!(missing.generate().unwrap_err().message.contains("must be declared before"));
+    }
+}
@@ -86,12 +86,14 @@
 //! // Config now contains locale "FR" and seed 42
 //! ```
 
-use std::{fs, path::PathBuf, sync::{LazyLock, Mutex}};
+use std::{fs, io::Write, path::{Path, PathBuf}, sync::{Arc, LazyLock, Mutex}};
 
+use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
-use serde::Deserialize;
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
-use crate::{type_spec::{Entity, GeneratorConfig, JsonGenerator}, CustomKeyFunction, JgdGeneratorError, JgdGlobalConfig};
+use crate::{fake::FakeKeys, type_spec::{data_pack, dependency, entity::{check_counts_in_array, check_counts_in_field, include_in_field, Mixins}, strict, ArraySpec, Check, CheckReport, Count, Entity, Field, ExplainPlan, GeneratorConfig, JsonGenerator, Root, StatsReport, TagReport}, Checkpoint, CheckpointWriter, CustomKeyFunction, format_warnings, FormatVersion, FuzzSpec, GenerationLimits, JgdErrorKind, JgdGeneratorError, JgdGlobalConfig, KeyCase, LocalConfig, LocaleFallback, Realism, RunMetadata};
 
 /// Default locale for data generation when no locale is specified.
 fn default_locale() -> String {
@@ -152,15 +154,17 @@ fn default_locale() -> String {
 /// }"#;
 /// let jgd = Jgd::from(schema);
 /// ```
-#[derive(Debug, Deserialize)]
+// Derived as a remote of itself so the `Deserialize` impl below can expand the mixins
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Jgd {
-    /// Schema format identifier (e.g., "jgd/v1").
+    /// Schema format identifier (`jgd/v1` or `jgd/v2`).
     ///
-    /// This field identifies the JGD format version and is used for schema validation
-    /// and compatibility checking. The `#[serde(rename = "$format")]` attribute maps
-    /// it to the JSON `$format` field.
+    /// This field identifies the JGD format version (see [`FormatVersion`]). Strict
+    /// parsing rejects unknown and newer formats, and [`Jgd::deprecations`] reports
+    /// them along with `jgd/v2` properties used by `jgd/v1` schemas. The
+    /// `#[serde(rename = "$format")]` attribute maps it to the JSON `$format` field.
     #[serde(rename = "$format")]
-    #[allow(dead_code)]
     pub format: String,
 
     /// User-defined schema version string.
@@ -174,37 +178,315 @@ pub struct Jgd {
     ///
     /// When provided, this seed ensures reproducible data generation across multiple
     /// executions. When `None`, generation uses non-deterministic randomness.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub seed: Option<u64>,
 
     /// Default locale for fake data generation.
     ///
     /// Specifies the locale code (e.g., "EN", "FR", "DE") used for generating
     /// locale-specific fake data. Defaults to "EN" when not specified.
-    #[serde(default = "default_locale", rename = "defaultLocale")]
+    #[serde(default = "default_locale", rename = "defaultLocale", alias = "default_locale")]
     pub default_locale: String,
 
+    /// Fixed "now" of the relative dates, in RFC 3339 (e.g. `2025-01-01T00:00:00Z`).
+    ///
+    /// Replaces the current time in the defaults of `chrono.dateTimeBefore`,
+    /// `chrono.dateTimeBetween` and the other relative dates, so a seeded schema gives
+    /// the same data whenever it runs.
+    #[serde(default, rename = "referenceDate", skip_serializing_if = "Option::is_none")]
+    pub reference_date: Option<DateTime<Utc>>,
+
+    /// Replaces the real-looking top-level domains of the email keys with `.test`.
+    ///
+    /// Fixtures that leak into a staging environment then never send mail to real
+    /// mailboxes; reserved domains such as `example.com` are kept.
+    #[serde(default, rename = "safeEmails", skip_serializing_if = "std::ops::Not::not")]
+    pub safe_emails: bool,
+
+    /// What to do with keys that have no data in `default_locale`.
+    ///
+    /// `warn` (the default) generates English data for them, and [`Jgd::explain`] lists
+    /// them; `error` fails the generation instead.
+    #[serde(default, rename = "localeFallback", skip_serializing_if = "LocaleFallback::is_warn")]
+    pub locale_fallback: LocaleFallback,
+
     /// Named entity definitions for entities mode (mutually exclusive with `root`).
     ///
     /// When present, the schema operates in entities mode where multiple named
     /// entities are generated. Each key represents an entity name, and the value
     /// contains the entity definition with its fields and generation rules.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub entities: Option<IndexMap<String, Entity>>,
 
-    /// Root entity definition for root mode (mutually exclusive with `entities`).
+    /// Root definition for root mode (mutually exclusive with `entities`).
+    ///
+    /// When present, the schema operates in root mode where a single structure is
+    /// generated: an entity, with its fields and generation rules applied to the
+    /// root level, or an `array` of any field for a top-level array of plain values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<Root>,
+
+    /// Guards against runaway generation.
+    ///
+    /// Limits the total number of generated records, the nesting depth and the
+    /// approximate output size. Exceeding any of them stops generation with a
+    /// descriptive `JgdGeneratorError`. All limits are unset by default.
+    #[serde(default, skip_serializing_if = "GenerationLimits::is_unset")]
+    pub limits: GenerationLimits,
+
+    /// Global perturbations for fuzz-style robustness testing.
+    ///
+    /// Randomly drops optional fields and replaces fields by boundary values such as
+    /// empty strings or `i64::MAX`. Disabled by default.
+    #[serde(default, skip_serializing_if = "FuzzSpec::is_unset")]
+    pub fuzz: FuzzSpec,
+
+    /// How clean the generated data is: `strict`, `messy` or `adversarial`.
+    ///
+    /// Adjusts the `optional` rates, string lengths and numeric outliers of the whole
+    /// schema. Unset by default, which generates the data as declared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub realism: Option<Realism>,
+
+    /// Naming convention for the keys of generated objects.
     ///
-    /// When present, the schema operates in root mode where a single entity
-    /// structure is generated. The entity definition contains fields and
-    /// generation rules applied to the root level.
-    #[serde(default)]
-    pub root: Option<Entity>,
+    /// One of `camelCase`, `snake_case`, `kebab-case` or `PascalCase`. Only the output
+    /// is affected: `ref` paths keep using the field names declared in the schema.
+    #[serde(default, rename = "keyCase", alias = "key_case", skip_serializing_if = "Option::is_none")]
+    pub key_case: Option<KeyCase>,
+
+    /// Static values injected verbatim into every generated record.
+    ///
+    /// Applies to the items of each entity in entities mode and to the root items in
+    /// root mode. Generated fields with the same name take precedence. An entity can
+    /// replace these with its own `constants` (use `{}` to opt out).
+    ///
+    /// ```json
+    /// { "constants": { "schemaVersion": 3, "source": "jgd" } }
+    /// ```
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub constants: serde_json::Map<String, Value>,
+
+    /// Named field groups that entities add to their fields with `include`.
+    ///
+    /// The includes are expanded when the schema is loaded, so the entity `fields`
+    /// already hold the included fields afterwards.
+    ///
+    /// ```json
+    /// {
+    ///   "mixins": { "auditFields": { "createdAt": "${chrono.dateTime}", "createdBy": "${name.name}" } },
+    ///   "entities": { "users": { "include": ["auditFields"], "fields": { ... } } }
+    /// }
+    /// ```
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub mixins: IndexMap<String, IndexMap<String, Field>>,
+
+    /// Invariants of the generated document verified by [`Jgd::check`], besides the
+    /// ones implied by `ref` fields and `uniqueBy`.
+    ///
+    /// ```json
+    /// { "checks": [{ "unique": "users.email" }, { "in": "orders", "sum": "lines.amount", "equals": "total" }] }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<Check>,
+}
+
+impl<'de> Deserialize<'de> for Jgd {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut jgd = Jgd::deserialize(deserializer)?;
+        jgd.include_mixins().map_err(serde::de::Error::custom)?;
+        jgd.check_counts().map_err(serde::de::Error::custom)?;
+        Ok(jgd)
+    }
+}
+
+impl Serialize for Jgd {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Jgd::serialize(self, serializer)
+    }
+}
+
+/// Converts an output error into a `JgdGeneratorError`.
+fn write_error(error: impl std::fmt::Display) -> JgdGeneratorError {
+    JgdGeneratorError {
+        message: format!("Error to write the output. Details: {}", error),
+        kind: JgdErrorKind::Output,
+        ..Default::default()
+    }
+}
+
+/// Writes raw JSON punctuation (`[`, `,`, ...) to the output.
+fn write_raw<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<(), JgdGeneratorError> {
+    writer.write_all(bytes).map_err(write_error)
+}
+
+/// Deserializes a generated value into `T`, reporting failures as `JgdGeneratorError`.
+fn deserialize_generated<T: DeserializeOwned>(value: Value) -> Result<T, JgdGeneratorError> {
+    serde_json::from_value(value).map_err(|error| JgdGeneratorError {
+        message: format!("Error to deserialize the generated value. Details: {}", error),
+        kind: JgdErrorKind::Serialization,
+        ..Default::default()
+    })
+}
+
+/// Serializes a value straight to the output.
+fn write_value<W: Write>(writer: &mut W, value: &Value) -> Result<(), JgdGeneratorError> {
+    serde_json::to_writer(writer, value).map_err(write_error)
+}
+
+/// Serializes a generated item, converting its keys when a key case is configured.
+fn write_item<W: Write>(writer: &mut W, value: &Value, key_case: Option<KeyCase>) -> Result<(), JgdGeneratorError> {
+    match key_case {
+        Some(key_case) => write_value(writer, &key_case.apply(value.clone())),
+        None => write_value(writer, value),
+    }
+}
+
+/// Streams an entity to the output, item by item.
+///
+/// Items are kept and returned only when `keep_items` is set, so they can be stored
+/// for cross-references; otherwise each item is dropped as soon as it is written.
+/// Each written item is reported to `writer` under `name` for checkpoints.
+fn write_entity<W: Write>(
+    name: &str,
+    entity: &Entity,
+    config: &mut GeneratorConfig,
+    local_config: Option<&mut LocalConfig>,
+    writer: &mut CheckpointWriter<W>,
+    keep_items: bool,
+) -> Result<Value, JgdGeneratorError> {
+    if entity.is_single() {
+        let value = entity.generate(config, local_config)?;
+        write_item(writer, &value, config.key_case)?;
+        writer.item_written(name, 1)?;
+        return Ok(value);
+    }
+
+    let mut items = Vec::new();
+    let mut written = 0;
+
+    write_raw(writer, b"[")?;
+    entity.generate_each(config, local_config, |config, item| {
+        if written > 0 {
+            write_raw(writer, b",")?;
+        }
+        write_item(writer, &item, config.key_case)?;
+        written += 1;
+        writer.item_written(name, written)?;
+        if keep_items {
+            items.push(item);
+        }
+        Ok(())
+    })?;
+    write_raw(writer, b"]")?;
+
+    Ok(Value::Array(items))
+}
+
+/// Converts the keys of a generated record when a key case is configured.
+fn record_with_key_case(value: Value, key_case: Option<KeyCase>) -> Value {
+    match key_case {
+        Some(key_case) => key_case.apply(value),
+        None => value,
+    }
+}
+
+/// Hands the items of an entity to `on_record` under `name`, one by one.
+///
+/// Like [`write_entity`], the items are kept and returned only when `keep_items` is set.
+fn each_entity_record<F>(
+    name: &str,
+    entity: &Entity,
+    config: &mut GeneratorConfig,
+    local_config: Option<&mut LocalConfig>,
+    keep_items: bool,
+    on_record: &mut F,
+) -> Result<Value, JgdGeneratorError>
+where
+    F: FnMut(&str, Value) -> Result<(), JgdGeneratorError>,
+{
+    if entity.is_single() {
+        let value = entity.generate(config, local_config)?;
+        on_record(name, record_with_key_case(value.clone(), config.key_case))?;
+        return Ok(value);
+    }
+
+    let mut items = Vec::new();
+    entity.generate_each(config, local_config, |config, item| {
+        if keep_items {
+            items.push(item.clone());
+        }
+        on_record(name, record_with_key_case(item, config.key_case))
+    })?;
+
+    Ok(Value::Array(items))
 }
 
 static GLOBAL_CONFIG: LazyLock<Mutex<JgdGlobalConfig>> = LazyLock::new(|| Mutex::new(JgdGlobalConfig::new()));
 
 impl Jgd {
 
+    /// Creates a root mode schema that generates `root`.
+    ///
+    /// The schema uses the `jgd/v1` format, version `1.0`, the default locale and no seed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::{Entity, Jgd};
+    /// let jgd = Jgd::from_root(Entity::default());
+    /// assert_eq!(jgd.generate().unwrap(), serde_json::json!({}));
+    /// ```
+    pub fn from_root(root: Entity) -> Self {
+        Self {
+            format: "jgd/v1".to_string(),
+            version: "1.0".to_string(),
+            seed: None,
+            default_locale: default_locale(),
+            reference_date: None,
+            safe_emails: false,
+            locale_fallback: LocaleFallback::default(),
+            entities: None,
+            root: Some(root.into()),
+            limits: GenerationLimits::default(),
+            fuzz: FuzzSpec::default(),
+            realism: None,
+            key_case: None,
+            constants: serde_json::Map::new(),
+            mixins: Mixins::new(),
+            checks: Vec::new(),
+        }
+    }
+
+    /// Expands the `include` of every entity with the schema `mixins`.
+    fn include_mixins(&mut self) -> Result<(), String> {
+        let mut active = Vec::new();
+        match &mut self.root {
+            Some(Root::Entity(root)) => root.include_mixins(&self.mixins, "root", &mut active)?,
+            Some(Root::Array { array }) => include_in_field(&mut array.of, &self.mixins, "root", &mut active)?,
+            Some(Root::Value(field)) => include_in_field(field, &self.mixins, "root", &mut active)?,
+            None => {},
+        }
+        for (name, entity) in self.entities.iter_mut().flatten() {
+            entity.include_mixins(&self.mixins, name, &mut active)?;
+        }
+        Ok(())
+    }
+
+    /// Checks that no range count of the schema is empty, e.g. `[5, 2]`.
+    fn check_counts(&self) -> Result<(), String> {
+        match &self.root {
+            Some(Root::Entity(root)) => root.check_counts("root")?,
+            Some(Root::Array { array }) => check_counts_in_array(array, "root")?,
+            Some(Root::Value(field)) => check_counts_in_field(field, "root")?,
+            None => {},
+        }
+        for (name, entity) in self.entities.iter().flatten() {
+            entity.check_counts(name)?;
+        }
+        Ok(())
+    }
+
     /// Loads a JGD schema from a file path.
     ///
     /// Reads the specified file and parses its JSON content into a `Jgd` struct.
@@ -242,6 +524,120 @@ impl Jgd {
         Self::from(jgd_string.unwrap())
     }
 
+    /// Parses a JGD schema, rejecting unknown properties.
+    ///
+    /// Unlike [`Jgd::from`], which ignores properties it does not know, strict parsing
+    /// reports typos such as `"feilds"` with the JSON Pointer of every unknown property.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let error = Jgd::from_str_strict(r#"{"$format": "jgd/v1", "version": "1.0", "root": {"feilds": {}}}"#)
+    ///     .unwrap_err();
+    /// assert_eq!(error.path.as_deref(), Some("/root/feilds"));
+    /// ```
+    pub fn from_str_strict(value: &str) -> Result<Self, JgdGeneratorError> {
+        let document = serde_json::from_str(value).map_err(|error| JgdGeneratorError {
+            message: format!("Error to parse the schema. Details: {}", error),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        })?;
+
+        Self::from_value_strict(document)
+    }
+
+    /// Parses a JGD schema from a JSON value, rejecting unknown properties.
+    ///
+    /// See [`Jgd::from_str_strict`].
+    pub fn from_value_strict(value: Value) -> Result<Self, JgdGeneratorError> {
+        strict::parse(value)
+    }
+
+    /// Lists the deprecated property spellings and format problems of a schema document.
+    ///
+    /// Multi-word properties are camelCase; snake_case spellings such as `unique_by`
+    /// are still accepted as aliases, and reported here so they can be migrated. An
+    /// unsupported `$format`, or a `jgd/v1` schema using `jgd/v2` properties such as
+    /// `realism`, is reported too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let document = serde_json::json!({
+    ///     "$format": "jgd/v1", "version": "1.0", "root": { "unique_by": ["id"], "fields": {} }
+    /// });
+    /// assert_eq!(Jgd::deprecations(&document), vec!["/root/unique_by is deprecated, use uniqueBy"]);
+    ///
+    /// let document = serde_json::json!({ "$format": "jgd/v1", "version": "1.0", "fuzz": {}, "root": { "fields": {} } });
+    /// assert_eq!(Jgd::deprecations(&document), vec!["/fuzz is a jgd/v2 property, set $format to jgd/v2"]);
+    /// ```
+    pub fn deprecations(document: &Value) -> Vec<String> {
+        let mut warnings = strict::deprecated_fields(document);
+        warnings.extend(format_warnings(document));
+        warnings
+    }
+
+    /// Returns the [`FormatVersion`] of the schema `$format`, or the error of an
+    /// unknown or newer format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::{FormatVersion, Jgd};
+    /// let jgd = Jgd::from(r#"{"$format": "jgd/v2", "version": "1.0", "root": {"fields": {}}}"#);
+    /// assert_eq!(jgd.format_version(), Ok(FormatVersion::V2));
+    /// ```
+    pub fn format_version(&self) -> Result<FormatVersion, String> {
+        self.format.parse()
+    }
+
+    /// Loads a JGD schema from a file path, rejecting unknown properties.
+    ///
+    /// See [`Jgd::from_str_strict`].
+    pub fn from_file_strict(path: &PathBuf) -> Result<Self, JgdGeneratorError> {
+        let content = fs::read_to_string(path).map_err(|error| JgdGeneratorError {
+            message: format!("Error to read the schema {}. Details: {}", path.display(), error),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        })?;
+
+        Self::from_str_strict(&content)
+    }
+
+    /// Writes the schema to a file as pretty-printed JSON.
+    ///
+    /// The written file loads back with [`Jgd::from_file`], so schemas built in code or
+    /// modified after parsing can be saved as `.jgd` files. Template strings are written
+    /// as declared, and unset optional properties are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::{Count, Jgd, Root};
+    /// let mut jgd = Jgd::from(r#"{"$format": "jgd/v1", "version": "1.0", "root": {"fields": {"id": "${ulid}"}}}"#);
+    /// jgd.root.as_mut().and_then(Root::entity_mut).unwrap().count = Some(Count::Fixed(10));
+    ///
+    /// let path = std::env::temp_dir().join(format!("saved_schema_{}.jgd", std::process::id()));
+    /// jgd.to_file(&path).unwrap();
+    /// assert_eq!(Jgd::from_file(&path).generate().unwrap().as_array().unwrap().len(), 10);
+    /// # std::fs::remove_file(&path).ok();
+    /// ```
+    pub fn to_file(&self, path: &PathBuf) -> Result<(), JgdGeneratorError> {
+        let content = serde_json::to_string_pretty(self).map_err(|error| JgdGeneratorError {
+            message: format!("Error to serialize the schema. Details: {}", error),
+            kind: JgdErrorKind::Serialization,
+            ..Default::default()
+        })?;
+
+        fs::write(path, content).map_err(|error| JgdGeneratorError {
+            message: format!("Error to write the schema {}. Details: {}", path.display(), error),
+            kind: JgdErrorKind::Output,
+            ..Default::default()
+        })
+    }
+
     /// Creates a generation configuration from this JGD schema.
     ///
     /// Builds a `GeneratorConfig` instance using the schema's locale and seed settings.
@@ -269,7 +665,20 @@ impl Jgd {
     /// // Config now uses French locale and seed 42
     /// ```
     pub fn create_config(&self) -> GeneratorConfig {
-        GeneratorConfig::new(&self.default_locale, self.seed)
+        let mut config = GeneratorConfig::new(&self.default_locale, self.seed);
+        config.limits = self.limits.clone();
+        config.fuzz = self.fuzz.clone();
+        config.realism = self.realism;
+        config.key_case = self.key_case;
+        config.constants = self.constants.clone();
+        config.locale_fallback = self.locale_fallback;
+        if self.safe_emails {
+            config = config.with_safe_emails(true);
+        }
+        match self.reference_date {
+            Some(reference_date) => config.with_reference_date(reference_date),
+            None => config,
+        }
     }
 
     /// Generates JSON data according to the schema definition.
@@ -328,19 +737,489 @@ impl Jgd {
     /// // Returns: {"users": {"name": "Alice"}, "posts": {"title": "Post"}}
     /// ```
     pub fn generate(&self) -> Result<Value, JgdGeneratorError> {
-        let mut config = self.create_config();
+        self.generate_with(self.create_config())
+    }
 
+    /// Generates the data with a configuration built by [`Jgd::create_config`] and then
+    /// customized, e.g. with another [`ValueProvider`](crate::ValueProvider).
+    pub fn generate_with(&self, mut config: GeneratorConfig) -> Result<Value, JgdGeneratorError> {
         if let Some(root) = &self.root {
-            return root.generate(&mut config, None);
+            let value = root.generate(&mut config, None).map_err(|error| error.within(&["root"]))?;
+            return Ok(self.apply_key_case(value, false));
         }
 
         if let Some(entities) = &self.entities {
-            return entities.generate(&mut config, None);
+            let value = entities.generate(&mut config, None)?;
+            return Ok(self.apply_key_case(value, true));
         }
 
         Ok(Value::Null)
     }
 
+    /// Generates the data along with the [`RunMetadata`] needed to reproduce it.
+    ///
+    /// The metadata records the seed of the run, drawn at random when the schema has
+    /// no `seed`. Embed it in the document with [`RunMetadata::embed`] or store it aside.
+    pub fn generate_with_metadata(&self) -> Result<(Value, RunMetadata), JgdGeneratorError> {
+        let config = self.create_config();
+        let metadata = RunMetadata::new(self, config.seed);
+        Ok((self.generate_with(config)?, metadata))
+    }
+
+    /// Generates the data along with the [`StatsReport`] of its fields.
+    pub fn generate_with_stats(&self) -> Result<(Value, StatsReport), JgdGeneratorError> {
+        let generated = self.generate()?;
+        let stats = StatsReport::new(&generated);
+        Ok((generated, stats))
+    }
+
+    /// Generates the data along with the [`CheckReport`] of its consistency checks.
+    pub fn generate_with_checks(&self) -> Result<(Value, CheckReport), JgdGeneratorError> {
+        let generated = self.generate()?;
+        let report = self.check(&generated);
+        Ok((generated, report))
+    }
+
+    /// Generates the data and deserializes it into `T`.
+    ///
+    /// Generation and deserialization errors are both returned as `JgdGeneratorError`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// #[derive(serde::Deserialize)]
+    /// struct User {
+    ///     name: String,
+    ///     age: u8,
+    /// }
+    ///
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "fields": { "name": "${name.firstName}", "age": 30 } }
+    /// }"#);
+    /// let user: User = jgd.generate_as().unwrap();
+    /// assert_eq!(user.age, 30);
+    /// ```
+    pub fn generate_as<T: DeserializeOwned>(&self) -> Result<T, JgdGeneratorError> {
+        deserialize_generated(self.generate()?)
+    }
+
+    /// Generates `count` root items and deserializes each of them into `T`.
+    ///
+    /// The root `count` of the schema is replaced by `count`, and a value root is
+    /// generated `count` times. Only root mode schemas are supported; entities mode
+    /// returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// #[derive(serde::Deserialize)]
+    /// struct Tag {
+    ///     label: String,
+    /// }
+    ///
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "fields": { "label": "tag-${index}" } }
+    /// }"#);
+    /// let tags: Vec<Tag> = jgd.generate_many_as(3).unwrap();
+    /// assert_eq!(tags[2].label, "tag-3");
+    /// ```
+    pub fn generate_many_as<T: DeserializeOwned>(&self, count: u64) -> Result<Vec<T>, JgdGeneratorError> {
+        let Some(root) = &self.root else {
+            return Err(JgdGeneratorError {
+                message: "Error to generate many items: the schema has no root".to_string(),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            });
+        };
+
+        let mut root = root.clone();
+        match &mut root {
+            Root::Array { array } => {
+                array.count = Some(Count::Fixed(count));
+                array.target_size = None;
+            },
+            Root::Entity(entity) => entity.count = Some(Count::Fixed(count)),
+            Root::Value(field) => {
+                let of = Box::new(field.clone());
+                let array = ArraySpec { of, count: Some(Count::Fixed(count)), prob_null: None, prob_empty: None, target_size: None };
+                root = Root::Array { array };
+            },
+        }
+
+        let mut config = self.create_config();
+        let value = root.generate(&mut config, None).map_err(|error| error.within(&["root"]))?;
+        let value = self.apply_key_case(value, false);
+
+        deserialize_generated(value)
+    }
+
+    /// Converts the output keys to `key_case`; in entities mode the entity names are kept.
+    fn apply_key_case(&self, value: Value, keep_entity_names: bool) -> Value {
+        let Some(key_case) = self.key_case else {
+            return value;
+        };
+
+        match value {
+            Value::Object(entities) if keep_entity_names => Value::Object(entities.into_iter()
+                .map(|(name, value)| (name, key_case.apply(value)))
+                .collect()),
+            value => key_case.apply(value),
+        }
+    }
+
+    /// Generates the data and serializes it straight into `writer` as compact JSON.
+    ///
+    /// Produces the same output as [`Jgd::generate`], but each generated item is written
+    /// as soon as it is ready instead of building the whole `serde_json::Value` tree first.
+    /// In root mode nothing is retained; in entities mode the items of each entity are still
+    /// kept (once) so later entities can reference them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "count": 3, "fields": { "id": "${index}" } }
+    /// }"#);
+    /// let mut output = Vec::new();
+    /// jgd.generate_to_writer(&mut output).unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), r#"[{"id":1},{"id":2},{"id":3}]"#);
+    /// ```
+    pub fn generate_to_writer<W: Write>(&self, writer: &mut W) -> Result<(), JgdGeneratorError> {
+        self.stream(&mut self.create_config(), &mut CheckpointWriter::new(writer))
+    }
+
+    /// Streams the data like [`Jgd::generate_to_writer`], recording checkpoints so a
+    /// cancelled run can be resumed.
+    ///
+    /// `on_checkpoint` is called every `every` items of each entity, after the output
+    /// written so far has been flushed; persist the checkpoint there. To resume, pass
+    /// the last persisted checkpoint as `resume`: the generation is replayed up to it
+    /// without writing anything, then only the remaining output is written. Truncating
+    /// the cancelled output to `checkpoint.bytes` and appending the resumed output gives
+    /// the same document as an uninterrupted run.
+    ///
+    /// Schemas without `seed` draw a seed, recorded in the checkpoints and reused when
+    /// resuming. Resuming fails when the schema seed differs from the checkpoint seed
+    /// or when the replay does not reach the checkpoint (e.g. the schema was changed).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "count": 4, "fields": { "id": "${index}" } }
+    /// }"#);
+    ///
+    /// let mut checkpoints = Vec::new();
+    /// let mut output = Vec::new();
+    /// jgd.generate_to_writer_resumable(&mut output, None, 2, |checkpoint| {
+    ///     checkpoints.push(checkpoint.clone());
+    ///     Ok(())
+    /// }).unwrap();
+    ///
+    /// // Resume after the second item
+    /// let mut rest = Vec::new();
+    /// jgd.generate_to_writer_resumable(&mut rest, Some(&checkpoints[0]), 2, |_| Ok(())).unwrap();
+    /// assert_eq!(String::from_utf8(rest).unwrap(), r#",{"id":3},{"id":4}]"#);
+    /// ```
+    pub fn generate_to_writer_resumable<W, F>(&self, writer: &mut W, resume: Option<&Checkpoint>, every: u64,
+        mut on_checkpoint: F) -> Result<(), JgdGeneratorError>
+    where
+        W: Write,
+        F: FnMut(&Checkpoint) -> Result<(), JgdGeneratorError>,
+    {
+        if let Some(resume) = resume {
+            resume.check_seed(self.seed)?;
+        }
+
+        let seed = self.seed.or(resume.map(|resume| resume.seed)).unwrap_or_else(rand::random);
+        let mut config = self.create_config();
+        config.rng = StdRng::seed_from_u64(seed);
+        config.seed = seed;
+
+        let mut writer = CheckpointWriter::with_checkpoints(writer, seed, resume, every, &mut on_checkpoint);
+        self.stream(&mut config, &mut writer)
+    }
+
+    /// Generates the data record by record, handing each record to `on_record` with the
+    /// name of the entity it belongs to as soon as it is ready.
+    ///
+    /// The records are the items [`Jgd::generate_to_writer`] streams: the root items (or
+    /// the root value), named `root`, or the items of every entity in generation order.
+    /// An entity without `count` gives a single record. As when streaming, only the items
+    /// of the entities are kept, so later entities can reference them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "entities": {
+    ///     "users": { "count": 2, "fields": { "id": "${index}" } },
+    ///     "settings": { "fields": { "theme": "dark" } }
+    ///   }
+    /// }"#);
+    /// let mut records = Vec::new();
+    /// jgd.generate_each_record(|entity, record| {
+    ///     records.push(format!("{} {}", entity, record));
+    ///     Ok(())
+    /// }).unwrap();
+    /// assert_eq!(records, [r#"users {"id":1}"#, r#"users {"id":2}"#, r#"settings {"theme":"dark"}"#]);
+    /// ```
+    pub fn generate_each_record<F>(&self, mut on_record: F) -> Result<(), JgdGeneratorError>
+    where
+        F: FnMut(&str, Value) -> Result<(), JgdGeneratorError>,
+    {
+        let mut config = self.create_config();
+
+        if let Some(Root::Entity(root)) = &self.root {
+            each_entity_record("root", root, &mut config, None, false, &mut on_record)
+                .map_err(|error| error.within(&["root"]))?;
+        } else if let Some(root) = &self.root {
+            let value = root.generate(&mut config, None).map_err(|error| error.within(&["root"]))?;
+            match value {
+                Value::Array(items) => {
+                    for item in items {
+                        on_record("root", record_with_key_case(item, config.key_case))?;
+                    }
+                },
+                value => on_record("root", record_with_key_case(value, config.key_case))?,
+            }
+        } else if let Some(entities) = &self.entities {
+            let mut local_config = LocalConfig::from_current_with_config(None, None, None);
+
+            for (name, entity) in dependency::generation_order(entities)? {
+                let _span = trace_span!(debug_span, "entity", name = %name);
+                local_config.entity_name = Some(name.clone());
+                let generated = each_entity_record(name, entity, &mut config, Some(&mut local_config), true, &mut on_record)
+                    .map_err(|error| error.within(&["entities", name]))?;
+                config.gen_value.insert(name.clone(), generated);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the generated document to `writer`, item by item.
+    fn stream<W: Write>(&self, config: &mut GeneratorConfig, writer: &mut CheckpointWriter<W>
+        ) -> Result<(), JgdGeneratorError> {
+        if let Some(Root::Entity(root)) = &self.root {
+            write_entity("root", root, config, None, writer, false).map_err(|error| error.within(&["root"]))?;
+        } else if let Some(root) = &self.root {
+            let value = root.generate(config, None).map_err(|error| error.within(&["root"]))?;
+            write_item(writer, &value, config.key_case)?;
+            writer.item_written("root", 1)?;
+        } else if let Some(entities) = &self.entities {
+            let mut local_config = LocalConfig::from_current_with_config(None, None, None);
+
+            write_raw(writer, b"{")?;
+            for (position, (name, entity)) in dependency::generation_order(entities)?.into_iter().enumerate() {
+                if position > 0 {
+                    write_raw(writer, b",")?;
+                }
+                serde_json::to_writer(&mut *writer, name).map_err(write_error)?;
+                write_raw(writer, b":")?;
+
+                let _span = trace_span!(debug_span, "entity", name = %name);
+                local_config.entity_name = Some(name.clone());
+                let generated = write_entity(name, entity, config, Some(&mut local_config), writer, true)
+                    .map_err(|error| error.within(&["entities", name]))?;
+                config.gen_value.insert(name.clone(), generated);
+            }
+            write_raw(writer, b"}")?;
+        } else {
+            write_value(writer, &Value::Null)?;
+        }
+
+        writer.finish()?;
+        writer.flush().map_err(write_error)
+    }
+
+    /// Builds a human-readable generation plan without generating any data.
+    ///
+    /// The plan lists the entities in generation order with their counts, the resolved
+    /// locale, the dependency edges created by `ref` fields and the template keys used by
+    /// each field. Unknown keys and unresolvable refs are reported as warnings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "fields": { "name": "${name.firstName}" } }
+    /// }"#);
+    /// let plan = jgd.explain();
+    /// assert!(plan.warnings.is_empty());
+    /// ```
+    pub fn explain(&self) -> ExplainPlan {
+        ExplainPlan::new(self)
+    }
+
+    /// Lists the output paths of the fields classified with `pii` or `tags`, without
+    /// generating data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "count": 3, "fields": { "email": { "of": "${internet.safeEmail}", "pii": true } } }
+    /// }"#);
+    /// assert_eq!(jgd.tag_report().paths_with("pii"), vec!["$[*].email"]);
+    /// ```
+    pub fn tag_report(&self) -> TagReport {
+        TagReport::new(self)
+    }
+
+    /// Anonymizes an existing JSON document, using this schema as a mask.
+    ///
+    /// Every field declared in the schema that exists in `document` is replaced by a
+    /// generated value; unlisted fields and the document structure are preserved.
+    /// Arrays are masked item by item and nested entities descend into nested objects.
+    /// In entities mode, each entity masks the top-level key with the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// # use serde_json::json;
+    /// let mask = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "fields": { "email": "user${index}@example.com" } }
+    /// }"#);
+    /// let masked = mask.mask(json!([{ "id": 7, "email": "jane@corp.com" }])).unwrap();
+    /// assert_eq!(masked, json!([{ "id": 7, "email": "user1@example.com" }]));
+    /// ```
+    pub fn mask(&self, document: Value) -> Result<Value, JgdGeneratorError> {
+        super::mask::mask_document(self, document)
+    }
+
+    /// Keeps only the `names` entities, plus the entities they reference.
+    ///
+    /// Referenced entities are kept so `ref` fields still resolve. Only entities mode
+    /// schemas can be narrowed down; unknown names return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let mut jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "entities": {
+    ///     "users": { "fields": { "id": 1 } },
+    ///     "tags": { "fields": { "name": "tag" } }
+    ///   }
+    /// }"#);
+    /// jgd.select_entities(&["users".to_string()]).unwrap();
+    /// assert_eq!(jgd.generate().unwrap(), serde_json::json!({ "users": { "id": 1 } }));
+    /// ```
+    pub fn select_entities(&mut self, names: &[String]) -> Result<(), JgdGeneratorError> {
+        let Some(entities) = &mut self.entities else {
+            return Err(JgdGeneratorError {
+                message: "Error to select entities: the schema has no entities".to_string(),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            });
+        };
+
+        super::selection::select_entities(entities, names)
+    }
+
+    /// Removes an entity or a field from the schema before generation.
+    ///
+    /// In entities mode `path` is an entity name (`users`) or a dot path to one of its
+    /// fields (`users.address.city`); in root mode it is a field path of the root entity.
+    /// Nested paths look through arrays and optionals of nested entities.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// let mut jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "fields": { "id": 1, "secret": "s3cr3t" } }
+    /// }"#);
+    /// jgd.exclude("secret").unwrap();
+    /// assert_eq!(jgd.generate().unwrap(), serde_json::json!({ "id": 1 }));
+    /// ```
+    pub fn exclude(&mut self, path: &str) -> Result<(), JgdGeneratorError> {
+        super::selection::exclude(self, path)
+    }
+
+    /// Overrides a single schema property, given as a dot path.
+    ///
+    /// The path is resolved against the schema document (`seed`, `root.count`,
+    /// `limits.maxRecords`); in entities mode it may start with an entity name
+    /// (`users.count`, `users.fields.status`). Missing intermediate objects are created,
+    /// but a property the schema does not know, such as `users.name` or a misspelled
+    /// `users.cuont`, is an error; new names are only accepted in maps like `fields`.
+    /// The patched schema is validated again and left unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// # use serde_json::json;
+    /// let mut jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "entities": { "users": { "count": 100, "fields": { "status": "pending" } } }
+    /// }"#);
+    /// jgd.set("users.count", json!(1)).unwrap();
+    /// jgd.set("users.fields.status", json!("active")).unwrap();
+    /// assert_eq!(jgd.generate().unwrap(), json!({ "users": [{ "status": "active" }] }));
+    /// ```
+    pub fn set(&mut self, path: &str, value: Value) -> Result<(), JgdGeneratorError> {
+        *self = super::overrides::set(self, path, value)?;
+        Ok(())
+    }
+
+    /// Merges an overlay document over the schema.
+    ///
+    /// The overlay is a partial schema (it needs neither `$format` nor `version`) merged
+    /// with JSON Merge Patch semantics: objects are merged recursively, so an overlay can
+    /// add entities or override counts and single fields; other values are replaced, and
+    /// `null` removes a property. The merged schema is validated again and left unchanged
+    /// on error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// # use serde_json::json;
+    /// let mut jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "root": { "count": 1000, "fields": { "env": "prod", "id": "${index}" } }
+    /// }"#);
+    /// jgd.merge(json!({ "root": { "count": 1, "fields": { "env": "dev" } } })).unwrap();
+    /// assert_eq!(jgd.generate().unwrap(), json!([{ "env": "dev", "id": 1 }]));
+    /// ```
+    pub fn merge(&mut self, overlay: Value) -> Result<(), JgdGeneratorError> {
+        *self = super::overrides::merge(self, overlay)?;
+        Ok(())
+    }
+
     /// Adds a custom key function to the global configuration.
     ///
     /// This method allows you to register custom faker patterns that can be used
@@ -384,6 +1263,74 @@ impl Jgd {
         }
         None
     }
+
+    /// Registers a data pack: user values for a fake key in a locale, which the key
+    /// picks from instead of the built-in data.
+    ///
+    /// Packs are global, like custom keys. The locale does not have to be a supported
+    /// one: a schema with `"defaultLocale": "EN_AU"` generates English data except for
+    /// the keys with an `EN_AU` pack. Registering a pack again replaces it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use jgd_rs::Jgd;
+    /// # use serde_json::json;
+    /// Jgd::add_data_pack("EN_AU", "address.cityName", vec![json!("Sydney"), json!("Perth")]).unwrap();
+    ///
+    /// let jgd = Jgd::from(r#"{
+    ///   "$format": "jgd/v1",
+    ///   "version": "1.0",
+    ///   "defaultLocale": "EN_AU",
+    ///   "root": { "fields": { "city": "${address.cityName}" } }
+    /// }"#);
+    /// let city = jgd.generate().unwrap()["city"].clone();
+    /// assert!(city == "Sydney" || city == "Perth");
+    ///
+    /// assert!(Jgd::add_data_pack("EN_AU", "address.unknown", vec![json!("x")]).is_err());
+    /// ```
+    pub fn add_data_pack(locale: &str, key: &str, values: Vec<Value>) -> Result<(), JgdGeneratorError> {
+        let error = |details: &str| JgdGeneratorError {
+            message: format!("Error to add the data pack {} {}. Details: {}", locale, key, details),
+            kind: JgdErrorKind::Schema,
+            key: Some(key.into()),
+            ..Default::default()
+        };
+
+        if !FakeKeys::new().contains_key(key) {
+            return Err(error("not a built-in fake key"));
+        }
+        if values.is_empty() {
+            return Err(error("the pack has no values"));
+        }
+
+        if let Ok(mut config) = GLOBAL_CONFIG.lock() {
+            config.data_packs.insert((locale.to_string(), key.to_string()), Arc::new(values));
+        }
+        Ok(())
+    }
+
+    /// Registers a data pack read from a file: a JSON array of values, or a CSV file
+    /// without header taking one value per row from its first column.
+    ///
+    /// Returns the number of values loaded.
+    pub fn load_data_pack(locale: &str, key: &str, path: &Path) -> Result<usize, JgdGeneratorError> {
+        let values = data_pack::read(path)?;
+        let count = values.len();
+        Self::add_data_pack(locale, key, values)?;
+        Ok(count)
+    }
+
+    /// Removes the data pack of `key` in `locale`, restoring the built-in data.
+    pub fn remove_data_pack(locale: &str, key: &str) {
+        if let Ok(mut config) = GLOBAL_CONFIG.lock() {
+            config.data_packs.remove(&(locale.to_string(), key.to_string()));
+        }
+    }
+
+    pub fn get_data_pack(locale: &str, key: &str) -> Option<Arc<Vec<Value>>> {
+        GLOBAL_CONFIG.lock().ok()?.data_packs.get(&(locale.to_string(), key.to_string())).cloned()
+    }
 }
 
 /// Implements conversion from string slice to `Jgd`.
@@ -978,9 +1925,380 @@ mod tests {
         Jgd::add_custom_key(key, Arc::new(|_| Ok(Value::String("worked".to_string()))));
 
         if let Some(func) = Jgd::get_custom_key(key) {
-            if let Ok(Value::String(value)) = func(Arguments::None) {
+            if let Ok(Value::String(value)) = func(Arguments::default()) {
                 assert_eq!("worked", value)
             }
         }
     }
+
+    #[test]
+    fn test_data_pack_replaces_built_in_data() {
+        // A locale of its own, so other tests never see the pack
+        let jgd = Jgd::from(serde_json::json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "defaultLocale": "PT_PT",
+            "localeFallback": "error",
+            "root": { "count": 20, "fields": { "title": "${name.title}" } }
+        }));
+        assert!(jgd.generate().is_err());
+
+        Jgd::add_data_pack("PT_PT", "name.title", vec![Value::from("Dr."), Value::from("Eng.")]).unwrap();
+        assert!(!jgd.explain().warnings.iter().any(|warning| warning.contains("name.title")));
+        let titles = jgd.generate().unwrap();
+        assert!(titles.as_array().unwrap().iter().all(|item| item["title"] == "Dr." || item["title"] == "Eng."));
+
+        Jgd::remove_data_pack("PT_PT", "name.title");
+        assert!(jgd.generate().is_err());
+        assert!(Jgd::add_data_pack("PT_PT", "name.title", vec![]).is_err());
+    }
+
+    #[test]
+    fn test_generate_to_writer_matches_generate() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 7,
+            "entities": {
+                "users": { "count": 3, "fields": { "name": "${name.firstName}", "age": { "number": { "min": 18, "max": 65, "integer": true } } } },
+                "owner": { "fields": { "user": { "ref": "users.name" }, "position": "${index}" } }
+            }
+        });
+
+        let expected = Jgd::from(schema.clone()).generate().unwrap();
+
+        let mut output = Vec::new();
+        Jgd::from(schema).generate_to_writer(&mut output).unwrap();
+        let streamed: Value = serde_json::from_slice(&output).unwrap();
+
+        assert_eq!(streamed["users"], expected["users"]);
+        assert!(streamed["owner"]["user"].is_string());
+        assert_eq!(streamed["owner"]["position"], json!(1));
+    }
+
+    #[test]
+    fn test_generate_each_record_matches_generate() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 7,
+            "keyCase": "snake_case",
+            "entities": {
+                "users": { "count": 3, "fields": { "firstName": "${name.firstName}" } },
+                "owner": { "fields": { "user": { "ref": "users.firstName" } } }
+            }
+        });
+
+        let expected = Jgd::from(schema.clone()).generate().unwrap();
+
+        let mut records = Vec::new();
+        Jgd::from(schema).generate_each_record(|entity, record| {
+            records.push((entity.to_string(), record));
+            Ok(())
+        }).unwrap();
+
+        let users: Vec<&Value> = records.iter().filter(|(entity, _)| entity == "users").map(|(_, record)| record).collect();
+        assert_eq!(users, expected["users"].as_array().unwrap().iter().collect::<Vec<_>>());
+        assert!(users[0]["first_name"].is_string());
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[3].0, "owner");
+    }
+
+    #[test]
+    fn test_generate_to_writer_resumes_from_checkpoint() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "users": { "count": 5, "fields": { "id": "${uuid.v4}", "name": "${name.firstName}" } },
+                "posts": { "count": 7, "fields": { "author": { "ref": "users.id" }, "title": "${lorem.sentence}" } }
+            }
+        }));
+
+        let mut checkpoints = Vec::new();
+        let mut full = Vec::new();
+        jgd.generate_to_writer_resumable(&mut full, None, 3, |checkpoint| {
+            checkpoints.push(checkpoint.clone());
+            Ok(())
+        }).unwrap();
+        let positions: Vec<(&str, u64)> = checkpoints.iter().map(|checkpoint| (checkpoint.entity.as_str(), checkpoint.index)).collect();
+        assert_eq!(positions, [("users", 3), ("posts", 3), ("posts", 6)]);
+
+        // The seed drawn for the run is reused on resume
+        let checkpoint = &checkpoints[1];
+        let mut rest = Vec::new();
+        jgd.generate_to_writer_resumable(&mut rest, Some(checkpoint), 3, |_| Ok(())).unwrap();
+        let mut resumed = full[..checkpoint.bytes as usize].to_vec();
+        resumed.extend(rest);
+        assert_eq!(resumed, full);
+
+        let moved = Checkpoint { index: 8, ..checkpoint.clone() };
+        let error = jgd.generate_to_writer_resumable(&mut Vec::new(), Some(&moved), 3, |_| Ok(())).unwrap_err();
+        assert_eq!(error.message, "Error to resume from the checkpoint posts #8. Details: the generation never reached it; was the schema changed?");
+    }
+
+    #[test]
+    fn test_relation_entity_generates_unique_pairs() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 11,
+            "entities": {
+                "users": { "count": 4, "fields": { "id": "${index}" } },
+                "follows": {
+                    "relation": { "from": "users.id", "to": "users.id", "count": 50, "noSelfLoops": true, "unique": true },
+                    "fields": { "weight": 1 }
+                }
+            }
+        });
+
+        let result = Jgd::from(schema).generate().unwrap();
+        let follows = result["follows"].as_array().unwrap();
+
+        // 4 users allow 12 distinct pairs without self-loops
+        assert_eq!(follows.len(), 12);
+
+        let mut pairs = std::collections::HashSet::new();
+        for follow in follows {
+            assert_ne!(follow["from"], follow["to"]);
+            assert_eq!(follow["weight"], json!(1));
+            assert!(pairs.insert((follow["from"].to_string(), follow["to"].to_string())));
+        }
+    }
+
+    #[test]
+    fn test_key_case_converts_output_keys_only() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 3,
+            "keyCase": "snake_case",
+            "entities": {
+                "userAccounts": { "count": 2, "fields": { "firstName": "${name.firstName}" } },
+                "posts": { "fields": { "authorName": { "ref": "userAccounts.firstName" } } }
+            }
+        });
+
+        let result = Jgd::from(schema.clone()).generate().unwrap();
+        assert!(result["userAccounts"][0]["first_name"].is_string());
+        assert!(result["posts"]["author_name"].is_string());
+
+        let mut output = Vec::new();
+        Jgd::from(schema).generate_to_writer(&mut output).unwrap();
+        let streamed: Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(streamed["userAccounts"], result["userAccounts"]);
+    }
+
+    #[test]
+    fn test_constants_injected_into_records() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "constants": { "schemaVersion": 3, "source": "jgd" },
+            "entities": {
+                "users": {
+                    "count": 2,
+                    "fields": { "source": "override", "address": { "fields": { "city": "Paris" } } }
+                },
+                "logs": { "constants": {}, "fields": { "level": "info" } }
+            }
+        });
+
+        let result = Jgd::from(schema).generate().unwrap();
+
+        for user in result["users"].as_array().unwrap() {
+            assert_eq!(user["schemaVersion"], json!(3));
+            assert_eq!(user["source"], json!("override"));
+            assert_eq!(user["address"], json!({ "city": "Paris" }));
+        }
+        assert_eq!(result["logs"], json!({ "level": "info" }));
+    }
+
+    #[test]
+    fn test_generate_as_reports_deserialize_errors() {
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Typed {
+            id: u64,
+        }
+
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "id": "not a number" } }
+        }));
+
+        let error = jgd.generate_as::<Typed>().unwrap_err();
+        assert!(error.message.starts_with("Error to deserialize the generated value"));
+
+        let many = jgd.generate_many_as::<Value>(4).unwrap();
+        assert_eq!(many.len(), 4);
+    }
+
+    #[test]
+    fn test_serialize_round_trips_examples() {
+        let mut names: Vec<String> = fs::read_dir("../examples").unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(names, [
+            "array-object-root.jgd", "customers-orders.jgd", "depth-index.jgd", "entities-blog-system.jgd",
+            "ranged-array-object-root.jgd", "root-address-fr-fr.jgd", "root-ecommerce.jgd", "root-user.jgd",
+            "single-object-root.jgd", "single-user.jgd", "user-post-entities-custom-keys.jgd",
+            "user-post-entities.jgd", "users-and-posts.jgd",
+        ]);
+
+        for name in names {
+            let path = PathBuf::from("../examples").join(name);
+            let jgd = serde_json::from_str::<Jgd>(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+            let serialized = serde_json::to_value(&jgd).unwrap();
+            let reloaded = Jgd::from(serialized.clone());
+            assert_eq!(serde_json::to_value(&reloaded).unwrap(), serialized, "{}", path.display());
+            assert_eq!(reloaded.generate().is_ok(), jgd.generate().is_ok(), "{}", path.display());
+        }
+    }
+
+    #[test]
+    fn test_from_file_strict_accepts_examples() {
+        // The key of user-post-entities-custom-keys.jgd, registered by the applications using it
+        Jgd::add_custom_key("custom.key", Arc::new(|_| Ok(Value::String("custom".to_string()))));
+
+        for entry in fs::read_dir("../examples").unwrap() {
+            let path = entry.unwrap().path();
+            let jgd = Jgd::from_file_strict(&path).unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+            let plan = jgd.explain();
+            assert!(plan.warnings.is_empty(), "{}: {:?}", path.display(), plan.warnings);
+            assert!(jgd.generate().is_ok(), "{}", path.display());
+        }
+    }
+
+    #[test]
+    fn test_serialize_keeps_declared_properties() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "defaultLocale": "EN",
+            "keyCase": "snake_case",
+            "limits": { "maxRecords": 100 },
+            "entities": {
+                "tags": { "count": [1, 3], "uniqueBy": ["name"], "fields": {
+                    "name": { "of": "${lorem.word}", "transforms": ["upper", "truncate(5)"] },
+                    "score": { "number": { "min": 1.0, "max": 5.0, "integer": true } },
+                    "note": { "optional": { "of": "n/a", "prob": 0.2 } },
+                    "ids": { "array": { "of": "${index}", "count": 2 } },
+                    "parent": { "ref": "tags.name" },
+                    "flag": false,
+                    "weight": 1.5,
+                    "missing": null
+                }, "corruption": { "duplicates": 0.0, "nulls": 0.1, "typos": 0.0, "outliers": 0.0 } },
+                "links": { "relation": { "from": "tags.name", "to": "tags.name", "count": 2, "unique": true,
+                    "fromField": "from", "toField": "to" }, "fields": {} }
+            }
+        });
+
+        let jgd = Jgd::from(schema.clone());
+        assert_eq!(serde_json::to_value(&jgd).unwrap(), schema);
+    }
+
+    #[test]
+    fn test_entities_generated_in_dependency_order() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "posts": { "count": 2, "fields": { "userId": { "ref": "users.id" } } },
+                "users": { "count": 1, "fields": { "id": "user-${index}" } }
+            }
+        }));
+
+        let value = jgd.generate().unwrap();
+        assert_eq!(value["posts"][1]["userId"], json!("user-1"));
+
+        let mut output = Vec::new();
+        jgd.generate_to_writer(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), r#"{"users":[{"id":"user-1"}],"posts":[{"userId":"user-1"},{"userId":"user-1"}]}"#);
+    }
+
+    #[test]
+    fn test_errors_carry_schema_pointer() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "users": { "count": 1, "fields": { "id": 1 } },
+                "posts": { "count": 1, "fields": {
+                    "author_id": { "ref": "users.missing" },
+                    "tags": { "array": { "count": 1, "of": "${unknown.key}" } }
+                } }
+            }
+        }));
+
+        let error = jgd.generate().unwrap_err();
+        assert_eq!(error.kind, JgdErrorKind::Reference);
+        assert_eq!(error.path.as_deref(), Some("/entities/posts/fields/author_id"));
+        assert_eq!(error.key.as_deref(), Some("users.missing"));
+
+        let mut jgd = jgd;
+        jgd.exclude("posts.author_id").unwrap();
+        let mut output = Vec::new();
+        let error = jgd.generate_to_writer(&mut output).unwrap_err();
+        assert_eq!(error.kind, JgdErrorKind::Template);
+        assert_eq!(error.path.as_deref(), Some("/entities/posts/fields/tags/array/of"));
+        assert_eq!(error.key.as_deref(), Some("${unknown.key}"));
+    }
+
+    #[test]
+    fn test_reference_date_anchors_relative_dates() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 5,
+            "referenceDate": "2020-06-01T00:00:00Z",
+            "root": {
+                "count": 10,
+                "fields": {
+                    "before": "${chrono.dateTimeBefore}",
+                    "between": "${chrono.dateTimeBetween}",
+                    "at": { "timeline": { "minGap": "1m", "maxGap": "1h" } }
+                }
+            }
+        });
+
+        let generated = Jgd::from(schema.clone()).generate().unwrap();
+        assert_eq!(Jgd::from(schema).generate().unwrap(), generated);
+        let reference: DateTime<Utc> = "2020-06-01T00:00:00Z".parse().unwrap();
+        for item in generated.as_array().unwrap() {
+            let between: DateTime<Utc> = item["between"].as_str().unwrap().parse().unwrap();
+            assert!(between >= reference - chrono::Duration::days(365) && between <= reference);
+            assert!(item["before"].as_str().unwrap() < "2020-06-01");
+        }
+        assert!(generated[0]["at"].as_str().unwrap() < "2020-06-01");
+    }
+
+    #[test]
+    fn test_safe_emails_and_pinned_domains() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 9,
+            "safeEmails": true,
+            "root": {
+                "count": 10,
+                "fields": {
+                    "free": "${internet.freeEmail}",
+                    "safe": "${internet.safeEmail}",
+                    "pinned": "${internet.email(acme.test)}",
+                    "company": "${internet.freeEmail(acme.com)}"
+                }
+            }
+        });
+
+        for item in Jgd::from(schema).generate().unwrap().as_array().unwrap() {
+            assert!(item["free"].as_str().unwrap().ends_with(".test"), "{}", item["free"]);
+            assert!(item["safe"].as_str().unwrap().contains("@example."), "{}", item["safe"]);
+            assert!(item["pinned"].as_str().unwrap().ends_with("@acme.test"), "{}", item["pinned"]);
+            assert!(item["company"].as_str().unwrap().ends_with("@acme.test"), "{}", item["company"]);
+        }
+    }
 }
@@ -0,0 +1,116 @@
+//! Schemas derived from Rust types.
+//!
+//! The `JgdSchema` trait describes how to generate instances of a type as a JGD
+//! [`Entity`]. It is usually implemented with `#[derive(JgdSchema)]`, available with
+//! the `derive` feature, so the schema follows the struct definition:
+//!
+//! ```rust,ignore
+//! use jgd_rs::JgdSchema;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize, JgdSchema)]
+//! struct User {
+//!     #[jgd(fake = "internet.safeEmail")]
+//!     email: String,
+//!     #[jgd(min = 18, max = 90)]
+//!     age: u8,
+//! }
+//!
+//! let user: User = User::jgd_schema().generate_as().unwrap();
+//! ```
+
+use crate::{type_spec::Entity, Jgd};
+
+/// A type that can describe itself as a JGD entity.
+pub trait JgdSchema {
+    /// Returns the entity that generates values of this type.
+    fn jgd_entity() -> Entity;
+
+    /// Returns a root mode schema that generates values of this type.
+    fn jgd_schema() -> Jgd {
+        Jgd::from_root(Self::jgd_entity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jgd_derive::JgdSchema;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, JgdSchema)]
+    struct Address {
+        #[jgd(fake = "address.cityName")]
+        city: String,
+    }
+
+    #[derive(Debug, Deserialize, JgdSchema)]
+    #[jgd(count = 3)]
+    struct User {
+        #[jgd(template = "user-${index}")]
+        id: String,
+        #[jgd(fake = "internet.safeEmail")]
+        email: String,
+        #[jgd(min = 18, max = 90)]
+        age: u8,
+        score: f64,
+        active: bool,
+        #[jgd(prob = 1.0)]
+        nickname: Option<String>,
+        #[jgd(count = "2..2")]
+        #[serde(rename = "homes")]
+        addresses: Vec<Address>,
+        #[jgd(skip)]
+        #[serde(default)]
+        internal: u32,
+    }
+
+    #[test]
+    fn test_derived_schema_generates_the_struct() {
+        let mut jgd = User::jgd_schema();
+        jgd.seed = Some(42);
+
+        let value = jgd.generate().unwrap();
+        assert!(value[0].get("internal").is_none());
+        assert!(value[0]["homes"].is_array());
+
+        let users: Vec<User> = jgd.generate_as().unwrap();
+        assert_eq!(users.len(), 3);
+        assert_eq!(users[1].id, "user-2");
+        assert!(users[0].email.contains('@'));
+        assert!((18..=90).contains(&users[0].age));
+        assert!((0.0..=100.0).contains(&users[0].score));
+        assert_eq!(value[0]["active"], serde_json::json!(users[0].active));
+        assert!(users[0].nickname.is_some());
+        assert_eq!(users[0].addresses.len(), 2);
+        assert!(!users[0].addresses[0].city.is_empty());
+        assert_eq!(users[0].internal, 0);
+    }
+
+    #[derive(Debug, Deserialize, JgdSchema)]
+    #[serde(rename_all = "camelCase")]
+    #[jgd(count = 5)]
+    struct Badge {
+        first_initial: char,
+        display_name: String,
+    }
+
+    #[test]
+    fn test_derived_schema_with_char_and_rename_all() {
+        let mut jgd = Badge::jgd_schema();
+        jgd.seed = Some(3);
+
+        let value = jgd.generate().unwrap();
+        assert!(value[0]["firstInitial"].is_string() && value[0]["displayName"].is_string());
+
+        let badges: Vec<Badge> = jgd.generate_as().unwrap();
+        assert!(badges.iter().all(|badge| badge.first_initial.is_ascii_alphabetic() && !badge.display_name.is_empty()));
+    }
+
+    #[test]
+    fn test_derived_entity_without_count_is_single() {
+        let entity = Address::jgd_entity();
+        assert!(entity.count.is_none());
+        assert!(Address::jgd_schema().generate().unwrap().is_object());
+    }
+}
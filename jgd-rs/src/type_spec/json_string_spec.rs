@@ -0,0 +1,83 @@
+//! # JSON String Specification Module
+//!
+//! Generated subtrees serialized to JSON text, for event envelopes whose payload is a
+//! string and database columns that store JSON as text:
+//!
+//! ```json
+//! {
+//!   "payload": {
+//!     "jsonString": {
+//!       "of": { "fields": { "orderId": "${random.uuid}", "total": { "number": { "min": 1, "max": 500 } } } }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! generates values such as `"{\"orderId\":\"4f7c…\",\"total\":72.5}"`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{type_spec::{Field, JsonGenerator}, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Specification of a field serialized to a JSON string.
+///
+/// `of` is generated like any field, with the context of the enclosing item, and its
+/// value is written as compact JSON text, or indented with two spaces when `pretty`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct JsonStringSpec {
+    /// Specification of the serialized value.
+    pub of: Box<Field>,
+
+    /// Indents the JSON text instead of writing it on one line.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pretty: bool,
+}
+
+impl JsonGenerator for JsonStringSpec {
+    /// Generates `of` and returns its JSON text.
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let value = self.of.generate(config, local_config).map_err(|error| error.within(&["jsonString", "of"]))?;
+        let text = match self.pretty {
+            true => serde_json::to_string_pretty(&value),
+            false => serde_json::to_string(&value),
+        };
+
+        text.map(Value::String).map_err(|error| JgdGeneratorError {
+            message: format!("Error to serialize the JSON string: {}", error),
+            kind: JgdErrorKind::Serialization,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    #[test]
+    fn test_json_string_embeds_serialized_subtree() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 2,
+                "fields": {
+                    "id": "${index}",
+                    "payload": { "jsonString": { "of": { "fields": { "kind": "order", "tags": { "tuple": ["a", "b"] } } } } },
+                    "pretty": { "jsonString": { "of": { "fields": { "ok": true } }, "pretty": true } }
+                }
+            }
+        }));
+
+        let generated = jgd.generate().unwrap();
+        assert_eq!(generated[1]["payload"], "{\"kind\":\"order\",\"tags\":[\"a\",\"b\"]}");
+        assert_eq!(generated[0]["pretty"], "{\n  \"ok\": true\n}");
+        let payload: Value = serde_json::from_str(generated[0]["payload"].as_str().unwrap()).unwrap();
+        assert_eq!(payload["tags"][1], "b");
+    }
+}
@@ -0,0 +1,144 @@
+//! # Length Specification Module
+//!
+//! Length limits of generated strings, for database columns such as `VARCHAR(50)`
+//! that reject the occasional long sentence of a fake key:
+//!
+//! ```json
+//! {
+//!   "title": { "of": "${lorem.sentence}", "length": { "max": 50 } },
+//!   "code": { "of": "${lorem.word}", "length": { "min": 4, "max": 8, "overflow": "regenerate" } }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{JgdErrorKind, JgdGeneratorError};
+
+fn default_retries() -> usize {
+    10
+}
+
+/// What to do with a string longer than `max`.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Overflow {
+    /// Keeps the first `max` characters.
+    #[default]
+    Truncate,
+
+    /// Generates the value again.
+    Regenerate,
+}
+
+/// Length limits, in characters, of the strings of a field.
+///
+/// Strings shorter than `min` are generated again, up to `retries` times before
+/// generation fails; strings longer than `max` are truncated or generated again
+/// depending on `overflow`. Values that are not strings are kept unchanged.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LengthSpec {
+    /// Smallest number of characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<usize>,
+
+    /// Largest number of characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<usize>,
+
+    /// Handling of strings longer than `max`, `truncate` by default.
+    #[serde(default)]
+    pub overflow: Overflow,
+
+    /// Values generated again before giving up, 10 by default.
+    #[serde(default = "default_retries")]
+    pub retries: usize,
+}
+
+impl LengthSpec {
+    /// Fits a generated value into the limits; `None` asks for the value to be generated again.
+    pub(crate) fn fit(&self, value: Value, attempt: usize) -> Result<Option<Value>, JgdGeneratorError> {
+        if self.min.zip(self.max).is_some_and(|(min, max)| min > max) {
+            return Err(self.error(&format!("min {} is greater than max {}", self.min.unwrap_or_default(), self.max.unwrap_or_default())));
+        }
+        let Value::String(text) = value else {
+            return Ok(Some(value));
+        };
+
+        let length = text.chars().count();
+        let violation = match (self.min, self.max) {
+            (Some(min), _) if length < min => format!("{:?} is shorter than {} characters", text, min),
+            (_, Some(max)) if length > max && self.overflow == Overflow::Truncate => {
+                return Ok(Some(Value::String(text.chars().take(max).collect())));
+            },
+            (_, Some(max)) if length > max => format!("{:?} is longer than {} characters", text, max),
+            _ => return Ok(Some(Value::String(text))),
+        };
+
+        match attempt >= self.retries {
+            true => Err(self.error(&format!("{} after {} attempts", violation, attempt + 1))),
+            false => Ok(None),
+        }
+    }
+
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to fit the length of the field: {}", details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    #[test]
+    fn test_length_truncates_or_regenerates() {
+        let truncate: LengthSpec = serde_json::from_value(json!({ "max": 3 })).unwrap();
+        assert_eq!(truncate.fit(json!("héllo"), 0).unwrap(), Some(json!("hél")));
+        assert_eq!(truncate.fit(json!(12345), 0).unwrap(), Some(json!(12345)));
+
+        let regenerate: LengthSpec = serde_json::from_value(json!({ "min": 2, "max": 3, "overflow": "regenerate", "retries": 1 })).unwrap();
+        assert_eq!(regenerate.fit(json!("abcd"), 0).unwrap(), None);
+        assert!(regenerate.fit(json!("a"), 1).unwrap_err().message.contains("shorter than 2 characters after 2 attempts"));
+
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 20,
+                "fields": {
+                    "title": { "of": "${lorem.sentence}", "length": { "max": 10 } },
+                    "word": { "of": "${lorem.word}", "length": { "min": 5, "overflow": "regenerate", "retries": 500 } }
+                }
+            }
+        }));
+        for item in jgd.generate().unwrap().as_array().unwrap() {
+            assert!(item["title"].as_str().unwrap().chars().count() <= 10);
+            assert!(item["word"].as_str().unwrap().chars().count() >= 5);
+        }
+    }
+
+    #[test]
+    fn test_length_cannot_share_an_object_with_another_wrapper() {
+        let combined = json!({ "of": "${lorem.word}", "length": { "max": 3 }, "assert": { "minLength": 10 } });
+        let error = serde_json::from_value::<crate::type_spec::Field>(combined).unwrap_err();
+        assert_eq!(error.to_string(), "the keys length and assert cannot be combined in one field, nest one of them in `of`");
+
+        let nested = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "fields": {
+                    "code": { "of": { "of": "${lorem.sentence}", "length": { "max": 3 } }, "assert": { "maxLength": 3 } }
+                }
+            }
+        }));
+        assert!(nested.generate().unwrap()["code"].as_str().unwrap().chars().count() <= 3);
+    }
+}
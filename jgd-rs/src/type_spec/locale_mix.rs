@@ -0,0 +1,158 @@
+//! # Locale Mix Module
+//!
+//! Weighted locales of the items of an entity, for international datasets whose
+//! records are not all from the same locale:
+//!
+//! ```json
+//! {
+//!   "users": {
+//!     "count": 100,
+//!     "localeMix": { "EN": 0.6, "PT_BR": 0.3, "JA_JP": 0.1 },
+//!     "fields": { "name": "${name.name}", "city": "${address.cityName}" }
+//!   }
+//! }
+//! ```
+//!
+//! or read from a field of each item, such as its country, so a Brazilian record gets
+//! Brazilian names and addresses:
+//!
+//! ```json
+//! {
+//!   "customers": {
+//!     "count": 100,
+//!     "localeFrom": { "field": "self.countryCode", "locales": { "BR": "PT_BR", "DE": "DE_DE" } },
+//!     "fields": { "countryCode": "${choice(BR, DE, US)}", "name": "${name.name}" }
+//!   }
+//! }
+//! ```
+
+use indexmap::IndexMap;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::type_spec::count::weighted_index;
+
+/// Relative weight of each locale of an entity's items.
+///
+/// Each item picks a locale by weight and generates all its template values (nested
+/// objects included) with the built-in fake data of that locale, data packs included.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct LocaleMix(pub IndexMap<String, f64>);
+
+impl LocaleMix {
+    /// Picks a locale proportionally to the weights; `None` when the mix is empty.
+    pub fn pick(&self, rng: &mut StdRng) -> Option<&str> {
+        let weights: Vec<f64> = self.0.values().copied().collect();
+        weighted_index(&weights, rng).map(|index| self.0.get_index(index).unwrap().0.as_str())
+    }
+}
+
+/// Locale of each item of an entity, read from one of its fields.
+///
+/// The fields declared before `field` are generated with the entity locale; the value
+/// of `field` then picks the locale of the fields after it, variants and nested objects
+/// included. Values missing from `locales` keep the entity locale.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct LocaleFrom {
+    /// Name of the field holding the value, optionally written `self.countryCode`.
+    pub field: String,
+
+    /// Locale of each value of the field, e.g. `{ "BR": "PT_BR" }`; values match
+    /// regardless of case.
+    pub locales: IndexMap<String, String>,
+}
+
+impl LocaleFrom {
+    /// Name of the field holding the value, without the `self.` prefix.
+    pub(crate) fn field(&self) -> &str {
+        self.field.strip_prefix("self.").unwrap_or(&self.field)
+    }
+
+    /// The locale mapped to the value of the field `key`, or `None` for other fields
+    /// and unmapped values.
+    pub(crate) fn locale(&self, key: &str, value: &Value) -> Option<&str> {
+        if key != self.field() {
+            return None;
+        }
+
+        let value = match value {
+            Value::String(value) => value.clone(),
+            value => value.to_string(),
+        };
+        self.locales.iter()
+            .find(|(mapped, _)| mapped.eq_ignore_ascii_case(&value))
+            .map(|(_, locale)| locale.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    #[test]
+    fn test_locale_mix_picks_a_locale_per_item() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 11,
+            "root": {
+                "count": 60,
+                "localeMix": { "EN": 1, "JA_JP": 1 },
+                "fields": { "name": "${name.lastName}" }
+            }
+        }));
+
+        let generated = jgd.generate().unwrap();
+        let japanese = generated.as_array().unwrap().iter()
+            .filter(|item| !item["name"].as_str().unwrap().is_ascii())
+            .count();
+        assert!(japanese > 10 && japanese < 50, "{} japanese names", japanese);
+
+        let english = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "count": 10, "localeMix": { "EN": 1, "JA_JP": 0 }, "fields": { "name": "${name.lastName}" } }
+        }));
+        assert!(english.generate().unwrap().as_array().unwrap().iter().all(|item| item["name"].as_str().unwrap().is_ascii()));
+    }
+
+    #[test]
+    fn test_locale_from_reads_the_locale_of_each_item() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 3,
+            "root": {
+                "count": 40,
+                "localeFrom": { "field": "self.country", "locales": { "jp": "JA_JP" } },
+                "fields": {
+                    "before": "${name.lastName}",
+                    "country": "${choice(JP, US)}",
+                    "name": "${name.lastName}",
+                    "address": { "fields": { "city": "${address.cityName}" } }
+                }
+            }
+        }));
+
+        let generated = jgd.generate().unwrap();
+        let items = generated.as_array().unwrap();
+        assert!(items.iter().all(|item| item["before"].as_str().unwrap().is_ascii()));
+        for item in items {
+            let japanese = item["country"] == "JP";
+            assert_eq!(!item["name"].as_str().unwrap().is_ascii(), japanese, "{}", item);
+            assert_eq!(!item["address"]["city"].as_str().unwrap().is_ascii(), japanese, "{}", item);
+        }
+        assert!(items.iter().any(|item| item["country"] == "JP") && items.iter().any(|item| item["country"] == "US"));
+
+        let missing = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "localeFrom": { "field": "country", "locales": {} }, "fields": { "name": "x" } }
+        }));
+        assert!(missing.generate().unwrap_err().message.contains("the field country of localeFrom is not in fields"));
+    }
+}
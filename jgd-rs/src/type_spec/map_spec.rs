@@ -0,0 +1,123 @@
+//! # Map Specification Module
+//!
+//! Objects with a variable number of generated keys, for JSON maps keyed by IDs, tags
+//! or names. Entity fields are fixed, so such maps cannot be expressed with `fields`:
+//!
+//! ```json
+//! {
+//!   "scores": {
+//!     "map": {
+//!       "count": [3, 6],
+//!       "key": "${lorem.word}",
+//!       "value": { "number": { "min": 0, "max": 100, "integer": true } }
+//!     }
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{type_spec::{Count, Field, GetCount, JsonGenerator}, GeneratorConfig, JgdGeneratorError, LocalConfig};
+
+/// Maximum attempts to generate a key that is not in the map yet.
+const MAX_KEY_ATTEMPTS: usize = 100;
+
+/// Specification of an object with generated keys and values.
+///
+/// `key` and `value` are generated once per entry, with `${index}` set to the entry
+/// position. Keys that are not strings are converted to their JSON text. A key that is
+/// already in the map is generated again; when no new key comes up after a few attempts,
+/// the map ends up with fewer entries than `count`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MapSpec {
+    /// Number of entries; defaults to 1 like [`ArraySpec`](crate::ArraySpec).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<Count>,
+
+    /// Specification of the entry keys, usually a template like `"${lorem.word}"`.
+    pub key: Box<Field>,
+
+    /// Specification of the entry values.
+    pub value: Box<Field>,
+}
+
+impl JsonGenerator for MapSpec {
+    /// Generates an object with `count` distinct generated keys.
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let count_items = self.count.count(config);
+
+        config.limits.reserve_records(&mut config.usage, count_items, local_config.as_deref())?;
+        config.limits.enter_level(&mut config.usage, local_config.as_deref())?;
+
+        let mut map = Map::new();
+        let mut local_config =
+            LocalConfig::from_current_with_config(None, Some(count_items), local_config);
+
+        for i in 0..count_items {
+            local_config.set_index(i as usize);
+
+            let mut key = None;
+            for _ in 0..MAX_KEY_ATTEMPTS {
+                let candidate = match self.key.generate(config, Some(&mut local_config))
+                    .map_err(|error| error.within(&["map", "key"]))? {
+                    Value::String(candidate) => candidate,
+                    candidate => candidate.to_string(),
+                };
+                if !map.contains_key(&candidate) {
+                    key = Some(candidate);
+                    break;
+                }
+            }
+
+            let Some(key) = key else {
+                trace_event!(warn, index = i, attempts = MAX_KEY_ATTEMPTS, "failed to generate a new map key");
+                break;
+            };
+
+            let value = self.value.generate(config, Some(&mut local_config))
+                .map_err(|error| error.within(&["map", "value"]))?;
+            map.insert(key, value);
+        }
+
+        config.limits.exit_level(&mut config.usage);
+
+        Ok(Value::Object(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_map_generates_distinct_keys() {
+        let mut config = GeneratorConfig::new("EN", Some(9));
+        let spec: MapSpec = serde_json::from_value(json!({
+            "count": 3,
+            "key": "user-${index}",
+            "value": { "fields": { "score": { "number": { "min": 1, "max": 5, "integer": true } } } }
+        })).unwrap();
+
+        let value = spec.generate(&mut config, None).unwrap();
+        let keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["user-1", "user-2", "user-3"]);
+        assert!(value["user-2"]["score"].is_i64());
+    }
+
+    #[test]
+    fn test_map_stops_when_keys_run_out() {
+        let jgd = crate::Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "flags": { "map": { "count": 5, "key": { "number": { "min": 1, "max": 2, "integer": true } }, "value": true } } } }
+        }));
+
+        let flags = jgd.generate().unwrap()["flags"].clone();
+        assert_eq!(flags.as_object().unwrap().len(), 2);
+        assert_eq!(flags["1"], true);
+    }
+}
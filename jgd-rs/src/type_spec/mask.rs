@@ -0,0 +1,147 @@
+//! Anonymization of existing JSON documents through a JGD mask.
+//!
+//! A mask is a regular JGD schema whose fields name the values to replace. Each masked
+//! field found in the document is replaced by a freshly generated value; everything else
+//! (unlisted fields, structure, array lengths) is kept as is.
+//!
+//! ```json
+//! {
+//!   "$format": "jgd/v1",
+//!   "version": "1.0",
+//!   "root": {
+//!     "fields": {
+//!       "name": "${name.name}",
+//!       "email": "${internet.safeEmail}",
+//!       "address": { "fields": { "street": "${address.streetName}" } }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Arrays in the document are masked item by item, so `${index}` follows the item position.
+//! A nested entity in the mask descends into the matching object instead of replacing it,
+//! and a flattened one masks its fields in the enclosing object.
+//! In entities mode, each entity masks the top-level document key with the same name, and
+//! an array root masks the items of a top-level array with the entity of its `of`.
+
+use serde_json::{Map, Value};
+
+use crate::{type_spec::{Entity, Field, GeneratorConfig, JsonGenerator, Root}, Jgd, JgdGeneratorError, LocalConfig};
+
+fn mask_value(entity: &Entity, value: &mut Value, config: &mut GeneratorConfig, local_config: &mut LocalConfig
+    ) -> Result<(), JgdGeneratorError> {
+    match value {
+        Value::Array(items) => {
+            let mut local_config =
+                LocalConfig::from_current_with_config(None, Some(items.len() as u64), Some(local_config));
+            for (index, item) in items.iter_mut().enumerate() {
+                local_config.set_index(index);
+                mask_value(entity, item, config, &mut local_config)?;
+            }
+        },
+        Value::Object(map) => {
+            let mut local_config = LocalConfig::from_current_with_config(None, None, Some(local_config));
+            mask_fields(entity, map, config, &mut local_config)?;
+        },
+        _ => {},
+    }
+
+    Ok(())
+}
+
+/// Masks the fields of an object; flattened entities mask the same object.
+fn mask_fields(entity: &Entity, map: &mut Map<String, Value>, config: &mut GeneratorConfig, local_config: &mut LocalConfig
+    ) -> Result<(), JgdGeneratorError> {
+    for (key, field) in &entity.fields {
+        if let Field::Entity(inner) = field {
+            if inner.flatten {
+                mask_fields(inner, map, config, local_config)?;
+                continue;
+            }
+        }
+        let Some(current) = map.get_mut(key) else {
+            continue;
+        };
+
+        local_config.field_name = Some(key.clone());
+        match field {
+            Field::Entity(inner) if current.is_object() || current.is_array() => {
+                mask_value(inner, current, config, local_config)?;
+            },
+            _ => *current = field.generate(config, Some(local_config))?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces the masked fields of `document` with generated values.
+pub(crate) fn mask_document(jgd: &Jgd, mut document: Value) -> Result<Value, JgdGeneratorError> {
+    let mut config = jgd.create_config();
+    let mut local_config = LocalConfig::from_current_with_config(None, None, None);
+
+    if let Some(Root::Entity(root)) = &jgd.root {
+        mask_value(root, &mut document, &mut config, &mut local_config)?;
+    } else if let Some(Root::Array { array }) = &jgd.root {
+        if let Field::Entity(root) = array.of.as_ref() {
+            mask_value(root, &mut document, &mut config, &mut local_config)?;
+        }
+    } else if let Some(entities) = &jgd.entities {
+        if let Value::Object(map) = &mut document {
+            for (name, entity) in entities {
+                if let Some(value) = map.get_mut(name) {
+                    local_config.entity_name = Some(name.clone());
+                    mask_value(entity, value, &mut config, &mut local_config)?;
+                }
+            }
+        }
+    }
+
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_mask_replaces_only_listed_fields() {
+        let mask = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 5,
+            "root": {
+                "fields": {
+                    "name": "Anonymous ${index}",
+                    "address": { "fields": { "street": "hidden" } },
+                    "missing": "never added"
+                }
+            }
+        }));
+
+        let document = json!([
+            { "id": 10, "name": "Real Person", "address": { "street": "Main St", "city": "Lyon" } },
+            { "id": 11, "name": "Other Person" }
+        ]);
+
+        let masked = mask.mask(document).unwrap();
+        assert_eq!(masked, json!([
+            { "id": 10, "name": "Anonymous 1", "address": { "street": "hidden", "city": "Lyon" } },
+            { "id": 11, "name": "Anonymous 2" }
+        ]));
+    }
+
+    #[test]
+    fn test_mask_entities_mode() {
+        let mask = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": { "users": { "fields": { "email": "user${index}@example.com" } } }
+        }));
+
+        let masked = mask.mask(json!({ "users": [{ "email": "a@b.c" }], "meta": { "email": "keep" } })).unwrap();
+        assert_eq!(masked["users"][0]["email"], json!("user1@example.com"));
+        assert_eq!(masked["meta"]["email"], json!("keep"));
+    }
+}
@@ -13,8 +13,36 @@
 //! - [`NumberSpec`] - Generates random numbers (integers or floats) within a range
 //! - [`ArraySpec`] - Generates arrays of elements with specified count and element types
 //! - [`Entity`] - Generates complex objects with multiple fields
+//! - [`Root`] - The entity or top-level array generated by a root mode schema
+//! - [`VariantSpec`] - One weighted kind of item of an entity with mixed item types
+//! - [`MapSpec`] - Generates objects with a variable number of generated keys
 //! - [`Field`] - Represents individual fields within entities
 //! - [`OptionalSpec`] - Wraps other specifications to make them optionally null
+//! - [`AddressSpec`] - Generates complete addresses whose parts come from one place
+//! - [`MoneySpec`] - Amounts rounded to the decimals of their currency
+//! - [`PartitionSpec`] - Numbers that add up to a total, such as percentages or invoice lines
+//! - [`ColorSpec`] - Colors as objects of numeric channels, for design and charting fixtures
+//! - [`CumulativeSpec`] - Running total of a sibling numeric field across generated items
+//! - [`StateMachineSpec`] - States that follow allowed transitions across generated items
+//! - [`TimelineSpec`] - Timestamps that increase strictly across generated items
+//! - [`TraceSpec`] - OpenTelemetry traces of nested spans in the OTLP JSON encoding
+//! - [`GeoJsonSpec`] - GeoJSON feature collections of points or polygons with generated properties
+//! - [`JsonStringSpec`] - Generated subtrees serialized to JSON text
+//! - [`RandomStringSpec`] - Random strings of a length and a named or custom character set
+//! - [`FormatSpec`] - Formats generated values into zero-padded ids, prefixed codes and fixed decimals
+//! - [`LengthSpec`] - Length limits of generated strings, truncating or regenerating them
+//! - [`AssertSpec`] - Constraints (pattern, length, bounds) that generated values must satisfy
+//! - [`IdFromSpec`] - Stable ids hashed from sibling fields, for reproducible record ids
+//! - [`TagReport`] - Output paths of the fields classified with `pii` or `tags`
+//! - [`StatsReport`] - Per-field statistics (min/max/mean, distinct values, null rate) of generated data
+//! - [`CheckReport`] - Violations of the refs, `uniqueBy` and declared `checks` in generated data
+//! - [`LocaleMix`] - Locales picked by weight for each item of an entity
+//! - [`LocaleFrom`] - Locale of each item of an entity read from one of its fields
+//! - [`CorrelationSpec`] - Keeps address fields of an entity consistent with each other
+//! - [`CorruptionSpec`] - Injects duplicates, nulls, typos and outliers for data-quality testing
+//! - [`RefDistribution`] - How a `ref` picks among the items of the array it points to
+//! - [`RelationSpec`] - Pairs values of generated entities into many-to-many join records
+//! - [`JgdSchema`] - Describes a Rust type as an entity, usually through `#[derive(JgdSchema)]`
 //! - [`Count`] - Defines how many items should be generated (fixed or range)
 //!
 //! # JGD Schema Compliance
@@ -33,24 +61,85 @@
 //! let generated_value = number_spec.generate(&mut config, None).unwrap();
 //! ```
 
+mod address_spec;
 mod array_spec;
+mod assert_spec;
+mod check_report;
+mod color_spec;
+mod correlation_spec;
+mod corruption_spec;
 mod count;
+mod cumulative_spec;
+mod dependency;
 mod entity;
+mod explain;
 mod field;
+mod format_spec;
+mod geojson_spec;
+mod id_from_spec;
 mod jgd;
+mod jgd_schema;
+mod length_spec;
+mod json_string_spec;
+mod locale_mix;
+mod map_spec;
+mod mask;
+mod money_spec;
 mod number_spec;
 mod optional_spec;
+mod overrides;
+mod partition_spec;
+mod random_string_spec;
+mod ref_distribution;
+mod relation_spec;
+mod root;
+mod selection;
+mod state_machine_spec;
+mod stats_report;
+mod strict;
+mod tag_report;
+mod timeline_spec;
+mod trace_spec;
 mod utils;
+mod variant_spec;
 
 // Re-export all types
+pub use address_spec::AddressSpec;
 pub use array_spec::ArraySpec;
+pub use assert_spec::AssertSpec;
+pub use check_report::{Check, CheckReport};
+pub use color_spec::{ColorFormat, ColorSpec};
+pub use correlation_spec::CorrelationSpec;
+pub use corruption_spec::CorruptionSpec;
 pub use count::*;
+pub use cumulative_spec::CumulativeSpec;
 pub use entity::Entity;
+pub use explain::*;
 pub use field::Field;
+pub use format_spec::FormatSpec;
+pub use geojson_spec::{GeoJsonSpec, GeometryKind};
+pub use id_from_spec::{HashAlgo, IdFromSpec};
 pub use jgd::Jgd;
+pub use jgd_schema::JgdSchema;
+pub use length_spec::{LengthSpec, Overflow};
+pub use json_string_spec::JsonStringSpec;
+pub use locale_mix::{LocaleFrom, LocaleMix};
+pub use map_spec::MapSpec;
+pub use money_spec::{CurrencySpec, MoneySpec};
 pub use number_spec::NumberSpec;
 pub use optional_spec::OptionalSpec;
+pub use partition_spec::{PartitionSpec, PartitionTotal};
+pub use random_string_spec::RandomStringSpec;
+pub use ref_distribution::RefDistribution;
+pub use relation_spec::RelationSpec;
+pub use root::Root;
+pub use state_machine_spec::StateMachineSpec;
+pub use stats_report::{FieldStats, StatsReport};
+pub use tag_report::TagReport;
+pub use timeline_spec::TimelineSpec;
+pub use trace_spec::TraceSpec;
 pub use utils::*;
+pub use variant_spec::VariantSpec;
 
 use serde_json::Value;
 
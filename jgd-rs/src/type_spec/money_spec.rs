@@ -0,0 +1,203 @@
+//! # Money Specification Module
+//!
+//! Amounts paired with a currency, rounded to the decimals of that currency (ISO 4217
+//! minor units: `JPY` has none, `BHD` has 3). Combining a `number` field with a
+//! `${finance.currencyCode}` field produces amounts such as `12.37 JPY`; a `money`
+//! field keeps the amount and the currency consistent:
+//!
+//! ```json
+//! {
+//!   "price": { "money": { "min": 1, "max": 500, "currency": ["USD", "JPY", "BHD"] } },
+//!   "total": { "money": { "min": 10, "max": 1000, "currency": "locale", "formatted": true } }
+//! }
+//! ```
+//!
+//! generates, for the `EN` locale:
+//!
+//! ```json
+//! {
+//!   "price": { "amount": 204, "currency": "JPY" },
+//!   "total": "356.20 USD"
+//! }
+//! ```
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{fake::{money::{minor_units, CURRENCIES}, LocaleFormat}, type_spec::JsonGenerator, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Currency value that stands for the currency of the record locale.
+const LOCALE_CURRENCY: &str = "locale";
+
+fn default_max() -> f64 {
+    1000.0
+}
+
+/// Currency of a `money` field: a code, `locale`, or codes picked at random.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum CurrencySpec {
+    /// An ISO 4217 code such as `EUR`, or `locale` for the currency of the record locale.
+    One(String),
+
+    /// Codes, one picked at random for each value.
+    Any(Vec<String>),
+}
+
+/// Specification of an amount of money with its currency.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct MoneySpec {
+    /// Smallest amount.
+    #[serde(default)]
+    pub min: f64,
+
+    /// Largest amount.
+    #[serde(default = "default_max")]
+    pub max: f64,
+
+    /// Currency of the amounts; a common currency picked at random when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<CurrencySpec>,
+
+    /// Generates a string such as `1,234.50 USD`, with the locale separators, instead
+    /// of an `{amount, currency}` object.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub formatted: bool,
+}
+
+impl MoneySpec {
+    fn currency(&self, config: &mut GeneratorConfig) -> Result<String, JgdGeneratorError> {
+        let currency = match &self.currency {
+            None => CURRENCIES[config.rng.random_range(0..CURRENCIES.len())].0,
+            Some(CurrencySpec::One(currency)) => currency,
+            Some(CurrencySpec::Any(currencies)) if currencies.is_empty() => {
+                return Err(JgdGeneratorError {
+                    message: "Error to generate the money: currency must list at least one currency".to_string(),
+                    kind: JgdErrorKind::Schema,
+                    ..Default::default()
+                });
+            },
+            Some(CurrencySpec::Any(currencies)) => &currencies[config.rng.random_range(0..currencies.len())],
+        };
+
+        if currency == LOCALE_CURRENCY {
+            return Ok(LocaleFormat::of(&config.locale).currency().to_string());
+        }
+        Ok(currency.to_uppercase())
+    }
+}
+
+impl JsonGenerator for MoneySpec {
+    /// Generates `{amount, currency}`, or the formatted amount, with the amount rounded
+    /// to the decimals of the currency and kept within `min` and `max`.
+    fn generate(&self, config: &mut GeneratorConfig, _local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        if self.min > self.max {
+            return Err(JgdGeneratorError {
+                message: format!("Error to generate the money: min {} is greater than max {}", self.min, self.max),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            });
+        }
+
+        let currency = self.currency(config)?;
+        let decimals = minor_units(&currency);
+        let factor = 10f64.powi(decimals as i32);
+
+        // Smallest and largest amounts in minor units that stay within the range
+        let lowest = (self.min * factor).round();
+        let lowest = if lowest / factor < self.min { lowest + 1.0 } else { lowest };
+        let highest = (self.max * factor).round();
+        let highest = if highest / factor > self.max { highest - 1.0 } else { highest };
+        if lowest > highest {
+            return Err(JgdGeneratorError {
+                message: format!("Error to generate the money: no {} amount lies between min {} and max {}", currency, self.min, self.max),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            });
+        }
+
+        let amount = (config.rng.random_range(self.min..=self.max) * factor).round().clamp(lowest, highest) / factor;
+
+        if self.formatted {
+            let number = format!("{:.*}", decimals as usize, amount);
+            let number = LocaleFormat::of(&config.locale).format_decimal(&number).unwrap_or(number);
+            return Ok(Value::String(format!("{} {}", number, currency)));
+        }
+
+        let amount = if decimals == 0 {
+            Value::from(amount as i64)
+        } else {
+            Value::from(amount)
+        };
+        let mut money = Map::new();
+        money.insert("amount".to_string(), amount);
+        money.insert("currency".to_string(), Value::String(currency));
+        Ok(Value::Object(money))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    #[test]
+    fn test_money_rounds_to_currency_decimals() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "defaultLocale": "DE_DE",
+            "root": {
+                "count": 20,
+                "fields": {
+                    "yen": { "money": { "min": 1, "max": 5000, "currency": "JPY" } },
+                    "dinar": { "money": { "min": 1, "max": 5, "currency": ["BHD"] } },
+                    "local": { "money": { "min": 1000, "max": 2000, "currency": "locale", "formatted": true } },
+                    "any": { "money": {} }
+                }
+            }
+        }));
+
+        for item in jgd.generate().unwrap().as_array().unwrap() {
+            assert!(item["yen"]["amount"].is_i64());
+            assert_eq!(item["yen"]["currency"], "JPY");
+            let dinar = item["dinar"]["amount"].as_f64().unwrap();
+            assert_eq!((dinar * 1000.0).round() / 1000.0, dinar);
+            let local = item["local"].as_str().unwrap();
+            assert!(local.ends_with(" EUR") && local.contains('.') && local.contains(','), "{}", local);
+            assert!(CURRENCIES.iter().any(|(code, _)| item["any"]["currency"] == *code));
+        }
+    }
+
+    #[test]
+    fn test_money_rounding_stays_within_the_range() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 50,
+                "fields": {
+                    "yen": { "money": { "min": 1.4, "max": 2.6, "currency": "JPY" } },
+                    "euro": { "money": { "min": 0.004, "max": 0.016, "currency": "EUR" } }
+                }
+            }
+        }));
+
+        for item in jgd.generate().unwrap().as_array().unwrap() {
+            assert_eq!(item["yen"]["amount"], json!(2));
+            assert_eq!(item["euro"]["amount"], json!(0.01));
+        }
+
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "yen": { "money": { "min": 1.2, "max": 1.4, "currency": "JPY" } } } }
+        }));
+        let error = jgd.generate().unwrap_err();
+        assert!(error.message.contains("no JPY amount lies between min 1.2 and max 1.4"), "{}", error.message);
+    }
+}
@@ -1,5 +1,5 @@
 use rand::Rng;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::{type_spec::JsonGenerator, JgdGeneratorError, LocalConfig};
@@ -48,7 +48,7 @@ use crate::{type_spec::JsonGenerator, JgdGeneratorError, LocalConfig};
 ///     integer: false,
 /// };
 /// ```
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct NumberSpec {
     /// The minimum value (inclusive) for generated numbers.
     ///
@@ -19,7 +19,7 @@
 //! - **API responses**: Fields that may be present based on user permissions or data availability
 
 use rand::Rng;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use crate::{type_spec::{Field, JsonGenerator}, JgdGeneratorError, LocalConfig};
 
@@ -111,7 +111,7 @@ fn default_prob() -> f64 {
 ///
 /// The struct uses Serde's `#[serde(default)]` attribute with a custom default function
 /// to provide the 0.5 probability when not explicitly specified in the input JSON.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct OptionalSpec {
     /// The field specification to generate when the probability condition is met.
     ///
@@ -180,8 +180,9 @@ impl JsonGenerator for OptionalSpec {
     /// data generation across runs.
     fn generate(&self, config: &mut super::GeneratorConfig, local_config: Option<&mut LocalConfig>
         ) -> Result<Value, JgdGeneratorError> {
-        if config.rng.random::<f64>() < self.prob {
-            self.of.generate(config, local_config)
+        let prob = config.realism.map_or(self.prob, |realism| realism.optional_prob(self.prob));
+        if config.rng.random::<f64>() < prob {
+            self.of.generate(config, local_config).map_err(|error| error.within(&["optional", "of"]))
         } else {
             Ok(Value::Null)
         }
@@ -485,6 +486,9 @@ mod tests {
         let array_spec = ArraySpec {
             count: Some(Count::Fixed(3)),
             of: Box::new(Field::Str("item".to_string())),
+            prob_null: None,
+            prob_empty: None,
+            target_size: None,
         };
 
         let optional = OptionalSpec {
@@ -0,0 +1,204 @@
+//! Value overrides and overlays patched into a parsed schema.
+//!
+//! An override sets a single schema property through a dot path, e.g.
+//! `users.count = 100` or `users.fields.status = "active"`, so one schema can be
+//! tweaked per run without maintaining variants of the file.
+//!
+//! An overlay is a partial schema document merged over the schema with JSON Merge
+//! Patch semantics (RFC 7386): objects are merged recursively, other values are
+//! replaced and `null` removes a property. A base schema plus environment-specific
+//! overlays can then share a single definition.
+
+use serde_json::{Map, Value};
+
+use crate::{type_spec::strict, Jgd, JgdErrorKind, JgdGeneratorError};
+
+fn override_error(path: &str, details: impl std::fmt::Display) -> JgdGeneratorError {
+    JgdGeneratorError {
+        message: format!("Error to apply the override {}. Details: {}", path, details),
+        kind: JgdErrorKind::Schema,
+        ..Default::default()
+    }
+}
+
+fn merge_error(details: impl std::fmt::Display) -> JgdGeneratorError {
+    JgdGeneratorError {
+        message: format!("Error to merge the schemas. Details: {}", details),
+        kind: JgdErrorKind::Schema,
+        ..Default::default()
+    }
+}
+
+/// Applies `patch` over `target` as a JSON Merge Patch.
+fn merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(&key);
+            } else {
+                merge_patch(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+/// Merges the `overlay` document over the schema.
+pub(crate) fn merge(jgd: &Jgd, overlay: Value) -> Result<Jgd, JgdGeneratorError> {
+    if !overlay.is_object() {
+        return Err(merge_error("the overlay must be a JSON object"));
+    }
+
+    let mut document = serde_json::to_value(jgd).map_err(merge_error)?;
+    merge_patch(&mut document, overlay);
+
+    if document.get("root").is_some() && document.get("entities").is_some() {
+        return Err(merge_error("a schema cannot have both root and entities"));
+    }
+
+    serde_json::from_value(document).map_err(merge_error)
+}
+
+/// Sets `value` at `path` in the schema.
+///
+/// The path is resolved against the schema document; in entities mode a path that
+/// starts with an entity name is resolved inside `entities`. Missing intermediate
+/// objects are created, but every property on the path must be one the schema knows,
+/// like in strict parsing: new names are only accepted where the schema allows any
+/// name, such as in `fields`, `entities` or `mixins`, so a misspelled path fails
+/// instead of being ignored.
+pub(crate) fn set(jgd: &Jgd, path: &str, value: Value) -> Result<Jgd, JgdGeneratorError> {
+    let mut document = serde_json::to_value(jgd).map_err(|error| override_error(path, error))?;
+    let unknown = strict::unknown_fields(&document);
+
+    let mut segments: Vec<&str> = path.split('.').collect();
+    if segments.iter().any(|segment| segment.is_empty()) {
+        return Err(override_error(path, "empty path segment"));
+    }
+
+    let is_entity = jgd.entities.as_ref().is_some_and(|entities| entities.contains_key(segments[0]));
+    if is_entity {
+        segments.insert(0, "entities");
+    }
+
+    let last = segments.pop().unwrap_or_default();
+    let mut current = &mut document;
+    for segment in segments {
+        let Value::Object(map) = current else {
+            return Err(override_error(path, format!("{} is not an object", segment)));
+        };
+        current = map.entry(segment).or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    match current {
+        Value::Object(map) => {
+            map.insert(last.to_string(), value);
+        },
+        _ => return Err(override_error(path, format!("the parent of {} is not an object", last))),
+    }
+
+    if let Some(pointer) = strict::unknown_fields(&document).into_iter().find(|pointer| !unknown.contains(pointer)) {
+        return Err(override_error(path, format!("{} is not a schema property", pointer)));
+    }
+
+    serde_json::from_value(document).map_err(|error| override_error(path, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_set_entity_properties() {
+        let mut jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": { "users": { "count": 5, "fields": { "status": "pending", "name": "${name.firstName}" } } }
+        }));
+
+        jgd.set("users.count", json!(2)).unwrap();
+        jgd.set("users.fields.status", json!("active")).unwrap();
+        jgd.set("seed", json!(9)).unwrap();
+
+        let value = jgd.generate().unwrap();
+        assert_eq!(value["users"].as_array().unwrap().len(), 2);
+        assert_eq!(value["users"][1]["status"], json!("active"));
+        assert_eq!(jgd.seed, Some(9));
+    }
+
+    #[test]
+    fn test_set_creates_missing_objects_and_validates() {
+        let mut jgd = Jgd::from(json!({
+            "$format": "jgd/v1", "version": "1.0", "root": { "fields": { "id": 1 } }
+        }));
+
+        jgd.set("limits.maxRecords", json!(10)).unwrap();
+        assert_eq!(jgd.limits.max_records, Some(10));
+
+        let error = jgd.set("root.count", json!("many")).unwrap_err();
+        assert!(error.message.starts_with("Error to apply the override root.count."));
+        assert_eq!(jgd.limits.max_records, Some(10));
+
+        jgd.set("root.fields.name", json!("Bob")).unwrap();
+        assert_eq!(jgd.generate().unwrap(), json!({ "id": 1, "name": "Bob" }));
+    }
+
+    #[test]
+    fn test_set_rejects_unknown_properties() {
+        let mut jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": { "users": { "count": 1, "fields": { "name": "Alice" } } }
+        }));
+
+        let error = jgd.set("users.name", json!("Bob")).unwrap_err();
+        assert_eq!(error.message, "Error to apply the override users.name. Details: /entities/users/name is not a schema property");
+        let error = jgd.set("nope.x", json!(1)).unwrap_err();
+        assert!(error.message.ends_with("/nope is not a schema property"), "{}", error.message);
+        assert!(jgd.set("limits.maxRecrods", json!(1)).is_err());
+
+        jgd.set("entities.posts", json!({ "count": 1, "fields": { "title": "post" } })).unwrap();
+        assert_eq!(jgd.generate().unwrap(), json!({ "users": [{ "name": "Alice" }], "posts": [{ "title": "post" }] }));
+    }
+
+    #[test]
+    fn test_merge_overlays_entities_and_settings() {
+        let mut jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "defaultLocale": "FR",
+            "entities": {
+                "users": { "count": 50, "fields": { "id": "${index}", "debug": true } },
+                "logs": { "count": 1, "fields": { "line": "x" } }
+            }
+        }));
+
+        jgd.merge(json!({
+            "seed": 3,
+            "entities": {
+                "users": { "count": 1, "fields": { "env": "dev", "debug": null } },
+                "logs": null,
+                "tags": { "count": 1, "fields": { "name": "tag" } }
+            }
+        })).unwrap();
+
+        assert_eq!(jgd.default_locale, "FR");
+        assert_eq!(jgd.seed, Some(3));
+        assert_eq!(jgd.generate().unwrap(), json!({
+            "users": [{ "id": 1, "env": "dev" }],
+            "tags": [{ "name": "tag" }]
+        }));
+
+        let error = jgd.merge(json!({ "root": { "fields": {} } })).unwrap_err();
+        assert_eq!(error.message, "Error to merge the schemas. Details: a schema cannot have both root and entities");
+    }
+}
@@ -0,0 +1,163 @@
+//! # Partition Specification Module
+//!
+//! Numbers that add up to a target, such as percentages summing to 100 or invoice
+//! lines summing to the invoice total. Independent `number` fields cannot express
+//! the constraint; a `partition` field splits the total at random:
+//!
+//! ```json
+//! {
+//!   "shares": { "partition": { "count": 4, "total": 100 } },
+//!   "total": { "number": { "min": 100, "max": 900 } },
+//!   "lines": { "partition": { "count": [1, 5], "total": "total", "decimals": 2, "min": 1 } }
+//! }
+//! ```
+//!
+//! generates arrays such as `[31, 12, 40, 17]` and `[120.07, 345.93, 58.4]`.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{type_spec::{Count, GetCount, JsonGenerator}, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Target sum of a partition.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum PartitionTotal {
+    /// A fixed total, e.g. `100`.
+    Fixed(f64),
+
+    /// Name of a sibling numeric field declared before the partition, e.g. `"total"`.
+    Field(String),
+}
+
+/// Specification of `count` numbers summing exactly to `total`.
+///
+/// The split is uniform over all the ways to partition the total, with every part at
+/// least `min`. Parts are rounded to `decimals` places and the rounding remainder is
+/// spread over the parts, so the sum is exact at that precision.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PartitionSpec {
+    /// Number of parts.
+    pub count: Count,
+
+    /// Sum of the parts.
+    pub total: PartitionTotal,
+
+    /// Smallest value of a part.
+    #[serde(default)]
+    pub min: f64,
+
+    /// Decimal places of the parts; integer parts by default.
+    #[serde(default)]
+    pub decimals: u32,
+}
+
+impl PartitionSpec {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to generate the partition: {}", details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+
+    fn total(&self, local_config: Option<&LocalConfig>) -> Result<f64, JgdGeneratorError> {
+        let name = match &self.total {
+            PartitionTotal::Fixed(total) => return Ok(*total),
+            PartitionTotal::Field(name) => name,
+        };
+
+        match local_config.and_then(|local_config| local_config.siblings.get(name)) {
+            Some(Value::Number(number)) => Ok(number.as_f64().unwrap_or_default()),
+            Some(_) => Err(self.error(&format!("the total field {} is not a number", name))),
+            None => Err(self.error(&format!("the total field {} must be declared before the partition", name))),
+        }
+    }
+}
+
+impl JsonGenerator for PartitionSpec {
+    /// Splits the total into `count` parts and returns them as an array.
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let total = self.total(local_config.as_deref())?;
+        let count = self.count.count(config);
+
+        // Works in units of the last decimal place, so the parts add up exactly
+        let factor = 10f64.powi(self.decimals as i32);
+        let total_units = (total * factor).round() as i64;
+        let min_units = (self.min * factor).round() as i64;
+        let free_units = total_units - min_units * count as i64;
+        if count == 0 {
+            return match total_units {
+                0 => Ok(Value::Array(vec![])),
+                _ => Err(self.error("a total other than 0 needs at least one part")),
+            };
+        }
+        if free_units < 0 {
+            return Err(self.error(&format!("{} parts of at least {} exceed the total {}", count, self.min, total)));
+        }
+
+        // Exponential weights give a uniform split of the free units
+        let weights: Vec<f64> = (0..count).map(|_| -(1.0 - config.rng.random::<f64>()).ln()).collect();
+        let weight_sum: f64 = weights.iter().sum();
+        let shares: Vec<f64> = weights.iter().map(|weight| free_units as f64 * weight / weight_sum).collect();
+        let mut parts: Vec<i64> = shares.iter().map(|share| share.floor() as i64).collect();
+
+        let mut remainder = free_units - parts.iter().sum::<i64>();
+        while remainder > 0 {
+            let index = config.rng.random_range(0..parts.len());
+            parts[index] += 1;
+            remainder -= 1;
+        }
+
+        Ok(Value::Array(parts.into_iter()
+            .map(|units| units + min_units)
+            .map(|units| match self.decimals {
+                0 => Value::from(units),
+                _ => Value::from(units as f64 / factor),
+            })
+            .collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    #[test]
+    fn test_partition_sums_to_total() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 20,
+                "fields": {
+                    "shares": { "partition": { "count": 4, "total": 100 } },
+                    "total": { "number": { "min": 100, "max": 900 } },
+                    "lines": { "partition": { "count": [1, 5], "total": "total", "decimals": 2, "min": 1 } }
+                }
+            }
+        }));
+
+        for item in jgd.generate().unwrap().as_array().unwrap() {
+            let shares = item["shares"].as_array().unwrap();
+            assert_eq!(shares.len(), 4);
+            assert_eq!(shares.iter().map(|share| share.as_i64().unwrap()).sum::<i64>(), 100);
+
+            let lines: Vec<f64> = item["lines"].as_array().unwrap().iter().map(|line| line.as_f64().unwrap()).collect();
+            assert!(lines.iter().all(|line| *line >= 1.0));
+            let total = (item["total"].as_f64().unwrap() * 100.0).round();
+            assert_eq!((lines.iter().sum::<f64>() * 100.0).round(), total);
+        }
+
+        let impossible = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "parts": { "partition": { "count": 3, "total": 10, "min": 5 } } } }
+        }));
+        assert!(impossible.generate().unwrap_err().message.contains("exceed the total"));
+    }
+}
@@ -0,0 +1,80 @@
+//! # Random String Specification Module
+//!
+//! Random strings of a length and a character set, for tokens, codes and opaque
+//! identifiers that are not passwords:
+//!
+//! ```json
+//! {
+//!   "token": { "randomString": { "length": 32, "charset": "hex" } },
+//!   "code": { "randomString": { "length": [6, 8], "charset": "ABCDEFGHJKLMNPQRSTUVWXYZ23456789" } }
+//! }
+//! ```
+//!
+//! The `${string.random(length, charset)}` key generates the same strings in templates.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{fake::strings::{charset, random_string, DEFAULT_CHARSET}, type_spec::{Count, GetCount, JsonGenerator}, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+fn default_charset() -> String {
+    DEFAULT_CHARSET.to_string()
+}
+
+/// Specification of a random string.
+///
+/// `charset` is `alpha`, `alnum`, `lower`, `upper`, `numeric`, `hex` or `base64url`;
+/// any other text is the set of characters to pick from.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RandomStringSpec {
+    /// Number of characters: a number, a `[min, max]` range or any other count.
+    pub length: Count,
+
+    /// Named or custom character set, `alnum` by default.
+    #[serde(default = "default_charset")]
+    pub charset: String,
+}
+
+impl JsonGenerator for RandomStringSpec {
+    /// Generates a string of `length` characters picked from the charset.
+    fn generate(&self, config: &mut GeneratorConfig, _local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let chars = charset(&self.charset);
+        if chars.is_empty() {
+            return Err(JgdGeneratorError {
+                message: "Error to generate the random string: the charset is empty".to_string(),
+                kind: JgdErrorKind::Schema,
+                ..Default::default()
+            });
+        }
+
+        let length = self.length.count(config) as usize;
+        Ok(Value::String(random_string(&chars, length, &mut config.rng)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_random_string_length_and_charset() {
+        let mut config = GeneratorConfig::new("EN", Some(8));
+        let hex: RandomStringSpec = serde_json::from_value(json!({ "length": 32, "charset": "hex" })).unwrap();
+        let token = hex.generate(&mut config, None).unwrap();
+        assert_eq!(token.as_str().unwrap().len(), 32);
+        assert!(token.as_str().unwrap().chars().all(|c| c.is_ascii_hexdigit()));
+
+        let custom: RandomStringSpec = serde_json::from_value(json!({ "length": [2, 4], "charset": "AB" })).unwrap();
+        for _ in 0..20 {
+            let code = custom.generate(&mut config, None).unwrap();
+            let code = code.as_str().unwrap();
+            assert!((2..=4).contains(&code.len()) && code.chars().all(|c| c == 'A' || c == 'B'), "{}", code);
+        }
+
+        let empty: RandomStringSpec = serde_json::from_value(json!({ "length": 3, "charset": "" })).unwrap();
+        assert!(empty.generate(&mut config, None).unwrap_err().message.contains("charset is empty"));
+    }
+}
@@ -0,0 +1,155 @@
+//! # Ref Distribution Module
+//!
+//! How a `ref` picks among the items of the entity array it points to. Uniform picks
+//! make every parent equally popular, while query-performance test data usually needs
+//! skew (a few users own most posts) or full coverage (every user has a post):
+//!
+//! ```json
+//! {
+//!   "authorId": { "ref": "users.id", "distribution": "zipf" },
+//!   "reviewerId": { "ref": "users.id", "distribution": "roundRobin" }
+//! }
+//! ```
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{GeneratorConfig, SpecId};
+
+/// Distribution of the values picked by a `ref` among its candidates.
+///
+/// Candidates are the values reachable through the ref path, in generation order.
+/// Every distribution draws from the schema seed, so seeded refs are reproducible.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RefDistribution {
+    /// Every candidate is equally likely.
+    #[default]
+    Uniform,
+
+    /// The candidate at rank `k` (1-based, in generation order) is picked with a weight
+    /// of `1 / k`, so the first items are referenced far more often than the last ones.
+    Zipf,
+
+    /// Candidates are picked in order, starting over after the last one, so every
+    /// candidate is referenced once before any is referenced twice.
+    RoundRobin,
+}
+
+/// Cumulative weights of `len` candidates, where the candidate at rank `k` weighs `1 / k`.
+fn zipf_cdf(len: usize) -> Vec<f64> {
+    (1..=len)
+        .scan(0.0, |total, rank| {
+            *total += 1.0 / rank as f64;
+            Some(*total)
+        })
+        .collect()
+}
+
+impl RefDistribution {
+    /// Whether this is the default, uniform distribution.
+    pub fn is_uniform(&self) -> bool {
+        *self == RefDistribution::Uniform
+    }
+
+    /// Picks the position of one of `len` candidates, or `None` when there are none.
+    ///
+    /// `cursor` identifies the ref for round-robin, which keeps its position in the
+    /// generator configuration for the whole generation session.
+    pub(crate) fn pick(&self, len: usize, cursor: (Option<String>, SpecId), config: &mut GeneratorConfig) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
+
+        match self {
+            RefDistribution::Uniform => Some(config.rng.random_range(0..len)),
+            RefDistribution::Zipf => {
+                let cdf = config.zipf_cdfs.entry(len).or_insert_with(|| zipf_cdf(len));
+                let target = config.rng.random_range(0.0..cdf[len - 1]);
+                Some(cdf.partition_point(|cumulative| *cumulative <= target).min(len - 1))
+            },
+            RefDistribution::RoundRobin => {
+                let position = config.ref_cursors.entry(cursor).or_default();
+                let picked = *position % len;
+                *position += 1;
+                Some(picked)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    fn schema(distribution: &str) -> Jgd {
+        Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 21,
+            "entities": {
+                "users": { "count": 10, "fields": { "id": "${index}" } },
+                "posts": { "count": 400, "fields": { "authorId": { "ref": "users.id", "distribution": distribution } } }
+            }
+        }))
+    }
+
+    fn posts_per_user(jgd: &Jgd) -> HashMap<u64, usize> {
+        let mut counts = HashMap::new();
+        for post in jgd.generate().unwrap()["posts"].as_array().unwrap() {
+            *counts.entry(post["authorId"].as_u64().unwrap()).or_default() += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_ref_distributions() {
+        let round_robin = posts_per_user(&schema("roundRobin"));
+        assert_eq!(round_robin.len(), 10);
+        assert!(round_robin.values().all(|count| *count == 40));
+
+        let zipf = posts_per_user(&schema("zipf"));
+        assert!(zipf[&1] > 3 * zipf[&10], "{:?}", zipf);
+
+        let uniform = schema("uniform");
+        assert_eq!(uniform.generate().unwrap(), uniform.generate().unwrap());
+    }
+
+    #[test]
+    fn test_round_robin_cursor_per_entity_of_a_mixin() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v2",
+            "version": "1.0",
+            "seed": 4,
+            "mixins": { "authored": { "authorId": { "ref": "users.id", "distribution": "roundRobin" } } },
+            "entities": {
+                "users": { "count": 10, "fields": { "id": "${index}" } },
+                "posts": { "count": 5, "include": ["authored"], "fields": {} },
+                "comments": { "count": 5, "include": ["authored"], "fields": {} }
+            }
+        }));
+
+        let generated = jgd.generate().unwrap();
+        let authors = |entity: &str| generated[entity].as_array().unwrap().iter()
+            .map(|item| item["authorId"].as_u64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(authors("posts"), vec![1, 2, 3, 4, 5]);
+        assert_eq!(authors("comments"), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_zipf_cdf() {
+        assert_eq!(zipf_cdf(3), vec![1.0, 1.5, 1.5 + 1.0 / 3.0]);
+
+        let mut config = GeneratorConfig::new("EN", Some(1));
+        for _ in 0..100 {
+            assert!(RefDistribution::Zipf.pick(3, (None, SpecId::default()), &mut config).unwrap() < 3);
+        }
+        assert_eq!(config.zipf_cdfs.len(), 1);
+    }
+}
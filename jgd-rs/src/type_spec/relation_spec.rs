@@ -0,0 +1,210 @@
+//! # Relation Specification Module
+//!
+//! This module provides many-to-many join generation through the `RelationSpec` struct.
+//! A relation pairs values taken from previously generated entities, producing join
+//! records such as followers, memberships or order lines.
+//!
+//! ## Overview
+//!
+//! Building realistic graphs with plain `ref` fields and `uniqueBy` is impractical:
+//! each ref is picked independently, so self-loops and duplicate edges are common.
+//! A relation picks both ends together and can rule those out:
+//! - `noSelfLoops` rejects pairs whose ends are the same value
+//! - `unique` rejects pairs that were already generated
+//!
+//! ## Example
+//!
+//! ```json
+//! {
+//!   "entities": {
+//!     "users": { "count": 50, "fields": { "id": "${uuid}" } },
+//!     "follows": {
+//!       "relation": {
+//!         "from": "users.id",
+//!         "to": "users.id",
+//!         "count": [100, 200],
+//!         "noSelfLoops": true,
+//!         "unique": true
+//!       },
+//!       "fields": { "since": "${chrono.date}" }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Each generated item holds the pair under `from` and `to` (renamed with `fromField`
+//! and `toField`) followed by the entity `fields`.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::{type_spec::{Count, GeneratorConfig}, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Maximum number of picks tried to find a pair that is not a self-loop.
+const MAX_ATTEMPTS: usize = 1000;
+
+fn default_from_field() -> String {
+    "from".to_string()
+}
+
+fn default_to_field() -> String {
+    "to".to_string()
+}
+
+/// Specification for generating join records between generated entities.
+///
+/// # Fields
+///
+/// - **`from`** / **`to`**: Dot-notation paths of the values to pair (e.g. `"users.id"`)
+/// - **`count`**: Number of pairs to generate; falls back to the entity `count`
+/// - **`noSelfLoops`**: Rejects pairs where both ends are equal
+/// - **`unique`**: Rejects pairs that were already generated
+/// - **`fromField`** / **`toField`**: Names of the generated keys (default `from` and `to`)
+///
+/// When `unique` is set, the count is capped at the number of distinct pairs available.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationSpec {
+    pub from: String,
+    pub to: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<Count>,
+    #[serde(default, alias = "no_self_loops", skip_serializing_if = "std::ops::Not::not")]
+    pub no_self_loops: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unique: bool,
+    #[serde(default = "default_from_field", alias = "from_field")]
+    pub from_field: String,
+    #[serde(default = "default_to_field", alias = "to_field")]
+    pub to_field: String,
+}
+
+/// Candidate values for both ends of a relation, resolved once per generation.
+pub(crate) struct RelationPool {
+    from: Vec<Value>,
+    to: Vec<Value>,
+}
+
+impl RelationSpec {
+    /// Resolves the `from` and `to` paths against the already generated entities.
+    pub(crate) fn pool(&self, config: &GeneratorConfig, local_config: Option<&LocalConfig>
+        ) -> Result<RelationPool, JgdGeneratorError> {
+        let values = |path: &str| -> Result<Vec<Value>, JgdGeneratorError> {
+            let values: Vec<Value> = config.get_values_from_path(path).into_iter().cloned().collect();
+            if values.is_empty() {
+                return Err(JgdGeneratorError {
+                    message: format!("Error to generate relation: the path {} has no values", path),
+                    entity: local_config.and_then(|config| config.entity_name.clone()),
+                    field: local_config.and_then(|config| config.field_name.clone()),
+                    kind: JgdErrorKind::Reference,
+                    path: Some("/relation".into()),
+                    key: Some(path.into()),
+                });
+            }
+            Ok(values)
+        };
+
+        Ok(RelationPool {
+            from: values(&self.from)?,
+            to: values(&self.to)?,
+        })
+    }
+
+    /// Caps `count` at the number of distinct pairs when `unique` is set.
+    pub(crate) fn cap_count(&self, pool: &RelationPool, count: u64) -> u64 {
+        if !self.unique {
+            return count;
+        }
+
+        let mut available = 0u64;
+        for from in &pool.from {
+            for to in &pool.to {
+                if !(self.no_self_loops && from == to) {
+                    available += 1;
+                }
+            }
+            if available >= count {
+                return count;
+            }
+        }
+
+        count.min(available)
+    }
+
+    /// Picks a pair of values, honouring `noSelfLoops`.
+    ///
+    /// Returns `None` when no valid pair was found after the maximum number of attempts.
+    pub(crate) fn pick(&self, pool: &RelationPool, config: &mut GeneratorConfig) -> Option<(Value, Value)> {
+        for _ in 0..MAX_ATTEMPTS {
+            let from = &pool.from[config.rng.random_range(0..pool.from.len())];
+            let to = &pool.to[config.rng.random_range(0..pool.to.len())];
+
+            if !(self.no_self_loops && from == to) {
+                return Some((from.clone(), to.clone()));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn spec(no_self_loops: bool, unique: bool) -> RelationSpec {
+        serde_json::from_value(json!({
+            "from": "users.id",
+            "to": "users.id",
+            "noSelfLoops": no_self_loops,
+            "unique": unique
+        })).unwrap()
+    }
+
+    fn config_with_users() -> GeneratorConfig {
+        let mut config = GeneratorConfig::new("EN", Some(42));
+        config.gen_value.insert("users".to_string(), json!([{"id": 1}, {"id": 2}, {"id": 3}]));
+        config
+    }
+
+    #[test]
+    fn test_deserialize_defaults() {
+        let relation = spec(false, false);
+        assert_eq!(relation.from_field, "from");
+        assert_eq!(relation.to_field, "to");
+        assert!(relation.count.is_none());
+    }
+
+    #[test]
+    fn test_pick_without_self_loops() {
+        let mut config = config_with_users();
+        let relation = spec(true, false);
+        let pool = relation.pool(&config, None).unwrap();
+
+        for _ in 0..50 {
+            let (from, to) = relation.pick(&pool, &mut config).unwrap();
+            assert_ne!(from, to);
+        }
+    }
+
+    #[test]
+    fn test_cap_count_unique() {
+        let config = config_with_users();
+
+        let relation = spec(true, true);
+        let pool = relation.pool(&config, None).unwrap();
+        assert_eq!(relation.cap_count(&pool, 100), 6);
+
+        let relation = spec(false, true);
+        assert_eq!(relation.cap_count(&pool, 100), 9);
+        assert_eq!(relation.cap_count(&pool, 4), 4);
+    }
+
+    #[test]
+    fn test_pool_missing_path() {
+        let config = GeneratorConfig::new("EN", Some(42));
+        let error = spec(false, false).pool(&config, None).err().unwrap();
+        assert!(error.message.contains("users.id"));
+    }
+}
@@ -0,0 +1,134 @@
+//! # Root Module
+//!
+//! What a root mode schema generates: an entity, which gives an object or an array of
+//! objects with `count`, an array of any field, which gives a top-level array of
+//! plain values without wrapping objects, or any other field, which gives a single
+//! value such as a token or a config value:
+//!
+//! ```json
+//! { "root": { "array": { "count": 3, "of": "${index}" } } }
+//! ```
+//!
+//! generates `[1, 2, 3]`, and `{ "root": "${uuid.v4}" }` a single UUID string.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::{type_spec::{ArraySpec, Entity, Field, JsonGenerator}, GeneratorConfig, JgdGeneratorError, LocalConfig};
+
+/// Root of a root mode schema.
+///
+/// The root is read like a [`Field`]: an `array` field gives an array root, an entity
+/// gives an entity root and any other field a value root.
+// A schema has a single root, so the size of the entity variant does not matter.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum Root {
+    /// A top-level array of generated values, e.g. strings or numbers.
+    Array { array: ArraySpec },
+
+    /// An entity, generating an object or, with `count`, an array of objects.
+    Entity(Entity),
+
+    /// A single value, e.g. a template string or a `number` spec.
+    Value(Field),
+}
+
+impl<'de> Deserialize<'de> for Root {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match <Field as Deserialize>::deserialize(deserializer)? {
+            Field::Array { array } => Root::Array { array },
+            Field::Entity(entity) => Root::Entity(entity),
+            field => Root::Value(field),
+        })
+    }
+}
+
+impl Root {
+    /// The root entity, or `None` for an array or value root.
+    pub fn entity(&self) -> Option<&Entity> {
+        match self {
+            Root::Entity(entity) => Some(entity),
+            _ => None,
+        }
+    }
+
+    /// The root entity, mutably, or `None` for an array or value root.
+    pub fn entity_mut(&mut self) -> Option<&mut Entity> {
+        match self {
+            Root::Entity(entity) => Some(entity),
+            _ => None,
+        }
+    }
+}
+
+impl From<Entity> for Root {
+    fn from(entity: Entity) -> Self {
+        Root::Entity(entity)
+    }
+}
+
+impl JsonGenerator for Root {
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        match self {
+            Root::Array { array } => array.generate(config, local_config),
+            Root::Entity(entity) => entity.generate(config, local_config),
+            Root::Value(field) => field.generate(config, local_config),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    #[test]
+    fn test_array_root_generates_plain_values() {
+        let numbers = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "array": { "count": 3, "of": "${index}" } }
+        }));
+        assert!(matches!(numbers.root, Some(Root::Array { .. })));
+        assert_eq!(numbers.generate().unwrap(), json!([1, 2, 3]));
+
+        let words = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "array": { "count": [2, 4], "of": "${lorem.word}" } }
+        }));
+        let words = words.generate().unwrap();
+        assert!((2..=4).contains(&words.as_array().unwrap().len()));
+        assert!(words.as_array().unwrap().iter().all(Value::is_string));
+
+        let entity = Jgd::from(json!({ "$format": "jgd/v1", "version": "1.0", "root": { "fields": { "id": 1 } } }));
+        assert!(entity.root.as_ref().and_then(Root::entity).is_some());
+    }
+
+    #[test]
+    fn test_value_root_generates_a_scalar() {
+        let token = Jgd::from(json!({ "$format": "jgd/v1", "version": "1.0", "root": "${uuid.v4}" }));
+        assert!(matches!(token.root, Some(Root::Value(Field::Template(_)))));
+        assert_eq!(token.generate().unwrap().as_str().unwrap().len(), 36);
+
+        let port = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "number": { "min": 1024, "max": 65535, "integer": true } }
+        }));
+        let port = port.generate().unwrap().as_i64().unwrap();
+        assert!((1024..=65535).contains(&port));
+
+        let literal = Jgd::from(json!({ "$format": "jgd/v1", "version": "1.0", "root": 42 }));
+        assert_eq!(literal.generate().unwrap(), json!(42));
+        assert_eq!(literal.generate_many_as::<i64>(3).unwrap(), vec![42, 42, 42]);
+    }
+}
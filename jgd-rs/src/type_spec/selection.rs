@@ -0,0 +1,163 @@
+//! Partial generation: selecting entities and excluding fields of a schema.
+//!
+//! Large schemas can be narrowed down before generation, without editing the file:
+//! [`Jgd::select_entities`] keeps a subset of the entities (plus the entities they
+//! reference, so refs still resolve) and [`Jgd::exclude`] removes single fields.
+
+use indexmap::IndexMap;
+
+use crate::{type_spec::{dependency, Entity, Field, Root}, Jgd, JgdErrorKind, JgdGeneratorError};
+
+fn selection_error(message: String) -> JgdGeneratorError {
+    JgdGeneratorError {
+        message,
+        kind: JgdErrorKind::Schema,
+        ..Default::default()
+    }
+}
+
+/// Keeps the `names` entities and, transitively, the entities they reference.
+pub(crate) fn select_entities(entities: &mut IndexMap<String, Entity>, names: &[String]
+    ) -> Result<(), JgdGeneratorError> {
+    let mut selected: Vec<String> = Vec::new();
+    let mut pending: Vec<String> = names.to_vec();
+
+    while let Some(name) = pending.pop() {
+        if selected.contains(&name) {
+            continue;
+        }
+
+        let Some(entity) = entities.get(&name) else {
+            return Err(selection_error(format!("Error to select the entity {}: not found in the schema", name)));
+        };
+
+        pending.extend(dependency::entity_refs(entity)
+            .into_iter()
+            .filter(|dependency| entities.contains_key(*dependency))
+            .map(str::to_string));
+        selected.push(name);
+    }
+
+    entities.retain(|name, _| selected.contains(name));
+    Ok(())
+}
+
+/// Returns the fields of the entity nested under `field`, looking through arrays and optionals.
+fn nested_fields(field: &mut Field) -> Option<&mut IndexMap<String, Field>> {
+    match field {
+        Field::Entity(entity) => Some(&mut entity.fields),
+        Field::Array { array } => nested_fields(&mut array.of),
+        Field::Map { map } => nested_fields(&mut map.value),
+        Field::Optional { optional } => nested_fields(&mut optional.of),
+        Field::JsonString { json_string } => nested_fields(&mut json_string.of),
+        Field::Transformed { of, .. } | Field::Formatted { of, .. } | Field::Limited { of, .. } | Field::Asserted { of, .. } | Field::Tagged { of, .. } => nested_fields(of),
+        _ => None,
+    }
+}
+
+/// Removes the field at the dot separated `path` from `fields`.
+fn remove_field(fields: &mut IndexMap<String, Field>, path: &str, full_path: &str) -> Result<(), JgdGeneratorError> {
+    let not_found = || selection_error(format!("Error to exclude the field {}: not found in the schema", full_path));
+
+    match path.split_once('.') {
+        None => fields.shift_remove(path).map(|_| ()).ok_or_else(not_found),
+        Some((name, rest)) => {
+            let nested = fields.get_mut(name).and_then(nested_fields).ok_or_else(not_found)?;
+            remove_field(nested, rest, full_path)
+        },
+    }
+}
+
+/// Removes an entity (`users`) or one of its fields (`users.address.city`).
+///
+/// In root mode the path is relative to the root entity, or to the items of an array
+/// root (`address.city`).
+pub(crate) fn exclude(jgd: &mut Jgd, path: &str) -> Result<(), JgdGeneratorError> {
+    match &mut jgd.root {
+        Some(Root::Entity(root)) => return remove_field(&mut root.fields, path, path),
+        Some(Root::Array { array }) => {
+            let fields = nested_fields(&mut array.of)
+                .ok_or_else(|| selection_error(format!("Error to exclude the field {}: not found in the schema", path)))?;
+            return remove_field(fields, path, path);
+        },
+        Some(Root::Value(field)) => {
+            let fields = nested_fields(field)
+                .ok_or_else(|| selection_error(format!("Error to exclude the field {}: not found in the schema", path)))?;
+            return remove_field(fields, path, path);
+        },
+        None => {},
+    }
+
+    let Some(entities) = &mut jgd.entities else {
+        return Err(selection_error(format!("Error to exclude {}: the schema has no entities", path)));
+    };
+
+    match path.split_once('.') {
+        None => entities.shift_remove(path)
+            .map(|_| ())
+            .ok_or_else(|| selection_error(format!("Error to exclude the entity {}: not found in the schema", path))),
+        Some((name, rest)) => match entities.get_mut(name) {
+            Some(entity) => remove_field(&mut entity.fields, rest, path),
+            None => Err(selection_error(format!("Error to exclude the field {}: not found in the schema", path))),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Jgd {
+        Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "users": { "count": 1, "fields": { "id": "u${index}", "address": { "fields": { "city": "Lyon", "zip": "1" } } } },
+                "posts": { "count": 1, "fields": { "userId": { "ref": "users.id" } } },
+                "tags": { "count": 1, "fields": { "name": "tag" } }
+            }
+        }))
+    }
+
+    #[test]
+    fn test_select_entities_keeps_dependencies() {
+        let mut jgd = schema();
+        jgd.select_entities(&["posts".to_string()]).unwrap();
+
+        let value = jgd.generate().unwrap();
+        assert_eq!(value, json!({
+            "users": [{ "id": "u1", "address": { "city": "Lyon", "zip": "1" } }],
+            "posts": [{ "userId": "u1" }]
+        }));
+
+        let error = schema().select_entities(&["comments".to_string()]).unwrap_err();
+        assert_eq!(error.message, "Error to select the entity comments: not found in the schema");
+    }
+
+    #[test]
+    fn test_exclude_entities_and_fields() {
+        let mut jgd = schema();
+        jgd.exclude("tags").unwrap();
+        jgd.exclude("users.address.zip").unwrap();
+
+        let value = jgd.generate().unwrap();
+        assert_eq!(value["users"], json!([{ "id": "u1", "address": { "city": "Lyon" } }]));
+        assert!(value.get("tags").is_none());
+
+        let error = jgd.exclude("users.address.street").unwrap_err();
+        assert_eq!(error.message, "Error to exclude the field users.address.street: not found in the schema");
+    }
+
+    #[test]
+    fn test_exclude_in_root_mode() {
+        let mut jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "name": "a", "items": { "array": { "count": 1, "of": { "fields": { "x": 1, "y": 2 } } } } } }
+        }));
+        jgd.exclude("items.y").unwrap();
+
+        assert_eq!(jgd.generate().unwrap(), json!({ "name": "a", "items": [{ "x": 1 }] }));
+    }
+}
@@ -0,0 +1,193 @@
+//! # State Machine Specification Module
+//!
+//! Event sequences that follow allowed transitions, for consumers that validate the
+//! order of states (an order is paid before it is shipped, never the other way).
+//!
+//! Each item of a generated array takes the next state of the sequence: the first item
+//! starts at `initial` and the following ones move along a transition picked by weight.
+//! A state without transitions is final, so the next item starts a new sequence:
+//!
+//! ```json
+//! {
+//!   "root": {
+//!     "count": 10,
+//!     "fields": {
+//!       "status": {
+//!         "stateMachine": {
+//!           "initial": "created",
+//!           "transitions": {
+//!             "created": { "paid": 0.9, "cancelled": 0.1 },
+//!             "paid": { "shipped": 1 },
+//!             "shipped": { "delivered": 1 }
+//!           }
+//!         }
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{type_spec::{count::weighted_index, JsonGenerator}, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig, SpecId};
+
+/// Specification of a state that follows the previous state of the sequence.
+///
+/// Sequences are kept per generated array: each entity or root array with a `count`
+/// starts its own. With `by`, each value of that sibling field has its own sequence,
+/// e.g. the events of several orders interleaved in one array.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StateMachineSpec {
+    /// State of the first item of each sequence.
+    pub initial: String,
+
+    /// Weighted next states of each state. Negative weights count as zero; states
+    /// missing from the map are final.
+    #[serde(default)]
+    pub transitions: IndexMap<String, IndexMap<String, f64>>,
+
+    /// Sibling field, declared before this one, whose values have separate sequences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+
+    /// Key of the running state of this field; not part of the schema.
+    #[serde(skip)]
+    pub id: SpecId,
+}
+
+impl StateMachineSpec {
+    /// Picks the state following `state`, or `None` when `state` is final.
+    fn next_state(&self, state: &str, config: &mut GeneratorConfig) -> Option<String> {
+        let transitions = self.transitions.get(state)?;
+        let weights: Vec<f64> = transitions.values().copied().collect();
+        let index = weighted_index(&weights, &mut config.rng)?;
+        transitions.get_index(index).map(|(next, _)| next.clone())
+    }
+
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to generate the state machine starting at {}: {}", self.initial, details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+}
+
+impl JsonGenerator for StateMachineSpec {
+    /// Moves the sequence of this field to its next state and returns it.
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let Some(local_config) = local_config else {
+            return Err(self.error("state machine fields must be fields of an object"));
+        };
+
+        let sequence = match &self.by {
+            Some(by) => match local_config.siblings.get(by) {
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+                None => return Err(self.error(&format!("the field {} must be declared before the state machine field", by))),
+            },
+            None => String::new(),
+        };
+
+        let key = (self.id, sequence);
+        let mut state = local_config.array_state.borrow_mut();
+        let next = state.states.get(&key)
+            .and_then(|current| self.next_state(current, config))
+            .unwrap_or_else(|| self.initial.clone());
+        state.states.insert(key, next.clone());
+
+        Ok(Value::String(next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    fn statuses(value: &serde_json::Value, field: &str) -> Vec<String> {
+        value.as_array().unwrap().iter().map(|item| item[field].as_str().unwrap().to_string()).collect()
+    }
+
+    #[test]
+    fn test_state_machine_follows_transitions_and_restarts() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 7,
+            "root": {
+                "count": 5,
+                "fields": {
+                    "status": {
+                        "stateMachine": {
+                            "initial": "created",
+                            "transitions": { "created": { "paid": 1 }, "paid": { "shipped": 1 } }
+                        }
+                    }
+                }
+            }
+        }));
+
+        let value = jgd.generate().unwrap();
+        assert_eq!(statuses(&value, "status"), ["created", "paid", "shipped", "created", "paid"]);
+    }
+
+    #[test]
+    fn test_state_machine_keeps_a_sequence_per_key() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 3,
+            "root": {
+                "count": 40,
+                "fields": {
+                    "order": { "number": { "min": 1, "max": 3, "integer": true } },
+                    "status": {
+                        "stateMachine": {
+                            "initial": "created",
+                            "by": "order",
+                            "transitions": {
+                                "created": { "paid": 0.8, "cancelled": 0.2 },
+                                "paid": { "shipped": 1 },
+                                "shipped": { "delivered": 1 }
+                            }
+                        }
+                    }
+                }
+            }
+        }));
+
+        let allowed = [
+            ("created", "paid"), ("created", "cancelled"), ("paid", "shipped"), ("shipped", "delivered"),
+            ("cancelled", "created"), ("delivered", "created"),
+        ];
+        let value = jgd.generate().unwrap();
+        let mut last: HashMap<i64, String> = HashMap::new();
+        for item in value.as_array().unwrap() {
+            let status = item["status"].as_str().unwrap().to_string();
+            match last.insert(item["order"].as_i64().unwrap(), status.clone()) {
+                Some(previous) => assert!(allowed.contains(&(previous.as_str(), status.as_str())), "{} -> {}", previous, status),
+                None => assert_eq!(status, "created"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_state_machine_requires_a_previous_by_field() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "status": { "stateMachine": { "initial": "new", "by": "order" } } } }
+        }));
+
+        let error = jgd.generate().unwrap_err();
+        assert_eq!(error.message, "Error to generate the state machine starting at new: the field order must be declared before the state machine field");
+        assert_eq!(error.path.as_deref(), Some("/root/fields/status"));
+    }
+}
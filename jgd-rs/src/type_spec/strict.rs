@@ -0,0 +1,457 @@
+//! Strict schema parsing: reports unknown properties instead of ignoring them.
+//!
+//! Serde ignores unknown properties, so a typo like `"feilds"` or `"uniqueby"`
+//! silently produces an empty entity or drops an option. Strict parsing walks the
+//! raw document against the properties each schema object accepts and fails with
+//! the JSON Pointer of every unknown property.
+//!
+//! Multi-word properties are camelCase (`defaultLocale`, `uniqueBy`, `noSelfLoops`).
+//! Their snake_case spellings are still accepted as deprecated aliases and reported
+//! by [`deprecated_fields`].
+
+use serde_json::{Map, Value};
+
+use crate::{format_error, FormatVersion, Jgd, JgdErrorKind, JgdGeneratorError};
+
+/// Schema-wide properties, with the format version that introduced each of them.
+const JGD_KEYS: &[(&str, FormatVersion)] = &[
+    ("$format", FormatVersion::V1), ("$schema", FormatVersion::V1), ("version", FormatVersion::V1),
+    ("seed", FormatVersion::V1), ("defaultLocale", FormatVersion::V1), ("entities", FormatVersion::V1),
+    ("root", FormatVersion::V1), ("referenceDate", FormatVersion::V2), ("safeEmails", FormatVersion::V2),
+    ("localeFallback", FormatVersion::V2), ("limits", FormatVersion::V2), ("fuzz", FormatVersion::V2),
+    ("realism", FormatVersion::V2), ("constants", FormatVersion::V2), ("mixins", FormatVersion::V2),
+    ("checks", FormatVersion::V2), ("keyCase", FormatVersion::V2),
+];
+/// Entity properties, with the format version that introduced each of them.
+const ENTITY_KEYS: &[(&str, FormatVersion)] = &[
+    ("count", FormatVersion::V1), ("seed", FormatVersion::V1), ("uniqueBy", FormatVersion::V1),
+    ("fields", FormatVersion::V1), ("include", FormatVersion::V2), ("relation", FormatVersion::V2),
+    ("transforms", FormatVersion::V2), ("constants", FormatVersion::V2), ("corruption", FormatVersion::V2),
+    ("correlations", FormatVersion::V2), ("localeMix", FormatVersion::V2), ("localeFrom", FormatVersion::V2),
+    ("variants", FormatVersion::V2), ("flatten", FormatVersion::V2),
+];
+/// Keys naming the kind of a field, with the format version that introduced each of them.
+const FIELD_KINDS: &[(&str, FormatVersion)] = &[
+    ("array", FormatVersion::V1), ("number", FormatVersion::V1), ("optional", FormatVersion::V1),
+    ("ref", FormatVersion::V1), ("map", FormatVersion::V2), ("tuple", FormatVersion::V2), ("raw", FormatVersion::V2),
+    ("literal", FormatVersion::V2), ("address", FormatVersion::V2), ("money", FormatVersion::V2),
+    ("partition", FormatVersion::V2), ("color", FormatVersion::V2), ("cumulative", FormatVersion::V2),
+    ("stateMachine", FormatVersion::V2), ("timeline", FormatVersion::V2), ("trace", FormatVersion::V2),
+    ("geojson", FormatVersion::V2), ("jsonString", FormatVersion::V2), ("randomString", FormatVersion::V2),
+];
+const VARIANT_KEYS: &[&str] = &["weight", "fields"];
+const ARRAY_KEYS: &[(&str, FormatVersion)] = &[
+    ("of", FormatVersion::V1), ("count", FormatVersion::V1), ("probNull", FormatVersion::V2),
+    ("probEmpty", FormatVersion::V2), ("targetSize", FormatVersion::V2),
+];
+const OPTIONAL_KEYS: &[&str] = &["of", "prob"];
+const NUMBER_KEYS: &[&str] = &["min", "max", "integer"];
+const REF_KEYS: &[(&str, FormatVersion)] = &[("ref", FormatVersion::V1), ("distribution", FormatVersion::V2)];
+const MAP_KEYS: &[&str] = &["count", "key", "value"];
+const ADDRESS_KEYS: &[&str] = &["formatted"];
+const MONEY_KEYS: &[&str] = &["min", "max", "currency", "formatted"];
+const PARTITION_KEYS: &[&str] = &["count", "total", "min", "decimals"];
+const COLOR_KEYS: &[&str] = &["format", "alpha"];
+const CUMULATIVE_KEYS: &[&str] = &["of", "initial", "decimals"];
+const STATE_MACHINE_KEYS: &[&str] = &["initial", "transitions", "by"];
+const TIMELINE_KEYS: &[&str] = &["start", "minGap", "maxGap", "format"];
+const GEOJSON_KEYS: &[&str] = &["count", "geometry", "bbox", "vertices", "radius", "properties"];
+const TRACE_KEYS: &[&str] = &["services", "spans", "name", "minDuration", "maxDuration", "attributes", "errorRate"];
+const RANDOM_STRING_KEYS: &[&str] = &["length", "charset"];
+const JSON_STRING_KEYS: &[&str] = &["of", "pretty"];
+const TRANSFORMED_KEYS: &[&str] = &["of", "transforms"];
+const FORMATTED_KEYS: &[&str] = &["format", "of"];
+const LIMITED_KEYS: &[&str] = &["of", "length"];
+const LENGTH_KEYS: &[&str] = &["min", "max", "overflow", "retries"];
+const ASSERTED_KEYS: &[&str] = &["of", "assert"];
+const ASSERT_KEYS: &[&str] = &["pattern", "minLength", "maxLength", "min", "max", "retries"];
+const TAGGED_KEYS: &[&str] = &["of", "pii", "tags"];
+const ID_FROM_KEYS: &[&str] = &["idFrom", "algo"];
+const RELATION_KEYS: &[&str] = &["from", "to", "count", "noSelfLoops", "unique", "fromField", "toField"];
+const CORRUPTION_KEYS: &[&str] = &["duplicates", "nulls", "typos", "outliers"];
+const LOCALE_FROM_KEYS: &[&str] = &["field", "locales"];
+const LIMITS_KEYS: &[&str] = &["maxRecords", "maxDepth", "maxOutputBytes"];
+const FUZZ_KEYS: &[&str] = &["dropOptional", "boundaries"];
+
+/// Deprecated spellings and the canonical property they stand for.
+const DEPRECATED_KEYS: &[(&str, &str)] = &[
+    ("default_locale", "defaultLocale"),
+    ("key_case", "keyCase"),
+    ("unique_by", "uniqueBy"),
+    ("no_self_loops", "noSelfLoops"),
+    ("from_field", "fromField"),
+    ("to_field", "toField"),
+    ("max_records", "maxRecords"),
+    ("max_depth", "maxDepth"),
+    ("max_output_bytes", "maxOutputBytes"),
+];
+
+fn pointer(path: &str, key: &str) -> String {
+    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"))
+}
+
+#[derive(Default)]
+struct Checker {
+    unknown: Vec<String>,
+    deprecated: Vec<String>,
+    /// Properties newer than `jgd/v1`, with the format version that introduced them.
+    versioned: Vec<(String, FormatVersion)>,
+}
+
+impl Checker {
+    /// Records the keys of `object` that are not in `known` or are deprecated spellings of them.
+    fn keys(&mut self, path: &str, object: &Map<String, Value>, known: &[&str]) {
+        for key in object.keys().filter(|key| !known.contains(&key.as_str())) {
+            let canonical = DEPRECATED_KEYS.iter()
+                .find(|(deprecated, canonical)| deprecated == key && known.contains(canonical));
+            match canonical {
+                Some((_, canonical)) => self.deprecated.push(
+                    format!("{} is deprecated, use {}", pointer(path, key), canonical)
+                ),
+                None => self.unknown.push(pointer(path, key)),
+            }
+        }
+    }
+
+    /// Checks the keys of `object` against `known` and records the ones newer than `jgd/v1`.
+    fn versioned_keys(&mut self, path: &str, object: &Map<String, Value>, known: &[(&str, FormatVersion)]) {
+        let names: Vec<&str> = known.iter().map(|(key, _)| *key).collect();
+        self.keys(path, object, &names);
+        for (key, version) in known {
+            if *version > FormatVersion::V1 && object.contains_key(*key) {
+                self.versioned.push((pointer(path, key), *version));
+            }
+        }
+    }
+
+    /// Records the wrapper `key` of a field, added by `jgd/v2`.
+    fn v2(&mut self, path: &str, key: &str) {
+        self.versioned.push((pointer(path, key), FormatVersion::V2));
+    }
+
+    fn object(&mut self, path: &str, value: Option<&Value>, known: &[&str]) {
+        if let Some(Value::Object(object)) = value {
+            self.keys(path, object, known);
+        }
+    }
+
+    fn entity(&mut self, path: &str, entity: &Value) {
+        let Value::Object(entity) = entity else {
+            return;
+        };
+
+        self.versioned_keys(path, entity, ENTITY_KEYS);
+        self.object(&pointer(path, "relation"), entity.get("relation"), RELATION_KEYS);
+        self.object(&pointer(path, "corruption"), entity.get("corruption"), CORRUPTION_KEYS);
+        self.object(&pointer(path, "localeFrom"), entity.get("localeFrom"), LOCALE_FROM_KEYS);
+
+        self.fields(path, entity);
+
+        if let Some(Value::Array(variants)) = entity.get("variants") {
+            for (index, variant) in variants.iter().enumerate() {
+                let path = pointer(&pointer(path, "variants"), &index.to_string());
+                if let Value::Object(variant) = variant {
+                    self.keys(&path, variant, VARIANT_KEYS);
+                    self.fields(&path, variant);
+                }
+            }
+        }
+    }
+
+    /// Checks the `fields` of an entity or variant.
+    fn fields(&mut self, path: &str, object: &Map<String, Value>) {
+        if let Some(Value::Object(fields)) = object.get("fields") {
+            let path = pointer(path, "fields");
+            for (name, field) in fields {
+                self.field(&pointer(&path, name), field);
+            }
+        }
+    }
+
+    /// Checks a field, picking the field kind the same way `Field` deserialization does.
+    fn field(&mut self, path: &str, field: &Value) {
+        let Value::Object(object) = field else {
+            return;
+        };
+
+        let wrapper = FIELD_KINDS.iter().find(|(key, _)| object.contains_key(*key));
+        match wrapper {
+            Some(&(wrapper, version)) => {
+                let known = [(wrapper, version)];
+                self.versioned_keys(path, object, if wrapper == "ref" { REF_KEYS } else { &known });
+                let path = pointer(path, wrapper);
+                let spec = object.get(wrapper);
+                match wrapper {
+                    "array" => {
+                        if let Some(Value::Object(array)) = spec {
+                            self.versioned_keys(&path, array, ARRAY_KEYS);
+                        }
+                        if let Some(of) = spec.and_then(|spec| spec.get("of")) {
+                            self.field(&pointer(&path, "of"), of);
+                        }
+                    },
+                    "optional" => self.spec(&path, spec, OPTIONAL_KEYS),
+                    "number" => self.object(&path, spec, NUMBER_KEYS),
+                    "map" => {
+                        self.object(&path, spec, MAP_KEYS);
+                        for key in ["key", "value"] {
+                            if let Some(field) = spec.and_then(|spec| spec.get(key)) {
+                                self.field(&pointer(&path, key), field);
+                            }
+                        }
+                    },
+                    "tuple" => {
+                        for (position, field) in spec.and_then(Value::as_array).into_iter().flatten().enumerate() {
+                            self.field(&pointer(&path, &position.to_string()), field);
+                        }
+                    },
+                    "address" => self.object(&path, spec, ADDRESS_KEYS),
+                    "money" => self.object(&path, spec, MONEY_KEYS),
+                    "partition" => self.object(&path, spec, PARTITION_KEYS),
+                    "color" => self.object(&path, spec, COLOR_KEYS),
+                    "cumulative" => self.object(&path, spec, CUMULATIVE_KEYS),
+                    "stateMachine" => self.object(&path, spec, STATE_MACHINE_KEYS),
+                    "timeline" => self.object(&path, spec, TIMELINE_KEYS),
+                    "trace" => {
+                        self.object(&path, spec, TRACE_KEYS);
+                        if let Some(name) = spec.and_then(|spec| spec.get("name")) {
+                            self.field(&pointer(&path, "name"), name);
+                        }
+                        if let Some(Value::Object(attributes)) = spec.and_then(|spec| spec.get("attributes")) {
+                            let path = pointer(&path, "attributes");
+                            for (key, field) in attributes {
+                                self.field(&pointer(&path, key), field);
+                            }
+                        }
+                    },
+                    "jsonString" => self.spec(&path, spec, JSON_STRING_KEYS),
+                    "randomString" => self.object(&path, spec, RANDOM_STRING_KEYS),
+                    "geojson" => {
+                        self.object(&path, spec, GEOJSON_KEYS);
+                        if let Some(Value::Object(properties)) = spec.and_then(|spec| spec.get("properties")) {
+                            let path = pointer(&path, "properties");
+                            for (key, field) in properties {
+                                self.field(&pointer(&path, key), field);
+                            }
+                        }
+                    },
+                    _ => {},
+                }
+            },
+            None if object.contains_key("of") && object.contains_key("transforms") => {
+                self.v2(path, "transforms");
+                self.spec(path, Some(field), TRANSFORMED_KEYS);
+            },
+            None if object.contains_key("format") && object.contains_key("of") => {
+                self.v2(path, "format");
+                self.spec(path, Some(field), FORMATTED_KEYS);
+            },
+            None if object.contains_key("of") && object.contains_key("length") => {
+                self.v2(path, "length");
+                self.spec(path, Some(field), LIMITED_KEYS);
+                self.object(&pointer(path, "length"), object.get("length"), LENGTH_KEYS);
+            },
+            None if object.contains_key("of") && object.contains_key("assert") => {
+                self.v2(path, "assert");
+                self.spec(path, Some(field), ASSERTED_KEYS);
+                self.object(&pointer(path, "assert"), object.get("assert"), ASSERT_KEYS);
+            },
+            None if object.contains_key("of") => {
+                self.v2(path, ["pii", "tags"].into_iter().find(|key| object.contains_key(*key)).unwrap_or("of"));
+                self.spec(path, Some(field), TAGGED_KEYS);
+            },
+            None if object.contains_key("idFrom") => {
+                self.v2(path, "idFrom");
+                self.object(path, Some(field), ID_FROM_KEYS);
+            },
+            None => self.entity(path, field),
+        }
+    }
+
+    /// Checks a spec with an `of` field.
+    fn spec(&mut self, path: &str, spec: Option<&Value>, known: &[&str]) {
+        self.object(path, spec, known);
+        if let Some(of) = spec.and_then(|spec| spec.get("of")) {
+            self.field(&pointer(path, "of"), of);
+        }
+    }
+
+    fn jgd(&mut self, document: &Value) {
+        let Value::Object(jgd) = document else {
+            return;
+        };
+
+        self.versioned_keys("", jgd, JGD_KEYS);
+        self.object("/limits", jgd.get("limits"), LIMITS_KEYS);
+        self.object("/fuzz", jgd.get("fuzz"), FUZZ_KEYS);
+
+        if let Some(Value::Object(mixins)) = jgd.get("mixins") {
+            for (name, fields) in mixins {
+                let path = pointer("/mixins", name);
+                for (field_name, field) in fields.as_object().into_iter().flatten() {
+                    self.field(&pointer(&path, field_name), field);
+                }
+            }
+        }
+
+        if let Some(root) = jgd.get("root") {
+            self.field("/root", root);
+        }
+
+        if let Some(Value::Object(entities)) = jgd.get("entities") {
+            for (name, entity) in entities {
+                self.entity(&pointer("/entities", name), entity);
+            }
+        }
+    }
+}
+
+/// Returns the JSON Pointers of the properties of `document` that the schema does not know.
+pub(crate) fn unknown_fields(document: &Value) -> Vec<String> {
+    let mut checker = Checker::default();
+    checker.jgd(document);
+    checker.unknown
+}
+
+/// Returns the JSON Pointers of the properties of `document` newer than `jgd/v1`,
+/// with the format version that introduced each of them.
+pub(crate) fn versioned_fields(document: &Value) -> Vec<(String, FormatVersion)> {
+    let mut checker = Checker::default();
+    checker.jgd(document);
+    checker.versioned
+}
+
+/// Returns a warning for each deprecated property spelling used in `document`.
+pub(crate) fn deprecated_fields(document: &Value) -> Vec<String> {
+    let mut checker = Checker::default();
+    checker.jgd(document);
+    checker.deprecated
+}
+
+/// Parses `document`, failing on unknown properties and unsupported formats.
+pub(crate) fn parse(document: Value) -> Result<Jgd, JgdGeneratorError> {
+    #[cfg(feature = "tracing")]
+    for warning in deprecated_fields(&document).into_iter().chain(crate::format_warnings(&document)) {
+        trace_event!(warn, "{}", warning);
+    }
+
+    if let Some(error) = format_error(&document) {
+        return Err(JgdGeneratorError {
+            message: format!("Error to parse the schema: {}", error),
+            kind: JgdErrorKind::Schema,
+            path: Some("/$format".into()),
+            ..Default::default()
+        });
+    }
+
+    let unknown = unknown_fields(&document);
+    if let Some(first) = unknown.first() {
+        return Err(JgdGeneratorError {
+            message: format!("Error to parse the schema: unknown fields {}", unknown.join(", ")),
+            kind: JgdErrorKind::Schema,
+            path: Some(first.as_str().into()),
+            ..Default::default()
+        });
+    }
+
+    serde_json::from_value(document).map_err(|error| JgdGeneratorError {
+        message: format!("Error to parse the schema. Details: {}", error),
+        kind: JgdErrorKind::Schema,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_fields_are_reported_with_pointers() {
+        let document = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "sed": 1,
+            "limits": { "maxRecord": 1 },
+            "mixins": { "audit": { "at": { "number": { "minn": 1, "max": 2 } } } },
+            "entities": {
+                "users": {
+                    "count": 1,
+                    "include": ["audit"],
+                    "uniqueby": ["id"],
+                    "fields": {
+                        "id": "${ulid}",
+                        "address": { "feilds": { "city": "Lyon" } },
+                        "tags": { "array": { "of": { "optional": { "of": 1, "probability": 0.5 } } }, "count": 2 },
+                        "age": { "number": { "min": 1, "max": 2, "int": true } },
+                        "user": { "ref": "users.id", "distribution": "zipf", "weight": 2 },
+                        "total": { "cumulative": { "of": "age", "start": 1 } },
+                        "scores": { "map": { "key": "${index}", "value": { "number": { "min": 1, "max": 2, "integr": true } } } }
+                    }
+                },
+                "links": { "relation": { "from": "users.id", "to": "users.id", "noSelfLoop": true } },
+                "events": { "variants": [{ "wieght": 1, "fields": { "n": { "number": { "mn": 1, "max": 2 } } } }] }
+            }
+        });
+
+        assert_eq!(unknown_fields(&document), vec![
+            "/sed",
+            "/limits/maxRecord",
+            "/mixins/audit/at/number/minn",
+            "/entities/users/uniqueby",
+            "/entities/users/fields/address/feilds",
+            "/entities/users/fields/tags/count",
+            "/entities/users/fields/tags/array/of/optional/probability",
+            "/entities/users/fields/age/number/int",
+            "/entities/users/fields/user/weight",
+            "/entities/users/fields/total/cumulative/start",
+            "/entities/users/fields/scores/map/value/number/integr",
+            "/entities/links/relation/noSelfLoop",
+            "/entities/events/variants/0/wieght",
+            "/entities/events/variants/0/fields/n/number/mn",
+        ]);
+    }
+
+    #[test]
+    fn test_deprecated_spellings_are_accepted() {
+        let document = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "default_locale": "FR",
+            "limits": { "max_records": 10 },
+            "entities": {
+                "users": { "count": 2, "unique_by": ["id"], "fields": { "id": "${index}", "unique_by": "x" } },
+                "links": { "relation": { "from": "users.id", "to": "users.id", "from_field": "a" } }
+            }
+        });
+
+        assert_eq!(deprecated_fields(&document), vec![
+            "/default_locale is deprecated, use defaultLocale",
+            "/limits/max_records is deprecated, use maxRecords",
+            "/entities/users/unique_by is deprecated, use uniqueBy",
+            "/entities/links/relation/from_field is deprecated, use fromField",
+        ]);
+
+        let jgd = parse(document).unwrap();
+        assert_eq!(jgd.default_locale, "FR");
+        assert_eq!(jgd.limits.max_records, Some(10));
+        let entities = jgd.entities.as_ref().unwrap();
+        assert_eq!(entities["users"].unique_by, vec!["id"]);
+        assert_eq!(entities["links"].relation.as_ref().unwrap().from_field, "a");
+        assert_eq!(serde_json::to_value(&entities["users"]).unwrap()["uniqueBy"], json!(["id"]));
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let schema = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "name": { "of": "${lorem.word}", "transforms": ["upper"] } } }
+        });
+        assert!(parse(schema).is_ok());
+
+        let error = parse(json!({ "$format": "jgd/v1", "version": "1.0", "root": { "feilds": {} } })).unwrap_err();
+        assert_eq!(error.message, "Error to parse the schema: unknown fields /root/feilds");
+        assert_eq!(error.path.as_deref(), Some("/root/feilds"));
+    }
+}
@@ -0,0 +1,192 @@
+//! Output paths of the fields classified with `pii` or `tags`.
+//!
+//! A [`TagReport`] is built from the schema, without generating data, by
+//! [`Jgd::tag_report`]. Paths are JSONPath expressions over the generated document,
+//! with the output key names (after `keyCase`):
+//!
+//! ```text
+//! $.users[*].email: pii, contact
+//! $.users[*].addresses[*].street: pii
+//! ```
+
+use std::fmt::Display;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::{type_spec::{Entity, Field, Root}, Jgd, KeyCase};
+
+/// Tags of a `Tagged` field, with `pii` first when set.
+pub(crate) fn field_tags(pii: bool, tags: &[String]) -> Vec<String> {
+    let mut all: Vec<String> = pii.then(|| "pii".to_string()).into_iter().collect();
+    for tag in tags {
+        if !all.contains(tag) {
+            all.push(tag.clone());
+        }
+    }
+    all
+}
+
+/// Tags carried by each output path of a schema.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TagReport {
+    /// Tags of each output path, in schema order.
+    pub paths: IndexMap<String, Vec<String>>,
+}
+
+impl TagReport {
+    pub(crate) fn new(jgd: &Jgd) -> Self {
+        let mut builder = ReportBuilder { key_case: jgd.key_case, report: TagReport::default() };
+        match &jgd.root {
+            Some(Root::Entity(root)) => builder.entity("$", root),
+            Some(Root::Array { array }) => builder.field("$[*]", &array.of),
+            Some(Root::Value(field)) => builder.field("$", field),
+            None => {},
+        }
+        for (name, entity) in jgd.entities.iter().flatten() {
+            builder.entity(&member("$", name), entity);
+        }
+        builder.report
+    }
+
+    /// Output paths carrying `tag`, e.g. every `pii` path.
+    pub fn paths_with(&self, tag: &str) -> Vec<&str> {
+        self.paths.iter()
+            .filter(|(_, tags)| tags.iter().any(|path_tag| path_tag == tag))
+            .map(|(path, _)| path.as_str())
+            .collect()
+    }
+}
+
+impl Display for TagReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (path, tags) in &self.paths {
+            writeln!(f, "{}: {}", path, tags.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// `path.name`, or `path['name']` when the name is not a plain identifier.
+pub(crate) fn member(path: &str, name: &str) -> String {
+    if !name.is_empty() && name.chars().all(|char| char.is_alphanumeric() || char == '_' || char == '-') {
+        format!("{}.{}", path, name)
+    } else {
+        format!("{}['{}']", path, name.replace('\\', "\\\\").replace('\'', "\\'"))
+    }
+}
+
+struct ReportBuilder {
+    key_case: Option<KeyCase>,
+    report: TagReport,
+}
+
+impl ReportBuilder {
+    /// Visits the fields of an entity (and of its variants, which share its path).
+    fn entity(&mut self, path: &str, entity: &Entity) {
+        let path = if entity.is_single() { path.to_string() } else { format!("{}[*]", path) };
+        let fields = entity.fields.iter()
+            .chain(entity.variants.iter().flat_map(|variant| variant.fields.iter()));
+        for (name, field) in fields {
+            if let Field::Entity(inner) = field {
+                if inner.flatten {
+                    self.entity(&path, inner);
+                    continue;
+                }
+            }
+            let name = self.key_case.map_or_else(|| name.clone(), |key_case| key_case.convert(name));
+            self.field(&member(&path, &name), field);
+        }
+    }
+
+    fn field(&mut self, path: &str, field: &Field) {
+        match field {
+            Field::Tagged { of, pii, tags } => {
+                let path_tags = self.report.paths.entry(path.to_string()).or_default();
+                for tag in field_tags(*pii, tags) {
+                    if !path_tags.contains(&tag) {
+                        path_tags.push(tag);
+                    }
+                }
+                self.field(path, of);
+            },
+            Field::Array { array } => self.field(&format!("{}[*]", path), &array.of),
+            Field::Tuple { tuple } => {
+                for (position, field) in tuple.iter().enumerate() {
+                    self.field(&format!("{}[{}]", path, position), field);
+                }
+            },
+            Field::Map { map } => self.field(&format!("{}.*", path), &map.value),
+            Field::Trace { trace } => {
+                for (key, field) in &trace.attributes {
+                    self.field(&format!("{}.resourceSpans[*].scopeSpans[*].spans[*].attributes.{}", path, key), field);
+                }
+            },
+            Field::GeoJson { geojson } => {
+                for (key, field) in &geojson.properties {
+                    self.field(&format!("{}.features[*].properties.{}", path, key), field);
+                }
+            },
+            Field::Optional { optional } => self.field(path, &optional.of),
+            Field::JsonString { json_string } => self.field(path, &json_string.of),
+            Field::Transformed { of, .. } | Field::Formatted { of, .. } | Field::Limited { of, .. }
+                | Field::Asserted { of, .. } => self.field(path, of),
+            Field::Entity(entity) => self.entity(path, entity),
+            _ => {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_tag_report_lists_output_paths() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "keyCase": "snake_case",
+            "entities": {
+                "users": {
+                    "count": 2,
+                    "fields": {
+                        "fullName": { "of": "${name.name}", "pii": true },
+                        "contactEmail": { "of": { "of": "${internet.safeEmail}", "transforms": ["lower"] }, "pii": true, "tags": ["contact", "pii"] },
+                        "addresses": { "array": { "count": 1, "of": { "fields": { "street": { "of": "${address.streetName}", "tags": ["location"] } } } } },
+                        "id": "${ulid}"
+                    }
+                },
+                "app settings": { "fields": { "apiKey": { "of": "${uuid.v4}", "tags": ["secret"] } } }
+            }
+        }));
+
+        let report = jgd.tag_report();
+        assert_eq!(report.to_string(), "\
+$.users[*].full_name: pii
+$.users[*].contact_email: pii, contact
+$.users[*].addresses[*].street: location
+$['app settings'].api_key: secret
+");
+        assert_eq!(report.paths_with("pii"), vec!["$.users[*].full_name", "$.users[*].contact_email"]);
+
+        let generated = jgd.generate().unwrap();
+        assert!(generated["users"][0]["contact_email"].as_str().unwrap().contains('@'));
+    }
+
+    #[test]
+    fn test_tags_cannot_share_an_object_with_another_wrapper() {
+        let error = serde_json::from_value::<Field>(json!({ "of": "abc", "transforms": ["upper"], "pii": true })).unwrap_err();
+        assert_eq!(error.to_string(), "the keys transforms and pii cannot be combined in one field, nest one of them in `of`");
+        assert!(serde_json::from_value::<Field>(json!({ "of": "abc", "format": "#{}", "tags": ["id"] })).is_err());
+
+        let jgd = serde_json::from_value::<Jgd>(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "code": { "of": "abc", "transforms": ["upper"], "pii": true } } }
+        }));
+        assert!(jgd.is_err());
+    }
+}
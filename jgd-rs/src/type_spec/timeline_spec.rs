@@ -0,0 +1,243 @@
+//! # Timeline Specification Module
+//!
+//! Timestamps that increase strictly across the items of a generated array, for event
+//! logs and histories that must be in chronological order without sorting them after
+//! generation:
+//!
+//! ```json
+//! {
+//!   "events": {
+//!     "count": 5,
+//!     "fields": {
+//!       "at": { "timeline": { "start": "2025-01-01T08:00:00Z", "minGap": "1m", "maxGap": "2h" } },
+//!       "day": { "timeline": { "minGap": "1d", "maxGap": "3d", "format": "%Y-%m-%d" } }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Gaps are a number followed by a unit: `ms`, `s`, `m`, `h`, `d` or `w`; a plain
+//! number is a number of seconds. With a `format`, `minGap` must be at least the
+//! smallest unit the format shows (`1d` for `%Y-%m-%d`), so the formatted values
+//! increase too.
+
+use chrono::{format::{Fixed, Item, Numeric, StrftimeItems}, DateTime, Duration, FixedOffset, NaiveDate, SecondsFormat};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{type_spec::JsonGenerator, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig, SpecId};
+
+fn default_min_gap() -> String {
+    "1s".to_string()
+}
+
+fn default_max_gap() -> String {
+    "1h".to_string()
+}
+
+/// Parses a gap such as `90s`, `5m` or `1d` into milliseconds.
+pub(crate) fn parse_gap(gap: &str) -> Option<i64> {
+    let gap = gap.trim();
+    let split = gap.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(gap.len());
+    let (number, unit) = gap.split_at(split);
+    let millis = match unit.trim() {
+        "ms" => 1.0,
+        "" | "s" => 1_000.0,
+        "m" => 60_000.0,
+        "h" => 3_600_000.0,
+        "d" => 86_400_000.0,
+        "w" => 604_800_000.0,
+        _ => return None,
+    };
+    number.parse::<f64>().ok().map(|number| (number * millis).round() as i64)
+}
+
+/// Returns the smallest gap, in milliseconds, that always changes a value formatted
+/// with `format`, or `None` when the pattern is not a valid `strftime` format.
+fn format_precision(format: &str) -> Option<i64> {
+    const DAY: i64 = 86_400_000;
+    let mut precision = i64::MAX;
+    for item in StrftimeItems::new(format) {
+        let unit = match item {
+            Item::Error => return None,
+            Item::Numeric(Numeric::Nanosecond, _)
+            | Item::Fixed(Fixed::Nanosecond | Fixed::Nanosecond3 | Fixed::Nanosecond6 | Fixed::Nanosecond9 | Fixed::RFC3339) => 1,
+            Item::Numeric(Numeric::Second | Numeric::Timestamp, _) | Item::Fixed(Fixed::RFC2822) => 1_000,
+            Item::Numeric(Numeric::Minute, _) => 60_000,
+            Item::Numeric(Numeric::Hour | Numeric::Hour12, _) => 3_600_000,
+            Item::Numeric(Numeric::Day | Numeric::Ordinal, _) => DAY,
+            Item::Numeric(Numeric::WeekFromSun | Numeric::WeekFromMon | Numeric::IsoWeek, _) => 7 * DAY,
+            Item::Numeric(Numeric::Month, _) | Item::Fixed(Fixed::ShortMonthName | Fixed::LongMonthName) => 31 * DAY,
+            Item::Numeric(Numeric::Quarter, _) => 92 * DAY,
+            Item::Numeric(Numeric::Year | Numeric::IsoYear | Numeric::YearMod100 | Numeric::IsoYearMod100, _) => 366 * DAY,
+            _ => continue,
+        };
+        precision = precision.min(unit);
+    }
+    Some(precision)
+}
+
+/// Specification of a timestamp later than the one of the previous item.
+///
+/// The first item of each generated array gets `start` (a random instant of the last
+/// year when not set) and every following item adds a random gap between `minGap` and
+/// `maxGap`. Timelines are kept per generated array, like cumulative fields.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineSpec {
+    /// Timestamp of the first item, in RFC 3339 (`2025-01-01T08:00:00Z`) or as a date (`2025-01-01`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+
+    /// Smallest gap between two items; it must be greater than zero.
+    #[serde(default = "default_min_gap")]
+    pub min_gap: String,
+
+    /// Largest gap between two items.
+    #[serde(default = "default_max_gap")]
+    pub max_gap: String,
+
+    /// `strftime` format of the values, e.g. `%Y-%m-%d`; RFC 3339 when not set.
+    ///
+    /// `minGap` must be at least the smallest unit shown by the format, e.g. `1d` for
+    /// `%Y-%m-%d`, otherwise consecutive items could get the same value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Key of the running state of this field; not part of the schema.
+    #[serde(skip)]
+    pub id: SpecId,
+}
+
+impl TimelineSpec {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to generate the timeline: {}", details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+
+    fn gaps(&self) -> Result<(i64, i64), JgdGeneratorError> {
+        let gap = |gap: &str| parse_gap(gap).ok_or_else(|| self.error(&format!("invalid gap {}", gap)));
+        let (min, max) = (gap(&self.min_gap)?, gap(&self.max_gap)?);
+        if min <= 0 {
+            return Err(self.error("minGap must be greater than zero"));
+        }
+        if min > max {
+            return Err(self.error(&format!("minGap {} is greater than maxGap {}", self.min_gap, self.max_gap)));
+        }
+        if let Some(format) = &self.format {
+            let precision = format_precision(format).ok_or_else(|| self.error(&format!("invalid format {}", format)))?;
+            if min < precision {
+                return Err(self.error(&format!(
+                    "minGap {} is shorter than the smallest unit of the format {}, so values would repeat",
+                    self.min_gap, format
+                )));
+            }
+        }
+        Ok((min, max))
+    }
+
+    fn start(&self, config: &mut GeneratorConfig) -> Result<DateTime<FixedOffset>, JgdGeneratorError> {
+        let Some(start) = &self.start else {
+            let millis = config.rng.random_range(0..Duration::days(365).num_milliseconds());
+            return Ok((config.now() - Duration::milliseconds(millis)).fixed_offset());
+        };
+
+        DateTime::parse_from_rfc3339(start)
+            .ok()
+            .or_else(|| NaiveDate::parse_from_str(start, "%Y-%m-%d").ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|date| date.and_utc().fixed_offset()))
+            .ok_or_else(|| self.error(&format!("invalid start {}", start)))
+    }
+}
+
+impl JsonGenerator for TimelineSpec {
+    /// Moves the timeline of this field forward by a random gap and returns the timestamp.
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        let Some(local_config) = local_config else {
+            return Err(self.error("timeline fields must be fields of an object"));
+        };
+        let (min_gap, max_gap) = self.gaps()?;
+
+        let previous = local_config.array_state.borrow().timestamps.get(&self.id).copied();
+        let timestamp = match previous {
+            Some(previous) => previous + Duration::milliseconds(config.rng.random_range(min_gap..=max_gap)),
+            None => self.start(config)?,
+        };
+        local_config.array_state.borrow_mut().timestamps.insert(self.id, timestamp);
+
+        Ok(Value::String(match &self.format {
+            Some(format) => timestamp.format(format).to_string(),
+            None => timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    #[test]
+    fn test_timeline_increases_across_items() {
+        assert_eq!((parse_gap("90"), parse_gap("5m"), parse_gap("1.5h"), parse_gap("2 d")), (Some(90_000), Some(300_000), Some(5_400_000), Some(172_800_000)));
+        assert_eq!(parse_gap("5y"), None);
+
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "count": 50,
+                "fields": {
+                    "at": { "timeline": { "start": "2025-01-01T08:00:00Z", "minGap": "1m", "maxGap": "2h" } },
+                    "day": { "timeline": { "start": "2025-01-01", "minGap": "1d", "maxGap": "3d", "format": "%Y-%m-%d" } }
+                }
+            }
+        }));
+
+        let generated = jgd.generate().unwrap();
+        let items = generated.as_array().unwrap();
+        assert_eq!(items[0]["at"], "2025-01-01T08:00:00Z");
+        assert_eq!(items[0]["day"], "2025-01-01");
+        for pair in items.windows(2) {
+            let previous = DateTime::parse_from_rfc3339(pair[0]["at"].as_str().unwrap()).unwrap();
+            let next = DateTime::parse_from_rfc3339(pair[1]["at"].as_str().unwrap()).unwrap();
+            assert!(next - previous >= Duration::minutes(1) && next - previous <= Duration::hours(2));
+            assert!(pair[0]["day"].as_str() < pair[1]["day"].as_str());
+        }
+
+        let zero = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "count": 2, "fields": { "at": { "timeline": { "minGap": "0s" } } } }
+        }));
+        assert!(zero.generate().unwrap_err().message.contains("minGap must be greater than zero"));
+    }
+
+    #[test]
+    fn test_timeline_format_must_be_valid_and_precise_enough() {
+        assert_eq!(format_precision("%Y-%m-%d"), Some(86_400_000));
+        assert_eq!(format_precision("%d/%m %H:%M"), Some(60_000));
+        assert_eq!(format_precision("%+"), Some(1));
+        assert_eq!(format_precision("%Q"), None);
+
+        let timeline = |timeline: Value| Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "count": 3, "fields": { "at": { "timeline": timeline } } }
+        })).generate();
+
+        let invalid = timeline(json!({ "format": "%Q" })).unwrap_err();
+        assert!(invalid.message.contains("invalid format %Q"), "{}", invalid.message);
+        let coarse = timeline(json!({ "format": "%Y-%m-%d" })).unwrap_err();
+        assert!(coarse.message.contains("minGap 1s is shorter than the smallest unit of the format %Y-%m-%d"));
+        assert!(timeline(json!({ "format": "%H:%M:%S" })).is_ok());
+    }
+}
@@ -0,0 +1,315 @@
+//! # Trace Specification Module
+//!
+//! OpenTelemetry traces in the OTLP JSON encoding, for observability pipelines,
+//! trace viewers and collectors that need realistic span trees:
+//!
+//! ```json
+//! {
+//!   "trace": {
+//!     "trace": {
+//!       "services": ["checkout", "payments", "postgres"],
+//!       "spans": [4, 12],
+//!       "maxDuration": "800ms",
+//!       "errorRate": 0.05,
+//!       "attributes": {
+//!         "http.status_code": { "number": { "min": 200, "max": 299, "integer": true } },
+//!         "user.id": "${random.uuid}"
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! Each value is an `ExportTraceServiceRequest`: the spans of one trace grouped into
+//! `resourceSpans` by service, with parents that start before and end after their
+//! children.
+
+use chrono::Duration;
+use indexmap::IndexMap;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::{type_spec::{timeline_spec::parse_gap, Count, Field, GetCount, JsonGenerator}, GeneratorConfig, JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Scope name of the generated spans.
+const SCOPE_NAME: &str = "jgd-rs";
+
+/// Span names used when `name` is not set.
+const SPAN_NAMES: &[&str] = &[
+    "GET /api/users", "POST /api/orders", "GET /api/products/{id}", "PUT /api/cart", "SELECT users",
+    "INSERT orders", "cache.get", "cache.set", "auth.verify", "queue.publish", "render", "serialize",
+];
+
+/// OTLP span kinds.
+const SPAN_KIND_INTERNAL: u64 = 1;
+const SPAN_KIND_SERVER: u64 = 2;
+
+/// OTLP status code of failed spans.
+const STATUS_CODE_ERROR: u64 = 2;
+
+fn default_services() -> Vec<String> {
+    vec!["frontend".to_string(), "api".to_string(), "database".to_string()]
+}
+
+fn default_spans() -> Count {
+    Count::Range((3, 10))
+}
+
+fn default_min_duration() -> String {
+    "10ms".to_string()
+}
+
+fn default_max_duration() -> String {
+    "2s".to_string()
+}
+
+/// Specification of an OpenTelemetry trace.
+///
+/// The root span lasts between `minDuration` and `maxDuration` and starts within the
+/// hour before the reference time. Every other span is the child of a span generated
+/// before it and runs within its parent. A span gets a service at random: it is a
+/// `SERVER` span when its service differs from the one of its parent and an
+/// `INTERNAL` span otherwise. `name` and `attributes` are generated once per span,
+/// with `${index}` set to the span position.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceSpec {
+    /// Names of the services the spans belong to (`service.name` resource attribute).
+    #[serde(default = "default_services")]
+    pub services: Vec<String>,
+
+    /// Number of spans of the trace, `[3, 10]` by default.
+    #[serde(default = "default_spans")]
+    pub spans: Count,
+
+    /// Specification of the span names; picked from common operations when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<Field>>,
+
+    /// Shortest duration of the root span, e.g. `10ms`.
+    #[serde(default = "default_min_duration")]
+    pub min_duration: String,
+
+    /// Longest duration of the root span, e.g. `2s`.
+    #[serde(default = "default_max_duration")]
+    pub max_duration: String,
+
+    /// Specifications of the span attributes, by attribute key.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub attributes: IndexMap<String, Field>,
+
+    /// Probability of a span having the error status, from 0 to 1.
+    #[serde(default)]
+    pub error_rate: f64,
+}
+
+/// A generated span, before its conversion to OTLP JSON.
+struct Span {
+    span_id: String,
+    parent: Option<usize>,
+    service: usize,
+    start: i64,
+    end: i64,
+}
+
+/// Converts a generated value to an OTLP `AnyValue`; integers are strings in OTLP JSON.
+fn any_value(value: Value) -> Value {
+    match value {
+        Value::Bool(value) => json!({ "boolValue": value }),
+        Value::Number(number) if number.is_f64() => json!({ "doubleValue": number }),
+        Value::Number(number) => json!({ "intValue": number.to_string() }),
+        Value::String(value) => json!({ "stringValue": value }),
+        Value::Array(values) => json!({ "arrayValue": { "values": values.into_iter().map(any_value).collect::<Vec<_>>() } }),
+        Value::Object(object) => json!({ "kvlistValue": { "values": key_values(object) } }),
+        Value::Null => json!({}),
+    }
+}
+
+/// Converts an object to a list of OTLP `KeyValue`s, skipping null values.
+fn key_values(object: Map<String, Value>) -> Vec<Value> {
+    object.into_iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(key, value)| json!({ "key": key, "value": any_value(value) }))
+        .collect()
+}
+
+fn hex_id(config: &mut GeneratorConfig, bytes: usize) -> String {
+    (0..bytes).map(|_| format!("{:02x}", config.rng.random::<u8>())).collect()
+}
+
+impl TraceSpec {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to generate the trace: {}", details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+
+    /// Duration range of the root span, in nanoseconds.
+    fn durations(&self) -> Result<(i64, i64), JgdGeneratorError> {
+        let duration = |duration: &str| parse_gap(duration)
+            .map(|millis| millis * 1_000_000)
+            .ok_or_else(|| self.error(&format!("invalid duration {}", duration)));
+        let (min, max) = (duration(&self.min_duration)?, duration(&self.max_duration)?);
+        if min <= 0 {
+            return Err(self.error("minDuration must be greater than zero"));
+        }
+        if min > max {
+            return Err(self.error(&format!("minDuration {} is greater than maxDuration {}", self.min_duration, self.max_duration)));
+        }
+        Ok((min, max))
+    }
+
+    /// Generates the span tree: ids, parents, services and times.
+    fn spans(&self, config: &mut GeneratorConfig, count: u64) -> Result<Vec<Span>, JgdGeneratorError> {
+        let (min, max) = self.durations()?;
+        let offset = config.rng.random_range(0..Duration::hours(1).num_nanoseconds().unwrap_or(i64::MAX));
+        let now = config.now().timestamp_nanos_opt()
+            .ok_or_else(|| self.error("the reference time is out of range"))?;
+        let start = now - max - offset;
+
+        let mut spans: Vec<Span> = Vec::with_capacity(count as usize);
+        for i in 0..count as usize {
+            let service = config.rng.random_range(0..self.services.len());
+            let span_id = hex_id(config, 8);
+            let span = match i {
+                0 => Span { span_id, parent: None, service, start, end: start + config.rng.random_range(min..=max) },
+                _ => {
+                    let parent = config.rng.random_range(0..i);
+                    let (parent_start, parent_end) = (spans[parent].start, spans[parent].end);
+                    let start = config.rng.random_range(parent_start..=parent_start + (parent_end - parent_start) / 2);
+                    let end = config.rng.random_range(start + 1..=parent_end);
+                    Span { span_id, parent: Some(parent), service, start, end }
+                },
+            };
+            spans.push(span);
+        }
+        Ok(spans)
+    }
+}
+
+impl JsonGenerator for TraceSpec {
+    /// Generates one trace as an OTLP JSON `ExportTraceServiceRequest`.
+    fn generate(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, JgdGeneratorError> {
+        if self.services.is_empty() {
+            return Err(self.error("services must not be empty"));
+        }
+        let count = self.spans.count(config).max(1);
+
+        config.limits.reserve_records(&mut config.usage, count, local_config.as_deref())?;
+        config.limits.enter_level(&mut config.usage, local_config.as_deref())?;
+
+        let trace_id = hex_id(config, 16);
+        let spans = self.spans(config, count)?;
+        let mut local_config = LocalConfig::from_current_with_config(None, Some(count), local_config);
+
+        let mut services: IndexMap<usize, Vec<Value>> = IndexMap::new();
+        for (i, span) in spans.iter().enumerate() {
+            local_config.set_index(i);
+
+            let name = match &self.name {
+                Some(name) => match name.generate(config, Some(&mut local_config))
+                    .map_err(|error| error.within(&["trace", "name"]))? {
+                    Value::String(name) => name,
+                    name => name.to_string(),
+                },
+                None => SPAN_NAMES[config.rng.random_range(0..SPAN_NAMES.len())].to_string(),
+            };
+            let attributes = match self.attributes.generate(config, Some(&mut local_config))
+                .map_err(|error| error.within(&["trace", "attributes"]))? {
+                Value::Object(attributes) => key_values(attributes),
+                _ => Vec::new(),
+            };
+
+            let parent = span.parent.map(|parent| &spans[parent]);
+            let kind = match parent {
+                Some(parent) if parent.service == span.service => SPAN_KIND_INTERNAL,
+                _ => SPAN_KIND_SERVER,
+            };
+            let status = match config.rng.random_bool(self.error_rate.clamp(0.0, 1.0)) {
+                true => json!({ "code": STATUS_CODE_ERROR, "message": "error" }),
+                false => json!({}),
+            };
+
+            let mut otlp_span = Map::new();
+            otlp_span.insert("traceId".to_string(), Value::String(trace_id.clone()));
+            otlp_span.insert("spanId".to_string(), Value::String(span.span_id.clone()));
+            if let Some(parent) = parent {
+                otlp_span.insert("parentSpanId".to_string(), Value::String(parent.span_id.clone()));
+            }
+            otlp_span.insert("name".to_string(), Value::String(name));
+            otlp_span.insert("kind".to_string(), Value::from(kind));
+            otlp_span.insert("startTimeUnixNano".to_string(), Value::String(span.start.to_string()));
+            otlp_span.insert("endTimeUnixNano".to_string(), Value::String(span.end.to_string()));
+            otlp_span.insert("attributes".to_string(), Value::Array(attributes));
+            otlp_span.insert("status".to_string(), status);
+            services.entry(span.service).or_default().push(Value::Object(otlp_span));
+        }
+
+        config.limits.exit_level(&mut config.usage);
+
+        let resource_spans: Vec<Value> = services.into_iter()
+            .map(|(service, spans)| json!({
+                "resource": { "attributes": [{ "key": "service.name", "value": { "stringValue": self.services[service] } }] },
+                "scopeSpans": [{ "scope": { "name": SCOPE_NAME }, "spans": spans }]
+            }))
+            .collect();
+        Ok(json!({ "resourceSpans": resource_spans }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_trace_spans_nest_within_their_parents() {
+        let mut config = GeneratorConfig::new("EN", Some(12));
+        let spec: TraceSpec = serde_json::from_value(json!({
+            "services": ["checkout", "payments"],
+            "spans": 20,
+            "name": "op-${index}",
+            "errorRate": 1.0,
+            "attributes": { "http.status_code": 200, "retry": true, "ratio": 0.5, "missing": null }
+        })).unwrap();
+
+        let trace = spec.generate(&mut config, None).unwrap();
+        let spans: Vec<&Value> = trace["resourceSpans"].as_array().unwrap().iter()
+            .flat_map(|resource| resource["scopeSpans"][0]["spans"].as_array().unwrap())
+            .collect();
+        assert_eq!(spans.len(), 20);
+
+        let times = |span: &Value| (
+            span["startTimeUnixNano"].as_str().unwrap().parse::<i64>().unwrap(),
+            span["endTimeUnixNano"].as_str().unwrap().parse::<i64>().unwrap(),
+        );
+        let by_id: HashMap<&str, &Value> = spans.iter().map(|span| (span["spanId"].as_str().unwrap(), *span)).collect();
+        let roots = spans.iter().filter(|span| span.get("parentSpanId").is_none()).count();
+        assert_eq!(roots, 1);
+        for span in &spans {
+            assert_eq!(span["traceId"], spans[0]["traceId"]);
+            assert_eq!(span["traceId"].as_str().unwrap().len(), 32);
+            assert_eq!(span["status"]["code"], 2);
+            let (start, end) = times(span);
+            assert!(start < end);
+            if let Some(parent) = span.get("parentSpanId") {
+                let (parent_start, parent_end) = times(by_id[parent.as_str().unwrap()]);
+                assert!(parent_start <= start && end <= parent_end);
+            }
+        }
+
+        let attributes = &spans[0]["attributes"];
+        assert_eq!(attributes.as_array().unwrap().len(), 3);
+        assert_eq!(attributes[0], json!({ "key": "http.status_code", "value": { "intValue": "200" } }));
+        assert_eq!(attributes[1]["value"], json!({ "boolValue": true }));
+        assert_eq!(attributes[2]["value"], json!({ "doubleValue": 0.5 }));
+
+        let invalid: TraceSpec = serde_json::from_value(json!({ "minDuration": "2s", "maxDuration": "1s" })).unwrap();
+        assert!(invalid.generate(&mut config, None).unwrap_err().message.contains("greater than maxDuration"));
+    }
+}
@@ -1,12 +1,73 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
 
+/// Splits argument content on the unquoted `,` and `..` separators.
+///
+/// A value quoted with `'...'` or `"..."` keeps its separators and parentheses; a quote
+/// only opens at the start of a value, and `\` escapes the next character inside quotes.
+fn split_quoted(content: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut chars = content.char_indices();
+
+    while let Some((index, char)) = chars.next() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if char == '\\' => escaped = true,
+            Some(open) if char == open => quote = None,
+            Some(_) => {},
+            None if (char == '\'' || char == '"') && content[start..index].trim().is_empty() => {
+                quote = Some(char);
+            },
+            None => {
+                let separator = [",", ".."].into_iter().find(|separator| content[index..].starts_with(separator));
+                if let Some(separator) = separator {
+                    parts.push(&content[start..index]);
+                    start = index + separator.len();
+                    for _ in 1..separator.len() {
+                        chars.next();
+                    }
+                }
+            },
+        }
+    }
+
+    parts.push(&content[start..]);
+    parts
+}
+
+/// Trims an argument value and removes its quotes, unescaping the quoted content.
+pub(crate) fn unquote(value: &str) -> String {
+    let value = value.trim();
+    let quoted = value.len() >= 2
+        && (value.starts_with('\'') && value.ends_with('\'') || value.starts_with('"') && value.ends_with('"'));
+    if !quoted {
+        return value.to_string();
+    }
+
+    let mut unquoted = String::with_capacity(value.len());
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => unquoted.extend(chars.next()),
+            _ => unquoted.push(char),
+        }
+    }
+    unquoted
+}
+
 /// Represents parsed arguments from faker pattern parameters.
 ///
-/// Arguments are typically extracted from parentheses in faker patterns like:
-/// - `faker.name.first_name()` → `Arguments::None`
-/// - `faker.number.number(100)` → `Arguments::Fixed("100")`
-/// - `faker.number.between(1,10)` → `Arguments::Range("1", "10")`
-/// - `faker.date.between(2020-01-01..2024-12-31)` → `Arguments::Range("2020-01-01", "2024-12-31")`
+/// Arguments are a positional list extracted from parentheses in faker patterns like:
+/// - `faker.name.first_name()` → `[]`
+/// - `faker.number.number(100)` → `["100"]`
+/// - `faker.number.between(1,10)` → `["1", "10"]`
+/// - `faker.date.between(2020-01-01..2024-12-31)` → `["2020-01-01", "2024-12-31"]`
+/// - `faker.date.between(2020-01-01, 2024-12-31, %Y)` → `["2020-01-01", "2024-12-31", "%Y"]`
+///
+/// Single values are read with [`get_str`](Self::get_str) and [`get_num`](Self::get_num);
+/// the `get_*` helpers with defaults read the first one or two values.
 ///
 /// # Examples
 ///
@@ -17,6 +78,12 @@ use chrono::{DateTime, NaiveDateTime, Utc};
 /// let args = Arguments::from("(42)");
 /// assert_eq!(args.get_number(0), 42);
 ///
+/// // Read values by position
+/// let args = Arguments::from("(1, 10, %Y)");
+/// assert_eq!(args.len(), 3);
+/// assert_eq!(args.get_num::<u32>(1), Some(10));
+/// assert_eq!(args.get_str(2), Some("%Y"));
+///
 /// // Parse range with comma
 /// let args = Arguments::from("(1,10)");
 /// let range = args.get_number_range(0, 100);
@@ -29,24 +96,26 @@ use chrono::{DateTime, NaiveDateTime, Utc};
 /// assert_eq!(start, "1");
 /// assert_eq!(end, "10");
 /// ```
-#[derive(Debug, Clone)]
-pub enum Arguments {
-    /// No arguments provided (empty parentheses or no parentheses)
-    None,
-    /// Single fixed argument value
-    Fixed(String),
-    /// Range with start and end values (separated by comma or dots)
-    Range(String, String),
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Arguments {
+    values: Vec<String>,
 }
 
 impl From<&str> for Arguments {
     /// Parses a string into Arguments enum.
     ///
     /// Expects input in the format `(content)` where content can be:
-    /// - Empty: `()` → `Arguments::None`
-    /// - Single value: `(42)` → `Arguments::Fixed("42")`
-    /// - Comma-separated: `(1,10)` → `Arguments::Range("1", "10")`
-    /// - Dot-separated: `(1..10)` → `Arguments::Range("1", "10")`
+    /// - Empty: `()` → `[]`
+    /// - Single value: `(42)` → `["42"]`
+    /// - Comma-separated: `(1,10,20)` → `["1", "10", "20"]`
+    /// - Dot-separated: `(1..10)` → `["1", "10"]`
+    /// - Both: `(1..10, 2)` → `["1", "10", "2"]`
+    ///
+    /// Values are trimmed and trailing empty values are dropped, so `(5..)` is `["5"]`.
+    /// Empty values before the last one are kept, so their position falls back to the default.
+    ///
+    /// Values containing `,`, `..` or parentheses can be quoted with `'...'` or `"..."`:
+    /// `('(###) ###-####, ext ###')` → `["(###) ###-####, ext ###"]`.
     ///
     /// # Examples
     ///
@@ -54,48 +123,87 @@ impl From<&str> for Arguments {
     /// use jgd_rs::Arguments;
     ///
     /// let args = Arguments::from("(42)");
-    /// assert!(matches!(args, Arguments::Fixed(ref s) if s == "42"));
+    /// assert_eq!(args.values(), ["42"]);
     ///
     /// let args = Arguments::from("(1,10)");
-    /// assert!(matches!(args, Arguments::Range(ref s1, ref s2) if s1 == "1" && s2 == "10"));
+    /// assert_eq!(args.values(), ["1", "10"]);
     ///
     /// let args = Arguments::from("(1..10)");
-    /// assert!(matches!(args, Arguments::Range(ref s1, ref s2) if s1 == "1" && s2 == "10"));
+    /// assert_eq!(args.values(), ["1", "10"]);
+    ///
+    /// let args = Arguments::from("('a, b', \"c..d\")");
+    /// assert_eq!(args.values(), ["a, b", "c..d"]);
     /// ```
     fn from(value: &str) -> Self {
-        let parts: Vec<&str> = if let Some(args_content) = value.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
-            if args_content.contains("..") {
-                args_content.split("..").map(|s| s.trim()).collect()
-            } else if args_content.contains(',') {
-                args_content.split(',').map(|s| s.trim()).collect()
-            } else {
-                vec![args_content.trim()]
-            }
-        } else {
-            return Arguments::None;
+        let Some(args_content) = value.strip_prefix('(').and_then(|s| s.strip_suffix(')')) else {
+            return Arguments::default();
         };
 
-        if parts.len() > 1 {
-            if parts[0].is_empty() && parts[1].is_empty() {
-                return Arguments::None;
-            }
-
-            if parts[1].is_empty() {
-                return Arguments::Fixed(parts[0].into());
-            }
-
-            return Arguments::Range(parts[0].into(), parts[1].into());
+        let mut parts = split_quoted(args_content);
+        while parts.last().is_some_and(|part| part.trim().is_empty()) {
+            parts.pop();
         }
 
-        if !parts[0].is_empty() {
-            return Arguments::Fixed(parts[0].into());
-        }
-
-        Arguments::None
+        Arguments { values: parts.into_iter().map(unquote).collect() }
     }
 }
 
 impl Arguments {
+    /// Creates arguments from positional values.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use jgd_rs::Arguments;
+    ///
+    /// let args = Arguments::new(vec!["1".to_string(), "10".to_string()]);
+    /// assert_eq!(args.get_number_range(0, 100), 1..10);
+    /// ```
+    pub fn new(values: Vec<String>) -> Self {
+        Arguments { values }
+    }
+
+    /// Returns the argument values, in order.
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` when no arguments were provided.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the argument at `index`, or `None` when there are fewer arguments.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use jgd_rs::Arguments;
+    ///
+    /// let args = Arguments::from("(a, b, c)");
+    /// assert_eq!(args.get_str(2), Some("c"));
+    /// assert_eq!(args.get_str(3), None);
+    /// ```
+    pub fn get_str(&self, index: usize) -> Option<&str> {
+        self.values.get(index).map(String::as_str)
+    }
+
+    /// Parses the argument at `index`, or returns `None` when it is missing or invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use jgd_rs::Arguments;
+    ///
+    /// let args = Arguments::from("(1, x, 3)");
+    /// assert_eq!(args.get_num::<i32>(2), Some(3));
+    /// assert_eq!(args.get_num::<i32>(1), None);
+    /// ```
+    pub fn get_num<T: std::str::FromStr>(&self, index: usize) -> Option<T> {
+        self.get_str(index).and_then(|arg| arg.parse::<T>().ok())
+    }
 
     /// Helper function to parse a single numeric argument.
     ///
@@ -141,18 +249,18 @@ impl Arguments {
     /// Arguments::parse_time("invalid", default) -> default (fallback)
     /// ```
     fn parse_time(arg: &str, default_value: time::OffsetDateTime) -> time::OffsetDateTime {
+        Self::try_parse_time(arg).unwrap_or(default_value)
+    }
+
+    /// Parses a time argument, returning `None` when it is not valid.
+    pub(crate) fn try_parse_time(arg: &str) -> Option<time::OffsetDateTime> {
         // Try parsing as RFC 3339 format (most common for APIs)
         // For now, we'll use a simple fallback since time parsing is complex
         // This could be enhanced with proper format descriptors later
 
         // Simple fallback: try to parse as Unix timestamp
-        if let Ok(timestamp) = arg.parse::<i64>() {
-            if let Ok(datetime) = time::OffsetDateTime::from_unix_timestamp(timestamp) {
-                return datetime;
-            }
-        }
-
-        default_value
+        let timestamp = arg.parse::<i64>().ok()?;
+        time::OffsetDateTime::from_unix_timestamp(timestamp).ok()
     }
 
     /// Helper function to parse a datetime argument.
@@ -177,18 +285,19 @@ impl Arguments {
     /// Arguments::parse_datetime("invalid", default) -> default (fallback)
     /// ```
     fn parse_datetime(arg: &str, default_value: DateTime<Utc>) -> DateTime<Utc> {
-        if let Ok(dt) = arg.parse::<DateTime<Utc>>() {
-            return dt;
-        }
+        Self::try_parse_datetime(arg).unwrap_or(default_value)
+    }
 
+    /// Parses a datetime argument, returning `None` when it matches none of the supported formats.
+    pub(crate) fn try_parse_datetime(arg: &str) -> Option<DateTime<Utc>> {
         // 1. Direct ISO 8601 UTC parse
         if let Ok(dt) = arg.parse::<DateTime<Utc>>() {
-            return dt;
+            return Some(dt);
         }
 
         // 2. RFC3339 (handles Z, offsets, fractional seconds)
         if let Ok(dt) = DateTime::parse_from_rfc3339(arg) {
-            return dt.with_timezone(&Utc);
+            return Some(dt.with_timezone(&Utc));
         }
 
         // 3. Try common patterns with timezone
@@ -200,7 +309,7 @@ impl Arguments {
         ];
         for fmt in tz_formats {
             if let Ok(dt) = DateTime::parse_from_str(arg, fmt) {
-                return dt.with_timezone(&Utc);
+                return Some(dt.with_timezone(&Utc));
             }
         }
 
@@ -213,11 +322,11 @@ impl Arguments {
         ];
         for fmt in naive_formats {
             if let Ok(ndt) = NaiveDateTime::parse_from_str(arg, fmt) {
-                return ndt.and_utc();
+                return Some(ndt.and_utc());
             }
         }
 
-        default_value
+        None
     }
 
     /// Extracts a string value from the arguments.
@@ -229,9 +338,9 @@ impl Arguments {
     /// * `default_value` - Value to return if no arguments are present
     ///
     /// # Returns
-    /// - `Arguments::None` → `default_value`
-    /// - `Arguments::Fixed(arg)` → `arg`
-    /// - `Arguments::Range(arg1, arg2)` → `arg1`
+    /// - `[]` → `default_value`
+    /// - `[arg]` → `arg`
+    /// - `[arg1, arg2, ..]` → `arg1`
     ///
     /// # Examples
     /// ```rust
@@ -243,15 +352,11 @@ impl Arguments {
     /// let args = Arguments::from("(start,end)");
     /// assert_eq!(args.get_string("default"), "start");
     ///
-    /// let args = Arguments::None;
+    /// let args = Arguments::default();
     /// assert_eq!(args.get_string("default"), "default");
     /// ```
     pub fn get_string<'a>(&'a self, default_value: &'a str) -> &'a str {
-        match self {
-            Arguments::None => default_value,
-            Arguments::Fixed(arg) => arg,
-            Arguments::Range(arg, _) => arg,
-        }
+        self.get_str(0).unwrap_or(default_value)
     }
 
     /// Extracts a tuple of string values from the arguments.
@@ -263,9 +368,9 @@ impl Arguments {
     /// * `default_end` - Value to return as end if no arguments are present or only one argument
     ///
     /// # Returns
-    /// - `Arguments::None` → `(default_start, default_end)`
-    /// - `Arguments::Fixed(arg)` → `(arg, default_end)`
-    /// - `Arguments::Range(arg1, arg2)` → `(arg1, arg2)`
+    /// - `[]` → `(default_start, default_end)`
+    /// - `[arg]` → `(arg, default_end)`
+    /// - `[arg1, arg2, ..]` → `(arg1, arg2)`
     ///
     /// # Examples
     /// ```rust
@@ -282,11 +387,7 @@ impl Arguments {
     /// assert_eq!(end, "100");
     /// ```
     pub fn get_string_tuple<'a>(&'a self, default_start: &'a str, default_end: &'a str) -> (&'a str, &'a str) {
-        match self {
-            Arguments::None => (default_start, default_end),
-            Arguments::Fixed(arg) => (arg, default_end),
-            Arguments::Range(arg1, arg2) => (arg1, arg2),
-        }
+        (self.get_str(0).unwrap_or(default_start), self.get_str(1).unwrap_or(default_end))
     }
 
     /// Extracts a numeric value from the arguments.
@@ -298,9 +399,9 @@ impl Arguments {
     /// * `default_value` - Value to return if no arguments are present or parsing fails
     ///
     /// # Returns
-    /// - `Arguments::None` → `default_value`
-    /// - `Arguments::Fixed(arg)` → parsed `arg` or `default_value` if parsing fails
-    /// - `Arguments::Range(arg1, arg2)` → parsed `arg1` or `default_value` if parsing fails
+    /// - `[]` → `default_value`
+    /// - `[arg]` → parsed `arg` or `default_value` if parsing fails
+    /// - `[arg1, arg2, ..]` → parsed `arg1` or `default_value` if parsing fails
     ///
     /// # Examples
     /// ```rust
@@ -316,10 +417,9 @@ impl Arguments {
     /// assert_eq!(args.get_number(0), 1);
     /// ```
     pub fn get_number<T: std::str::FromStr>(&self, default_value: T) -> T {
-        match self {
-            Arguments::None => default_value,
-            Arguments::Fixed(arg) => Self::parse_number(arg, default_value),
-            Arguments::Range(arg, _) => Self::parse_number(arg, default_value),
+        match self.get_str(0) {
+            Some(arg) => Self::parse_number(arg, default_value),
+            None => default_value,
         }
     }
 
@@ -333,9 +433,9 @@ impl Arguments {
     /// * `default_end` - Value to use as range end if no second argument or parsing fails
     ///
     /// # Returns
-    /// - `Arguments::None` → `default_start..default_end`
-    /// - `Arguments::Fixed(arg)` → `parsed_arg..default_end`
-    /// - `Arguments::Range(arg1, arg2)` → `parsed_arg1..parsed_arg2`
+    /// - `[]` → `default_start..default_end`
+    /// - `[arg]` → `parsed_arg..default_end`
+    /// - `[arg1, arg2, ..]` → `parsed_arg1..parsed_arg2`
     ///
     /// # Examples
     /// ```rust
@@ -354,12 +454,9 @@ impl Arguments {
     pub fn get_number_range<T: std::str::FromStr>(
         &self, default_start: T, default_end: T
     ) -> std::ops::Range<T> {
-        match self {
-            Arguments::None => default_start..default_end,
-            Arguments::Fixed(arg) => Self::parse_number(arg, default_start)..default_end,
-            Arguments::Range(arg1, arg2) =>
-                Self::parse_number(arg1, default_start)..Self::parse_number(arg2, default_end),
-        }
+        let start = self.get_num(0).unwrap_or(default_start);
+        let end = self.get_num(1).unwrap_or(default_end);
+        start..end
     }
 
     /// Extracts a time value from the arguments.
@@ -371,9 +468,9 @@ impl Arguments {
     /// * `default_value` - Value to return if no arguments are present or parsing fails
     ///
     /// # Returns
-    /// - `Arguments::None` → `default_value`
-    /// - `Arguments::Fixed(arg)` → parsed `arg` or `default_value` if parsing fails
-    /// - `Arguments::Range(arg1, arg2)` → parsed `arg1` or `default_value` if parsing fails
+    /// - `[]` → `default_value`
+    /// - `[arg]` → parsed `arg` or `default_value` if parsing fails
+    /// - `[arg1, arg2, ..]` → parsed `arg1` or `default_value` if parsing fails
     ///
     /// # Examples
     /// ```rust
@@ -385,10 +482,9 @@ impl Arguments {
     /// let result = args.get_time(default);
     /// ```
     pub fn get_time(&self, default_value: time::OffsetDateTime) -> time::OffsetDateTime {
-        match self {
-            Arguments::None => default_value,
-            Arguments::Fixed(arg) => Self::parse_time(arg, default_value),
-            Arguments::Range(arg, _) => Self::parse_time(arg, default_value),
+        match self.get_str(0) {
+            Some(arg) => Self::parse_time(arg, default_value),
+            None => default_value,
         }
     }
 
@@ -402,9 +498,9 @@ impl Arguments {
     /// * `default_end` - Value to use as end time if no second argument or parsing fails
     ///
     /// # Returns
-    /// - `Arguments::None` → `(default_start, default_end)`
-    /// - `Arguments::Fixed(arg)` → `(parsed_arg, default_end)`
-    /// - `Arguments::Range(arg1, arg2)` → `(parsed_arg1, parsed_arg2)`
+    /// - `[]` → `(default_start, default_end)`
+    /// - `[arg]` → `(parsed_arg, default_end)`
+    /// - `[arg1, arg2, ..]` → `(parsed_arg1, parsed_arg2)`
     ///
     /// # Examples
     /// ```rust
@@ -419,12 +515,9 @@ impl Arguments {
     pub fn get_time_range(
         &self, default_start: time::OffsetDateTime, default_end: time::OffsetDateTime
     ) -> (time::OffsetDateTime, time::OffsetDateTime) {
-        match self {
-            Arguments::None => (default_start, default_end),
-            Arguments::Fixed(arg) => (Self::parse_time(arg, default_start), default_end),
-            Arguments::Range(arg1, arg2) =>
-                (Self::parse_time(arg1, default_start), Self::parse_time(arg2, default_end)),
-        }
+        let start = self.get_str(0).and_then(Self::try_parse_time).unwrap_or(default_start);
+        let end = self.get_str(1).and_then(Self::try_parse_time).unwrap_or(default_end);
+        (start, end)
     }
 
     /// Extracts a datetime value from the arguments.
@@ -436,9 +529,9 @@ impl Arguments {
     /// * `default_value` - Value to return if no arguments are present or parsing fails
     ///
     /// # Returns
-    /// - `Arguments::None` → `default_value`
-    /// - `Arguments::Fixed(arg)` → parsed `arg` or `default_value` if parsing fails
-    /// - `Arguments::Range(arg1, arg2)` → parsed `arg1` or `default_value` if parsing fails
+    /// - `[]` → `default_value`
+    /// - `[arg]` → parsed `arg` or `default_value` if parsing fails
+    /// - `[arg1, arg2, ..]` → parsed `arg1` or `default_value` if parsing fails
     ///
     /// # Examples
     /// ```rust
@@ -450,10 +543,9 @@ impl Arguments {
     /// let result = args.get_datetime(default);
     /// ```
     pub fn get_datetime(&self, default_value: DateTime<Utc>) -> DateTime<Utc> {
-        match self {
-            Arguments::None => default_value,
-            Arguments::Fixed(arg) => Self::parse_datetime(arg, default_value),
-            Arguments::Range(arg, _) => Self::parse_datetime(arg, default_value),
+        match self.get_str(0) {
+            Some(arg) => Self::parse_datetime(arg, default_value),
+            None => default_value,
         }
     }
 
@@ -467,9 +559,9 @@ impl Arguments {
     /// * `default_end` - Value to use as end datetime if no second argument or parsing fails
     ///
     /// # Returns
-    /// - `Arguments::None` → `(default_start, default_end)`
-    /// - `Arguments::Fixed(arg)` → `(parsed_arg, default_end)`
-    /// - `Arguments::Range(arg1, arg2)` → `(parsed_arg1, parsed_arg2)`
+    /// - `[]` → `(default_start, default_end)`
+    /// - `[arg]` → `(parsed_arg, default_end)`
+    /// - `[arg1, arg2, ..]` → `(parsed_arg1, parsed_arg2)`
     ///
     /// # Examples
     /// ```rust
@@ -484,12 +576,9 @@ impl Arguments {
     pub fn get_datetime_range(
         &self, default_start: DateTime<Utc>, default_end: DateTime<Utc>
     ) -> (DateTime<Utc>, DateTime<Utc>) {
-        match self {
-            Arguments::None => (default_start, default_end),
-            Arguments::Fixed(arg) => (Self::parse_datetime(arg, default_start), default_end),
-            Arguments::Range(arg1, arg2) =>
-                (Self::parse_datetime(arg1, default_start), Self::parse_datetime(arg2, default_end)),
-        }
+        let start = self.get_str(0).and_then(Self::try_parse_datetime).unwrap_or(default_start);
+        let end = self.get_str(1).and_then(Self::try_parse_datetime).unwrap_or(default_end);
+        (start, end)
     }
 }
 
@@ -501,271 +590,170 @@ mod tests {
     #[test]
     fn test_parse_simple_string() {
         let args = Arguments::from("lorem");
-        assert!(matches!(args, Arguments::None));
+        assert!(args.is_empty());
     }
 
     #[test]
     fn test_parse_empty_parentheses() {
         let args = Arguments::from("()");
-        assert!(matches!(args, Arguments::None));
+        assert!(args.is_empty());
     }
 
     #[test]
     fn test_parse_single_number() {
         let args = Arguments::from("(1)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "1");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["1"]);
     }
 
     #[test]
     fn test_parse_comma_separated_numbers() {
         let args = Arguments::from("(1,2)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "1");
-            assert_eq!(end, "2");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["1", "2"]);
     }
 
     #[test]
     fn test_parse_comma_separated_with_spaces() {
         let args = Arguments::from("(1, 2)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "1");
-            assert_eq!(end, "2");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["1", "2"]);
     }
 
     #[test]
     fn test_parse_large_number() {
         let args = Arguments::from("(123)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "123");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["123"]);
     }
 
     #[test]
     fn test_parse_multiple_comma_separated() {
         let args = Arguments::from("(123,456,789)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "123");
-            assert_eq!(end, "456");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["123", "456", "789"]);
+        assert_eq!(args.get_num::<u32>(2), Some(789));
     }
 
     #[test]
     fn test_parse_range_with_dots() {
         let args = Arguments::from("(1..2)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "1");
-            assert_eq!(end, "2");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["1", "2"]);
     }
 
     #[test]
     fn test_parse_range_with_dots_and_spaces() {
         let args = Arguments::from("(1.. 2)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "1");
-            assert_eq!(end, "2");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["1", "2"]);
     }
 
     #[test]
     fn test_parse_range_with_multiple_dots() {
         let args = Arguments::from("(1..2..3)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "1");
-            assert_eq!(end, "2");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["1", "2", "3"]);
     }
 
     #[test]
     fn test_parse_percentage() {
         let args = Arguments::from("(75)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "75");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["75"]);
     }
 
     #[test]
     fn test_parse_datetime_with_space() {
         let args = Arguments::from("(2024-01-01 00:00:00)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "2024-01-01 00:00:00");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["2024-01-01 00:00:00"]);
     }
 
     #[test]
     fn test_parse_datetime_iso() {
         let args = Arguments::from("(2024-12-31T23:59:59)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "2024-12-31T23:59:59");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["2024-12-31T23:59:59"]);
     }
 
     #[test]
     fn test_parse_datetime_range_comma() {
         let args = Arguments::from("(2024-01-01 00:00:00, 2024-12-31T23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "2024-01-01 00:00:00");
-            assert_eq!(end, "2024-12-31T23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["2024-01-01 00:00:00", "2024-12-31T23:59:59"]);
     }
 
     #[test]
     fn test_parse_datetime_range_dots_with_spaces() {
         let args = Arguments::from("(2024-01-01 00:00:00.. 2024-12-31T23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "2024-01-01 00:00:00");
-            assert_eq!(end, "2024-12-31T23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["2024-01-01 00:00:00", "2024-12-31T23:59:59"]);
     }
 
     #[test]
     fn test_parse_datetime_range_comma_no_spaces() {
         let args = Arguments::from("(2024-01-01 00:00:00,2024-12-31T23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "2024-01-01 00:00:00");
-            assert_eq!(end, "2024-12-31T23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["2024-01-01 00:00:00", "2024-12-31T23:59:59"]);
     }
 
     #[test]
     fn test_parse_datetime_range_dots_no_spaces() {
         let args = Arguments::from("(2024-01-01 00:00:00..2024-12-31T23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "2024-01-01 00:00:00");
-            assert_eq!(end, "2024-12-31T23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["2024-01-01 00:00:00", "2024-12-31T23:59:59"]);
     }
 
     #[test]
     fn test_parse_time_start() {
         let args = Arguments::from("(00:00:00)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "00:00:00");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["00:00:00"]);
     }
 
     #[test]
     fn test_parse_time_end() {
         let args = Arguments::from("(23:59:59)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "23:59:59");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["23:59:59"]);
     }
 
     #[test]
     fn test_parse_time_range_comma() {
         let args = Arguments::from("(00:00:00, 23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "00:00:00");
-            assert_eq!(end, "23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["00:00:00", "23:59:59"]);
     }
 
     #[test]
     fn test_parse_time_range_dots_with_spaces() {
         let args = Arguments::from("(00:00:00.. 23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "00:00:00");
-            assert_eq!(end, "23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["00:00:00", "23:59:59"]);
     }
 
     #[test]
     fn test_parse_time_range_comma_no_spaces() {
         let args = Arguments::from("(00:00:00,23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "00:00:00");
-            assert_eq!(end, "23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["00:00:00", "23:59:59"]);
     }
 
     #[test]
     fn test_parse_time_range_dots_no_spaces() {
         let args = Arguments::from("(00:00:00..23:59:59)");
-        if let Arguments::Range(start, end) = args {
-            assert_eq!(start, "00:00:00");
-            assert_eq!(end, "23:59:59");
-        } else {
-            panic!("Expected Range argument");
-        }
+        assert_eq!(args.values(), ["00:00:00", "23:59:59"]);
     }
 
     #[test]
     fn test_parse_format_string() {
         let args = Arguments::from("((###) ###-####)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "(###) ###-####");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["(###) ###-####"]);
     }
 
     // Test getter methods
     #[test]
     fn test_get_string_default() {
-        let args = Arguments::None;
+        let args = Arguments::default();
         assert_eq!(args.get_string("default"), "default");
     }
 
     #[test]
     fn test_get_string_fixed() {
-        let args = Arguments::Fixed("test".to_string());
+        let args = Arguments::new(vec!["test".to_string()]);
         assert_eq!(args.get_string("default"), "test");
     }
 
     #[test]
     fn test_get_string_range() {
-        let args = Arguments::Range("start".to_string(), "end".to_string());
+        let args = Arguments::new(vec!["start".to_string(), "end".to_string()]);
         assert_eq!(args.get_string("default"), "start");
     }
 
     #[test]
     fn test_get_string_tuple_none() {
-        let args = Arguments::None;
+        let args = Arguments::default();
         let (start, end) = args.get_string_tuple("def_start", "def_end");
         assert_eq!(start, "def_start");
         assert_eq!(end, "def_end");
@@ -773,7 +761,7 @@ mod tests {
 
     #[test]
     fn test_get_string_tuple_fixed() {
-        let args = Arguments::Fixed("value".to_string());
+        let args = Arguments::new(vec!["value".to_string()]);
         let (start, end) = args.get_string_tuple("def_start", "def_end");
         assert_eq!(start, "value");
         assert_eq!(end, "def_end");
@@ -781,7 +769,7 @@ mod tests {
 
     #[test]
     fn test_get_string_tuple_range() {
-        let args = Arguments::Range("start".to_string(), "end".to_string());
+        let args = Arguments::new(vec!["start".to_string(), "end".to_string()]);
         let (start, end) = args.get_string_tuple("def_start", "def_end");
         assert_eq!(start, "start");
         assert_eq!(end, "end");
@@ -789,37 +777,37 @@ mod tests {
 
     #[test]
     fn test_get_number_default() {
-        let args = Arguments::None;
+        let args = Arguments::default();
         assert_eq!(args.get_number(42), 42);
     }
 
     #[test]
     fn test_get_number_fixed() {
-        let args = Arguments::Fixed("123".to_string());
+        let args = Arguments::new(vec!["123".to_string()]);
         assert_eq!(args.get_number(42), 123);
     }
 
     #[test]
     fn test_get_number_fixed_invalid() {
-        let args = Arguments::Fixed("invalid".to_string());
+        let args = Arguments::new(vec!["invalid".to_string()]);
         assert_eq!(args.get_number(42), 42);
     }
 
     #[test]
     fn test_get_number_range() {
-        let args = Arguments::Range("100".to_string(), "200".to_string());
+        let args = Arguments::new(vec!["100".to_string(), "200".to_string()]);
         assert_eq!(args.get_number(42), 100);
     }
 
     #[test]
     fn test_get_number_range_invalid() {
-        let args = Arguments::Range("invalid".to_string(), "200".to_string());
+        let args = Arguments::new(vec!["invalid".to_string(), "200".to_string()]);
         assert_eq!(args.get_number(42), 42);
     }
 
     #[test]
     fn test_get_number_range_function() {
-        let args = Arguments::None;
+        let args = Arguments::default();
         let range = args.get_number_range(1, 10);
         assert_eq!(range.start, 1);
         assert_eq!(range.end, 10);
@@ -827,7 +815,7 @@ mod tests {
 
     #[test]
     fn test_get_number_range_function_fixed() {
-        let args = Arguments::Fixed("5".to_string());
+        let args = Arguments::new(vec!["5".to_string()]);
         let range = args.get_number_range(1, 10);
         assert_eq!(range.start, 5);
         assert_eq!(range.end, 10);
@@ -835,7 +823,7 @@ mod tests {
 
     #[test]
     fn test_get_number_range_function_range() {
-        let args = Arguments::Range("3".to_string(), "7".to_string());
+        let args = Arguments::new(vec!["3".to_string(), "7".to_string()]);
         let range = args.get_number_range(1, 10);
         assert_eq!(range.start, 3);
         assert_eq!(range.end, 7);
@@ -844,7 +832,7 @@ mod tests {
     #[test]
     fn test_get_datetime_default() {
         let default_dt = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
-        let args = Arguments::None;
+        let args = Arguments::default();
         assert_eq!(args.get_datetime(default_dt), default_dt);
     }
 
@@ -852,7 +840,7 @@ mod tests {
     fn test_get_datetime_fixed_valid() {
         let default_dt = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
         let expected_dt = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
-        let args = Arguments::Fixed("2024-01-01T00:00:00Z".to_string());
+        let args = Arguments::new(vec!["2024-01-01T00:00:00Z".to_string()]);
         assert_eq!(args.get_datetime(default_dt), expected_dt);
     }
 
@@ -860,14 +848,14 @@ mod tests {
     fn test_get_datetime_fixed_space_format() {
         let default_dt = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
         let expected_dt = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc();
-        let args = Arguments::Fixed("2024-01-01 00:00:00".to_string());
+        let args = Arguments::new(vec!["2024-01-01 00:00:00".to_string()]);
         assert_eq!(args.get_datetime(default_dt), expected_dt);
     }
 
     #[test]
     fn test_get_datetime_fixed_invalid() {
         let default_dt = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
-        let args = Arguments::Fixed("invalid-date".to_string());
+        let args = Arguments::new(vec!["invalid-date".to_string()]);
         assert_eq!(args.get_datetime(default_dt), default_dt);
     }
 
@@ -878,7 +866,7 @@ mod tests {
         let expected_start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc();
         let expected_end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().and_hms_opt(23, 59, 59).unwrap().and_utc();
 
-        let args = Arguments::Range("2024-01-01 00:00:00".to_string(), "2024-12-31 23:59:59".to_string());
+        let args = Arguments::new(vec!["2024-01-01 00:00:00".to_string(), "2024-12-31 23:59:59".to_string()]);
         let (start, end) = args.get_datetime_range(default_start, default_end);
         assert_eq!(start, expected_start);
         assert_eq!(end, expected_end);
@@ -887,60 +875,55 @@ mod tests {
     #[test]
     fn test_edge_case_empty_content() {
         let args = Arguments::from("()");
-        assert!(matches!(args, Arguments::None));
+        assert!(args.is_empty());
     }
 
     #[test]
     fn test_edge_case_only_comma() {
         let args = Arguments::from("(,)");
-        match args {
-            Arguments::Range(start, end) => {
-                assert_eq!(start, "");
-                assert_eq!(end, "");
-            },
-            Arguments::Fixed(value) => {
-                assert_eq!(value, "");
-            },
-            Arguments::None => {
-                // Also acceptable
-            }
-        }
+        assert!(args.is_empty());
     }
 
     #[test]
     fn test_edge_case_only_dots() {
         let args = Arguments::from("(..)");
-        match args {
-            Arguments::Range(start, end) => {
-                assert_eq!(start, "");
-                assert_eq!(end, "");
-            },
-            Arguments::Fixed(value) => {
-                assert_eq!(value, "");
-            },
-            Arguments::None => {
-                // Also acceptable
-            }
-        }
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    fn test_edge_case_leading_empty_value() {
+        let args = Arguments::from("(,5)");
+        assert_eq!(args.values(), ["", "5"]);
+        assert_eq!(args.get_number_range(1, 10), 1..5);
+    }
+
+    #[test]
+    fn test_parse_quoted_values() {
+        let args = Arguments::from("('(###) ###-####, ext ###')");
+        assert_eq!(args.values(), ["(###) ###-####, ext ###"]);
+
+        let args = Arguments::from(r#"("1..2", 'it\'s', "a \"b\"", '')"#);
+        assert_eq!(args.values(), ["1..2", "it's", "a \"b\"", ""]);
+
+        let args = Arguments::from("('a..b'..c)");
+        assert_eq!(args.values(), ["a..b", "c"]);
+
+        let args = Arguments::from("(O'Brien, 'x')");
+        assert_eq!(args.values(), ["O'Brien", "x"]);
+
+        let args = Arguments::from("(1000..9999, \"#,##0.00\")");
+        assert_eq!(args.values(), ["1000", "9999", "#,##0.00"]);
     }
 
     #[test]
     fn test_edge_case_trailing_comma() {
         let args = Arguments::from("(123,)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "123");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["123"]);
     }
 
     #[test]
     fn test_edge_case_trailing_dots() {
         let args = Arguments::from("(123..)");
-        if let Arguments::Fixed(value) = args {
-            assert_eq!(value, "123");
-        } else {
-            panic!("Expected Fixed argument");
-        }
+        assert_eq!(args.values(), ["123"]);
     }
 }
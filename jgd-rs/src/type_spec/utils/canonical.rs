@@ -0,0 +1,64 @@
+use serde_json::{Map, Number, Value};
+
+/// Largest magnitude below which every whole `f64` is exactly representable as an `i64`.
+const MAX_SAFE_WHOLE: f64 = 9_007_199_254_740_992.0;
+
+fn canonical_number(number: Number) -> Number {
+    match number.as_f64() {
+        Some(float) if !number.is_i64() && !number.is_u64() => {
+            if float == 0.0 {
+                // Folds -0.0 into 0
+                Number::from(0)
+            } else if float.fract() == 0.0 && float.abs() < MAX_SAFE_WHOLE {
+                Number::from(float as i64)
+            } else {
+                number
+            }
+        },
+        _ => number,
+    }
+}
+
+/// Rewrites a generated value into a canonical form for byte-stable output.
+///
+/// - object keys are sorted, recursively
+/// - whole floats are written as integers (`3.0` → `3`) and `-0.0` becomes `0`
+///
+/// Combined with a fixed seed, the serialized result does not depend on field
+/// declaration order or float formatting, which makes it suitable for snapshot tests.
+///
+/// # Examples
+///
+/// ```rust
+/// use jgd_rs::canonicalize;
+/// use serde_json::json;
+///
+/// let value = canonicalize(json!({ "b": 2.0, "a": [{ "d": 1, "c": -0.0 }] }));
+/// assert_eq!(value.to_string(), r#"{"a":[{"c":0,"d":1}],"b":2}"#);
+/// ```
+pub fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter()
+                .map(|(key, value)| (key, canonicalize(value)))
+                .collect::<Map<String, Value>>())
+        },
+        Value::Array(items) => Value::Array(items.into_iter().map(canonicalize).collect()),
+        Value::Number(number) => Value::Number(canonical_number(number)),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_canonicalize_sorts_keys_and_numbers() {
+        let value = canonicalize(json!({ "z": 1.5, "a": { "y": 10.0, "b": -0.0 }, "m": [3.0, 2] }));
+        assert_eq!(value.to_string(), r#"{"a":{"b":0,"y":10},"m":[3,2],"z":1.5}"#);
+    }
+}
@@ -0,0 +1,133 @@
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{JgdErrorKind, JgdGeneratorError};
+
+/// Position reached by a streaming generation, for resuming a cancelled run.
+///
+/// Generation is deterministic for a given seed, so a run resumed from a checkpoint
+/// (see [`crate::Jgd::generate_to_writer_resumable`]) replays the generation up to the
+/// checkpoint without writing it, then writes exactly the remaining output. Appending
+/// that output to the first `bytes` bytes of the cancelled output gives the same
+/// document as an uninterrupted run.
+///
+/// ```json
+/// { "seed": 42, "entity": "posts", "index": 20000, "bytes": 3145728 }
+/// ```
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Seed of the run. Runs of a schema without `seed` draw one and record it here.
+    pub seed: u64,
+
+    /// Entity whose items were being written, or `root` in root mode.
+    pub entity: String,
+
+    /// Number of items of `entity` written so far.
+    pub index: u64,
+
+    /// Number of output bytes written so far, from the start of the document.
+    pub bytes: u64,
+}
+
+impl Checkpoint {
+    fn error(&self, details: &str) -> JgdGeneratorError {
+        JgdGeneratorError {
+            message: format!("Error to resume from the checkpoint {} #{}. Details: {}", self.entity, self.index, details),
+            kind: JgdErrorKind::Schema,
+            ..Default::default()
+        }
+    }
+
+    /// Checks that the checkpoint was recorded with the seed the schema declares, if any.
+    pub(crate) fn check_seed(&self, seed: Option<u64>) -> Result<(), JgdGeneratorError> {
+        match seed {
+            Some(seed) if seed != self.seed => Err(self.error(&format!(
+                "the checkpoint was recorded with the seed {}, but the schema seed is {}", self.seed, seed
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Callback receiving each checkpoint once the output before it has been flushed.
+pub(crate) type OnCheckpoint<'a> = &'a mut dyn FnMut(&Checkpoint) -> Result<(), JgdGeneratorError>;
+
+/// Output of a streaming generation that records checkpoints and, when resuming,
+/// discards everything written before the checkpoint.
+pub(crate) struct CheckpointWriter<'a, W: Write> {
+    writer: &'a mut W,
+    seed: u64,
+    bytes: u64,
+    resume: Option<&'a Checkpoint>,
+    every: u64,
+    on_checkpoint: Option<OnCheckpoint<'a>>,
+}
+
+impl<'a, W: Write> CheckpointWriter<'a, W> {
+    /// Wraps `writer` without checkpoints.
+    pub(crate) fn new(writer: &'a mut W) -> Self {
+        Self { writer, seed: 0, bytes: 0, resume: None, every: 0, on_checkpoint: None }
+    }
+
+    /// Wraps `writer`, calling `on_checkpoint` every `every` items of each entity and
+    /// skipping the output up to `resume`, if given.
+    pub(crate) fn with_checkpoints(writer: &'a mut W, seed: u64, resume: Option<&'a Checkpoint>, every: u64,
+        on_checkpoint: OnCheckpoint<'a>) -> Self {
+        Self { writer, seed, bytes: 0, resume, every, on_checkpoint: Some(on_checkpoint) }
+    }
+
+    /// Records that `index` items of `entity` have been written.
+    pub(crate) fn item_written(&mut self, entity: &str, index: u64) -> Result<(), JgdGeneratorError> {
+        if let Some(resume) = self.resume {
+            if resume.entity == entity && resume.index == index {
+                if resume.bytes != self.bytes {
+                    return Err(resume.error(&format!(
+                        "the output up to the checkpoint is {} bytes, but {} were recorded; was the schema changed?",
+                        self.bytes, resume.bytes
+                    )));
+                }
+                self.resume = None;
+            }
+            return Ok(());
+        }
+
+        if self.every == 0 || !index.is_multiple_of(self.every) {
+            return Ok(());
+        }
+
+        if let Some(on_checkpoint) = self.on_checkpoint.as_mut() {
+            self.writer.flush().map_err(|error| JgdGeneratorError {
+                message: format!("Error to write the output. Details: {}", error),
+                kind: JgdErrorKind::Output,
+                ..Default::default()
+            })?;
+            on_checkpoint(&Checkpoint { seed: self.seed, entity: entity.to_string(), index, bytes: self.bytes })?;
+        }
+
+        Ok(())
+    }
+
+    /// Fails when the generation ended before reaching the checkpoint to resume from.
+    pub(crate) fn finish(&self) -> Result<(), JgdGeneratorError> {
+        match self.resume {
+            Some(resume) => Err(resume.error("the generation never reached it; was the schema changed?")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Write for CheckpointWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = match self.resume {
+            Some(_) => buf.len(),
+            None => self.writer.write(buf)?,
+        };
+        self.bytes += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
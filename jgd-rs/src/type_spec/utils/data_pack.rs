@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::{JgdErrorKind, JgdGeneratorError};
+
+/// Reads the values of a data pack file.
+///
+/// `.json` files hold an array of values (strings, numbers or objects); any other file
+/// is read as CSV without header, one value per row taken from its first column.
+pub(crate) fn read(path: &Path) -> Result<Vec<Value>, JgdGeneratorError> {
+    let error = |details: String| JgdGeneratorError {
+        message: format!("Error to read the data pack {}. Details: {}", path.display(), details),
+        kind: JgdErrorKind::Schema,
+        ..Default::default()
+    };
+
+    let content = std::fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+    let is_json = path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if is_json {
+        return match serde_json::from_str(&content).map_err(|e| error(e.to_string()))? {
+            Value::Array(values) => Ok(values),
+            _ => Err(error("expected an array of values".to_string())),
+        };
+    }
+
+    Ok(content.lines()
+        .map(first_column)
+        .filter(|value| !value.is_empty())
+        .map(Value::String)
+        .collect())
+}
+
+/// First field of a CSV row; a quoted field may contain commas and `""` escapes.
+fn first_column(row: &str) -> String {
+    let row = row.trim();
+    let Some(quoted) = row.strip_prefix('"') else {
+        return row.split(',').next().unwrap_or_default().trim().to_string();
+    };
+
+    let mut value = String::new();
+    let mut chars = quoted.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if chars.peek() == Some(&'"') => {
+                chars.next();
+                value.push('"');
+            },
+            '"' => break,
+            char => value.push(char),
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_json_and_csv_packs() {
+        let dir = std::env::temp_dir().join(format!("jgd-data-pack-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let json = dir.join("cities.json");
+        std::fs::write(&json, r#"["Porto", "Braga"]"#).unwrap();
+        assert_eq!(read(&json).unwrap(), vec![Value::from("Porto"), Value::from("Braga")]);
+
+        let csv = dir.join("companies.csv");
+        std::fs::write(&csv, "Acme,US\n\n\"Foo, \"\"Bar\"\"\",PT\n").unwrap();
+        assert_eq!(read(&csv).unwrap(), vec![Value::from("Acme"), Value::from("Foo, \"Bar\"")]);
+
+        std::fs::write(&json, r#"{"city": "Porto"}"#).unwrap();
+        assert!(read(&json).unwrap_err().message.contains("expected an array"));
+        assert!(read(&dir.join("missing.csv")).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
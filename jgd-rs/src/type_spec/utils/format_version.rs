@@ -0,0 +1,135 @@
+use std::{fmt, str::FromStr};
+
+use serde_json::Value;
+
+use crate::type_spec::strict::versioned_fields;
+
+/// Version of the JGD format declared by the `$format` property of a schema.
+///
+/// - `jgd/v1`: `version`, `seed`, `defaultLocale` and `root` or `entities`; entities
+///   with `count`, `seed`, `uniqueBy` and `fields`; `array`, `number`, `optional` and
+///   `ref` fields.
+/// - `jgd/v2`: adds the schema-wide options `referenceDate`, `safeEmails`,
+///   `localeFallback`, `limits`, `fuzz`, `realism`, `constants`, `mixins`, `checks`
+///   and `keyCase`, the other entity properties (`relation`, `transforms`, `variants`,
+///   ...) and field kinds (`money`, `timeline`, `of` wrappers, ...).
+///
+/// `jgd/v1` schemas using `jgd/v2` options still generate, with a warning to migrate
+/// them. Strict parsing rejects unknown formats and versions newer than
+/// [`FormatVersion::LATEST`]; lenient parsing reports them as warnings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FormatVersion {
+    V1,
+    V2,
+}
+
+impl FormatVersion {
+    /// Newest format this crate generates.
+    pub const LATEST: FormatVersion = FormatVersion::V2;
+
+    /// The `$format` value, such as `jgd/v1`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FormatVersion::V1 => "jgd/v1",
+            FormatVersion::V2 => "jgd/v2",
+        }
+    }
+}
+
+impl fmt::Display for FormatVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FormatVersion {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "jgd/v1" => Ok(FormatVersion::V1),
+            "jgd/v2" => Ok(FormatVersion::V2),
+            _ => match value.strip_prefix("jgd/v").and_then(|number| number.parse::<u32>().ok()) {
+                Some(_) => Err(format!(
+                    "/$format {} is newer than the supported {}, upgrade jgd-rs",
+                    value, FormatVersion::LATEST
+                )),
+                None => Err(format!("/$format {} is unknown, expected jgd/v1 or jgd/v2", value)),
+            },
+        }
+    }
+}
+
+/// Returns the error of the `$format` of `document`, when it is not a supported format.
+pub(crate) fn format_error(document: &Value) -> Option<String> {
+    let format = document.get("$format")?.as_str()?;
+    format.parse::<FormatVersion>().err()
+}
+
+/// Returns the warnings of the `$format` of `document`: an unsupported format, or
+/// properties added by a newer format than the declared one.
+pub(crate) fn format_warnings(document: &Value) -> Vec<String> {
+    let Some(format) = document.get("$format").and_then(Value::as_str) else {
+        return Vec::new();
+    };
+
+    match format.parse::<FormatVersion>() {
+        Ok(declared) => versioned_fields(document).into_iter()
+            .filter(|(_, version)| *version > declared)
+            .map(|(pointer, version)| format!("{} is a {} property, set $format to {}", pointer, version, version))
+            .collect(),
+        Err(error) => vec![error],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_format_versions() {
+        assert_eq!("jgd/v2".parse(), Ok(FormatVersion::V2));
+        assert!(FormatVersion::V1 < FormatVersion::LATEST);
+        assert!("jgd/v3".parse::<FormatVersion>().unwrap_err().contains("is newer than the supported jgd/v2"));
+        assert!("jgd/1".parse::<FormatVersion>().unwrap_err().contains("is unknown, expected jgd/v1 or jgd/v2"));
+
+        let v1 = json!({ "$format": "jgd/v1", "version": "1.0", "realism": "messy", "root": { "fields": {} } });
+        assert_eq!(format_warnings(&v1), vec!["/realism is a jgd/v2 property, set $format to jgd/v2"]);
+        assert_eq!(format_error(&v1), None);
+
+        let v2 = json!({ "$format": "jgd/v2", "version": "1.0", "realism": "messy", "root": { "fields": {} } });
+        assert!(format_warnings(&v2).is_empty());
+
+        let nested = json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "entities": {
+                "users": {
+                    "count": 2,
+                    "transforms": ["upper"],
+                    "fields": {
+                        "id": "${index}",
+                        "salary": { "money": { "min": 1, "max": 9 } },
+                        "tags": { "array": { "of": { "of": "x", "pii": true }, "count": 1, "probEmpty": 0.5 } }
+                    }
+                },
+                "follows": { "relation": { "from": "users.id", "to": "users.id" } },
+                "events": { "fields": { "at": { "timeline": {} }, "user": { "ref": "users.id", "distribution": "zipf" } } }
+            }
+        });
+        assert_eq!(format_warnings(&nested), [
+            "/entities/users/transforms", "/entities/users/fields/salary/money", "/entities/users/fields/tags/array/probEmpty",
+            "/entities/users/fields/tags/array/of/pii", "/entities/follows/relation", "/entities/events/fields/at/timeline",
+            "/entities/events/fields/user/distribution",
+        ].map(|pointer| format!("{} is a jgd/v2 property, set $format to jgd/v2", pointer)));
+        assert!(crate::Jgd::from_value_strict(nested).is_ok());
+
+        let future = json!({ "$format": "jgd/v9", "version": "1.0", "root": { "fields": {} } });
+        assert_eq!(format_warnings(&future), vec![format_error(&future).unwrap()]);
+        let error = crate::Jgd::from_value_strict(future).unwrap_err();
+        assert_eq!(error.path.as_deref(), Some("/$format"));
+        assert!(crate::Jgd::from_value_strict(v1).is_ok());
+    }
+}
@@ -0,0 +1,112 @@
+use indexmap::IndexMap;
+use rand::{rngs::StdRng, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+use crate::type_spec::Field;
+
+/// Length of the very long strings emitted as boundary values.
+const LONG_STRING_LENGTH: usize = 10_000;
+
+/// Global perturbations that turn the generator into a fuzzing input source.
+///
+/// Unlike an entity `corruption`, fuzzing applies to every generated object. Both rates
+/// are probabilities between 0.0 and 1.0 and default to 0.0 (disabled). Fuzzing can be
+/// declared in the schema under `"fuzz"` or set programmatically on [`crate::Jgd::fuzz`].
+///
+/// ```json
+/// {
+///   "fuzz": { "dropOptional": 0.2, "boundaries": 0.05 }
+/// }
+/// ```
+///
+/// - `dropOptional`: chance that each `optional` field is left out of its object.
+/// - `boundaries`: chance that each string, number or array field is replaced by a
+///   boundary value: an empty or very long string, `0`, `i64::MIN`, `i64::MAX`, the
+///   extreme floats or an empty array.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FuzzSpec {
+    #[serde(default)]
+    pub drop_optional: f64,
+    #[serde(default)]
+    pub boundaries: f64,
+}
+
+fn chance(rng: &mut StdRng, rate: f64) -> bool {
+    rate > 0.0 && rng.random_bool(rate.min(1.0))
+}
+
+/// Returns a boundary value of the same type as `value`, or `None` for other types.
+pub(crate) fn boundary(value: &Value, rng: &mut StdRng) -> Option<Value> {
+    let boundary = match value {
+        Value::String(_) => match rng.random_bool(0.5) {
+            true => Value::String(String::new()),
+            false => Value::String("x".repeat(LONG_STRING_LENGTH)),
+        },
+        Value::Number(number) if number.is_f64() => {
+            let float = [0.0, f64::MAX, -f64::MAX][rng.random_range(0..3)];
+            Value::Number(Number::from_f64(float)?)
+        },
+        Value::Number(_) => Value::Number([0, i64::MIN, i64::MAX][rng.random_range(0..3)].into()),
+        Value::Array(_) => Value::Array(vec![]),
+        _ => return None,
+    };
+    Some(boundary)
+}
+
+impl FuzzSpec {
+    /// Whether no perturbation is enabled.
+    pub fn is_unset(&self) -> bool {
+        self.drop_optional <= 0.0 && self.boundaries <= 0.0
+    }
+
+    /// Drops optional fields of a generated object and replaces fields by boundary values.
+    pub(crate) fn apply(&self, object: &mut Map<String, Value>, fields: &IndexMap<String, Field>, rng: &mut StdRng) {
+        if self.is_unset() {
+            return;
+        }
+
+        for (key, field) in fields {
+            if matches!(field, Field::Optional { .. }) && chance(rng, self.drop_optional) {
+                object.remove(key);
+                continue;
+            }
+
+            if let Some(value) = object.get_mut(key) {
+                if chance(rng, self.boundaries) {
+                    if let Some(boundary) = boundary(value, rng) {
+                        *value = boundary;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use serde_json::json;
+
+    #[test]
+    fn test_fuzz_drops_optional_fields_and_emits_boundaries() {
+        let fields: IndexMap<String, Field> = serde_json::from_value(json!({
+            "name": "Alice",
+            "age": 30,
+            "nickname": { "optional": { "of": "Al", "prob": 1.0 } },
+            "active": true
+        })).unwrap();
+        let mut object = json!({ "name": "Alice", "age": 30, "nickname": "Al", "active": true });
+        let fuzz = FuzzSpec { drop_optional: 1.0, boundaries: 1.0 };
+
+        fuzz.apply(object.as_object_mut().unwrap(), &fields, &mut StdRng::seed_from_u64(3));
+
+        assert!(object.get("nickname").is_none());
+        assert!(["", "x".repeat(LONG_STRING_LENGTH).as_str()].contains(&object["name"].as_str().unwrap()));
+        assert!([0, i64::MIN, i64::MAX].contains(&object["age"].as_i64().unwrap()));
+        assert_eq!(object["active"], true);
+        assert!(FuzzSpec::default().is_unset());
+    }
+}
@@ -0,0 +1,184 @@
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{JgdErrorKind, JgdGeneratorError, LocalConfig};
+
+/// Guards that stop runaway generation before it exhausts memory.
+///
+/// Every limit is optional; `None` means unlimited. Limits can be declared in the schema
+/// under `"limits"` or set programmatically on [`crate::Jgd::limits`].
+///
+/// ```json
+/// {
+///   "limits": { "maxRecords": 1000000, "maxDepth": 16, "maxOutputBytes": 104857600 }
+/// }
+/// ```
+///
+/// - `maxRecords`: total generated items (entity instances and array elements).
+/// - `maxDepth`: maximum nesting of entities and arrays.
+/// - `maxOutputBytes`: approximate output size, measured as the compact JSON size of
+///   the top-level items.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationLimits {
+    #[serde(default, alias = "max_records", skip_serializing_if = "Option::is_none")]
+    pub max_records: Option<u64>,
+    #[serde(default, alias = "max_depth", skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+    #[serde(default, alias = "max_output_bytes", skip_serializing_if = "Option::is_none")]
+    pub max_output_bytes: Option<u64>,
+}
+
+/// Running totals checked against [`GenerationLimits`].
+#[derive(Debug, Clone, Default)]
+pub struct GenerationUsage {
+    pub records: u64,
+    pub depth: usize,
+    pub output_bytes: u64,
+}
+
+/// `Write` sink that only counts the bytes written to it.
+struct ByteCounter(u64);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Size of `value` serialized as compact JSON.
+pub(crate) fn compact_size(value: &Value) -> u64 {
+    let mut counter = ByteCounter(0);
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0
+}
+
+fn limit_error(message: String, local_config: Option<&LocalConfig>) -> JgdGeneratorError {
+    JgdGeneratorError {
+        message,
+        entity: local_config.and_then(|config| config.entity_name.clone()),
+        field: local_config.and_then(|config| config.field_name.clone()),
+        kind: JgdErrorKind::Limit,
+        path: None,
+        key: None,
+    }
+}
+
+impl GenerationLimits {
+    /// Returns `true` when no limit is set.
+    pub fn is_unset(&self) -> bool {
+        self == &GenerationLimits::default()
+    }
+
+    /// Registers `count` new items, failing when the total exceeds `maxRecords`.
+    pub fn reserve_records(&self, usage: &mut GenerationUsage, count: u64, local_config: Option<&LocalConfig>
+        ) -> Result<(), JgdGeneratorError> {
+        usage.records = usage.records.saturating_add(count);
+        match self.max_records {
+            Some(max) if usage.records > max => Err(limit_error(format!(
+                "Generation limit exceeded: {} records requested, but maxRecords is {}", usage.records, max
+            ), local_config)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Enters one nesting level, failing when it goes deeper than `maxDepth`.
+    ///
+    /// The level is only entered on success, so a failed call needs no matching `exit_level`.
+    pub fn enter_level(&self, usage: &mut GenerationUsage, local_config: Option<&LocalConfig>
+        ) -> Result<(), JgdGeneratorError> {
+        let depth = usage.depth + 1;
+        match self.max_depth {
+            Some(max) if depth > max => Err(limit_error(format!(
+                "Generation limit exceeded: nesting depth {} is deeper than maxDepth {}", depth, max
+            ), local_config)),
+            _ => {
+                usage.depth = depth;
+                Ok(())
+            }
+        }
+    }
+
+    /// Leaves a nesting level entered with [`GenerationLimits::enter_level`].
+    pub fn exit_level(&self, usage: &mut GenerationUsage) {
+        usage.depth = usage.depth.saturating_sub(1);
+    }
+
+    /// Adds the compact size of a top-level item, failing when the total exceeds `maxOutputBytes`.
+    ///
+    /// Nested items are skipped, since they are already counted as part of their parent.
+    pub fn account_output(&self, usage: &mut GenerationUsage, value: &Value, local_config: Option<&LocalConfig>
+        ) -> Result<(), JgdGeneratorError> {
+        let Some(max) = self.max_output_bytes else {
+            return Ok(());
+        };
+        if usage.depth > 1 {
+            return Ok(());
+        }
+
+        usage.output_bytes = usage.output_bytes.saturating_add(compact_size(value));
+
+        if usage.output_bytes > max {
+            return Err(limit_error(format!(
+                "Generation limit exceeded: output reached {} bytes, but maxOutputBytes is {}", usage.output_bytes, max
+            ), local_config));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_camel_case() {
+        let limits: GenerationLimits = serde_json::from_value(json!({
+            "maxRecords": 10, "maxDepth": 2
+        })).unwrap();
+
+        assert_eq!(limits.max_records, Some(10));
+        assert_eq!(limits.max_depth, Some(2));
+        assert_eq!(limits.max_output_bytes, None);
+    }
+
+    #[test]
+    fn test_reserve_records() {
+        let limits = GenerationLimits { max_records: Some(5), ..Default::default() };
+        let mut usage = GenerationUsage::default();
+
+        assert!(limits.reserve_records(&mut usage, 5, None).is_ok());
+        let error = limits.reserve_records(&mut usage, 1, None).unwrap_err();
+        assert!(error.message.contains("maxRecords is 5"));
+    }
+
+    #[test]
+    fn test_enter_and_exit_level() {
+        let limits = GenerationLimits { max_depth: Some(1), ..Default::default() };
+        let mut usage = GenerationUsage::default();
+
+        assert!(limits.enter_level(&mut usage, None).is_ok());
+        assert!(limits.enter_level(&mut usage, None).is_err());
+        assert_eq!(usage.depth, 1);
+        limits.exit_level(&mut usage);
+        assert_eq!(usage.depth, 0);
+    }
+
+    #[test]
+    fn test_account_output() {
+        let limits = GenerationLimits { max_output_bytes: Some(10), ..Default::default() };
+        let mut usage = GenerationUsage { depth: 1, ..Default::default() };
+
+        assert!(limits.account_output(&mut usage, &json!("abc"), None).is_ok());
+        assert!(limits.account_output(&mut usage, &json!("abcdefgh"), None).is_err());
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 use rand::{random_range, rngs::StdRng, SeedableRng};
 use serde_json::Value;
 
-use crate::fake::{FakeGenerator, FakeKeys};
+use crate::{fake::{FakeGenerator, FakeKeys}, FuzzSpec, GenerationLimits, GenerationUsage, KeyCase, LocaleFallback, Realism, SpecId, ValueProvider};
 
 /// Configuration for JSON data generation in the JGD system.
 ///
@@ -43,9 +46,7 @@ pub struct GeneratorConfig {
     /// This field determines the language and regional settings used for generating
     /// locale-specific fake data such as names, addresses, and phone numbers.
     /// Common values include "EN" for English, "ES" for Spanish, etc.
-    ///
-    /// Currently marked as `dead_code` but preserved for future locale-specific features.
-    #[allow(dead_code)]
+    /// It is also the locale of the `localeFormat` transform.
     pub locale: String,
 
     /// Keys for accessing fake data categories.
@@ -55,12 +56,12 @@ pub struct GeneratorConfig {
     /// fake generator to produce realistic test data.
     pub fake_keys: FakeKeys,
 
-    /// Generator for producing locale-specific fake data.
+    /// Source of the template key values.
     ///
-    /// This component generates realistic fake data such as names, addresses,
-    /// phone numbers, and other locale-specific information based on the
-    /// configured locale setting.
-    pub fake_generator: FakeGenerator,
+    /// A [`FakeGenerator`] for the configured locale by default, generating realistic
+    /// names, addresses, phone numbers and other locale-specific data. Replace it with
+    /// [`GeneratorConfig::with_provider`] to use another data source.
+    pub provider: Box<dyn ValueProvider>,
 
     /// Random number generator for deterministic or random generation.
     ///
@@ -69,6 +70,9 @@ pub struct GeneratorConfig {
     /// truly random output.
     pub rng: StdRng,
 
+    /// Seed of `rng`, drawn at random when none was given; see [`RunMetadata`](crate::RunMetadata).
+    pub seed: u64,
+
     /// Map storing generated values for cross-references and relationships.
     ///
     /// This map maintains the state of previously generated values during a
@@ -76,6 +80,45 @@ pub struct GeneratorConfig {
     /// generated values or maintaining relationships between different parts
     /// of the generated data structure.
     pub gen_value: serde_json::Map<String, Value>,
+
+    /// Guards against runaway generation (records, depth and output size).
+    pub limits: GenerationLimits,
+
+    /// Running totals checked against `limits` during the generation session.
+    pub usage: GenerationUsage,
+
+    /// Perturbations applied to every generated object, for fuzz-style testing.
+    pub fuzz: FuzzSpec,
+
+    /// How clean the generated data is; the declared data when `None`.
+    pub realism: Option<Realism>,
+
+    /// Naming convention applied to the output keys, if any.
+    pub key_case: Option<KeyCase>,
+
+    /// Schema-level constants injected into every top-level record.
+    pub constants: serde_json::Map<String, Value>,
+
+    /// Whether keys without data in `locale` fall back to English or fail.
+    pub locale_fallback: LocaleFallback,
+
+    /// Next position of each round-robin `ref`, keyed by its entity and its field id.
+    pub(crate) ref_cursors: HashMap<(Option<String>, SpecId), usize>,
+
+    /// Cumulative Zipf weights of the `ref` candidates, per number of candidates.
+    pub(crate) zipf_cdfs: HashMap<usize, Vec<f64>>,
+
+    /// "Now" of the relative dates, set with [`GeneratorConfig::with_reference_date`].
+    pub(crate) reference_date: Option<DateTime<Utc>>,
+
+    /// Whether emails use reserved domains, set with [`GeneratorConfig::with_safe_emails`].
+    pub(crate) safe_emails: bool,
+}
+
+/// Locale and provider replaced while generating an item of another locale.
+pub(crate) struct SwitchedLocale {
+    locale: String,
+    provider: Box<dyn ValueProvider>,
 }
 
 impl GeneratorConfig {
@@ -115,19 +158,90 @@ impl GeneratorConfig {
     /// - Consistent development environments
     pub fn new(locale: &str, seed: Option<u64>) -> Self {
         let fake_keys = FakeKeys::new();
-        let fake_generator = FakeGenerator::new(locale);
-        let rng = StdRng::seed_from_u64(seed.unwrap_or(rand::random()));
+        let provider = Box::new(FakeGenerator::new(locale));
+        let seed = seed.unwrap_or_else(rand::random);
+        let rng = StdRng::seed_from_u64(seed);
         let locale = locale.to_string();
 
         Self {
             locale,
             fake_keys,
-            fake_generator,
+            provider,
             rng,
+            seed,
             gen_value: serde_json::Map::new(),
+            limits: GenerationLimits::default(),
+            usage: GenerationUsage::default(),
+            fuzz: FuzzSpec::default(),
+            realism: None,
+            key_case: None,
+            constants: serde_json::Map::new(),
+            locale_fallback: LocaleFallback::default(),
+            ref_cursors: HashMap::new(),
+            zipf_cdfs: HashMap::new(),
+            reference_date: None,
+            safe_emails: false,
+        }
+    }
+
+    /// Generates with the built-in fake data of `locale` until [`GeneratorConfig::restore_locale`],
+    /// for the items of an entity with a `localeMix`.
+    pub(crate) fn switch_locale(&mut self, locale: &str) -> SwitchedLocale {
+        let provider = Box::new(self.fake_generator(locale));
+        SwitchedLocale {
+            locale: std::mem::replace(&mut self.locale, locale.to_string()),
+            provider: std::mem::replace(&mut self.provider, provider),
+        }
+    }
+
+    /// Restores the locale and provider replaced by [`GeneratorConfig::switch_locale`].
+    pub(crate) fn restore_locale(&mut self, previous: SwitchedLocale) {
+        self.locale = previous.locale;
+        self.provider = previous.provider;
+    }
+
+    /// Generates relative dates from `reference_date` instead of the current time, so
+    /// seeded runs give the same dates whenever they run.
+    ///
+    /// Replaces the provider with the built-in fake data anchored at that date; call
+    /// [`GeneratorConfig::with_provider`] afterwards to use another one.
+    pub fn with_reference_date(mut self, reference_date: DateTime<Utc>) -> Self {
+        self.reference_date = Some(reference_date);
+        self.provider = Box::new(self.fake_generator(&self.locale));
+        self
+    }
+
+    /// Replaces the real-looking top-level domains of the email keys with the reserved
+    /// `.test`, for fixtures that may end up in a system sending mail.
+    ///
+    /// Replaces the provider like [`GeneratorConfig::with_reference_date`].
+    pub fn with_safe_emails(mut self, safe_emails: bool) -> Self {
+        self.safe_emails = safe_emails;
+        self.provider = Box::new(self.fake_generator(&self.locale));
+        self
+    }
+
+    /// The reference date, or the current time when none is set.
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        self.reference_date.unwrap_or_else(Utc::now)
+    }
+
+    /// Built-in fake data of `locale`, anchored at the reference date.
+    fn fake_generator(&self, locale: &str) -> FakeGenerator {
+        let generator = FakeGenerator::new(locale).with_safe_emails(self.safe_emails);
+        match self.reference_date {
+            Some(reference_date) => generator.with_reference_date(reference_date),
+            None => generator,
         }
     }
 
+    /// Replaces the source of the template key values, e.g. with a fixed dictionary or
+    /// a client of another data service. See [`ValueProvider`].
+    pub fn with_provider(mut self, provider: Box<dyn ValueProvider>) -> Self {
+        self.provider = provider;
+        self
+    }
+
     /// Retrieves a random item from an array and extracts a specific field.
     ///
     /// This is a utility method for working with arrays of objects during generation.
@@ -259,6 +373,38 @@ impl GeneratorConfig {
 
         current_value
     }
+
+    /// Collects every value reachable through a dot-notation path.
+    ///
+    /// Unlike [`GeneratorConfig::get_value_from_path`], arrays are not sampled: each item
+    /// is visited, so `"users.id"` returns the `id` of every generated user.
+    pub fn get_values_from_path(&self, path: &str) -> Vec<&Value> {
+        let mut keys = path.split('.');
+        let mut current: Vec<&Value> = match keys.next().and_then(|key| self.gen_value.get(key)) {
+            Some(value) => vec![value],
+            None => return vec![],
+        };
+
+        for key in keys {
+            current = current.into_iter()
+                .flat_map(|value| match value {
+                    Value::Array(items) => items.iter().collect(),
+                    _ => vec![value],
+                })
+                .filter_map(|value| match value {
+                    Value::Object(map) => map.get(key),
+                    _ => None,
+                })
+                .collect();
+        }
+
+        current.into_iter()
+            .flat_map(|value| match value {
+                Value::Array(items) => items.iter().collect(),
+                _ => vec![value],
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -497,4 +643,14 @@ mod tests {
         assert_eq!(config.gen_value.get("key1"), Some(&json!("value1")));
         assert_eq!(config.gen_value.get("key2"), Some(&json!({"nested": "value2"})));
     }
+
+    #[test]
+    fn test_get_values_from_path_visits_every_item() {
+        let mut config = GeneratorConfig::new("EN", Some(42));
+        config.gen_value.insert("users".to_string(), json!([{"id": 1}, {"id": 2}, {"name": "x"}]));
+
+        assert_eq!(config.get_values_from_path("users.id"), vec![&json!(1), &json!(2)]);
+        assert_eq!(config.get_values_from_path("users").len(), 3);
+        assert!(config.get_values_from_path("missing.id").is_empty());
+    }
 }
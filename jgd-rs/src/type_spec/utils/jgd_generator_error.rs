@@ -2,15 +2,64 @@ use std::fmt::Display;
 
 use serde::Serialize;
 
+/// Category of a [`JgdGeneratorError`], so tools can react to errors without
+/// parsing their message.
+#[derive(Debug, Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum JgdErrorKind {
+    /// An unknown template key or invalid key arguments.
+    Template,
+    /// A `ref` or relation path without generated values.
+    Reference,
+    /// A generation limit was exceeded.
+    Limit,
+    /// An invalid schema, override, overlay or selection.
+    Schema,
+    /// The generated output could not be written.
+    Output,
+    /// A value could not be serialized or deserialized.
+    Serialization,
+    #[default]
+    Other,
+}
+
+/// Error returned by schema loading, editing and generation.
+///
+/// `path` and `key` are boxed to keep `Result<_, JgdGeneratorError>` small.
 #[derive(Debug, Serialize, Default, Clone)]
 pub struct JgdGeneratorError {
     pub message: String,
     pub entity: Option<String>,
     pub field: Option<String>,
+    pub kind: JgdErrorKind,
+    /// JSON Pointer to the schema location of the error, e.g. `/entities/posts/fields/author_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<Box<str>>,
+    /// The offending template key or ref path, e.g. `${name.unknown}` or `users.id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<Box<str>>,
+}
+
+impl JgdGeneratorError {
+    /// Prepends schema path segments to the error path, from the outermost to the innermost.
+    ///
+    /// Generators call this while the error bubbles up, so the final path points from
+    /// the schema document root to the failing field.
+    pub fn within(mut self, segments: &[&str]) -> Self {
+        let prefix: String = segments.iter()
+            .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+            .collect();
+        self.path = Some((prefix + self.path.as_deref().unwrap_or_default()).into());
+        self
+    }
 }
 
 impl Display for JgdGeneratorError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(path) = &self.path {
+            return write!(f, "{} (at {})", self.message, path);
+        }
+
         match (&self.entity, &self.field) {
             (Some(entity), Some(field)) => write!(f, "{} (entity: {}, field: {})", self.message, entity, field),
             (Some(entity), None) => write!(f, "{} (entity: {})", self.message, entity),
@@ -19,3 +68,26 @@ impl Display for JgdGeneratorError {
         }
     }
 }
+
+impl std::error::Error for JgdGeneratorError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_within_builds_escaped_pointer() {
+        let error = JgdGeneratorError {
+            message: "Error".to_string(),
+            kind: JgdErrorKind::Template,
+            ..Default::default()
+        };
+
+        let error = error.within(&["fields", "a/b~c"]).within(&["entities", "posts"]);
+        assert_eq!(error.path.as_deref(), Some("/entities/posts/fields/a~1b~0c"));
+        assert_eq!(error.to_string(), "Error (at /entities/posts/fields/a~1b~0c)");
+
+        let boxed: Box<dyn std::error::Error> = Box::new(error);
+        assert!(boxed.source().is_none());
+    }
+}
@@ -9,18 +9,21 @@ pub type CustomKeyFunction = Arc<dyn (Fn(Arguments) -> Result<Value, String>) +
 #[derive(Default)]
 pub struct JgdGlobalConfig {
     pub custom_keys: HashMap<&'static str, CustomKeyFunction>,
+    /// User values of fake keys, by locale and key.
+    pub data_packs: HashMap<(String, String), Arc<Vec<Value>>>,
 }
 
 impl std::fmt::Debug for JgdGlobalConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("JgdGlobalConfig")
             .field("custom_keys", &format!("HashMap with {} entries", self.custom_keys.len()))
+            .field("data_packs", &format!("HashMap with {} entries", self.data_packs.len()))
             .finish()
     }
 }
 
 impl JgdGlobalConfig {
     pub fn new() -> Self {
-        Self { custom_keys: HashMap::new() }
+        Self { custom_keys: HashMap::new(), data_packs: HashMap::new() }
     }
 }
@@ -0,0 +1,169 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+/// Naming convention applied to object keys in the generated output.
+///
+/// Set with `"keyCase"` at the schema top level (or `--key-case` on the CLI). Field
+/// names are converted only in the output, so `ref` paths keep using the names
+/// declared in the schema. Entity names in entities mode are left untouched.
+///
+/// ```json
+/// { "keyCase": "snake_case" }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum KeyCase {
+    /// `firstName`
+    Camel,
+    /// `first_name`
+    Snake,
+    /// `first-name`
+    Kebab,
+    /// `FirstName`
+    Pascal,
+}
+
+impl FromStr for KeyCase {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "camelCase" | "camel" => Ok(KeyCase::Camel),
+            "snake_case" | "snake" => Ok(KeyCase::Snake),
+            "kebab-case" | "kebab" => Ok(KeyCase::Kebab),
+            "PascalCase" | "pascal" => Ok(KeyCase::Pascal),
+            _ => Err(format!(
+                "Error to parse the key case {}: expected camelCase, snake_case, kebab-case or PascalCase", value
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for KeyCase {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Serialize for KeyCase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Display for KeyCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            KeyCase::Camel => "camelCase",
+            KeyCase::Snake => "snake_case",
+            KeyCase::Kebab => "kebab-case",
+            KeyCase::Pascal => "PascalCase",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Splits a key into lowercase words on separators and case changes
+/// (`userID_v2` → `user`, `id`, `v2`; `HTTPServer` → `http`, `server`).
+fn split_words(key: &str) -> Vec<String> {
+    let chars: Vec<char> = key.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !current.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if !previous.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.extend(c.to_lowercase());
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+impl KeyCase {
+    /// Converts a single key to this convention.
+    pub fn convert(&self, key: &str) -> String {
+        let words = split_words(key);
+        if words.is_empty() {
+            return key.to_string();
+        }
+
+        match self {
+            KeyCase::Snake => words.join("_"),
+            KeyCase::Kebab => words.join("-"),
+            KeyCase::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            KeyCase::Camel => words.iter()
+                .enumerate()
+                .map(|(i, word)| if i == 0 { word.clone() } else { capitalize(word) })
+                .collect(),
+        }
+    }
+
+    /// Converts the keys of every object in `value`, recursively.
+    pub fn apply(&self, value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(map.into_iter()
+                .map(|(key, value)| (self.convert(&key), self.apply(value)))
+                .collect()),
+            Value::Array(items) => Value::Array(items.into_iter().map(|item| self.apply(item)).collect()),
+            value => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_convert_keys() {
+        assert_eq!(KeyCase::Snake.convert("firstName"), "first_name");
+        assert_eq!(KeyCase::Camel.convert("first_name"), "firstName");
+        assert_eq!(KeyCase::Kebab.convert("HTTPServer"), "http-server");
+        assert_eq!(KeyCase::Pascal.convert("user-id"), "UserId");
+        assert_eq!(KeyCase::Snake.convert("userID2"), "user_id2");
+    }
+
+    #[test]
+    fn test_apply_recursively() {
+        let value = json!({ "userName": "a", "homeAddress": { "zipCode": "1" }, "tagList": [{ "tagName": "x" }] });
+        assert_eq!(KeyCase::Snake.apply(value), json!({
+            "user_name": "a", "home_address": { "zip_code": "1" }, "tag_list": [{ "tag_name": "x" }]
+        }));
+    }
+
+    #[test]
+    fn test_parse_key_case() {
+        assert_eq!("kebab-case".parse::<KeyCase>(), Ok(KeyCase::Kebab));
+        assert!("SCREAMING".parse::<KeyCase>().is_err());
+    }
+}
@@ -1,12 +1,39 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use chrono::{DateTime, FixedOffset};
 use rand::rngs::StdRng;
 use serde_json::Value;
 
-use crate::{Replacer};
+use crate::{Replacer, SpecId};
 
 const INDEX_KEY: &str = "index";
 const COUNT_KEY: &str = "count";
 const ENTITY_NAME_KEY: &str = "entity.name";
 const FIELD_NAME_KEY: &str = "field.name";
+const PREVIOUS_KEY: &str = "previous";
+const ITEMS_KEY: &str = "items[";
+
+/// Follows a dot separated `path` into `value`; numeric segments index arrays.
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|index| items.get(index)),
+            _ => None,
+        })
+}
+
+/// State of the stateful fields of a generated array, keyed by field specification.
+#[derive(Default)]
+pub(crate) struct ArrayState {
+    /// Running totals of cumulative fields, by field id.
+    pub(crate) totals: HashMap<SpecId, f64>,
+    /// Current state of state machine fields, by field id and value of their `by` field.
+    pub(crate) states: HashMap<(SpecId, String), String>,
+    /// Last timestamp of timeline fields, by field id.
+    pub(crate) timestamps: HashMap<SpecId, DateTime<FixedOffset>>,
+}
 
 pub struct LocalConfig {
     /// Random number generator for deterministic or random generation.
@@ -23,6 +50,16 @@ pub struct LocalConfig {
     pub indices: Vec<usize>,
 
     pub count_items: u64,
+
+    /// Items already generated by the nearest entity array, for `${previous.field}` and
+    /// `${items[0].field}`. Shared with nested levels, so they read it without copying.
+    pub(crate) items: Rc<Vec<Value>>,
+
+    /// Fields already generated for the current object, read by cumulative fields.
+    pub(crate) siblings: serde_json::Map<String, Value>,
+
+    /// State of the cumulative, state machine and timeline fields of the nearest entity array.
+    pub(crate) array_state: Rc<RefCell<ArrayState>>,
 }
 
 impl LocalConfig {
@@ -33,6 +70,9 @@ impl LocalConfig {
             field_name: None,
             indices: vec![],
             count_items: 0,
+            items: Rc::default(),
+            siblings: serde_json::Map::new(),
+            array_state: Rc::default(),
         }
     }
 
@@ -60,6 +100,9 @@ impl LocalConfig {
             field_name: field_name.map(|v| v.to_string()),
             indices,
             count_items,
+            items: Rc::default(),
+            siblings: serde_json::Map::new(),
+            array_state: Rc::default(),
         }
     }
 
@@ -82,7 +125,7 @@ impl LocalConfig {
             } else {
                 config.rng.clone()
             };
-            return Self::from_current(
+            let mut local_config = Self::from_current(
                 rng,
                 count_items,
                 config.entity_name.as_deref(),
@@ -90,6 +133,9 @@ impl LocalConfig {
                 index,
                 Some(&config.indices)
             );
+            local_config.items = Rc::clone(&config.items);
+            local_config.array_state = Rc::clone(&config.array_state);
+            return local_config;
         }
 
         let (count_items, index) = if let Some(count_items) = count_items {
@@ -114,7 +160,48 @@ impl LocalConfig {
         }
     }
 
+    /// Returns `true` when `key` is resolved from the generation context
+    /// (`index`, `count`, `entity.name`, `field.name`, `previous`, `items[n]`) instead of a fake generator.
+    pub fn is_context_key(key: &str) -> bool {
+        matches!(key, INDEX_KEY | COUNT_KEY | ENTITY_NAME_KEY | FIELD_NAME_KEY) || Self::is_item_key(key)
+    }
+
+    /// Returns `true` when `key` reads an already generated item (`previous.field`, `items[0].field`).
+    pub fn is_item_key(key: &str) -> bool {
+        let rest = match key.strip_prefix(PREVIOUS_KEY) {
+            Some(rest) => rest,
+            None => match key.strip_prefix(ITEMS_KEY).and_then(|rest| rest.split_once(']')) {
+                Some((index, rest)) if index.trim().parse::<isize>().is_ok() => rest,
+                _ => return false,
+            },
+        };
+        rest.is_empty() || rest.starts_with('.')
+    }
+
+    /// Resolves `previous[.path]` and `items[n][.path]`, where a negative `n` counts from
+    /// the last generated item. Items or fields that do not exist yet resolve to `null`.
+    fn process_item_key(&self, key: &str) -> Value {
+        let (item, path) = match key.strip_prefix(PREVIOUS_KEY) {
+            Some(path) => (self.items.last(), path),
+            None => {
+                let (index, path) = key[ITEMS_KEY.len()..].split_once(']').unwrap_or_default();
+                let index: isize = index.trim().parse().unwrap_or_default();
+                let index = match index < 0 {
+                    true => self.items.len().checked_sub(index.unsigned_abs()),
+                    false => Some(index.unsigned_abs()),
+                };
+                (index.and_then(|index| self.items.get(index)), path)
+            },
+        };
+
+        item.and_then(|item| lookup(item, path)).cloned().unwrap_or(Value::Null)
+    }
+
     pub fn process_key(&self, replacer: &Replacer) -> Option<Value> {
+        if Self::is_item_key(&replacer.key) {
+            return Some(self.process_item_key(&replacer.key));
+        }
+
         match replacer.key.as_str() {
             INDEX_KEY => {
                 let depth = replacer.arguments.get_number(1) - 1;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// What to do with keys that have no data in the schema locale.
+///
+/// The `fake` crate only has localized data for some keys of each locale (see
+/// [`FakeKeys::has_locale_data`](crate::FakeKeys::has_locale_data)); the other keys
+/// generate English data. Set with `"localeFallback"` at the schema top level:
+///
+/// ```json
+/// { "defaultLocale": "PT_BR", "localeFallback": "error" }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LocaleFallback {
+    /// Generate English data; [`Jgd::explain`](crate::Jgd::explain) lists the fallbacks
+    /// and the CLI prints them as warnings.
+    #[default]
+    Warn,
+
+    /// Fail the generation on the first key without data in the locale.
+    Error,
+}
+
+impl LocaleFallback {
+    /// Whether this is the default, warning behavior.
+    pub fn is_warn(&self) -> bool {
+        *self == LocaleFallback::Warn
+    }
+}
@@ -4,10 +4,37 @@ mod replacer;
 mod arguments;
 mod jgd_global_config;
 mod jgd_generator_error;
+mod generation_limits;
+mod fuzz;
+mod realism;
+mod format_version;
+mod transform;
+mod key_case;
+mod canonical;
+mod checkpoint;
+mod locale_fallback;
+pub(crate) mod data_pack;
+mod value_provider;
+mod run_metadata;
+mod spec_id;
 
 pub use generator_config::*;
 pub use replacer::*;
 pub use arguments::*;
 pub use jgd_global_config::*;
 pub use jgd_generator_error::*;
+pub use generation_limits::*;
+pub use fuzz::*;
+pub use realism::Realism;
+pub use format_version::FormatVersion;
+pub(crate) use format_version::{format_error, format_warnings};
+pub use transform::*;
+pub use key_case::*;
+pub use canonical::*;
+pub use checkpoint::Checkpoint;
+pub(crate) use checkpoint::CheckpointWriter;
 pub use local_config::*;
+pub use locale_fallback::LocaleFallback;
+pub use value_provider::ValueProvider;
+pub use run_metadata::{RunMetadata, META_KEY};
+pub use spec_id::SpecId;
@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use rand::{rngs::StdRng, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::fuzz;
+use crate::{fake::{pick, strings}, type_spec::{corruption_spec, Field}, Arguments, GeneratorConfig};
+
+/// Share of the declared `optional` probability kept by the `messy` profile.
+const MESSY_OPTIONAL: f64 = 0.5;
+
+/// Share of the declared `optional` probability kept by the `adversarial` profile.
+const ADVERSARIAL_OPTIONAL: f64 = 0.25;
+
+/// Whitespace padded around the strings of the `messy` profile.
+const PADDING: &[&str] = &[" ", "  ", "\t", "\n", " \u{00A0}"];
+
+/// How clean the generated data is, for the whole schema.
+///
+/// One schema can produce both clean demo data and messy robustness-test data without
+/// editing every field. Set with `"realism"` at the schema top level (or `--realism` on
+/// the CLI):
+///
+/// ```json
+/// { "realism": "messy" }
+/// ```
+///
+/// Like `fuzz`, the string and number changes apply to the fields of every generated
+/// object; top-level arrays of plain values are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Realism {
+    /// Clean data: every `optional` field is generated, strings and numbers are kept.
+    Strict,
+
+    /// Real-world data: `optional` fields are generated half as often, 5% of the strings
+    /// get stray whitespace, a typo, a different case or a shorter length, and 2% of the
+    /// numbers fall outside their range.
+    Messy,
+
+    /// Hostile data: `optional` fields are generated a quarter as often, 15% of the
+    /// strings are empty, whitespace-only, very long or injection-like, and 10% of the
+    /// numbers are `0`, extreme or outside their range.
+    Adversarial,
+}
+
+impl FromStr for Realism {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "strict" => Ok(Realism::Strict),
+            "messy" => Ok(Realism::Messy),
+            "adversarial" => Ok(Realism::Adversarial),
+            _ => Err(format!("Error to parse the realism {}: expected strict, messy or adversarial", value)),
+        }
+    }
+}
+
+fn chance(rng: &mut StdRng, rate: f64) -> bool {
+    rate > 0.0 && rng.random_bool(rate.min(1.0))
+}
+
+impl Realism {
+    /// Probability of an `optional` field declared with `prob`.
+    pub(crate) fn optional_prob(self, prob: f64) -> f64 {
+        match self {
+            Realism::Strict => 1.0,
+            Realism::Messy => prob * MESSY_OPTIONAL,
+            Realism::Adversarial => prob * ADVERSARIAL_OPTIONAL,
+        }
+    }
+
+    /// Chances that a string and that a number are changed.
+    fn rates(self) -> (f64, f64) {
+        match self {
+            Realism::Strict => (0.0, 0.0),
+            Realism::Messy => (0.05, 0.02),
+            Realism::Adversarial => (0.15, 0.1),
+        }
+    }
+
+    fn string(self, text: &str, config: &mut GeneratorConfig) -> Value {
+        let rng = &mut config.rng;
+        let text = match (self, rng.random_range(0..4)) {
+            (Realism::Messy, 0) => format!("{}{}{}", pick(PADDING, rng), text, pick(PADDING, rng)),
+            (Realism::Messy, 1) => corruption_spec::typo(text, config),
+            (Realism::Messy, 2) => text.to_uppercase(),
+            (Realism::Messy, _) => {
+                let length = text.chars().count();
+                text.chars().take(rng.random_range(0..=length / 2)).collect()
+            },
+            (_, 0) => return fuzz::boundary(&Value::String(String::new()), rng).unwrap_or_default(),
+            _ => return strings::edge(&Arguments::default(), rng),
+        };
+        Value::String(text)
+    }
+
+    fn number(self, value: &Value, field: &Field, config: &mut GeneratorConfig) -> Value {
+        match self {
+            Realism::Adversarial if config.rng.random_bool(0.5) => {
+                fuzz::boundary(value, &mut config.rng).unwrap_or_else(|| value.clone())
+            },
+            _ => corruption_spec::outlier(value, Some(field), config),
+        }
+    }
+
+    /// Changes some strings and numbers of a generated object, by the profile rates.
+    pub(crate) fn apply(self, object: &mut Map<String, Value>, fields: &IndexMap<String, Field>, config: &mut GeneratorConfig) {
+        let (strings, numbers) = self.rates();
+        if strings <= 0.0 && numbers <= 0.0 {
+            return;
+        }
+
+        for (key, field) in fields {
+            let Some(value) = object.get_mut(key) else {
+                continue;
+            };
+            match value {
+                Value::String(text) if chance(&mut config.rng, strings) => *value = self.string(text, config),
+                Value::Number(_) if chance(&mut config.rng, numbers) => *value = self.number(value, field, config),
+                _ => {},
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    fn generate(realism: &str) -> Vec<Value> {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 11,
+            "realism": realism,
+            "root": {
+                "count": 400,
+                "fields": {
+                    "name": "Alice Smith",
+                    "age": { "number": { "min": 18, "max": 65, "integer": true } },
+                    "bio": { "optional": { "of": "hello", "prob": 0.8 } }
+                }
+            }
+        }));
+        jgd.generate().unwrap().as_array().unwrap().clone()
+    }
+
+    #[test]
+    fn test_realism_profiles() {
+        let strict = generate("strict");
+        assert!(strict.iter().all(|item| item["bio"] == "hello" && item["name"] == "Alice Smith"));
+        assert!(strict.iter().all(|item| (18..=65).contains(&item["age"].as_i64().unwrap())));
+
+        let count = |items: &[Value], test: fn(&Value) -> bool| items.iter().filter(|item| test(item)).count();
+        let messy = generate("messy");
+        let adversarial = generate("adversarial");
+        assert!(count(&messy, |item| item["bio"].is_null()) > count(&strict, |item| item["bio"].is_null()));
+        assert!(count(&adversarial, |item| item["bio"].is_null()) > count(&messy, |item| item["bio"].is_null()));
+        assert!(count(&messy, |item| item["name"] != "Alice Smith") > 0);
+        assert!(count(&adversarial, |item| item["name"] != "Alice Smith") > count(&messy, |item| item["name"] != "Alice Smith"));
+        assert!(count(&adversarial, |item| !(18..=65).contains(&item["age"].as_i64().unwrap_or(0))) > 0);
+
+        assert_eq!("messy".parse(), Ok(Realism::Messy));
+        assert!("chaotic".parse::<Realism>().is_err());
+    }
+}
@@ -3,20 +3,28 @@ use std::sync::LazyLock;
 use regex::Regex;
 use serde_json::Value;
 
-use crate::{type_spec::GeneratorConfig, Arguments, Jgd, JgdGeneratorError, LocalConfig};
+use crate::{type_spec::GeneratorConfig, apply_transforms_with, Arguments, Jgd, JgdErrorKind, JgdGeneratorError, LocalConfig, LocaleFallback, Transform};
 
 /// Global regex pattern for matching JGD fake data placeholders.
 ///
 /// This regex matches patterns in the format `${key}` or `${key(arguments)}` where:
 /// - `key` can contain dots for nested paths (e.g., `name.firstName`)
 /// - `arguments` are optional and enclosed in parentheses
+/// - optional `|transform` pipes follow the key (e.g., `${name.firstName|upper}`)
 ///
 /// Examples of matched patterns:
 /// - `${name.firstName}`
 /// - `${address.cityName}`
 /// - `${lorem.words(5)}`
 /// - `${number.integer(1..100)}`
-static RE_FAKES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\$\{(.+?)(\(.+?\))?\})").unwrap());
+/// - `${lorem.words(3)|slugify|truncate(20)}`
+/// - `${number.numberWithFormat('(###) ###-####, ext ###')}`
+///
+/// `$${` is an escape for a literal `${` and never starts a placeholder. Quoted arguments
+/// may contain `)`, `}` and `|`.
+static RE_FAKES: LazyLock<Regex> = LazyLock::new(|| Regex::new(
+    r#"(\$\{([^|}]+?)(\((?:'(?:[^'\\]|\\.)*'|"(?:[^"\\]|\\.)*"|.)+?\))?((?:\|[^|}]+)*)\})"#
+).unwrap());
 
 /// Represents a single placeholder replacement within a JGD template string.
 ///
@@ -112,9 +120,9 @@ pub struct Replacer {
     ///
     /// # Argument Types
     ///
-    /// - `Arguments::None`: No arguments provided (e.g., `${name.firstName}`)
-    /// - `Arguments::Fixed(value)`: Single argument (e.g., `${lorem.words(5)}`)
-    /// - `Arguments::Range(start, end)`: Range arguments (e.g., `${number.between(1,100)}`)
+    /// - No arguments (e.g., `${name.firstName}`)
+    /// - A single argument (e.g., `${lorem.words(5)}`)
+    /// - Several positional arguments (e.g., `${number.between(1,100)}`)
     ///
     /// # Usage
     ///
@@ -124,6 +132,12 @@ pub struct Replacer {
     /// - Date generators use arguments for date ranges or offsets
     pub arguments: Arguments,
 
+    /// Transforms piped after the key, applied in order to the generated value.
+    ///
+    /// `${name.firstName|upper|truncate(3)}` yields `[Upper, Truncate(3)]`. Holds the
+    /// parse error when a pipe names an unknown transform, which is reported on generation.
+    pub transforms: Result<Vec<Transform>, String>,
+
     /// The complete original placeholder tag from the template.
     ///
     /// This is the full matched text including `${` and `}` delimiters.
@@ -154,6 +168,7 @@ impl Replacer {
     /// - Group 1: Full match including `${` and `}`
     /// - Group 2: The key portion (before any parentheses)
     /// - Group 3: Optional arguments portion (including parentheses)
+    /// - Group 4: Optional `|transform` pipes
     ///
     /// # Examples
     ///
@@ -185,7 +200,14 @@ impl Replacer {
 
         let arguments = Arguments::from(arguments.as_str());
 
-        Self { start, end, length, key, pattern, arguments, tag }
+        let transforms = captures.get(4)
+            .map_or("", |m| m.as_str())
+            .split('|')
+            .filter(|pipe| !pipe.trim().is_empty())
+            .map(Transform::try_from)
+            .collect();
+
+        Self { start, end, length, key, pattern, arguments, transforms, tag }
     }
 
     /// Generates a replacement value for this placeholder using available data sources.
@@ -207,9 +229,9 @@ impl Replacer {
     ///    for this key using `Jgd::get_custom_key()`. Custom functions are registered
     ///    via `Jgd::add_custom_key()` and receive the parsed arguments.
     ///
-    /// 3. **Fake Generator Keys**: Falls back to the built-in fake data generators
-    ///    in `GeneratorConfig`. These provide standard faker functionality for
-    ///    names, addresses, lorem text, numbers, etc.
+    /// 3. **Provider Keys**: Falls back to the [`ValueProvider`](crate::ValueProvider) of
+    ///    `GeneratorConfig`, by default the built-in fake data generators for names,
+    ///    addresses, lorem text, numbers, etc.
     ///
     /// # Arguments
     ///
@@ -248,6 +270,14 @@ impl Replacer {
     /// - A custom key function returns an error
     /// - The fake generator encounters an error during value generation
     pub fn generate_value(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
+        ) -> Result<Value, String> {
+        let transforms = self.transforms.as_ref().map_err(|message| message.clone())?;
+        let value = self.generate_raw_value(config, local_config)?;
+
+        Ok(apply_transforms_with(transforms, value, &config.locale, &mut config.rng))
+    }
+
+    fn generate_raw_value(&self, config: &mut GeneratorConfig, local_config: Option<&mut LocalConfig>
         ) -> Result<Value, String> {
         if let Some(local_config) = local_config {
             let value = local_config.process_key(self);
@@ -260,11 +290,18 @@ impl Replacer {
             return func(self.arguments.clone());
         }
 
-        if config.fake_keys.contains_key(&self.key) {
-            return config.fake_generator.generate_by_key(self, &mut config.rng);
+        if config.provider.provides(&self.key) {
+            if config.locale_fallback == LocaleFallback::Error && !config.provider.has_locale_data(&self.key) {
+                return Err(format!(
+                    "Error to process the pattern {}: {} has no {} data and localeFallback is error",
+                    self.tag, self.key, config.locale
+                ));
+            }
+            return config.provider.generate_by_key(self, &mut config.rng);
         }
 
-        Err(format!("Error to process the pattern {}", self.tag))
+        trace_event!(debug, key = %self.key, "template key could not be resolved");
+        Err(config.fake_keys.unknown_key_message(&self.tag, &self.key))
     }
 }
 
@@ -322,6 +359,7 @@ impl From<&str> for Replacer {
                 key: pattern.to_string(),
                 pattern: pattern.to_string(),
                 arguments,
+                transforms: Ok(vec![]),
                 tag,
             }
         }
@@ -366,6 +404,7 @@ impl From<&str> for Replacer {
 /// let collection = ReplacerCollection::new("Hello ${name.firstName}!".to_string());
 /// let result = collection.replace(&mut config);
 /// ```
+#[derive(Debug, Clone)]
 pub struct ReplacerCollection {
     /// The original template string containing placeholders.
     ///
@@ -388,8 +427,17 @@ pub struct ReplacerCollection {
     /// When `true`, replacement can return any JSON type directly.
     /// When `false`, replacement always returns a string with substitutions.
     pub full_replace: bool,
+
+    /// Byte positions of the escaping `$` of each `$${` sequence.
+    ///
+    /// `$${literal}` is generated as `${literal}`: the escaping `$` is removed and the
+    /// sequence is never parsed as a placeholder.
+    pub escapes: Vec<usize>,
 }
 
+/// Escape sequence for a literal `${` in templates.
+const ESCAPE: &str = "$${";
+
 impl ReplacerCollection {
     /// Creates a new `ReplacerCollection` by analyzing a template string.
     ///
@@ -428,10 +476,23 @@ impl ReplacerCollection {
     /// // No replacement case
     /// let collection = ReplacerCollection::new("Hello world!".to_string());
     /// assert!(collection.is_empty());
+    ///
+    /// // Escaped placeholder, generated verbatim as `echo ${HOME}`
+    /// let collection = ReplacerCollection::new("echo $${HOME}".to_string());
+    /// assert_eq!(collection.escapes, vec![5]);
     /// ```
     pub fn new(value: String) -> Self {
+        let escapes: Vec<usize> = value.match_indices(ESCAPE).map(|(position, _)| position).collect();
+
+        // Hides the `{` of escaped sequences (same byte length) so they never start a match
+        let masked = if escapes.is_empty() {
+            None
+        } else {
+            Some(value.replace(ESCAPE, "$$\0"))
+        };
+
         let collection: Vec<Replacer> = RE_FAKES
-            .captures_iter(&value)
+            .captures_iter(masked.as_deref().unwrap_or(&value))
             .map(|captures| Replacer::new(&captures))
             .collect();
 
@@ -445,14 +506,16 @@ impl ReplacerCollection {
             value,
             collection,
             full_replace,
+            escapes,
         }
     }
 
-    /// Checks if the collection contains any placeholders.
+    /// Checks if the collection has nothing to replace.
     ///
     /// # Returns
     ///
-    /// `true` if no placeholders were found in the template string, `false` otherwise.
+    /// `true` if no placeholders nor `$${` escapes were found in the template string,
+    /// `false` otherwise.
     ///
     /// # Examples
     ///
@@ -466,7 +529,7 @@ impl ReplacerCollection {
     /// assert!(!not_empty.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.collection.is_empty()
+        self.collection.is_empty() && self.escapes.is_empty()
     }
 
     /// Gets the first (and only) replacer for full replacement scenarios.
@@ -525,7 +588,7 @@ impl ReplacerCollection {
     ///
     /// # Key Validation
     ///
-    /// Only placeholders with keys present in `config.fake_keys` are replaced.
+    /// Only placeholders with keys the `config.provider` provides are replaced.
     /// Invalid keys are left as-is in the output string.
     ///
     /// # Examples
@@ -570,11 +633,19 @@ impl ReplacerCollection {
                 message,
                 entity: entity_name,
                 field: field_name,
+                kind: JgdErrorKind::Template,
+                path: None,
+                key: Some(replacer.tag.as_str().into()),
             });
         }
 
         let mut value = self.value.clone();
+        let mut escapes = self.escapes.iter().rev().peekable();
         for replacer in self.collection.iter().rev() {
+            while let Some(position) = escapes.next_if(|position| **position > replacer.start) {
+                value.remove(*position);
+            }
+
             let new_value = replacer.generate_value(config, local_config.as_deref_mut());
             match new_value {
                 Ok(new_value) => {
@@ -589,10 +660,17 @@ impl ReplacerCollection {
                     message,
                     entity: entity_name,
                     field: field_name,
+                    kind: JgdErrorKind::Template,
+                    path: None,
+                    key: Some(replacer.tag.as_str().into()),
                 })
             }
         }
 
+        for position in escapes {
+            value.remove(*position);
+        }
+
         Ok(Value::String(value))
     }
 }
@@ -721,6 +799,25 @@ mod tests {
         assert!(!non_empty_collection.is_empty());
     }
 
+    #[test]
+    fn test_replacer_collection_escapes() {
+        let mut config = create_test_config();
+
+        let collection = ReplacerCollection::new("$${x}".to_string());
+        assert!(collection.collection.is_empty());
+        assert!(!collection.is_empty());
+        assert_eq!(collection.replace(&mut config, None).unwrap(), Value::String("${x}".to_string()));
+
+        let collection = ReplacerCollection::new("$${HOME} is ${address.cityName}, $${USER}".to_string());
+        assert_eq!(collection.escapes, vec![0, 33]);
+        assert!(!collection.full_replace);
+        let result = collection.replace(&mut config, None).unwrap();
+        let result = result.as_str().unwrap();
+        assert!(result.starts_with("${HOME} is "));
+        assert!(result.ends_with(", ${USER}"));
+        assert!(!result.contains("${address"));
+    }
+
     #[test]
     fn test_replacer_collection_get_full_replacer() {
         let collection = ReplacerCollection::new("${name.firstName}".to_string());
@@ -893,6 +990,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_replacer_collection_pipes_transforms() {
+        let mut config = create_test_config();
+        let collection = ReplacerCollection::new("${lorem.words(3)|upper|truncate(5)} ok".to_string());
+
+        let replacer = &collection.collection[0];
+        assert_eq!(replacer.key, "lorem.words");
+        assert_eq!(replacer.pattern, "lorem.words(3)");
+        assert_eq!(replacer.transforms, Ok(vec![Transform::Upper, Transform::Truncate(5)]));
+
+        let value = collection.replace(&mut config, None).unwrap();
+        let (words, suffix) = value.as_str().unwrap().rsplit_once(' ').unwrap();
+        assert_eq!(suffix, "ok");
+        assert_eq!(words, words.to_uppercase());
+        assert!(words.chars().count() <= 5);
+    }
+
+    #[test]
+    fn test_replacer_collection_quoted_arguments() {
+        let mut config = create_test_config();
+        let collection = ReplacerCollection::new(
+            "${number.numberWithFormat('(###) ###-####, ext ###')} / ${number.numberWithFormat(\"}|#\")|upper}".to_string()
+        );
+
+        assert_eq!(collection.collection.len(), 2);
+        assert_eq!(collection.collection[0].arguments.values(), ["(###) ###-####, ext ###"]);
+        assert_eq!(collection.collection[1].arguments.values(), ["}|#"]);
+        assert_eq!(collection.collection[1].transforms, Ok(vec![Transform::Upper]));
+
+        let value = collection.replace(&mut config, None).unwrap();
+        let (phone, other) = value.as_str().unwrap().split_once(" / ").unwrap();
+        assert_eq!(phone.len(), "(###) ###-####, ext ###".len());
+        assert!(phone.starts_with('(') && phone.contains(", ext "));
+        assert!(other.starts_with("}|"));
+    }
+
+    #[test]
+    fn test_replacer_collection_unknown_transform() {
+        let mut config = create_test_config();
+        let collection = ReplacerCollection::new("${name.firstName|shout}".to_string());
+
+        let error = collection.replace(&mut config, None).unwrap_err();
+        assert!(error.message.contains("shout"));
+    }
+
     #[test]
     fn test_regex_pattern_matching() {
         let test_cases = vec![
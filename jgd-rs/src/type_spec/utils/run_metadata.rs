@@ -0,0 +1,114 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::Jgd;
+
+/// Key of the metadata block embedded at the top of a generated document.
+pub const META_KEY: &str = "_meta";
+
+/// Settings of a generation run, recorded to reproduce the dataset later.
+///
+/// The seed is the one the run used, drawn at random when the schema has no `seed`:
+/// generating the schema again with that seed gives the same data.
+///
+/// ```json
+/// {
+///   "format": "jgd/v1",
+///   "schemaVersion": "1.0",
+///   "seed": 42,
+///   "locale": "EN",
+///   "generatedAt": "2025-01-31T10:00:00Z",
+///   "crateVersion": "0.2.1"
+/// }
+/// ```
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunMetadata {
+    /// Schema format, e.g. `jgd/v1`.
+    pub format: String,
+
+    /// The schema `version`.
+    pub schema_version: String,
+
+    /// Seed of the run.
+    pub seed: u64,
+
+    /// Locale of the generated data.
+    pub locale: String,
+
+    /// The schema `referenceDate`, when the relative dates were anchored to one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_date: Option<DateTime<Utc>>,
+
+    /// UTC time of the generation, in RFC 3339 format.
+    pub generated_at: String,
+
+    /// Version of the jgd-rs crate that generated the data.
+    pub crate_version: String,
+}
+
+impl RunMetadata {
+    /// Metadata of a run of `jgd` with `seed`, generated now.
+    pub fn new(jgd: &Jgd, seed: u64) -> Self {
+        Self {
+            format: jgd.format.clone(),
+            schema_version: jgd.version.clone(),
+            seed,
+            locale: jgd.default_locale.clone(),
+            reference_date: jgd.reference_date,
+            generated_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
+    /// Embeds the metadata as a `_meta` block at the top of a generated document.
+    ///
+    /// Objects get `_meta` as their first key; other documents (a root array) are
+    /// wrapped as `{ "_meta": ..., "data": document }`.
+    pub fn embed(&self, document: Value) -> Value {
+        let meta = serde_json::to_value(self).unwrap_or_default();
+        let mut embedded = Map::new();
+        embedded.insert(META_KEY.to_string(), meta);
+        match document {
+            Value::Object(object) => embedded.extend(object),
+            document => {
+                embedded.insert("data".to_string(), document);
+            },
+        }
+        Value::Object(embedded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_metadata_reproduces_the_run() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "2.1",
+            "defaultLocale": "FR_FR",
+            "root": { "count": 2, "fields": { "name": "${name.firstName}" } }
+        }));
+
+        let (generated, metadata) = jgd.generate_with_metadata().unwrap();
+        assert_eq!(metadata.schema_version, "2.1");
+        assert_eq!(metadata.locale, "FR_FR");
+        assert_eq!(metadata.crate_version, env!("CARGO_PKG_VERSION"));
+
+        let mut replay = jgd;
+        replay.seed = Some(metadata.seed);
+        assert_eq!(replay.generate().unwrap(), generated);
+
+        let embedded = metadata.embed(generated.clone());
+        assert_eq!(embedded[META_KEY]["seed"], json!(metadata.seed));
+        assert_eq!(embedded["data"], generated);
+
+        let embedded = metadata.embed(json!({ "users": [] }));
+        assert_eq!(embedded.as_object().unwrap().keys().collect::<Vec<_>>(), [META_KEY, "users"]);
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Next identifier handed out by [`SpecId::default`].
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Identifier of a spec that keeps state across the items it generates, such as the
+/// position of a round-robin `ref` or the running total of a `cumulative` field.
+///
+/// Each spec gets a new id when it is loaded or built. Clones and moves keep it, so the
+/// state follows the spec, and ids are never reused, so a spec never picks up the state
+/// of another one. The id is not part of the schema: it is neither read nor written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecId(u64);
+
+impl Default for SpecId {
+    fn default() -> Self {
+        SpecId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Field;
+
+    use super::*;
+
+    #[test]
+    fn test_spec_ids_are_unique_and_kept_by_clones() {
+        assert_ne!(SpecId::default(), SpecId::default());
+
+        let field: Field = serde_json::from_value(json!({ "ref": "users.id" })).unwrap();
+        let Field::Ref { id, .. } = &field else {
+            panic!("expected a ref");
+        };
+        let Field::Ref { id: cloned, .. } = field.clone() else {
+            panic!("expected a ref");
+        };
+        assert_eq!(*id, cloned);
+        assert_eq!(serde_json::to_value(&field).unwrap(), json!({ "ref": "users.id" }));
+    }
+}
@@ -0,0 +1,392 @@
+use std::fmt::Display;
+
+use rand::{rngs::StdRng, seq::{index, SliceRandom}, SeedableRng};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::fake::LocaleFormat;
+
+/// Locale used by `localeFormat` when the caller has none.
+const DEFAULT_LOCALE: &str = "EN";
+
+/// A post-generation transform applied to a generated value.
+///
+/// Transforms are written as names with optional arguments, the same way in a field
+/// `transforms` list and after a pipe in templates:
+///
+/// ```json
+/// {
+///   "slug": { "of": "${lorem.words(3)}", "transforms": ["slugify", "truncate(20)"] },
+///   "code": "${address.countryCode|lower}"
+/// }
+/// ```
+///
+/// Transforms only change string values; numbers, booleans, objects and arrays are
+/// returned unchanged, except numbers formatted by `localeFormat` and arrays reordered
+/// by `shuffle` and `sample(n)`. These draw from the generator seed, so the order is
+/// random but the same on every run with the same seed:
+///
+/// ```json
+/// { "winners": { "of": { "array": { "count": 20, "of": "${index}" } }, "transforms": ["sample(3)"] } }
+/// ```
+///
+/// `json` opts a template made of a single placeholder into a typed value:
+/// `"age": "${number.digit|json}"` generates `7` rather than `"7"`. Templates mixing
+/// placeholders and text always generate strings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Transform {
+    /// `upper`: converts to uppercase.
+    Upper,
+    /// `lower`: converts to lowercase.
+    Lower,
+    /// `title`: uppercases the first letter of each word and lowercases the others.
+    Title,
+    /// `capitalize`: uppercases the first letter, keeping the others.
+    Capitalize,
+    /// `slugify`: lowercase words joined by `-`, without punctuation.
+    Slugify,
+    /// `trim`: removes leading and trailing whitespace.
+    Trim,
+    /// `truncate(n)`: keeps at most `n` characters.
+    Truncate(usize),
+    /// `replace(from,to)`: replaces every occurrence of `from` with `to`.
+    Replace(String, String),
+    /// `localeFormat`: formats dates and numbers with the month names and separators of the locale.
+    LocaleFormat,
+    /// `json`: parses a string as JSON (a number, boolean, null, array or object), keeping
+    /// strings that are not valid JSON.
+    Json,
+    /// `shuffle`: puts the items of an array in a random order.
+    Shuffle,
+    /// `sample(n)`: keeps `n` items of an array picked at random, in a random order, or
+    /// all of them shuffled when the array is shorter.
+    Sample(usize),
+}
+
+impl TryFrom<&str> for Transform {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let (name, arguments) = match value.split_once('(') {
+            Some((name, rest)) => match rest.strip_suffix(')') {
+                Some(arguments) => (name.trim(), Some(arguments)),
+                None => return Err(format!("Error to parse the transform {}", value)),
+            },
+            None => (value, None),
+        };
+
+        match (name, arguments) {
+            ("upper", None) => Ok(Transform::Upper),
+            ("lower", None) => Ok(Transform::Lower),
+            ("title", None) => Ok(Transform::Title),
+            ("capitalize", None) => Ok(Transform::Capitalize),
+            ("slugify", None) => Ok(Transform::Slugify),
+            ("trim", None) => Ok(Transform::Trim),
+            ("localeFormat", None) => Ok(Transform::LocaleFormat),
+            ("json", None) => Ok(Transform::Json),
+            ("shuffle", None) => Ok(Transform::Shuffle),
+            ("sample", Some(amount)) => amount.trim().parse()
+                .map(Transform::Sample)
+                .map_err(|_| format!("Error to parse the transform {}: expected a number of items", value)),
+            ("truncate", Some(length)) => length.trim().parse()
+                .map(Transform::Truncate)
+                .map_err(|_| format!("Error to parse the transform {}: expected a length", value)),
+            ("replace", Some(arguments)) => match arguments.split_once(',') {
+                Some((from, to)) if !from.trim().is_empty() => {
+                    Ok(Transform::Replace(from.trim().to_string(), to.trim().to_string()))
+                },
+                _ => Err(format!("Error to parse the transform {}: expected replace(from,to)", value)),
+            },
+            _ => Err(format!("Error to parse the transform {}: unknown transform", value)),
+        }
+    }
+}
+
+impl TryFrom<String> for Transform {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Transform::try_from(value.as_str())
+    }
+}
+
+impl Serialize for Transform {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transform::Upper => write!(f, "upper"),
+            Transform::Lower => write!(f, "lower"),
+            Transform::Title => write!(f, "title"),
+            Transform::Capitalize => write!(f, "capitalize"),
+            Transform::Slugify => write!(f, "slugify"),
+            Transform::Trim => write!(f, "trim"),
+            Transform::Truncate(length) => write!(f, "truncate({})", length),
+            Transform::Replace(from, to) => write!(f, "replace({},{})", from, to),
+            Transform::LocaleFormat => write!(f, "localeFormat"),
+            Transform::Json => write!(f, "json"),
+            Transform::Shuffle => write!(f, "shuffle"),
+            Transform::Sample(amount) => write!(f, "sample({})", amount),
+        }
+    }
+}
+
+/// Uppercases the first letter of `value`.
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Uppercases the first letter of each word and lowercases the others; words are
+/// separated by whitespace or `-`, so `jean-luc` becomes `Jean-Luc`.
+fn title(value: &str) -> String {
+    let mut title = String::with_capacity(value.len());
+    let mut word_start = true;
+    for c in value.chars() {
+        if word_start {
+            title.extend(c.to_uppercase());
+        } else {
+            title.extend(c.to_lowercase());
+        }
+        word_start = c.is_whitespace() || c == '-';
+    }
+    title
+}
+
+fn slugify(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
+
+impl Transform {
+    /// Applies the transform to a string, formatting with the `EN` conventions.
+    pub fn apply_str(&self, value: &str) -> String {
+        self.apply_str_in(value, DEFAULT_LOCALE)
+    }
+
+    /// Applies the transform to a string; `locale` is used by `localeFormat`.
+    pub fn apply_str_in(&self, value: &str, locale: &str) -> String {
+        match self {
+            Transform::Upper => value.to_uppercase(),
+            Transform::Lower => value.to_lowercase(),
+            Transform::Title => title(value),
+            Transform::Capitalize => capitalize(value),
+            Transform::Slugify => slugify(value),
+            Transform::Trim => value.trim().to_string(),
+            Transform::Truncate(length) => value.chars().take(*length).collect(),
+            Transform::Replace(from, to) => value.replace(from.as_str(), to),
+            Transform::LocaleFormat => LocaleFormat::of(locale).format_str(value).unwrap_or_else(|| value.to_string()),
+            Transform::Json | Transform::Shuffle | Transform::Sample(_) => value.to_string(),
+        }
+    }
+
+    /// Applies the transform to a generated value, formatting with the `EN` conventions.
+    pub fn apply(&self, value: Value) -> Value {
+        self.apply_in(value, DEFAULT_LOCALE)
+    }
+
+    /// Applies the transform to a generated value; `locale` is used by `localeFormat`.
+    ///
+    /// `localeFormat` also turns numbers into formatted strings and `json` turns strings
+    /// into typed values; other transforms return non-string values unchanged. `shuffle`
+    /// and `sample` draw from a generator seeded with 0, see [`Transform::apply_with`].
+    pub fn apply_in(&self, value: Value, locale: &str) -> Value {
+        self.apply_with(value, locale, &mut StdRng::seed_from_u64(0))
+    }
+
+    /// Applies the transform to a generated value; `shuffle` and `sample` draw from `rng`.
+    pub fn apply_with(&self, value: Value, locale: &str, rng: &mut StdRng) -> Value {
+        match (self, value) {
+            (Transform::Shuffle, Value::Array(mut items)) => {
+                items.shuffle(rng);
+                Value::Array(items)
+            },
+            (Transform::Sample(amount), Value::Array(mut items)) => {
+                let picked = index::sample(rng, items.len(), (*amount).min(items.len()));
+                Value::Array(picked.iter().map(|position| std::mem::take(&mut items[position])).collect())
+            },
+            (Transform::Json, Value::String(value)) => {
+                serde_json::from_str(&value).unwrap_or(Value::String(value))
+            },
+            (_, Value::String(value)) => Value::String(self.apply_str_in(&value, locale)),
+            (Transform::LocaleFormat, Value::Number(number)) => {
+                match LocaleFormat::of(locale).format_decimal(&number.to_string()) {
+                    Some(formatted) => Value::String(formatted),
+                    None => Value::Number(number),
+                }
+            },
+            (_, value) => value,
+        }
+    }
+}
+
+/// Applies `transforms` in order, formatting with the `EN` conventions.
+pub fn apply_transforms(transforms: &[Transform], value: Value) -> Value {
+    apply_transforms_in(transforms, value, DEFAULT_LOCALE)
+}
+
+/// Applies `transforms` in order; `locale` is used by `localeFormat`.
+pub fn apply_transforms_in(transforms: &[Transform], value: Value, locale: &str) -> Value {
+    apply_transforms_with(transforms, value, locale, &mut StdRng::seed_from_u64(0))
+}
+
+/// Applies `transforms` in order; `shuffle` and `sample` draw from `rng`.
+pub fn apply_transforms_with(transforms: &[Transform], value: Value, locale: &str, rng: &mut StdRng) -> Value {
+    transforms.iter().fold(value, |value, transform| transform.apply_with(value, locale, rng))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_transforms() {
+        assert_eq!(Transform::try_from("upper"), Ok(Transform::Upper));
+        assert_eq!(Transform::try_from(" truncate(20) "), Ok(Transform::Truncate(20)));
+        assert_eq!(Transform::try_from("replace(a, b)"), Ok(Transform::Replace("a".into(), "b".into())));
+        assert!(Transform::try_from("truncate(x)").is_err());
+        assert!(Transform::try_from("shout").is_err());
+    }
+
+    #[test]
+    fn test_apply_transforms() {
+        assert_eq!(Transform::Slugify.apply_str("  Hello, World! 2024 "), "hello-world-2024");
+        assert_eq!(Transform::Truncate(3).apply_str("ação!"), "açã");
+        assert_eq!(Transform::Title.apply_str("éLAN vital jean-luc"), "Élan Vital Jean-Luc");
+        assert_eq!(Transform::Capitalize.apply_str("lorem ipsum DOLOR."), "Lorem ipsum DOLOR.");
+        assert_eq!(Transform::try_from("title"), Ok(Transform::Title));
+
+        let transforms: Vec<Transform> = serde_json::from_value(json!(["trim", "upper", "replace(A,4)"])).unwrap();
+        assert_eq!(apply_transforms(&transforms, json!(" banana ")), json!("B4N4N4"));
+        assert_eq!(apply_transforms(&transforms, json!(42)), json!(42));
+    }
+
+    #[test]
+    fn test_string_transforms_handle_non_ascii() {
+        let cases = [
+            ("upper", "straße ñ", "STRASSE Ñ"),
+            ("lower", "ÉCOLE ΣΟΦΙΑ", "école σοφια"),
+            ("title", "ÉLAN VITAL d'ÅSA", "Élan Vital D'åsa"),
+            ("title", "", ""),
+            ("capitalize", "ñandú GRANDE", "Ñandú GRANDE"),
+            ("capitalize", "", ""),
+            ("slugify", "Crème Brûlée — 2 parts!", "crème-brûlée-2-parts"),
+            ("trim", "\u{3000} café\t", "café"),
+            ("truncate(2)", "日本語", "日本"),
+            ("replace(ü,ue)", "Müller Grün", "Mueller Gruen"),
+        ];
+
+        for (name, input, expected) in cases {
+            let transform = Transform::try_from(name).unwrap();
+            assert_eq!(transform.to_string(), name);
+            assert_eq!(transform.apply_str(input), expected, "{}", name);
+            assert_eq!(transform.apply(json!(input)), json!(expected), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_template_pipes_apply_transforms() {
+        let jgd = crate::Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "fields": {
+                    "upper": "${choice('straße')|upper}",
+                    "title": "${choice('jean-luc ÉLAN')|title}",
+                    "chained": "${choice(' Crème Brûlée ')|trim|slugify|truncate(5)}"
+                }
+            }
+        }));
+
+        let value = jgd.generate().unwrap();
+        assert_eq!(value["upper"], json!("STRASSE"));
+        assert_eq!(value["title"], json!("Jean-Luc Élan"));
+        assert_eq!(value["chained"], json!("crème"));
+    }
+
+    #[test]
+    fn test_locale_format_transform() {
+        let transforms = vec![Transform::try_from("localeFormat").unwrap()];
+        assert_eq!(apply_transforms_in(&transforms, json!("2024-07-14"), "FR_FR"), json!("14 juillet 2024"));
+        assert_eq!(apply_transforms_in(&transforms, json!(1234567), "DE_DE"), json!("1.234.567"));
+        assert_eq!(apply_transforms_in(&transforms, json!(-9876.5), "FR_FR"), json!("-9 876,5"));
+        assert_eq!(apply_transforms(&transforms, json!("1500.25")), json!("1,500.25"));
+        assert_eq!(apply_transforms(&transforms, json!("n/a")), json!("n/a"));
+        assert_eq!(apply_transforms(&transforms, json!(true)), json!(true));
+    }
+
+    #[test]
+    fn test_json_transform_types_single_placeholders() {
+        let jgd = crate::Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": {
+                "fields": {
+                    "age": "${number.digit|json}",
+                    "active": "${choice(true, false)|json}",
+                    "tags": { "of": "${choice('[\"a\",\"b\"]')}", "transforms": ["json"] },
+                    "name": "${name.firstName|json}",
+                    "label": "age ${number.digit|json}"
+                }
+            }
+        }));
+
+        let value = jgd.generate().unwrap();
+        assert!(value["age"].is_u64());
+        assert!(value["active"].is_boolean());
+        assert_eq!(value["tags"], json!(["a", "b"]));
+        assert!(value["name"].is_string());
+        assert!(value["label"].as_str().unwrap().starts_with("age "));
+    }
+
+    #[test]
+    fn test_shuffle_and_sample_are_seeded() {
+        assert_eq!(Transform::try_from("sample(3)"), Ok(Transform::Sample(3)));
+        assert!(Transform::try_from("sample(all)").is_err());
+
+        let schema = |seed: u64| crate::Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": seed,
+            "root": {
+                "fields": {
+                    "order": { "of": { "array": { "count": 10, "of": "${index}" } }, "transforms": ["shuffle"] },
+                    "winners": { "of": { "array": { "count": 10, "of": "${index}" } }, "transforms": ["sample(3)"] },
+                    "all": { "of": { "array": { "count": 2, "of": "${index}" } }, "transforms": ["sample(5)"] }
+                }
+            }
+        }));
+
+        let value = schema(7).generate().unwrap();
+        assert_eq!(value, schema(7).generate().unwrap());
+        let mut order: Vec<u64> = value["order"].as_array().unwrap().iter().map(|item| item.as_u64().unwrap()).collect();
+        assert_ne!(order, (1..=10).collect::<Vec<_>>());
+        order.sort();
+        assert_eq!(order, (1..=10).collect::<Vec<_>>());
+
+        let winners = value["winners"].as_array().unwrap();
+        assert_eq!(winners.len(), 3);
+        assert!(winners.iter().all(|winner| (1..=10).contains(&winner.as_u64().unwrap())));
+        assert!(winners[0] != winners[1] && winners[1] != winners[2] && winners[0] != winners[2]);
+        assert_eq!(value["all"].as_array().unwrap().len(), 2);
+        assert_eq!(apply_transforms(&[Transform::Shuffle], json!("abc")), json!("abc"));
+    }
+}
@@ -0,0 +1,107 @@
+use rand::rngs::StdRng;
+use serde_json::Value;
+
+use crate::Replacer;
+
+/// Source of the values of template keys such as `${name.firstName}`.
+///
+/// [`FakeGenerator`](crate::FakeGenerator) is the default provider, backed by the `fake`
+/// crate. Another provider (a fixed dictionary, a remote service, ...) can be injected
+/// with [`GeneratorConfig::with_provider`](crate::GeneratorConfig::with_provider) and
+/// used through [`Jgd::generate_with`](crate::Jgd::generate_with). Custom keys and context
+/// keys are resolved before the provider is asked.
+///
+/// # Examples
+///
+/// ```rust
+/// use jgd_rs::{Jgd, Replacer, ValueProvider};
+/// use rand::rngs::StdRng;
+/// use serde_json::{json, Value};
+///
+/// struct Fixed;
+///
+/// impl ValueProvider for Fixed {
+///     fn provides(&self, key: &str) -> bool {
+///         key == "name.firstName"
+///     }
+///
+///     fn generate_by_key(&self, _replacer: &Replacer, _rng: &mut StdRng) -> Result<Value, String> {
+///         Ok(json!("Ada"))
+///     }
+/// }
+///
+/// let jgd = Jgd::from(r#"{
+///   "$format": "jgd/v1",
+///   "version": "1.0",
+///   "root": { "fields": { "name": "${name.firstName}" } }
+/// }"#);
+/// let config = jgd.create_config().with_provider(Box::new(Fixed));
+/// assert_eq!(jgd.generate_with(config).unwrap(), json!({ "name": "Ada" }));
+/// ```
+pub trait ValueProvider {
+    /// Whether the provider has values for `key`; other keys are reported as unknown.
+    fn provides(&self, key: &str) -> bool;
+
+    /// Generates the value of the key of `replacer`, using its arguments.
+    ///
+    /// Returns an error message when the key or its arguments are not valid.
+    fn generate_by_key(&self, replacer: &Replacer, rng: &mut StdRng) -> Result<Value, String>;
+
+    /// Whether `key` generates data of the configured locale, rather than falling back
+    /// to another one; checked when `localeFallback` is `error`.
+    fn has_locale_data(&self, _key: &str) -> bool {
+        true
+    }
+
+    /// Generates a street name and building number, for composite addresses.
+    ///
+    /// Uses the `address.streetName` and `address.buildingNumber` keys by default.
+    fn street_and_number(&self, rng: &mut StdRng) -> (Value, Value) {
+        let mut generate = |pattern: &str| self.generate_by_key(&Replacer::from(pattern), rng).unwrap_or_default();
+        let street = generate("${address.streetName}");
+        (street, generate("${address.buildingNumber}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Jgd;
+
+    struct Dictionary;
+
+    impl ValueProvider for Dictionary {
+        fn provides(&self, key: &str) -> bool {
+            key.starts_with("address.")
+        }
+
+        fn generate_by_key(&self, replacer: &Replacer, _rng: &mut StdRng) -> Result<Value, String> {
+            Ok(json!(format!("<{}>", replacer.key)))
+        }
+    }
+
+    #[test]
+    fn test_injected_provider_replaces_fake_data() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 3,
+            "root": { "fields": { "city": "${address.cityName}", "home": { "address": {} } } }
+        }));
+
+        let generated = jgd.generate_with(jgd.create_config().with_provider(Box::new(Dictionary))).unwrap();
+        assert_eq!(generated["city"], "<address.cityName>");
+        assert_eq!(generated["home"]["street"], "<address.streetName>");
+        assert_eq!(generated["home"]["number"], "<address.buildingNumber>");
+
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "root": { "fields": { "name": "${name.firstName}" } }
+        }));
+        let error = jgd.generate_with(jgd.create_config().with_provider(Box::new(Dictionary))).unwrap_err();
+        assert!(error.message.contains("name.firstName"));
+    }
+}
@@ -0,0 +1,76 @@
+//! # Variant Specification Module
+//!
+//! Heterogeneous arrays, such as analytics event streams where most events are page
+//! views, some are clicks and a few are purchases. An entity with `variants` generates
+//! its `fields` as a common envelope and adds the fields of one variant, picked by
+//! weight for each item:
+//!
+//! ```json
+//! {
+//!   "root": {
+//!     "count": 100,
+//!     "fields": { "id": "${uuid.v4}", "at": "${chrono.dateTime}" },
+//!     "variants": [
+//!       { "weight": 70, "fields": { "type": "pageView", "url": "${internet.domainSuffix}" } },
+//!       { "weight": 20, "fields": { "type": "click", "target": "${lorem.word}" } },
+//!       { "weight": 10, "fields": { "type": "purchase", "amount": { "number": { "min": 1, "max": 500 } } } }
+//!     ]
+//!   }
+//! }
+//! ```
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::type_spec::Field;
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+/// One kind of item of an entity with [`variants`](crate::Entity::variants).
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct VariantSpec {
+    /// Relative weight of this variant. Negative weights count as zero.
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+
+    /// Fields added after the entity fields when this variant is picked.
+    #[serde(default)]
+    pub fields: IndexMap<String, Field>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Jgd;
+
+    #[test]
+    fn test_variants_share_the_envelope() {
+        let jgd = Jgd::from(json!({
+            "$format": "jgd/v1",
+            "version": "1.0",
+            "seed": 4,
+            "root": {
+                "count": 200,
+                "fields": { "id": "${index}" },
+                "variants": [
+                    { "weight": 7, "fields": { "type": "pageView", "url": "${internet.domainSuffix}" } },
+                    { "weight": 3, "fields": { "type": "click", "target": "${lorem.word}" } },
+                    { "weight": 0, "fields": { "type": "never" } }
+                ]
+            }
+        }));
+
+        let events = jgd.generate().unwrap();
+        let events = events.as_array().unwrap();
+        let page_views = events.iter().filter(|event| event["type"] == "pageView").count();
+        assert!((110..=170).contains(&page_views), "{} page views", page_views);
+        assert!(events.iter().all(|event| event["type"] != "never"));
+
+        let click = events.iter().find(|event| event["type"] == "click").unwrap();
+        let keys: Vec<&String> = click.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["id", "type", "target"]);
+    }
+}